@@ -0,0 +1,13 @@
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+
+    if args.len() != 3 {
+        eprintln!("Usage: fix-codegen <dictionary.xml> <output.rs>");
+        std::process::exit(1);
+    }
+
+    if let Err(err) = rust_fix::codegen::generate_file(&args[1], &args[2]) {
+        eprintln!("Can not generate {}: {:?}", args[2], err);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,376 @@
+use std::{
+    collections::HashMap,
+    io::{self, Read},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    blocking_connection::{self, BlockingConnection, SharedConnection},
+    fix_session_config,
+    fix_transport::{self, FirstFrame},
+    BlockingFixSender, FixFrameDecoder, FixSession, FixSessionConfig, FixSessionEvent,
+    FixSessionId, MessageStore, SessionClock,
+};
+
+// Pause before accepting again after a failed accept
+const ACCEPT_ERROR_DELAY: Duration = Duration::from_millis(100);
+
+/// Same as [`crate::FixAcceptor`], but runs every connection in its own thread over
+/// `std::net::TcpStream`, so it does not need an async runtime.
+///
+/// A connection is assigned to the session by BeginString, SenderCompID and TargetCompID of
+/// its first message. Connections of unknown sessions and second connections of a session
+/// which is already connected are closed without an answer.
+pub struct BlockingFixAcceptor<C: SessionClock, S: MessageStore> {
+    sessions: Vec<FixSession<C, S>>,
+    logon_timeout: Duration,
+}
+
+impl<C, S> BlockingFixAcceptor<C, S>
+where
+    C: SessionClock + Send + 'static,
+    S: MessageStore + Send + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            sessions: Vec::new(),
+            logon_timeout: FixSessionConfig::DEFAULT_LOGON_TIMEOUT,
+        }
+    }
+
+    /// Adds the session the counterparty can log on to. The session has to be an acceptor.
+    pub fn with_session(mut self, session: FixSession<C, S>) -> Self {
+        self.sessions.push(session);
+        self
+    }
+
+    /// How long a new connection may stay without a complete first message
+    pub fn with_logon_timeout(mut self, timeout: Duration) -> Self {
+        self.logon_timeout = timeout;
+        self
+    }
+
+    /// Starts to listen in a new thread. Fails with `InvalidInput` if a session is an
+    /// initiator or two sessions have the same id.
+    pub fn bind(self, addr: impl ToSocketAddrs) -> io::Result<BlockingFixAcceptorHandle> {
+        fix_session_config::check_acceptor_sessions(
+            self.sessions.iter().map(|session| session.get_config()),
+        )?;
+
+        let listener = TcpListener::bind(addr)?;
+        let local_addr = listener.local_addr()?;
+
+        let (events, events_receiver) = mpsc::channel();
+        let mut senders = HashMap::new();
+        let mut slots = HashMap::new();
+
+        for session in self.sessions {
+            let session_id = session.get_config().get_session_id();
+
+            let events = events.clone();
+            let id = session_id.clone();
+            let connection = Arc::new(Mutex::new(BlockingConnection::new(session, move |event| {
+                let _ = events.send((id.clone(), event));
+            })));
+
+            senders.insert(
+                session_id.clone(),
+                BlockingFixSender::new(connection.clone()),
+            );
+            slots.insert(
+                session_id,
+                SessionSlot {
+                    connection,
+                    connected: AtomicBool::new(false),
+                },
+            );
+        }
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let slots = Arc::new(slots);
+        let logon_timeout = self.logon_timeout;
+
+        let listener_stop = stop.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if listener_stop.load(Ordering::Acquire) {
+                    return;
+                }
+
+                let Ok(stream) = stream else {
+                    // Errors like EMFILE last until connections are closed, retrying at once
+                    // spins
+                    thread::sleep(ACCEPT_ERROR_DELAY);
+                    continue;
+                };
+
+                let _ = stream.set_nodelay(true);
+                let slots = slots.clone();
+                thread::spawn(move || serve(stream, &slots, logon_timeout));
+            }
+        });
+
+        Ok(BlockingFixAcceptorHandle {
+            local_addr,
+            senders,
+            events: events_receiver,
+            stop,
+        })
+    }
+}
+
+impl<C, S> Default for BlockingFixAcceptor<C, S>
+where
+    C: SessionClock + Send + 'static,
+    S: MessageStore + Send + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Running [`BlockingFixAcceptor`]. Events of all the sessions are received together with
+/// the id of the session. Dropping the handle stops accepting new connections.
+#[derive(Debug)]
+pub struct BlockingFixAcceptorHandle {
+    local_addr: SocketAddr,
+    senders: HashMap<FixSessionId, BlockingFixSender>,
+    events: mpsc::Receiver<(FixSessionId, FixSessionEvent)>,
+    stop: Arc<AtomicBool>,
+}
+
+impl BlockingFixAcceptorHandle {
+    pub fn get_local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    pub fn get_sender(&self, session_id: &FixSessionId) -> Option<&BlockingFixSender> {
+        self.senders.get(session_id)
+    }
+
+    pub fn get_session_ids(&self) -> impl Iterator<Item = &FixSessionId> {
+        self.senders.keys()
+    }
+
+    /// Logon and logout, inbound application messages and disconnects of the sessions
+    pub fn recv(&self) -> Option<(FixSessionId, FixSessionEvent)> {
+        self.events.recv().ok()
+    }
+
+    /// Returns `None` on timeout
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<(FixSessionId, FixSessionEvent)> {
+        self.events.recv_timeout(timeout).ok()
+    }
+}
+
+impl Drop for BlockingFixAcceptorHandle {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        // Wakes up the listener blocked in accept
+        let _ = TcpStream::connect(self.local_addr);
+    }
+}
+
+struct SessionSlot<C: SessionClock, S: MessageStore> {
+    connection: SharedConnection<C, S>,
+    connected: AtomicBool,
+}
+
+fn serve<C: SessionClock, S: MessageStore>(
+    mut stream: TcpStream,
+    slots: &HashMap<FixSessionId, SessionSlot<C, S>>,
+    logon_timeout: Duration,
+) {
+    let Some((session_id, received)) = read_session_id(&mut stream, logon_timeout) else {
+        return;
+    };
+
+    // Unknown CompIDs
+    let Some(slot) = slots.get(&session_id) else {
+        return;
+    };
+
+    // One connection per session
+    if slot.connected.swap(true, Ordering::AcqRel) {
+        return;
+    }
+
+    blocking_connection::run_connection(&slot.connection, stream, &received);
+    slot.connected.store(false, Ordering::Release);
+}
+
+// Reads up to the first complete message. Returns the session it belongs to and all the
+// bytes read, so the session receives them too. The whole message has to arrive within
+// the timeout, not every read.
+fn read_session_id(stream: &mut TcpStream, timeout: Duration) -> Option<(FixSessionId, Vec<u8>)> {
+    let deadline = Instant::now() + timeout;
+    let mut decoder = FixFrameDecoder::new();
+    let mut received = Vec::new();
+    let mut buffer = [0u8; 4096];
+
+    loop {
+        match fix_transport::read_first_frame(&mut decoder) {
+            FirstFrame::Incomplete => {}
+            FirstFrame::Session(session_id) => return Some((session_id, received)),
+            FirstFrame::Invalid => return None,
+        }
+
+        let remaining = deadline
+            .checked_duration_since(Instant::now())
+            .filter(|remaining| !remaining.is_zero())?;
+        stream.set_read_timeout(Some(remaining)).ok()?;
+
+        let size = stream.read(&mut buffer).ok()?;
+        if size == 0 {
+            return None;
+        }
+
+        decoder.append(&buffer[..size]);
+        received.extend_from_slice(&buffer[..size]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpStream,
+        time::Duration,
+    };
+
+    use crate::{
+        tags, BlockingFixAcceptor, BlockingFixAcceptorHandle, BlockingFixInitiator,
+        FixMessageReader, FixMessageWriter, FixSession, FixSessionConfig, FixSessionEvent,
+        FixSessionId, SessionRole,
+    };
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn start_acceptor() -> BlockingFixAcceptorHandle {
+        let config = FixSessionConfig::new(SessionRole::Acceptor, "FIX.4.4", "VENUE", "CLIENT");
+
+        BlockingFixAcceptor::new()
+            .with_session(FixSession::new(config))
+            .bind("127.0.0.1:0")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_initiator_and_acceptor_exchange_messages() {
+        let acceptor = start_acceptor();
+        let port = acceptor.get_local_addr().port();
+
+        let config = FixSessionConfig::new(SessionRole::Initiator, "FIX.4.4", "CLIENT", "VENUE");
+        let (client, client_events) =
+            BlockingFixInitiator::new(FixSession::new(config), "127.0.0.1", port).start();
+
+        let session_id = FixSessionId::new("FIX.4.4", "VENUE", "CLIENT");
+        assert_eq!(
+            Some((session_id.clone(), FixSessionEvent::LoggedOn)),
+            acceptor.recv_timeout(TIMEOUT)
+        );
+        assert_eq!(
+            Some(FixSessionEvent::LoggedOn),
+            client_events.recv_timeout(TIMEOUT)
+        );
+
+        let mut order = FixMessageWriter::new("FIX.4.4", "D");
+        order.with_value(tags::CL_ORD_ID, "order-1");
+        client.send(order).unwrap();
+
+        let Some((_, FixSessionEvent::Application(message))) = acceptor.recv_timeout(TIMEOUT)
+        else {
+            panic!("Application message expected");
+        };
+        assert_eq!(
+            Some("order-1"),
+            FixMessageReader::from_bytes(&message)
+                .get_value(tags::CL_ORD_ID)
+                .unwrap()
+        );
+
+        let venue = acceptor.get_sender(&session_id).unwrap();
+        venue.send(FixMessageWriter::new("FIX.4.4", "8")).unwrap();
+        let Some(FixSessionEvent::Application(_)) = client_events.recv_timeout(TIMEOUT) else {
+            panic!("Application message expected");
+        };
+    }
+
+    #[test]
+    fn test_unknown_comp_id_is_closed() {
+        let acceptor = start_acceptor();
+
+        let mut logon = FixMessageWriter::new("FIX.4.4", "A");
+        logon.with_value(tags::SENDER_COMP_ID, "UNKNOWN");
+        logon.with_value(tags::TARGET_COMP_ID, "VENUE");
+        logon.with_value(tags::MSG_SEQ_NUM, 1);
+
+        let mut stream = TcpStream::connect(acceptor.get_local_addr()).unwrap();
+        stream.set_read_timeout(Some(TIMEOUT)).unwrap();
+        stream.write_all(&logon.compile_message()).unwrap();
+
+        let mut buffer = [0u8; 1024];
+        assert!(matches!(stream.read(&mut buffer), Ok(0) | Err(_)));
+    }
+
+    #[test]
+    fn test_invalid_sessions_are_rejected() {
+        let session = |role, target_comp_id: &str| {
+            FixSession::new(FixSessionConfig::new(
+                role,
+                "FIX.4.4",
+                "VENUE",
+                target_comp_id,
+            ))
+        };
+
+        let err = BlockingFixAcceptor::new()
+            .with_session(session(SessionRole::Initiator, "CLIENT"))
+            .bind("127.0.0.1:0")
+            .unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+
+        let err = BlockingFixAcceptor::new()
+            .with_session(session(SessionRole::Acceptor, "CLIENT"))
+            .with_session(session(SessionRole::Acceptor, "CLIENT"))
+            .bind("127.0.0.1:0")
+            .unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+        assert!(err.to_string().contains("FIX.4.4:VENUE->CLIENT"));
+    }
+
+    #[test]
+    fn test_slow_first_message_is_closed() {
+        let config = FixSessionConfig::new(SessionRole::Acceptor, "FIX.4.4", "VENUE", "CLIENT");
+        let acceptor = BlockingFixAcceptor::new()
+            .with_session(FixSession::new(config))
+            .with_logon_timeout(Duration::from_millis(300))
+            .bind("127.0.0.1:0")
+            .unwrap();
+
+        let mut stream = TcpStream::connect(acceptor.get_local_addr()).unwrap();
+        stream.set_read_timeout(Some(TIMEOUT)).unwrap();
+
+        // Every byte comes before the timeout, the whole message does not
+        let mut writer = stream.try_clone().unwrap();
+        std::thread::spawn(move || {
+            for byte in b"8=FIX.4.4\x019=100\x0135=A\x01" {
+                if writer.write_all(&[*byte]).is_err() {
+                    return;
+                }
+                std::thread::sleep(Duration::from_millis(100));
+            }
+        });
+
+        let started = std::time::Instant::now();
+        let mut buffer = [0u8; 1024];
+        assert!(matches!(stream.read(&mut buffer), Ok(0) | Err(_)));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+}
@@ -0,0 +1,313 @@
+use std::{
+    io::{self, Read, Write},
+    net::{Shutdown, TcpStream},
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    fix_transport, DisconnectReason, FixFrameDecoder, FixMessageWriter, FixSession,
+    FixSessionError, FixSessionEvent, MessageStore, SessionClock,
+};
+
+const READ_BUFFER_SIZE: usize = 8 * 1024;
+const TIMER_INTERVAL: Duration = Duration::from_secs(1);
+// Used when heartbeats are turned off
+const DEFAULT_WRITE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Session together with the connection it is running over. The reading thread and the
+/// application threads take turns under the mutex; whoever changes the session writes the
+/// outbound messages it produced.
+pub(crate) struct BlockingConnection<C: SessionClock, S: MessageStore> {
+    session: FixSession<C, S>,
+    stream: Option<TcpStream>,
+    emit: Box<dyn FnMut(FixSessionEvent) + Send>,
+    logged_on: bool,
+    stop: bool,
+    closed: bool,
+}
+
+pub(crate) type SharedConnection<C, S> = Arc<Mutex<BlockingConnection<C, S>>>;
+
+impl<C: SessionClock, S: MessageStore> BlockingConnection<C, S> {
+    pub fn new(
+        session: FixSession<C, S>,
+        emit: impl FnMut(FixSessionEvent) + Send + 'static,
+    ) -> Self {
+        Self {
+            session,
+            stream: None,
+            emit: Box::new(emit),
+            logged_on: false,
+            stop: false,
+            closed: false,
+        }
+    }
+
+    fn is_connected(&self) -> bool {
+        self.stream.is_some()
+    }
+
+    pub fn is_session_time(&self) -> bool {
+        self.session.is_session_time()
+    }
+
+    /// Logout is requested or every sender is dropped
+    pub fn is_stopped(&self) -> bool {
+        self.stop
+    }
+
+    /// Thread running the session is finished. Dropping `emit` lets the receiver know.
+    pub fn set_closed(&mut self) {
+        self.closed = true;
+        self.emit = Box::new(|_| {});
+    }
+
+    pub fn emit(&mut self, event: FixSessionEvent) {
+        (self.emit)(event);
+    }
+
+    // Writes the outbound messages and passes everything else to the application
+    fn flush(&mut self) {
+        while let Some(event) = self.session.poll_event() {
+            match event {
+                FixSessionEvent::Send(message) => {
+                    let Some(stream) = self.stream.as_mut() else {
+                        continue;
+                    };
+
+                    // Write timeout keeps a counterparty which stopped reading from blocking
+                    // the senders waiting for the mutex
+                    match stream.write_all(&message) {
+                        Ok(()) => {}
+                        Err(err) if is_timeout(&err) => self.close("Write timed out".to_string()),
+                        Err(err) => self.close(err.to_string()),
+                    }
+                }
+                FixSessionEvent::Disconnect(reason) => {
+                    if let Some(stream) = self.stream.take() {
+                        let _ = stream.shutdown(Shutdown::Both);
+                    }
+                    (self.emit)(FixSessionEvent::Disconnect(reason));
+                }
+                event => {
+                    if event == FixSessionEvent::LoggedOn {
+                        self.logged_on = true;
+                    }
+                    (self.emit)(event);
+                }
+            }
+        }
+    }
+
+    // Counterparty which does not take a message within the heartbeat interval is gone
+    fn write_timeout(&self) -> Duration {
+        match self.session.get_heart_bt_int() {
+            interval if interval.is_zero() => DEFAULT_WRITE_TIMEOUT,
+            interval => interval,
+        }
+    }
+
+    fn close(&mut self, error: String) {
+        self.session.on_disconnected();
+
+        if let Some(stream) = self.stream.take() {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+
+        (self.emit)(FixSessionEvent::Disconnect(DisconnectReason::Transport(
+            error,
+        )));
+    }
+}
+
+/// Part of the connection used by [`BlockingFixSender`], so the sender does not depend on
+/// the clock and store types.
+pub(crate) trait SessionHandle: Send {
+    fn send(&mut self, writer: FixMessageWriter) -> Result<u64, FixSessionError>;
+    fn logout(&mut self, text: Option<&str>) -> Result<(), FixSessionError>;
+}
+
+impl<C, S> SessionHandle for BlockingConnection<C, S>
+where
+    C: SessionClock + Send,
+    S: MessageStore + Send,
+{
+    fn send(&mut self, writer: FixMessageWriter) -> Result<u64, FixSessionError> {
+        if self.closed {
+            return Err(FixSessionError::Closed);
+        }
+
+        let result = self.session.send(writer);
+        self.flush();
+        result
+    }
+
+    fn logout(&mut self, text: Option<&str>) -> Result<(), FixSessionError> {
+        if self.closed {
+            return Err(FixSessionError::Closed);
+        }
+
+        self.stop = true;
+        self.session.logout(text);
+        self.flush();
+        Ok(())
+    }
+}
+
+/// Handle which sends outbound application messages of the session from any thread.
+/// Clones send to the same session.
+#[derive(Clone)]
+pub struct BlockingFixSender {
+    connection: Arc<Mutex<dyn SessionHandle>>,
+}
+
+impl BlockingFixSender {
+    pub(crate) fn new(connection: Arc<Mutex<dyn SessionHandle>>) -> Self {
+        Self { connection }
+    }
+
+    /// Stamps the header of the application message and writes it to the connection.
+    /// Returns MsgSeqNum (34) of the message.
+    pub fn send(&self, writer: FixMessageWriter) -> Result<u64, FixSessionError> {
+        lock(&self.connection).send(writer)
+    }
+
+    /// Starts the graceful logout. The session is not connected again after it.
+    pub fn logout(&self, text: Option<&str>) -> Result<(), FixSessionError> {
+        lock(&self.connection).logout(text)
+    }
+}
+
+impl std::fmt::Debug for BlockingFixSender {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlockingFixSender").finish_non_exhaustive()
+    }
+}
+
+/// Events of the session: logon and logout, inbound application messages and disconnects.
+/// `FixSessionEvent::Send` is written to the connection and is never received here.
+#[derive(Debug)]
+pub struct BlockingFixReceiver {
+    events: std::sync::mpsc::Receiver<FixSessionEvent>,
+}
+
+impl BlockingFixReceiver {
+    pub(crate) fn new(events: std::sync::mpsc::Receiver<FixSessionEvent>) -> Self {
+        Self { events }
+    }
+
+    /// Returns `None` when the thread running the session is finished
+    pub fn recv(&self) -> Option<FixSessionEvent> {
+        self.events.recv().ok()
+    }
+
+    /// Returns `None` on timeout or when the thread running the session is finished
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<FixSessionEvent> {
+        self.events.recv_timeout(timeout).ok()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ConnectionEnd {
+    pub logged_on: bool,
+    pub stop: bool,
+}
+
+/// Runs the session over the connection in the current thread until it is disconnected.
+///
+/// `received` holds the bytes which were read to identify the session.
+pub(crate) fn run_connection<C, S>(
+    connection: &SharedConnection<C, S>,
+    stream: TcpStream,
+    received: &[u8],
+) -> ConnectionEnd
+where
+    C: SessionClock,
+    S: MessageStore,
+{
+    let mut decoder = FixFrameDecoder::new();
+    decoder.append(received);
+
+    let write_timeout = lock(connection).write_timeout();
+    let mut reader = match prepare_stream(&stream, write_timeout) {
+        Ok(reader) => reader,
+        Err(err) => {
+            let mut connection = lock(connection);
+            connection.close(err.to_string());
+            return ConnectionEnd {
+                logged_on: false,
+                stop: connection.stop,
+            };
+        }
+    };
+
+    {
+        let mut connection = lock(connection);
+        connection.stream = Some(stream);
+        connection.logged_on = false;
+        connection.session.on_connected();
+        fix_transport::process_frames(&mut connection.session, &mut decoder);
+        connection.flush();
+    }
+
+    let mut buffer = vec![0u8; READ_BUFFER_SIZE];
+    let mut last_timer = Instant::now();
+
+    loop {
+        let read = reader.read(&mut buffer);
+
+        // Every sender is dropped, so nobody can log the session out
+        let abandoned = Arc::strong_count(connection) == 1;
+
+        let mut connection = lock(connection);
+
+        match read {
+            Ok(0) => connection.close("Connection is closed".to_string()),
+            Ok(size) => {
+                decoder.append(&buffer[..size]);
+                fix_transport::process_frames(&mut connection.session, &mut decoder);
+            }
+            Err(err) if is_timeout(&err) => {}
+            Err(err) => connection.close(err.to_string()),
+        }
+
+        if abandoned && !connection.stop {
+            connection.stop = true;
+            connection.session.logout(None);
+        }
+
+        if last_timer.elapsed() >= TIMER_INTERVAL {
+            connection.session.on_timer();
+            last_timer = Instant::now();
+        }
+
+        connection.flush();
+
+        if !connection.is_connected() {
+            return ConnectionEnd {
+                logged_on: connection.logged_on,
+                stop: connection.stop,
+            };
+        }
+    }
+}
+
+pub(crate) fn lock<T: ?Sized>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+fn is_timeout(err: &io::Error) -> bool {
+    matches!(
+        err.kind(),
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+    )
+}
+
+// Read timeout wakes the thread to drive the session timers. Returns the stream to read from.
+fn prepare_stream(stream: &TcpStream, write_timeout: Duration) -> io::Result<TcpStream> {
+    stream.set_write_timeout(Some(write_timeout))?;
+    let reader = stream.try_clone()?;
+    reader.set_read_timeout(Some(TIMER_INTERVAL))?;
+    Ok(reader)
+}
@@ -0,0 +1,370 @@
+use std::{
+    net::TcpStream,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    blocking_connection::{self, BlockingConnection, SharedConnection},
+    BlockingFixReceiver, BlockingFixSender, DisconnectReason, FixSession, FixSessionEvent,
+    MessageStore, SessionClock,
+};
+
+// How often a sleeping thread checks whether it has to stop
+const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(50);
+// How often a disconnected initiator checks whether the session schedule has started
+const SCHEDULE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Same as [`crate::FixInitiator`], but runs the session in a thread over
+/// `std::net::TcpStream`, so it does not need an async runtime.
+///
+/// When the connection is lost or can not be established, the thread reports
+/// `DisconnectReason::Transport` and connects again after the reconnect interval, which
+/// doubles after each attempt up to the maximum and drops back to the minimum after a
+/// successful Logon. Outside of the session schedule the thread does not connect and waits
+/// for the session to start. The thread stops after the logout requested by
+/// `BlockingFixSender::logout` or when every [`BlockingFixSender`] is dropped.
+pub struct BlockingFixInitiator<C: SessionClock, S: MessageStore> {
+    session: FixSession<C, S>,
+    host: String,
+    port: u16,
+    min_reconnect_interval: Duration,
+    max_reconnect_interval: Duration,
+}
+
+impl<C, S> BlockingFixInitiator<C, S>
+where
+    C: SessionClock + Send + 'static,
+    S: MessageStore + Send + 'static,
+{
+    pub const DEFAULT_MIN_RECONNECT_INTERVAL: Duration = Duration::from_secs(1);
+    pub const DEFAULT_MAX_RECONNECT_INTERVAL: Duration = Duration::from_secs(30);
+
+    pub fn new(session: FixSession<C, S>, host: impl Into<String>, port: u16) -> Self {
+        Self {
+            session,
+            host: host.into(),
+            port,
+            min_reconnect_interval: Self::DEFAULT_MIN_RECONNECT_INTERVAL,
+            max_reconnect_interval: Self::DEFAULT_MAX_RECONNECT_INTERVAL,
+        }
+    }
+
+    /// Delay before the first reconnect attempt and the limit it doubles up to
+    pub fn with_reconnect_interval(mut self, min: Duration, max: Duration) -> Self {
+        self.min_reconnect_interval = min;
+        self.max_reconnect_interval = max.max(min);
+        self
+    }
+
+    pub fn get_host(&self) -> &str {
+        &self.host
+    }
+
+    pub fn get_port(&self) -> u16 {
+        self.port
+    }
+
+    /// Spawns the thread running the session
+    pub fn start(self) -> (BlockingFixSender, BlockingFixReceiver) {
+        let (events, receiver) = mpsc::channel();
+
+        let connection = Arc::new(Mutex::new(BlockingConnection::new(
+            self.session,
+            move |event| {
+                let _ = events.send(event);
+            },
+        )));
+
+        let sender = BlockingFixSender::new(connection.clone());
+        let endpoint = (self.host, self.port);
+        let intervals = (self.min_reconnect_interval, self.max_reconnect_interval);
+
+        thread::spawn(move || {
+            run(&connection, endpoint, intervals);
+            blocking_connection::lock(&connection).set_closed();
+        });
+
+        (sender, BlockingFixReceiver::new(receiver))
+    }
+}
+
+fn run<C: SessionClock, S: MessageStore>(
+    connection: &SharedConnection<C, S>,
+    (host, port): (String, u16),
+    (min_reconnect_interval, max_reconnect_interval): (Duration, Duration),
+) {
+    let mut reconnect_interval = min_reconnect_interval;
+
+    loop {
+        if !blocking_connection::lock(connection).is_session_time() {
+            if !wait_session_time(connection) {
+                return;
+            }
+
+            reconnect_interval = min_reconnect_interval;
+        }
+
+        match TcpStream::connect((host.as_str(), port)) {
+            Ok(stream) => {
+                let _ = stream.set_nodelay(true);
+
+                let end = blocking_connection::run_connection(connection, stream, &[]);
+                if end.stop {
+                    return;
+                }
+
+                if end.logged_on {
+                    reconnect_interval = min_reconnect_interval;
+                }
+            }
+            Err(err) => {
+                let reason = DisconnectReason::Transport(err.to_string());
+                emit(connection, FixSessionEvent::Disconnect(reason));
+            }
+        }
+
+        if !sleep_unless_stopped(connection, reconnect_interval) {
+            return;
+        }
+
+        reconnect_interval = (reconnect_interval * 2).min(max_reconnect_interval);
+    }
+}
+
+// Returns `false` if the session has to be stopped
+fn wait_session_time<C: SessionClock, S: MessageStore>(
+    connection: &SharedConnection<C, S>,
+) -> bool {
+    while !blocking_connection::lock(connection).is_session_time() {
+        if !sleep_unless_stopped(connection, SCHEDULE_CHECK_INTERVAL) {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn emit<C: SessionClock, S: MessageStore>(
+    connection: &SharedConnection<C, S>,
+    event: FixSessionEvent,
+) {
+    blocking_connection::lock(connection).emit(event);
+}
+
+// Returns `false` if the session has to be stopped
+fn sleep_unless_stopped<C: SessionClock, S: MessageStore>(
+    connection: &SharedConnection<C, S>,
+    duration: Duration,
+) -> bool {
+    let until = Instant::now() + duration;
+
+    loop {
+        if Arc::strong_count(connection) == 1 || blocking_connection::lock(connection).is_stopped()
+        {
+            return false;
+        }
+
+        let now = Instant::now();
+        if now >= until {
+            return true;
+        }
+
+        thread::sleep((until - now).min(STOP_CHECK_INTERVAL));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::{TcpListener, TcpStream},
+        time::Duration,
+    };
+
+    use crate::{
+        tags, utils, BlockingFixInitiator, BlockingFixReceiver, BlockingFixSender,
+        DisconnectReason, FixFrameDecoder, FixMessageWriter, FixSession, FixSessionConfig,
+        FixSessionEvent, ManualClock, SessionRole, SessionSchedule, UtcTimeOnly, UtcTimestamp,
+    };
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn read_message(stream: &mut TcpStream, decoder: &mut FixFrameDecoder) -> String {
+        let mut buffer = [0u8; 1024];
+
+        loop {
+            if let Some(frame) = decoder.next_frame().unwrap() {
+                return utils::bytes_to_fix_string(frame);
+            }
+
+            let size = stream.read(&mut buffer).unwrap();
+            assert!(size > 0, "Connection is closed");
+            decoder.append(&buffer[..size]);
+        }
+    }
+
+    fn outbound(msg_type: &str, seq_num: u64) -> Vec<u8> {
+        let mut writer = FixMessageWriter::new("FIX.4.4", msg_type);
+        writer.with_value(tags::SENDER_COMP_ID, "VENUE");
+        writer.with_value(tags::TARGET_COMP_ID, "CLIENT");
+        writer.with_value(tags::MSG_SEQ_NUM, seq_num);
+        if msg_type == "A" {
+            writer.with_value(tags::ENCRYPT_METHOD, 0);
+            writer.with_value(tags::HEART_BT_INT, 30);
+        }
+        writer.compile_message()
+    }
+
+    fn start_initiator(listener: &TcpListener) -> (BlockingFixSender, BlockingFixReceiver) {
+        let port = listener.local_addr().unwrap().port();
+        let config = FixSessionConfig::new(SessionRole::Initiator, "FIX.4.4", "CLIENT", "VENUE");
+
+        BlockingFixInitiator::new(FixSession::new(config), "127.0.0.1", port)
+            .with_reconnect_interval(Duration::from_millis(10), Duration::from_millis(100))
+            .start()
+    }
+
+    #[test]
+    fn test_logon_and_exchange_messages() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let (sender, receiver) = start_initiator(&listener);
+
+        let (mut stream, _) = listener.accept().unwrap();
+        stream.set_read_timeout(Some(TIMEOUT)).unwrap();
+        let mut decoder = FixFrameDecoder::new();
+
+        assert!(read_message(&mut stream, &mut decoder).contains("|35=A|"));
+        stream.write_all(&outbound("A", 1)).unwrap();
+        assert_eq!(
+            Some(FixSessionEvent::LoggedOn),
+            receiver.recv_timeout(TIMEOUT)
+        );
+
+        let mut order = FixMessageWriter::new("FIX.4.4", "D");
+        order.with_value(tags::CL_ORD_ID, "order-1");
+        assert_eq!(2, sender.send(order).unwrap());
+        assert!(read_message(&mut stream, &mut decoder).contains("|11=order-1|"));
+
+        stream.write_all(&outbound("8", 2)).unwrap();
+        let Some(FixSessionEvent::Application(_)) = receiver.recv_timeout(TIMEOUT) else {
+            panic!("Application message expected");
+        };
+
+        sender.logout(None).unwrap();
+        assert!(read_message(&mut stream, &mut decoder).contains("|35=5|"));
+        stream.write_all(&outbound("5", 3)).unwrap();
+
+        assert_eq!(
+            Some(FixSessionEvent::LoggedOut),
+            receiver.recv_timeout(TIMEOUT)
+        );
+        assert_eq!(
+            Some(FixSessionEvent::Disconnect(DisconnectReason::LoggedOut)),
+            receiver.recv_timeout(TIMEOUT)
+        );
+        // Thread is finished after the logout
+        assert_eq!(None, receiver.recv_timeout(TIMEOUT));
+        assert!(sender
+            .send(FixMessageWriter::new("FIX.4.4", "D"))
+            .unwrap_err()
+            .is_closed());
+    }
+
+    #[test]
+    fn test_reconnect_after_connection_is_lost() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let (_sender, receiver) = start_initiator(&listener);
+
+        {
+            let (mut stream, _) = listener.accept().unwrap();
+            let logon = read_message(&mut stream, &mut FixFrameDecoder::new());
+            assert!(logon.contains("|34=1|"));
+        }
+
+        let Some(FixSessionEvent::Disconnect(DisconnectReason::Transport(_))) =
+            receiver.recv_timeout(TIMEOUT)
+        else {
+            panic!("Transport disconnect expected");
+        };
+
+        let (mut stream, _) = listener.accept().unwrap();
+        let logon = read_message(&mut stream, &mut FixFrameDecoder::new());
+        assert!(logon.contains("|34=2|"));
+    }
+
+    #[test]
+    fn test_counterparty_not_reading_is_disconnected() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let config = FixSessionConfig::new(SessionRole::Initiator, "FIX.4.4", "CLIENT", "VENUE")
+            .with_heart_bt_int(1);
+        let (sender, receiver) =
+            BlockingFixInitiator::new(FixSession::new(config), "127.0.0.1", port).start();
+
+        let (mut stream, _) = listener.accept().unwrap();
+        read_message(&mut stream, &mut FixFrameDecoder::new());
+        stream.write_all(&outbound("A", 1)).unwrap();
+        assert_eq!(
+            Some(FixSessionEvent::LoggedOn),
+            receiver.recv_timeout(TIMEOUT)
+        );
+
+        // Counterparty does not read, so the socket buffers fill up. Sending fails after the
+        // write times out and the session is disconnected.
+        let text = "x".repeat(1024 * 1024);
+        for _ in 0..1000 {
+            let mut message = FixMessageWriter::new("FIX.4.4", "B");
+            message.with_value(tags::TEXT, &text);
+            if sender.send(message).is_err() {
+                break;
+            }
+        }
+
+        let Some(FixSessionEvent::Disconnect(DisconnectReason::Transport(error))) =
+            receiver.recv_timeout(TIMEOUT)
+        else {
+            panic!("Transport disconnect expected");
+        };
+        assert_eq!("Write timed out", error);
+    }
+
+    #[test]
+    fn test_wait_for_session_time() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let schedule = SessionSchedule::daily(
+            UtcTimeOnly::new(8, 0, 0, 0).unwrap(),
+            UtcTimeOnly::new(17, 0, 0, 0).unwrap(),
+        );
+        let config = FixSessionConfig::new(SessionRole::Initiator, "FIX.4.4", "CLIENT", "VENUE")
+            .with_schedule(schedule);
+        let at = |hour| {
+            UtcTimestamp::new(2024, 1, 2, hour, 0, 0, 0)
+                .unwrap()
+                .to_system_time()
+        };
+        let clock = ManualClock::new(at(7));
+
+        let (_sender, _receiver) = BlockingFixInitiator::new(
+            FixSession::with_clock(config, clock.clone()),
+            "127.0.0.1",
+            port,
+        )
+        .with_reconnect_interval(Duration::from_millis(10), Duration::from_millis(100))
+        .start();
+
+        // No connection before the session starts
+        listener.set_nonblocking(true).unwrap();
+        std::thread::sleep(Duration::from_millis(1500));
+        assert!(listener.accept().is_err());
+
+        clock.set(at(8));
+        listener.set_nonblocking(false).unwrap();
+        let (mut stream, _) = listener.accept().unwrap();
+        stream.set_read_timeout(Some(TIMEOUT)).unwrap();
+        assert!(read_message(&mut stream, &mut FixFrameDecoder::new()).contains("|35=A|"));
+    }
+}
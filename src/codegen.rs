@@ -0,0 +1,994 @@
+//! Generates Rust structs for the messages of a data dictionary.
+//!
+//! Every message gets a struct with a field per FIX field, `encode` into `FixMessageWriter`
+//! and `decode` from `FixMessageReader`. Repeating groups become structs as well. Components
+//! are already resolved by `DataDictionary`, so their fields are placed into the messages
+//! directly. Header and trailer fields are not generated: they are written by the session.
+//!
+//! Fields with a set of values get an enum with `TryFrom<&str>` and `as_str`. Values which
+//! are not in the dictionary are kept in the `Other` variant. MultipleValueString and
+//! MultipleCharValue fields with a set of values become `Vec` of the enum.
+//!
+//! Data fields become `Vec<u8>`. Their length field is not a member of the struct: it is
+//! written from the length of the value.
+//!
+//! The code can be generated by the `fix-codegen` binary or from a `build.rs`:
+//!
+//! ```ignore
+//! rust_fix::codegen::generate_file("spec/FIX44.xml", out_dir.join("fix44.rs")).unwrap();
+//! ```
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Write},
+    path::Path,
+};
+
+use crate::{
+    DataDictionary, DataDictionaryError, FieldDefinition, FixDataType, GroupLayout, LayoutItem, Tag,
+};
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
+
+pub fn generate(dictionary: &DataDictionary) -> String {
+    let mut generator = Generator {
+        dictionary,
+        groups: Vec::new(),
+        enums: HashMap::new(),
+    };
+
+    let mut out = String::new();
+    generator
+        .write(&mut out)
+        .expect("Writing to String can not fail");
+
+    out
+}
+
+/// Loads the dictionary and writes the generated code into `out_path`
+pub fn generate_file(
+    dictionary_path: impl AsRef<Path>,
+    out_path: impl AsRef<Path>,
+) -> Result<(), DataDictionaryError> {
+    let dictionary = DataDictionary::load(dictionary_path)?;
+    std::fs::write(out_path, generate(&dictionary))?;
+    Ok(())
+}
+
+/// Name of the Rust field for the FIX field name: `ClOrdID` becomes `cl_ord_id`
+pub fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut result = String::new();
+
+    for (index, c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && index > 0 {
+            let prev = chars[index - 1];
+            let next = chars.get(index + 1).copied();
+
+            // "IDs" is a plural of "ID", not "I" + "Ds"
+            let is_plural_suffix = next == Some('s')
+                && chars
+                    .get(index + 2)
+                    .is_none_or(|after| after.is_ascii_uppercase());
+
+            let starts_word = prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase()
+                    && next.is_some_and(|next| next.is_ascii_lowercase())
+                    && !is_plural_suffix);
+
+            if starts_word {
+                result.push('_');
+            }
+        }
+
+        result.push(c.to_ascii_lowercase());
+    }
+
+    if KEYWORDS.contains(&result.as_str()) {
+        return format!("r#{}", result);
+    }
+
+    result
+}
+
+struct GroupStruct<'d> {
+    name: String,
+    layout: &'d GroupLayout,
+}
+
+struct StructField {
+    name: String,
+    tag: Tag,
+    kind: FieldKind,
+}
+
+enum FieldKind {
+    Value {
+        data_type: FixDataType,
+        rust_type: String,
+        required: bool,
+    },
+    /// Data field together with the length field which precedes it
+    Data {
+        length_tag: Tag,
+        required: bool,
+    },
+    Group {
+        struct_name: String,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Target {
+    Message,
+    GroupEntry,
+}
+
+struct Generator<'d> {
+    dictionary: &'d DataDictionary,
+    groups: Vec<GroupStruct<'d>>,
+    enums: HashMap<Tag, String>,
+}
+
+impl<'d> Generator<'d> {
+    fn write(&mut self, out: &mut String) -> fmt::Result {
+        writeln!(
+            out,
+            "// Generated by rust-fix codegen from the {} dictionary. Do not edit.",
+            self.dictionary.get_version()
+        )?;
+
+        let dictionary = self.dictionary;
+
+        let message_names: HashSet<&str> = dictionary
+            .get_messages()
+            .iter()
+            .map(|message| message.get_name())
+            .collect();
+
+        for field in dictionary.get_fields() {
+            if !has_enum(field) {
+                continue;
+            }
+
+            let mut name = field.get_name().to_string();
+
+            if message_names.contains(name.as_str()) {
+                name.push_str("Value");
+            }
+
+            write_enum(out, &name, field)?;
+            self.enums.insert(field.get_tag(), name);
+        }
+
+        for message in dictionary.get_messages() {
+            let fields =
+                self.get_struct_fields(message.get_layout().get_items(), message.get_name());
+
+            writeln!(out)?;
+            writeln!(
+                out,
+                "/// {} (MsgType = {})",
+                message.get_name(),
+                message.get_msg_type()
+            )?;
+            write_struct(out, message.get_name(), &fields)?;
+            writeln!(out)?;
+            writeln!(out, "impl {} {{", message.get_name())?;
+            writeln!(
+                out,
+                "    pub const MSG_TYPE: &str = {:?};",
+                message.get_msg_type()
+            )?;
+            writeln!(out)?;
+            write_encode(out, &fields, Target::Message)?;
+            writeln!(out)?;
+            write_decode(out, &fields, Target::Message)?;
+            writeln!(out, "}}")?;
+        }
+
+        let mut index = 0;
+        while index < self.groups.len() {
+            let name = self.groups[index].name.clone();
+            let layout = self.groups[index].layout;
+            let fields = self.get_struct_fields(layout.get_layout().get_items(), &name);
+
+            writeln!(out)?;
+            writeln!(
+                out,
+                "/// Entry of {} ({}) repeating group",
+                self.get_field_name(layout.get_count_tag()),
+                layout.get_count_tag()
+            )?;
+            write_struct(out, &name, &fields)?;
+            writeln!(out)?;
+            writeln!(out, "impl {} {{", name)?;
+            write_definition(out, layout, &fields)?;
+            writeln!(out)?;
+            write_encode(out, &fields, Target::GroupEntry)?;
+            writeln!(out)?;
+            write_decode(out, &fields, Target::GroupEntry)?;
+            writeln!(out, "}}")?;
+
+            index += 1;
+        }
+
+        Ok(())
+    }
+
+    fn get_struct_fields(&mut self, items: &'d [LayoutItem], owner_name: &str) -> Vec<StructField> {
+        let mut result = Vec::new();
+        let mut found_tags = HashSet::new();
+
+        // Length fields of the data fields of these items
+        let data_fields: HashMap<Tag, Tag> = self
+            .dictionary
+            .get_data_fields()
+            .iter()
+            .filter(|(_, data_tag)| items.iter().any(|itm| itm.get_tag() == *data_tag))
+            .map(|(length_tag, data_tag)| (*data_tag, *length_tag))
+            .collect();
+
+        for itm in items {
+            if !found_tags.insert(itm.get_tag()) {
+                continue;
+            }
+
+            if data_fields
+                .values()
+                .any(|length_tag| *length_tag == itm.get_tag())
+            {
+                continue;
+            }
+
+            let kind = match itm {
+                LayoutItem::Field { tag, required } if data_fields.contains_key(tag) => {
+                    FieldKind::Data {
+                        length_tag: data_fields[tag],
+                        required: *required,
+                    }
+                }
+                LayoutItem::Field { tag, required } => {
+                    let data_type = self
+                        .dictionary
+                        .get_field(*tag)
+                        .map(|field| field.get_data_type())
+                        .unwrap_or(FixDataType::String);
+
+                    let rust_type = match self.enums.get(tag) {
+                        Some(enum_name) if is_multiple_value(data_type) => {
+                            format!("Vec<{}>", enum_name)
+                        }
+                        Some(enum_name) => enum_name.clone(),
+                        None => get_rust_type(data_type).to_string(),
+                    };
+
+                    FieldKind::Value {
+                        data_type,
+                        rust_type,
+                        required: *required,
+                    }
+                }
+                LayoutItem::Group(group) => FieldKind::Group {
+                    struct_name: self.register_group(group, owner_name),
+                },
+            };
+
+            result.push(StructField {
+                name: to_snake_case(&self.get_field_name(itm.get_tag())),
+                tag: itm.get_tag(),
+                kind,
+            });
+        }
+
+        result
+    }
+
+    // The same group is used by many messages, so a single struct is generated for it.
+    // Groups which have the same count tag but different fields are prefixed by the owner.
+    fn register_group(&mut self, group: &'d GroupLayout, owner_name: &str) -> String {
+        let count_tag = group.get_count_tag();
+
+        if let Some(existing) = self.groups.iter().find(|itm| {
+            itm.layout.get_count_tag() == count_tag && itm.layout.get_layout() == group.get_layout()
+        }) {
+            return existing.name.clone();
+        }
+
+        let mut name = self.get_field_name(count_tag);
+
+        if self.groups.iter().any(|itm| itm.name == name) {
+            name = format!("{}{}", owner_name, name);
+        }
+
+        self.groups.push(GroupStruct {
+            name: name.clone(),
+            layout: group,
+        });
+
+        name
+    }
+
+    fn get_field_name(&self, tag: Tag) -> String {
+        match self.dictionary.get_field(tag) {
+            Some(field) => field.get_name().to_string(),
+            None => format!("Field{}", tag),
+        }
+    }
+}
+
+fn get_rust_type(data_type: FixDataType) -> &'static str {
+    match data_type {
+        FixDataType::Int => "i64",
+        FixDataType::Length
+        | FixDataType::SeqNum
+        | FixDataType::NumInGroup
+        | FixDataType::TagNum
+        | FixDataType::DayOfMonth => "u64",
+        FixDataType::Float
+        | FixDataType::Qty
+        | FixDataType::Price
+        | FixDataType::PriceOffset
+        | FixDataType::Amt
+        | FixDataType::Percentage => "rust_fix::FixDecimal",
+        FixDataType::Char => "char",
+        FixDataType::Boolean => "bool",
+        FixDataType::UtcTimestamp => "rust_fix::UtcTimestamp",
+        FixDataType::UtcTimeOnly => "rust_fix::UtcTimeOnly",
+        FixDataType::UtcDateOnly | FixDataType::LocalMktDate => "rust_fix::LocalMktDate",
+        _ => "String",
+    }
+}
+
+fn is_primitive(rust_type: &str) -> bool {
+    matches!(rust_type, "i64" | "u64" | "char" | "bool")
+}
+
+// Boolean fields are kept as bool
+fn has_enum(field: &FieldDefinition) -> bool {
+    field.has_values() && field.get_data_type() != FixDataType::Boolean
+}
+
+// Field holds a list of space separated values
+fn is_multiple_value(data_type: FixDataType) -> bool {
+    matches!(
+        data_type,
+        FixDataType::MultipleCharValue | FixDataType::MultipleValueString
+    )
+}
+
+/// Name of the enum variant for the value description: `NEW_ORDER_SINGLE` becomes `NewOrderSingle`
+pub fn to_variant_name(description: &str) -> String {
+    let mut result = String::new();
+
+    for word in description.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut chars = word.chars();
+
+        if let Some(first) = chars.next() {
+            result.push(first.to_ascii_uppercase());
+
+            if word.chars().any(|c| c.is_ascii_lowercase()) {
+                result.extend(chars);
+            } else {
+                result.extend(chars.map(|c| c.to_ascii_lowercase()));
+            }
+        }
+    }
+
+    if !result.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        result.insert_str(0, "Value");
+    }
+
+    result
+}
+
+fn write_enum(out: &mut String, name: &str, field: &FieldDefinition) -> fmt::Result {
+    // "Other" is taken by the values which are not in the dictionary
+    let mut variants: Vec<(String, &str)> = Vec::new();
+
+    for value in field.get_values() {
+        let mut variant = to_variant_name(value.get_description());
+
+        if variant == "Other" || variants.iter().any(|(itm, _)| *itm == variant) {
+            variant = format!("{}{}", variant, to_variant_name(value.get_value()));
+        }
+
+        let mut index = 2;
+        while variants.iter().any(|(itm, _)| *itm == variant) {
+            variant = format!("{}{}", to_variant_name(value.get_description()), index);
+            index += 1;
+        }
+
+        variants.push((variant, value.get_value()));
+    }
+
+    let tag = format!("rust_fix::Tag({})", field.get_tag());
+    let data_type = format!("rust_fix::FixDataType::{:?}", field.get_data_type());
+
+    writeln!(out)?;
+    writeln!(out, "/// {} ({}) values", field.get_name(), field.get_tag())?;
+    writeln!(out, "#[derive(Debug, Clone, PartialEq, Eq, Hash)]")?;
+    writeln!(out, "pub enum {} {{", name)?;
+    for (variant, value) in &variants {
+        writeln!(out, "    /// `{}`", value)?;
+        writeln!(out, "    {},", variant)?;
+    }
+    writeln!(out, "    /// Value which is not in the dictionary")?;
+    writeln!(out, "    Other(String),")?;
+    writeln!(out, "}}")?;
+
+    writeln!(out)?;
+    writeln!(out, "impl {} {{", name)?;
+    writeln!(out, "    pub fn as_str(&self) -> &str {{")?;
+    writeln!(out, "        match self {{")?;
+    for (variant, value) in &variants {
+        writeln!(out, "            Self::{} => {:?},", variant, value)?;
+    }
+    writeln!(out, "            Self::Other(value) => value,")?;
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+
+    writeln!(out)?;
+    writeln!(out, "impl TryFrom<&str> for {} {{", name)?;
+    writeln!(out, "    type Error = rust_fix::FixSerializeError;")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "    fn try_from(value: &str) -> Result<Self, Self::Error> {{"
+    )?;
+    // Enum of a multiple value field is a single value of the list
+    let separator_check = if is_multiple_value(field.get_data_type()) {
+        "value.contains(' ') || "
+    } else {
+        ""
+    };
+
+    writeln!(
+        out,
+        "        if {}!{}.is_valid(value) {{",
+        separator_check, data_type
+    )?;
+    writeln!(
+        out,
+        "            return Err(rust_fix::FixSerializeError::InvalidFieldValue {{ tag: {}, value: value.to_string(), data_type: {} }});",
+        tag, data_type
+    )?;
+    writeln!(out, "        }}")?;
+    writeln!(out)?;
+    writeln!(out, "        Ok(match value {{")?;
+    for (variant, value) in &variants {
+        writeln!(out, "            {:?} => Self::{},", value, variant)?;
+    }
+    writeln!(out, "            _ => Self::Other(value.to_string()),")?;
+    writeln!(out, "        }})")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+
+    writeln!(out)?;
+    writeln!(out, "impl rust_fix::FixValue for {} {{", name)?;
+    writeln!(out, "    fn write_fix_value(&self, out: &mut Vec<u8>) {{")?;
+    writeln!(
+        out,
+        "        out.extend_from_slice(self.as_str().as_bytes());"
+    )?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+
+    writeln!(out)?;
+    writeln!(out, "impl rust_fix::FromFixValue for {} {{", name)?;
+    writeln!(out, "    fn from_fix_value(value: &str) -> Option<Self> {{")?;
+    writeln!(out, "        Self::try_from(value).ok()")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+
+    writeln!(out)?;
+    writeln!(out, "impl rust_fix::FixEnum for {} {{", name)?;
+    writeln!(out, "    const TAG: rust_fix::Tag = {};", tag)?;
+    writeln!(
+        out,
+        "    const DATA_TYPE: rust_fix::FixDataType = {};",
+        data_type
+    )?;
+    writeln!(out, "}}")
+}
+
+fn write_struct(out: &mut String, name: &str, fields: &[StructField]) -> fmt::Result {
+    writeln!(out, "#[derive(Debug, Clone, PartialEq)]")?;
+    writeln!(out, "pub struct {} {{", name)?;
+
+    for field in fields {
+        let rust_type = match &field.kind {
+            FieldKind::Value {
+                rust_type,
+                required: true,
+                ..
+            } => rust_type.clone(),
+            FieldKind::Value {
+                rust_type,
+                required: false,
+                ..
+            } => format!("Option<{}>", rust_type),
+            FieldKind::Data { required: true, .. } => "Vec<u8>".to_string(),
+            FieldKind::Data {
+                required: false, ..
+            } => "Option<Vec<u8>>".to_string(),
+            FieldKind::Group { struct_name } => format!("Vec<{}>", struct_name),
+        };
+
+        writeln!(out, "    /// Tag {}", field.tag)?;
+        writeln!(out, "    pub {}: {},", field.name, rust_type)?;
+    }
+
+    writeln!(out, "}}")
+}
+
+fn write_definition(out: &mut String, layout: &GroupLayout, fields: &[StructField]) -> fmt::Result {
+    writeln!(
+        out,
+        "    pub fn definition() -> rust_fix::FixGroupDefinition {{"
+    )?;
+    writeln!(
+        out,
+        "        rust_fix::FixGroupDefinition::new(rust_fix::Tag({}), rust_fix::Tag({}))",
+        layout.get_count_tag(),
+        layout.get_delimiter_tag()
+    )?;
+
+    let tags: Vec<String> = fields
+        .iter()
+        .flat_map(|field| match field.kind {
+            FieldKind::Value { .. } => vec![field.tag],
+            FieldKind::Data { length_tag, .. } => vec![length_tag, field.tag],
+            FieldKind::Group { .. } => Vec::new(),
+        })
+        .map(|tag| format!("rust_fix::Tag({})", tag))
+        .collect();
+
+    if !tags.is_empty() {
+        writeln!(out, "            .with_tags(&[{}])", tags.join(", "))?;
+    }
+
+    for field in fields {
+        if let FieldKind::Group { struct_name } = &field.kind {
+            writeln!(
+                out,
+                "            .with_group({}::definition())",
+                struct_name
+            )?;
+        }
+    }
+
+    writeln!(out, "    }}")
+}
+
+fn write_encode(out: &mut String, fields: &[StructField], target: Target) -> fmt::Result {
+    let (arg_name, arg_type) = match target {
+        Target::Message => ("writer", "rust_fix::FixMessageWriter"),
+        Target::GroupEntry => ("entry", "rust_fix::FixGroupEntryWriter"),
+    };
+
+    let arg_prefix = if fields.is_empty() { "_" } else { "" };
+
+    writeln!(
+        out,
+        "    pub fn encode(&self, {}{}: &mut {}) {{",
+        arg_prefix, arg_name, arg_type
+    )?;
+
+    for field in fields {
+        let tag = format!("rust_fix::Tag({})", field.tag);
+
+        match &field.kind {
+            FieldKind::Value {
+                data_type,
+                rust_type,
+                required,
+            } => {
+                // Message writer knows the timestamp precision of the session
+                let method = match (target, data_type) {
+                    (Target::Message, FixDataType::UtcTimestamp) => Some("with_utc_timestamp"),
+                    (Target::Message, FixDataType::UtcTimeOnly) => Some("with_utc_time_only"),
+                    _ => None,
+                };
+
+                match (required, method) {
+                    (true, Some(method)) => writeln!(
+                        out,
+                        "        {}.{}({}, self.{});",
+                        arg_name, method, tag, field.name
+                    )?,
+                    (true, None) => {
+                        // Strings and enums are not Copy
+                        let borrow = if rust_type.contains("::") || is_primitive(rust_type) {
+                            ""
+                        } else {
+                            "&"
+                        };
+
+                        writeln!(
+                            out,
+                            "        {}.with_value({}, {}self.{});",
+                            arg_name, tag, borrow, field.name
+                        )?
+                    }
+                    (false, method) => {
+                        writeln!(out, "        if let Some(value) = &self.{} {{", field.name)?;
+                        match method {
+                            Some(method) => writeln!(
+                                out,
+                                "            {}.{}({}, *value);",
+                                arg_name, method, tag
+                            )?,
+                            None => writeln!(
+                                out,
+                                "            {}.with_value({}, value);",
+                                arg_name, tag
+                            )?,
+                        }
+                        writeln!(out, "        }}")?;
+                    }
+                }
+            }
+            FieldKind::Data {
+                length_tag,
+                required: true,
+            } => writeln!(
+                out,
+                "        {}.with_data(rust_fix::Tag({}), {}, &self.{});",
+                arg_name, length_tag, tag, field.name
+            )?,
+            FieldKind::Data {
+                length_tag,
+                required: false,
+            } => {
+                writeln!(out, "        if let Some(value) = &self.{} {{", field.name)?;
+                writeln!(
+                    out,
+                    "            {}.with_data(rust_fix::Tag({}), {}, value);",
+                    arg_name, length_tag, tag
+                )?;
+                writeln!(out, "        }}")?;
+            }
+            FieldKind::Group { .. } => {
+                writeln!(
+                    out,
+                    "        let mut group = rust_fix::FixGroupWriter::new({});",
+                    tag
+                )?;
+                writeln!(out, "        for itm in &self.{} {{", field.name)?;
+                writeln!(out, "            itm.encode(group.add_entry());")?;
+                writeln!(out, "        }}")?;
+                writeln!(out, "        {}.with_group(&group);", arg_name)?;
+            }
+        }
+    }
+
+    writeln!(out, "    }}")
+}
+
+fn write_decode(out: &mut String, fields: &[StructField], target: Target) -> fmt::Result {
+    let arg_prefix = if fields.is_empty() { "_" } else { "" };
+
+    match target {
+        Target::Message => {
+            writeln!(
+                out,
+                "    pub fn decode({}reader: &rust_fix::FixMessageReader) -> Result<Self, rust_fix::FixSerializeError> {{",
+                arg_prefix
+            )?;
+
+            // Fields of the groups must not be taken for the message fields with the same tag
+            let groups: Vec<String> = fields
+                .iter()
+                .filter_map(|field| match &field.kind {
+                    FieldKind::Group { struct_name } => {
+                        Some(format!("{}::definition()", struct_name))
+                    }
+                    FieldKind::Value { .. } | FieldKind::Data { .. } => None,
+                })
+                .collect();
+
+            if !fields.is_empty() {
+                writeln!(
+                    out,
+                    "        let entry = reader.read_fields(&[{}])?;",
+                    groups.join(", ")
+                )?;
+            }
+        }
+        Target::GroupEntry => writeln!(
+            out,
+            "    pub fn decode({}entry: &rust_fix::FixGroupEntry) -> Result<Self, rust_fix::FixSerializeError> {{",
+            arg_prefix
+        )?,
+    }
+
+    writeln!(out, "        Ok(Self {{")?;
+
+    for field in fields {
+        let tag = format!("rust_fix::Tag({})", field.tag);
+
+        match &field.kind {
+            FieldKind::Value {
+                data_type,
+                required,
+                ..
+            } => {
+                write!(
+                    out,
+                    "            {}: rust_fix::decode_fix_value({}, entry.get_value({}), rust_fix::FixDataType::{:?})?",
+                    field.name, tag, tag, data_type
+                )?;
+
+                if *required {
+                    write!(
+                        out,
+                        ".ok_or(rust_fix::FixSerializeError::RequiredTagMissing {{ tag: {} }})?",
+                        tag
+                    )?;
+                }
+
+                writeln!(out, ",")?;
+            }
+            FieldKind::Data { required, .. } => {
+                write!(
+                    out,
+                    "            {}: entry.get_data({}).map(<[u8]>::to_vec)",
+                    field.name, tag
+                )?;
+
+                if *required {
+                    write!(
+                        out,
+                        ".ok_or(rust_fix::FixSerializeError::RequiredTagMissing {{ tag: {} }})?",
+                        tag
+                    )?;
+                }
+
+                writeln!(out, ",")?;
+            }
+            FieldKind::Group { struct_name } => {
+                writeln!(
+                    out,
+                    "            {}: entry.get_group({}).iter().map({}::decode).collect::<Result<_, _>>()?,",
+                    field.name, tag, struct_name
+                )?;
+            }
+        }
+    }
+
+    writeln!(out, "        }})")?;
+    writeln!(out, "    }}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_snake_case, to_variant_name};
+    use crate::{codegen, DataDictionary};
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!("cl_ord_id", to_snake_case("ClOrdID"));
+        assert_eq!("no_party_ids", to_snake_case("NoPartyIDs"));
+        assert_eq!("security_id_source", to_snake_case("SecurityIDSource"));
+        assert_eq!("no_md_entries", to_snake_case("NoMDEntries"));
+        assert_eq!("no_ioi_qualifiers", to_snake_case("NoIOIQualifiers"));
+        assert_eq!("price2", to_snake_case("Price2"));
+        assert_eq!("r#yield", to_snake_case("Yield"));
+    }
+
+    #[test]
+    fn test_variant_name() {
+        assert_eq!("NewOrderSingle", to_variant_name("NEW_ORDER_SINGLE"));
+        assert_eq!("Fix50sp2", to_variant_name("FIX50SP2"));
+        assert_eq!("Iso2022Jp", to_variant_name("ISO-2022-JP"));
+        assert_eq!("BuyMinus", to_variant_name("BuyMinus"));
+        assert_eq!("Value1", to_variant_name("1"));
+    }
+
+    #[test]
+    fn test_generated_enum() {
+        use crate::{fixt11_messages::MsgDirection, FixMessageReader, FixMessageWriter};
+
+        assert_eq!(MsgDirection::Send, MsgDirection::try_from("S").unwrap());
+        assert_eq!("R", MsgDirection::Receive.as_str());
+        assert_eq!(
+            MsgDirection::Other("X".to_string()),
+            MsgDirection::try_from("X").unwrap()
+        );
+        assert!(MsgDirection::try_from("XY").is_err());
+
+        let mut writer = FixMessageWriter::new("FIXT.1.1", "A");
+        writer.with_enum(&MsgDirection::Receive);
+        let fix_message = writer.compile_message();
+
+        let reader = FixMessageReader::from_bytes(&fix_message);
+        assert_eq!(
+            Some(MsgDirection::Receive),
+            reader.get_enum::<MsgDirection>().unwrap()
+        );
+
+        let reader = FixMessageReader::from_str("8=FIXT.1.1|9=9|35=A|385=SR|10=000|");
+        assert!(reader.get_enum::<MsgDirection>().is_err());
+    }
+
+    #[test]
+    fn test_fixt11_messages_are_up_to_date() {
+        let generated = codegen::generate(&DataDictionary::fixt11());
+
+        assert!(
+            generated == include_str!("fixt11_messages.rs"),
+            "src/fixt11_messages.rs is outdated. Regenerate it with: cargo run --bin fix-codegen -- spec/FIXT11.xml src/fixt11_messages.rs"
+        );
+    }
+
+    #[test]
+    fn test_generated_message_round_trip() {
+        use crate::{fixt11_messages::*, tags, FixMessageReader, FixMessageWriter};
+
+        let logon = Logon {
+            encrypt_method: EncryptMethod::NoneOther,
+            heart_bt_int: 30,
+            raw_data: Some(vec![0xff, 0x01, b'=', 0x80]),
+            reset_seq_num_flag: Some(true),
+            next_expected_msg_seq_num: None,
+            max_message_size: None,
+            no_msg_types: vec![NoMsgTypes {
+                ref_msg_type: Some("D".to_string()),
+                msg_direction: Some(MsgDirection::Send),
+                ref_appl_ver_id: None,
+                ref_appl_ext_id: None,
+                ref_cstm_appl_ver_id: None,
+                default_ver_indicator: None,
+            }],
+            test_message_indicator: None,
+            username: Some("user".to_string()),
+            password: None,
+            new_password: None,
+            encrypted_password_method: None,
+            encrypted_password: None,
+            encrypted_new_password: None,
+            session_status: None,
+            default_appl_ver_id: DefaultApplVerID::Fix50sp2,
+            default_appl_ext_id: None,
+            default_cstm_appl_ver_id: None,
+            text: None,
+            encoded_text: None,
+        };
+
+        let mut writer = FixMessageWriter::new("FIXT.1.1", Logon::MSG_TYPE);
+        logon.encode(&mut writer);
+        let fix_message = writer.compile_message();
+
+        let reader = FixMessageReader::from_bytes(&fix_message);
+        assert_eq!(Some("30"), reader.get_value(tags::HEART_BT_INT).unwrap());
+        assert_eq!(
+            Some("Y"),
+            reader.get_value(tags::RESET_SEQ_NUM_FLAG).unwrap()
+        );
+        assert_eq!(Some("4"), reader.get_value(tags::RAW_DATA_LENGTH).unwrap());
+
+        assert_eq!(logon, Logon::decode(&reader).unwrap());
+    }
+
+    #[test]
+    fn test_decode_skips_group_fields() {
+        let dictionary = DataDictionary::parse(
+            r#"
+            <fix major="4" minor="4">
+                <header/>
+                <trailer/>
+                <messages>
+                    <message name="News" msgtype="B">
+                        <group name="NoLinesOfText" required="Y">
+                            <field name="Text" required="Y"/>
+                        </group>
+                        <field name="Text" required="N"/>
+                    </message>
+                </messages>
+                <components/>
+                <fields>
+                    <field number="33" name="NoLinesOfText" type="NUMINGROUP"/>
+                    <field number="58" name="Text" type="STRING"/>
+                </fields>
+            </fix>"#,
+        )
+        .unwrap();
+
+        let generated = codegen::generate(&dictionary);
+        assert!(
+            generated.contains("let entry = reader.read_fields(&[NoLinesOfText::definition()])?;")
+        );
+        assert!(generated.contains("text: rust_fix::decode_fix_value(rust_fix::Tag(58), entry.get_value(rust_fix::Tag(58))"));
+        assert!(!generated.contains("reader.get_value"));
+    }
+
+    #[test]
+    fn test_data_field() {
+        let dictionary = DataDictionary::parse(
+            r#"
+            <fix major="4" minor="4">
+                <header/>
+                <trailer/>
+                <messages>
+                    <message name="News" msgtype="B">
+                        <group name="NoLinesOfText" required="Y">
+                            <field name="Text" required="Y"/>
+                            <field name="EncodedTextLen" required="N"/>
+                            <field name="EncodedText" required="N"/>
+                        </group>
+                    </message>
+                </messages>
+                <components/>
+                <fields>
+                    <field number="33" name="NoLinesOfText" type="NUMINGROUP"/>
+                    <field number="58" name="Text" type="STRING"/>
+                    <field number="354" name="EncodedTextLen" type="LENGTH"/>
+                    <field number="355" name="EncodedText" type="DATA"/>
+                </fields>
+            </fix>"#,
+        )
+        .unwrap();
+
+        let generated = codegen::generate(&dictionary);
+        assert!(generated.contains("pub encoded_text: Option<Vec<u8>>,"));
+        assert!(!generated.contains("encoded_text_len"));
+        assert!(generated
+            .contains(".with_tags(&[rust_fix::Tag(58), rust_fix::Tag(354), rust_fix::Tag(355)])"));
+        assert!(
+            generated.contains("entry.with_data(rust_fix::Tag(354), rust_fix::Tag(355), value);")
+        );
+        assert!(generated
+            .contains("encoded_text: entry.get_data(rust_fix::Tag(355)).map(<[u8]>::to_vec),"));
+    }
+
+    #[test]
+    fn test_multiple_value_enum() {
+        let dictionary = DataDictionary::parse(
+            r#"
+            <fix major="4" minor="4">
+                <header/>
+                <trailer/>
+                <messages>
+                    <message name="NewOrderSingle" msgtype="D">
+                        <field name="ExecInst" required="N"/>
+                    </message>
+                </messages>
+                <components/>
+                <fields>
+                    <field number="18" name="ExecInst" type="MULTIPLECHARVALUE">
+                        <value enum="1" description="NOT_HELD"/>
+                        <value enum="G" description="ALL_OR_NONE"/>
+                    </field>
+                </fields>
+            </fix>"#,
+        )
+        .unwrap();
+
+        let generated = codegen::generate(&dictionary);
+        assert!(generated.contains("pub enum ExecInst {"));
+        assert!(generated.contains(
+            "if value.contains(' ') || !rust_fix::FixDataType::MultipleCharValue.is_valid(value) {"
+        ));
+        assert!(generated.contains("pub exec_inst: Option<Vec<ExecInst>>,"));
+        assert!(generated.contains("exec_inst: rust_fix::decode_fix_value(rust_fix::Tag(18), entry.get_value(rust_fix::Tag(18)), rust_fix::FixDataType::MultipleCharValue)?,"));
+    }
+
+    #[test]
+    fn test_decode_missing_required_field() {
+        use crate::{fixt11_messages::TestRequest, FixMessageReader};
+
+        let reader = FixMessageReader::from_str("8=FIXT.1.1|9=5|35=1|10=000|");
+        assert!(TestRequest::decode(&reader)
+            .unwrap_err()
+            .is_required_tag_missing());
+    }
+}
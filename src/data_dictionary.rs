@@ -0,0 +1,819 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+};
+
+use crate::{
+    fixt11_messages::ApplVerID, xml_element::XmlElement, DataDictionaryError, FixDataType,
+    FixGroupDefinition, Tag,
+};
+
+const FIXT11_XML: &str = include_str!("../spec/FIXT11.xml");
+
+/// Field, message, component and group definitions of a FIX version
+/// loaded from a QuickFIX dictionary (`FIX44.xml`, `FIXT11.xml`, ...).
+///
+/// Components are resolved while loading, so message layouts contain only fields and groups.
+///
+/// Only the FIXT.1.1 session dictionary is built in, see [`DataDictionary::fixt11`].
+/// Dictionaries of the application versions (`FIX44.xml`, `FIX50SP2.xml`, ...) are not
+/// shipped with the crate and are loaded with [`DataDictionary::load`] or
+/// [`DataDictionary::parse`].
+#[derive(Debug, Clone)]
+pub struct DataDictionary {
+    version: String,
+    major: u32,
+    minor: u32,
+    service_pack: u32,
+    fields: BTreeMap<Tag, FieldDefinition>,
+    field_tags: HashMap<String, Tag>,
+    data_fields: Vec<(Tag, Tag)>,
+    header: MessageLayout,
+    trailer: MessageLayout,
+    messages: Vec<MessageDefinition>,
+    message_index: HashMap<String, usize>,
+}
+
+impl DataDictionary {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, DataDictionaryError> {
+        let xml = std::fs::read_to_string(path)?;
+        Self::parse(&xml)
+    }
+
+    pub fn parse(xml: &str) -> Result<Self, DataDictionaryError> {
+        let root = XmlElement::parse(xml)?;
+
+        if root.name != "fix" {
+            return Err(DataDictionaryError::MissingElement("fix"));
+        }
+
+        let major = read_number_attribute(&root, "major")?;
+        let minor = read_number_attribute(&root, "minor")?;
+        let service_pack = match root.get_attribute("servicepack") {
+            Some(_) => read_number_attribute(&root, "servicepack")?,
+            None => 0,
+        };
+
+        let mut version = format!(
+            "{}.{}.{}",
+            root.get_attribute("type").unwrap_or("FIX"),
+            major,
+            minor
+        );
+
+        if service_pack > 0 {
+            version.push_str(&format!("SP{}", service_pack));
+        }
+
+        let fields = read_fields(
+            root.get_child("fields")
+                .ok_or(DataDictionaryError::MissingElement("fields"))?,
+        )?;
+
+        let field_tags = fields
+            .values()
+            .map(|field| (field.name.clone(), field.tag))
+            .collect();
+
+        let data_fields = read_data_fields(&fields, &field_tags);
+
+        let mut components = HashMap::new();
+        if let Some(element) = root.get_child("components") {
+            for component in &element.children {
+                components.insert(read_attribute(component, "name")?, component);
+            }
+        }
+
+        let layout_reader = LayoutReader {
+            field_tags: &field_tags,
+            components,
+        };
+
+        let header = match root.get_child("header") {
+            Some(element) => layout_reader.read_layout(element)?,
+            None => MessageLayout::default(),
+        };
+
+        let trailer = match root.get_child("trailer") {
+            Some(element) => layout_reader.read_layout(element)?,
+            None => MessageLayout::default(),
+        };
+
+        let mut messages = Vec::new();
+        let mut message_index = HashMap::new();
+
+        if let Some(element) = root.get_child("messages") {
+            for message in &element.children {
+                let definition = MessageDefinition {
+                    name: read_attribute(message, "name")?.to_string(),
+                    msg_type: read_attribute(message, "msgtype")?.to_string(),
+                    category: message.get_attribute("msgcat").unwrap_or("app").to_string(),
+                    layout: layout_reader.read_layout(message)?,
+                };
+
+                message_index.insert(definition.msg_type.clone(), messages.len());
+                messages.push(definition);
+            }
+        }
+
+        Ok(Self {
+            version,
+            major,
+            minor,
+            service_pack,
+            fields,
+            field_tags,
+            data_fields,
+            header,
+            trailer,
+            messages,
+            message_index,
+        })
+    }
+
+    /// Session level dictionary of FIXT.1.1 shipped with the crate. It is the only
+    /// dictionary which is built in.
+    pub fn fixt11() -> Self {
+        Self::parse(FIXT11_XML).expect("Embedded FIXT11.xml is invalid")
+    }
+
+    /// Version of the dictionary: `FIX.4.4`, `FIXT.1.1`, `FIX.5.0SP2`
+    pub fn get_version(&self) -> &str {
+        self.version.as_str()
+    }
+
+    pub fn get_major(&self) -> u32 {
+        self.major
+    }
+
+    pub fn get_minor(&self) -> u32 {
+        self.minor
+    }
+
+    pub fn get_service_pack(&self) -> u32 {
+        self.service_pack
+    }
+
+    pub fn get_field(&self, tag: Tag) -> Option<&FieldDefinition> {
+        self.fields.get(&tag)
+    }
+
+    pub fn get_field_by_name(&self, name: &str) -> Option<&FieldDefinition> {
+        self.fields.get(self.field_tags.get(name)?)
+    }
+
+    /// Length and data tag pairs for [`crate::FixMessageIterator::with_data_fields`]. The length
+    /// field of `RawData` is `RawDataLength` or `RawDataLen`.
+    pub fn get_data_fields(&self) -> &[(Tag, Tag)] {
+        self.data_fields.as_slice()
+    }
+
+    /// All the fields ordered by tag
+    pub fn get_fields(&self) -> impl Iterator<Item = &FieldDefinition> {
+        self.fields.values()
+    }
+
+    pub fn get_header(&self) -> &MessageLayout {
+        &self.header
+    }
+
+    pub fn get_trailer(&self) -> &MessageLayout {
+        &self.trailer
+    }
+
+    pub fn is_header_field(&self, tag: Tag) -> bool {
+        self.header.contains_tag(tag)
+    }
+
+    pub fn is_trailer_field(&self, tag: Tag) -> bool {
+        self.trailer.contains_tag(tag)
+    }
+
+    pub fn get_message(&self, msg_type: &str) -> Option<&MessageDefinition> {
+        let index = self.message_index.get(msg_type)?;
+        self.messages.get(*index)
+    }
+
+    pub fn get_message_by_name(&self, name: &str) -> Option<&MessageDefinition> {
+        self.messages.iter().find(|message| message.name == name)
+    }
+
+    /// Messages in the order they are declared in the dictionary
+    pub fn get_messages(&self) -> &[MessageDefinition] {
+        self.messages.as_slice()
+    }
+
+    /// Group definition for `FixMessageReader::get_group`. Nested groups of the message
+    /// and the groups of the header are found as well.
+    pub fn get_group_definition(
+        &self,
+        msg_type: &str,
+        count_tag: Tag,
+    ) -> Option<FixGroupDefinition> {
+        let group = self
+            .get_message(msg_type)
+            .and_then(|message| message.layout.find_group(count_tag))
+            .or_else(|| self.header.find_group(count_tag))
+            .or_else(|| self.trailer.find_group(count_tag))?;
+
+        Some(group.to_fix_group_definition())
+    }
+}
+
+/// Dictionaries of a FIXT.1.1 session: the transport dictionary defines the header, the
+/// trailer and the session messages, the application dictionaries define the other messages
+/// of every application version the session supports.
+#[derive(Debug, Clone)]
+pub struct FixtDictionaries {
+    transport: DataDictionary,
+    applications: HashMap<ApplVerID, DataDictionary>,
+}
+
+impl FixtDictionaries {
+    pub fn new(transport: DataDictionary) -> Self {
+        Self {
+            transport,
+            applications: HashMap::new(),
+        }
+    }
+
+    pub fn with_application(mut self, appl_ver_id: ApplVerID, dictionary: DataDictionary) -> Self {
+        self.applications.insert(appl_ver_id, dictionary);
+        self
+    }
+
+    pub fn get_transport(&self) -> &DataDictionary {
+        &self.transport
+    }
+
+    pub fn get_application(&self, appl_ver_id: &ApplVerID) -> Option<&DataDictionary> {
+        self.applications.get(appl_ver_id)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldDefinition {
+    tag: Tag,
+    name: String,
+    data_type: FixDataType,
+    values: Vec<FieldValueDefinition>,
+}
+
+impl FieldDefinition {
+    pub fn get_tag(&self) -> Tag {
+        self.tag
+    }
+
+    pub fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn get_data_type(&self) -> FixDataType {
+        self.data_type
+    }
+
+    /// Allowed values of the field. Empty if the field accepts any value of its type.
+    pub fn get_values(&self) -> &[FieldValueDefinition] {
+        self.values.as_slice()
+    }
+
+    pub fn has_values(&self) -> bool {
+        !self.values.is_empty()
+    }
+
+    pub fn get_value_description(&self, value: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|itm| itm.value == value)
+            .map(|itm| itm.description.as_str())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldValueDefinition {
+    value: String,
+    description: String,
+}
+
+impl FieldValueDefinition {
+    pub fn get_value(&self) -> &str {
+        self.value.as_str()
+    }
+
+    pub fn get_description(&self) -> &str {
+        self.description.as_str()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MessageDefinition {
+    name: String,
+    msg_type: String,
+    category: String,
+    layout: MessageLayout,
+}
+
+impl MessageDefinition {
+    pub fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn get_msg_type(&self) -> &str {
+        self.msg_type.as_str()
+    }
+
+    /// `admin` for session level messages, `app` for the others
+    pub fn get_category(&self) -> &str {
+        self.category.as_str()
+    }
+
+    pub fn is_admin(&self) -> bool {
+        self.category == "admin"
+    }
+
+    pub fn get_layout(&self) -> &MessageLayout {
+        &self.layout
+    }
+}
+
+/// Fields and groups of a message, header, trailer or group entry in the declared order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageLayout {
+    items: Vec<LayoutItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutItem {
+    Field { tag: Tag, required: bool },
+    Group(GroupLayout),
+}
+
+impl LayoutItem {
+    /// Tag of the field or NumInGroup tag of the group
+    pub fn get_tag(&self) -> Tag {
+        match self {
+            Self::Field { tag, .. } => *tag,
+            Self::Group(group) => group.count_tag,
+        }
+    }
+
+    pub fn is_required(&self) -> bool {
+        match self {
+            Self::Field { required, .. } => *required,
+            Self::Group(group) => group.required,
+        }
+    }
+}
+
+impl MessageLayout {
+    pub fn get_items(&self) -> &[LayoutItem] {
+        self.items.as_slice()
+    }
+
+    /// Checks the fields and groups of this level, the fields of the groups are not checked
+    pub fn contains_tag(&self, tag: Tag) -> bool {
+        self.get_item(tag).is_some()
+    }
+
+    pub fn get_item(&self, tag: Tag) -> Option<&LayoutItem> {
+        self.items.iter().find(|itm| itm.get_tag() == tag)
+    }
+
+    pub fn get_required_tags(&self) -> impl Iterator<Item = Tag> + '_ {
+        self.items
+            .iter()
+            .filter(|itm| itm.is_required())
+            .map(|itm| itm.get_tag())
+    }
+
+    /// Finds the group of this level or a nested one
+    pub fn find_group(&self, count_tag: Tag) -> Option<&GroupLayout> {
+        for itm in &self.items {
+            if let LayoutItem::Group(group) = itm {
+                if group.count_tag == count_tag {
+                    return Some(group);
+                }
+
+                if let Some(result) = group.layout.find_group(count_tag) {
+                    return Some(result);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupLayout {
+    count_tag: Tag,
+    required: bool,
+    layout: MessageLayout,
+}
+
+impl GroupLayout {
+    pub fn get_count_tag(&self) -> Tag {
+        self.count_tag
+    }
+
+    pub fn is_required(&self) -> bool {
+        self.required
+    }
+
+    /// First field of the entry which starts every entry of the group
+    pub fn get_delimiter_tag(&self) -> Tag {
+        self.layout.items[0].get_tag()
+    }
+
+    /// Layout of a single entry
+    pub fn get_layout(&self) -> &MessageLayout {
+        &self.layout
+    }
+
+    pub fn to_fix_group_definition(&self) -> FixGroupDefinition {
+        let mut result = FixGroupDefinition::new(self.count_tag, self.get_delimiter_tag());
+
+        for itm in &self.layout.items {
+            result = match itm {
+                LayoutItem::Field { tag, .. } => result.with_tags(&[*tag]),
+                LayoutItem::Group(group) => result.with_group(group.to_fix_group_definition()),
+            };
+        }
+
+        result
+    }
+}
+
+struct LayoutReader<'d> {
+    field_tags: &'d HashMap<String, Tag>,
+    components: HashMap<&'d str, &'d XmlElement>,
+}
+
+impl<'d> LayoutReader<'d> {
+    fn read_layout(&self, element: &XmlElement) -> Result<MessageLayout, DataDictionaryError> {
+        let mut items = Vec::new();
+        self.read_items(element, true, &mut Vec::new(), &mut items)?;
+        Ok(MessageLayout { items })
+    }
+
+    // Fields of a component which is not required are not required as well
+    fn read_items<'e>(
+        &self,
+        element: &'e XmlElement,
+        parent_required: bool,
+        components_stack: &mut Vec<&'e str>,
+        items: &mut Vec<LayoutItem>,
+    ) -> Result<(), DataDictionaryError>
+    where
+        'd: 'e,
+    {
+        for child in &element.children {
+            if !matches!(child.name.as_str(), "field" | "group" | "component") {
+                continue;
+            }
+
+            let name = read_attribute(child, "name")?;
+            let required = parent_required && child.get_attribute("required") == Some("Y");
+
+            match child.name.as_str() {
+                "field" => items.push(LayoutItem::Field {
+                    tag: self.get_field_tag(name)?,
+                    required,
+                }),
+                "group" => {
+                    let mut group_items = Vec::new();
+                    self.read_items(child, true, components_stack, &mut group_items)?;
+
+                    if group_items.is_empty() {
+                        return Err(DataDictionaryError::EmptyGroup(name.to_string()));
+                    }
+
+                    items.push(LayoutItem::Group(GroupLayout {
+                        count_tag: self.get_field_tag(name)?,
+                        required,
+                        layout: MessageLayout { items: group_items },
+                    }));
+                }
+                "component" => {
+                    let Some(component) = self.components.get(name) else {
+                        return Err(DataDictionaryError::UnknownComponent(name.to_string()));
+                    };
+
+                    if components_stack.contains(&name) {
+                        return Err(DataDictionaryError::RecursiveComponent(name.to_string()));
+                    }
+
+                    components_stack.push(name);
+                    self.read_items(component, required, components_stack, items)?;
+                    components_stack.pop();
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_field_tag(&self, name: &str) -> Result<Tag, DataDictionaryError> {
+        match self.field_tags.get(name) {
+            Some(tag) => Ok(*tag),
+            None => Err(DataDictionaryError::UnknownField(name.to_string())),
+        }
+    }
+}
+
+fn read_fields(
+    element: &XmlElement,
+) -> Result<BTreeMap<Tag, FieldDefinition>, DataDictionaryError> {
+    let mut result = BTreeMap::new();
+
+    for field in &element.children {
+        let name = read_attribute(field, "name")?;
+        let data_type_name = read_attribute(field, "type")?;
+
+        let Some(data_type) = FixDataType::from_name(data_type_name) else {
+            return Err(DataDictionaryError::UnknownDataType {
+                field: name.to_string(),
+                data_type: data_type_name.to_string(),
+            });
+        };
+
+        let mut values = Vec::new();
+        for value in &field.children {
+            values.push(FieldValueDefinition {
+                value: read_attribute(value, "enum")?.to_string(),
+                description: value.get_attribute("description").unwrap_or("").to_string(),
+            });
+        }
+
+        let tag = Tag(read_number_attribute(field, "number")?);
+
+        result.insert(
+            tag,
+            FieldDefinition {
+                tag,
+                name: name.to_string(),
+                data_type,
+                values,
+            },
+        );
+    }
+
+    Ok(result)
+}
+
+fn read_attribute<'e>(
+    element: &'e XmlElement,
+    attribute: &'static str,
+) -> Result<&'e str, DataDictionaryError> {
+    match element.get_attribute(attribute) {
+        Some(value) => Ok(value),
+        None => Err(DataDictionaryError::MissingAttribute {
+            element: element.name.clone(),
+            attribute,
+        }),
+    }
+}
+
+fn read_data_fields(
+    fields: &BTreeMap<Tag, FieldDefinition>,
+    field_tags: &HashMap<String, Tag>,
+) -> Vec<(Tag, Tag)> {
+    fields
+        .values()
+        .filter(|field| matches!(field.data_type, FixDataType::Data | FixDataType::XmlData))
+        .filter_map(|field| {
+            let length_tag = ["Length", "Len"].into_iter().find_map(|suffix| {
+                let tag = field_tags.get(&format!("{}{}", field.name, suffix))?;
+                let length = fields.get(tag)?;
+                (length.data_type == FixDataType::Length).then_some(*tag)
+            })?;
+
+            Some((length_tag, field.tag))
+        })
+        .collect()
+}
+
+fn read_number_attribute(
+    element: &XmlElement,
+    attribute: &'static str,
+) -> Result<u32, DataDictionaryError> {
+    let value = read_attribute(element, attribute)?;
+
+    match value.parse() {
+        Ok(result) => Ok(result),
+        Err(_) => Err(DataDictionaryError::InvalidAttribute {
+            element: element.name.clone(),
+            attribute,
+            value: value.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tags, DataDictionary, DataDictionaryError, FixDataType, FixMessageReader};
+
+    const FIX44_SAMPLE: &str = r#"
+    <fix major="4" minor="4" servicepack="0">
+        <header>
+            <field name="BeginString" required="Y"/>
+            <field name="BodyLength" required="Y"/>
+            <field name="MsgType" required="Y"/>
+        </header>
+        <trailer>
+            <field name="CheckSum" required="Y"/>
+        </trailer>
+        <messages>
+            <message name="NewOrderSingle" msgtype="D" msgcat="app">
+                <field name="ClOrdID" required="Y"/>
+                <component name="Parties" required="N"/>
+                <component name="Instrument" required="Y"/>
+                <field name="Side" required="Y"/>
+                <field name="OrderQty" required="N"/>
+            </message>
+        </messages>
+        <components>
+            <component name="Instrument">
+                <field name="Symbol" required="Y"/>
+                <group name="NoSecurityAltID" required="N">
+                    <field name="SecurityAltID" required="N"/>
+                    <field name="SecurityAltIDSource" required="N"/>
+                </group>
+            </component>
+            <component name="Parties">
+                <group name="NoPartyIDs" required="N">
+                    <field name="PartyID" required="N"/>
+                    <field name="PartyIDSource" required="N"/>
+                    <field name="PartyRole" required="N"/>
+                    <component name="PtysSubGrp" required="N"/>
+                </group>
+            </component>
+            <component name="PtysSubGrp">
+                <group name="NoPartySubIDs" required="N">
+                    <field name="PartySubID" required="Y"/>
+                    <field name="PartySubIDType" required="Y"/>
+                </group>
+            </component>
+        </components>
+        <fields>
+            <field number="8" name="BeginString" type="STRING"/>
+            <field number="9" name="BodyLength" type="LENGTH"/>
+            <field number="10" name="CheckSum" type="STRING"/>
+            <field number="11" name="ClOrdID" type="STRING"/>
+            <field number="35" name="MsgType" type="STRING"/>
+            <field number="38" name="OrderQty" type="QTY"/>
+            <field number="54" name="Side" type="CHAR">
+                <value enum="1" description="BUY"/>
+                <value enum="2" description="SELL"/>
+            </field>
+            <field number="55" name="Symbol" type="STRING"/>
+            <field number="448" name="PartyID" type="STRING"/>
+            <field number="447" name="PartyIDSource" type="CHAR"/>
+            <field number="452" name="PartyRole" type="INT"/>
+            <field number="453" name="NoPartyIDs" type="NUMINGROUP"/>
+            <field number="454" name="NoSecurityAltID" type="NUMINGROUP"/>
+            <field number="455" name="SecurityAltID" type="STRING"/>
+            <field number="456" name="SecurityAltIDSource" type="STRING"/>
+            <field number="523" name="PartySubID" type="STRING"/>
+            <field number="802" name="NoPartySubIDs" type="NUMINGROUP"/>
+            <field number="803" name="PartySubIDType" type="INT"/>
+        </fields>
+    </fix>"#;
+
+    #[test]
+    fn test_fields() {
+        let dictionary = DataDictionary::parse(FIX44_SAMPLE).unwrap();
+
+        assert_eq!("FIX.4.4", dictionary.get_version());
+
+        let side = dictionary.get_field(tags::SIDE).unwrap();
+        assert_eq!("Side", side.get_name());
+        assert_eq!(FixDataType::Char, side.get_data_type());
+        assert_eq!(2, side.get_values().len());
+        assert_eq!(Some("SELL"), side.get_value_description("2"));
+
+        let qty = dictionary.get_field_by_name("OrderQty").unwrap();
+        assert_eq!(tags::ORDER_QTY, qty.get_tag());
+        assert!(!qty.has_values());
+    }
+
+    #[test]
+    fn test_message_layout_with_components() {
+        let dictionary = DataDictionary::parse(FIX44_SAMPLE).unwrap();
+
+        let message = dictionary.get_message("D").unwrap();
+        assert_eq!("NewOrderSingle", message.get_name());
+        assert!(!message.is_admin());
+
+        let layout = message.get_layout();
+        let tags_in_order: Vec<_> = layout.get_items().iter().map(|itm| itm.get_tag()).collect();
+        assert_eq!(
+            vec![
+                tags::CL_ORD_ID,
+                tags::NO_PARTY_IDS,
+                tags::SYMBOL,
+                tags::NO_SECURITY_ALT_ID,
+                tags::SIDE,
+                tags::ORDER_QTY
+            ],
+            tags_in_order
+        );
+
+        let required: Vec<_> = layout.get_required_tags().collect();
+        assert_eq!(vec![tags::CL_ORD_ID, tags::SYMBOL, tags::SIDE], required);
+
+        let sub_ids = layout.find_group(tags::NO_PARTY_SUB_IDS).unwrap();
+        assert_eq!(tags::PARTY_SUB_ID, sub_ids.get_delimiter_tag());
+        assert!(sub_ids
+            .get_layout()
+            .get_item(tags::PARTY_SUB_ID_TYPE)
+            .unwrap()
+            .is_required());
+
+        assert!(dictionary.is_header_field(tags::MSG_TYPE));
+        assert!(dictionary.is_trailer_field(tags::CHECK_SUM));
+        assert!(!dictionary.is_header_field(tags::SIDE));
+    }
+
+    #[test]
+    fn test_group_definition_for_reader() {
+        let dictionary = DataDictionary::parse(FIX44_SAMPLE).unwrap();
+        let definition = dictionary
+            .get_group_definition("D", tags::NO_PARTY_IDS)
+            .unwrap();
+
+        let fix_string = "8=FIX.4.4|9=20|35=D|11=ORDER1|453=2|448=BROKER1|452=1|802=1|523=DESK|803=9|448=TRADER1|452=11|55=AAPL|54=1|10=000|";
+        let reader = FixMessageReader::from_str(fix_string);
+        let parties = reader.get_group(&definition).unwrap();
+
+        assert_eq!(2, parties.len());
+        assert_eq!(1, parties[0].get_group(tags::NO_PARTY_SUB_IDS).len());
+        assert_eq!(Some("TRADER1"), parties[1].get_value(tags::PARTY_ID));
+    }
+
+    #[test]
+    fn test_embedded_fixt11() {
+        let dictionary = DataDictionary::fixt11();
+
+        assert_eq!("FIXT.1.1", dictionary.get_version());
+
+        let logon = dictionary.get_message("A").unwrap();
+        assert!(logon.is_admin());
+        assert!(logon
+            .get_layout()
+            .get_item(tags::DEFAULT_APPL_VER_ID)
+            .unwrap()
+            .is_required());
+
+        assert!(dictionary
+            .get_group_definition("0", tags::NO_HOPS)
+            .is_some());
+        assert_eq!(
+            Some("FIX50SP2"),
+            dictionary
+                .get_field(tags::APPL_VER_ID)
+                .unwrap()
+                .get_value_description("9")
+        );
+
+        let data_fields = dictionary.get_data_fields();
+        assert!(data_fields.contains(&(tags::RAW_DATA_LENGTH, tags::RAW_DATA)));
+        assert!(data_fields.contains(&(tags::XML_DATA_LEN, tags::XML_DATA)));
+        assert!(data_fields.contains(&(tags::ENCRYPTED_PASSWORD_LEN, tags::ENCRYPTED_PASSWORD)));
+        assert!(!data_fields
+            .iter()
+            .any(|(length_tag, _)| *length_tag == tags::BODY_LENGTH));
+    }
+
+    #[test]
+    fn test_errors() {
+        let result = DataDictionary::parse(
+            r#"<fix major="4" minor="4"><messages><message name="X" msgtype="X"><field name="Unknown" required="Y"/></message></messages><fields/></fix>"#,
+        );
+        assert!(
+            matches!(result, Err(DataDictionaryError::UnknownField(name)) if name == "Unknown")
+        );
+
+        let result = DataDictionary::parse(
+            r#"<fix major="4" minor="4"><fields><field number="1" name="Account" type="NOPE"/></fields></fix>"#,
+        );
+        assert!(matches!(
+            result,
+            Err(DataDictionaryError::UnknownDataType { .. })
+        ));
+
+        let result = DataDictionary::parse(
+            r#"<fix major="4" minor="4"><header><component name="A" required="Y"/></header><components><component name="A"><component name="A" required="Y"/></component></components><fields/></fix>"#,
+        );
+        assert!(matches!(
+            result,
+            Err(DataDictionaryError::RecursiveComponent(_))
+        ));
+
+        assert!(matches!(
+            DataDictionary::load("/not/existing/FIX44.xml"),
+            Err(DataDictionaryError::Io(_))
+        ));
+    }
+}
@@ -1,16 +1,87 @@
+use crate::{FixDataType, Tag};
+
 #[derive(Debug)]
 pub enum FixSerializeError {
     VersionTagNotFound,
     MessageTypeTagNotFound,
     CheckSumTagNotFound,
     InvalidCheckSum,
-    FixDelimiterNotFound,
+    FixDelimiterNotFound {
+        offset: usize,
+    },
     BodyLenTagNotFound,
+    GarbageBeforeHeader {
+        skipped: usize,
+    },
+    BodyLenTooLarge {
+        declared: usize,
+        max: usize,
+    },
+    MissingEquals {
+        offset: usize,
+    },
+    EmptyTag {
+        offset: usize,
+    },
+    NonNumericTag {
+        offset: usize,
+    },
+    InvalidUtf8 {
+        offset: usize,
+    },
+    InvalidBodyLenValue,
+    InvalidBodyLength {
+        declared: usize,
+        actual: usize,
+    },
+    TagOutOfOrder {
+        tag: Tag,
+        expected: usize,
+        actual: usize,
+    },
+    InvalidFieldValue {
+        tag: Tag,
+        value: String,
+        data_type: FixDataType,
+    },
+    GroupCountMismatch {
+        tag: Tag,
+        declared: usize,
+        actual: usize,
+    },
+    GroupFieldOutOfOrder {
+        tag: Tag,
+    },
+    /// Field other than the delimiter appears twice in one entry of a group
+    GroupFieldRepeated {
+        tag: Tag,
+    },
+    RequiredTagMissing {
+        tag: Tag,
+    },
+    /// Value of the data field is not followed by the delimiter after the number of bytes
+    /// declared by its length field
+    DataLengthMismatch {
+        tag: Tag,
+        offset: usize,
+    },
 }
 
 impl FixSerializeError {
+    /// Byte offset inside the message where the problem was found
+    pub fn get_offset(&self) -> Option<usize> {
+        match self {
+            Self::FixDelimiterNotFound { offset }
+            | Self::MissingEquals { offset }
+            | Self::EmptyTag { offset }
+            | Self::NonNumericTag { offset }
+            | Self::InvalidUtf8 { offset }
+            | Self::DataLengthMismatch { offset, .. } => Some(*offset),
+            _ => None,
+        }
+    }
+
     pub fn is_version_tag_not_found(&self) -> bool {
-        println!("{:?}", self);
         match self {
             Self::VersionTagNotFound => true,
             _ => false,
@@ -37,4 +108,164 @@ impl FixSerializeError {
             _ => false,
         }
     }
+
+    pub fn is_body_len_tag_not_found(&self) -> bool {
+        matches!(self, Self::BodyLenTagNotFound)
+    }
+
+    pub fn is_garbage_before_header(&self) -> bool {
+        matches!(self, Self::GarbageBeforeHeader { .. })
+    }
+
+    pub fn is_body_len_too_large(&self) -> bool {
+        matches!(self, Self::BodyLenTooLarge { .. })
+    }
+
+    pub fn is_empty_tag(&self) -> bool {
+        matches!(self, Self::EmptyTag { .. })
+    }
+
+    pub fn is_non_numeric_tag(&self) -> bool {
+        matches!(self, Self::NonNumericTag { .. })
+    }
+
+    pub fn is_invalid_body_length(&self) -> bool {
+        matches!(self, Self::InvalidBodyLength { .. })
+    }
+
+    pub fn is_invalid_field_value(&self) -> bool {
+        matches!(self, Self::InvalidFieldValue { .. })
+    }
+
+    pub fn is_tag_out_of_order(&self) -> bool {
+        matches!(self, Self::TagOutOfOrder { .. })
+    }
+
+    pub fn is_group_count_mismatch(&self) -> bool {
+        matches!(self, Self::GroupCountMismatch { .. })
+    }
+
+    pub fn is_group_field_out_of_order(&self) -> bool {
+        matches!(self, Self::GroupFieldOutOfOrder { .. })
+    }
+
+    pub fn is_group_field_repeated(&self) -> bool {
+        matches!(self, Self::GroupFieldRepeated { .. })
+    }
+
+    pub fn is_required_tag_missing(&self) -> bool {
+        matches!(self, Self::RequiredTagMissing { .. })
+    }
+
+    pub fn is_data_length_mismatch(&self) -> bool {
+        matches!(self, Self::DataLengthMismatch { .. })
+    }
+}
+
+/// Problems found while loading a data dictionary
+#[derive(Debug)]
+pub enum DataDictionaryError {
+    Io(std::io::Error),
+    InvalidXml {
+        offset: usize,
+        reason: &'static str,
+    },
+    MissingElement(&'static str),
+    MissingAttribute {
+        element: String,
+        attribute: &'static str,
+    },
+    InvalidAttribute {
+        element: String,
+        attribute: &'static str,
+        value: String,
+    },
+    UnknownDataType {
+        field: String,
+        data_type: String,
+    },
+    UnknownField(String),
+    UnknownComponent(String),
+    RecursiveComponent(String),
+    EmptyGroup(String),
+}
+
+impl From<std::io::Error> for DataDictionaryError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Problems found while loading [`crate::SessionSettings`].
+/// Validation errors name the section (`SESSION 1`, ...) and the key.
+#[derive(Debug)]
+pub enum SessionSettingsError {
+    Io(std::io::Error),
+    InvalidLine {
+        line: usize,
+    },
+    UnknownSection {
+        line: usize,
+        name: String,
+    },
+    MissingKey {
+        section: String,
+        key: &'static str,
+    },
+    InvalidValue {
+        section: String,
+        key: &'static str,
+        value: String,
+    },
+    DuplicateSession {
+        section: String,
+        other_section: String,
+    },
+}
+
+impl SessionSettingsError {
+    /// Section the problem was found in
+    pub fn get_section(&self) -> Option<&str> {
+        match self {
+            Self::MissingKey { section, .. }
+            | Self::InvalidValue { section, .. }
+            | Self::DuplicateSession { section, .. } => Some(section),
+            _ => None,
+        }
+    }
+
+    pub fn get_key(&self) -> Option<&str> {
+        match self {
+            Self::MissingKey { key, .. } | Self::InvalidValue { key, .. } => Some(key),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for SessionSettingsError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+#[derive(Debug)]
+pub enum FixSessionError {
+    NotLoggedOn,
+    Store(std::io::Error),
+    /// Task which runs the session is stopped
+    Closed,
+}
+
+impl FixSessionError {
+    pub fn is_not_logged_on(&self) -> bool {
+        matches!(self, Self::NotLoggedOn)
+    }
+
+    pub fn is_store(&self) -> bool {
+        matches!(self, Self::Store(_))
+    }
+
+    pub fn is_closed(&self) -> bool {
+        matches!(self, Self::Closed)
+    }
 }
@@ -0,0 +1,526 @@
+use std::{
+    collections::BTreeMap,
+    fs::{File, OpenOptions},
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::{MessageStore, UtcTimestamp};
+
+// Offset and size of every message in the body file
+type Index = BTreeMap<u64, (u64, usize)>;
+
+/// When the written data is flushed from the OS cache to the disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FsyncPolicy {
+    /// Left to the OS. Survives a crash of the process, but not of the machine.
+    #[default]
+    Never,
+    /// After every message and every sequence number update
+    Always,
+    /// On the first write after the interval has passed since the previous flush
+    Interval(Duration),
+}
+
+/// Message store kept in files, using the same layout as QuickFIX:
+///
+/// - `<prefix>.body` - compiled messages, one after another;
+/// - `<prefix>.header` - `seq_num,offset,size ` entries which point into the body file;
+/// - `<prefix>.seqnums` - `sender : target` next sequence numbers;
+/// - `<prefix>.session` - creation time of the session as UTCTimestamp.
+///
+/// A write torn by a crash is dropped on open: the incomplete last header entry and the
+/// body bytes which are not referenced by the header are truncated, so the store keeps
+/// every message written before.
+pub struct FileMessageStore {
+    body_path: PathBuf,
+    header_path: PathBuf,
+    seqnums_path: PathBuf,
+    session_path: PathBuf,
+    body: File,
+    header: File,
+    seqnums: File,
+    session: File,
+    index: Index,
+    next_sender_seq_num: u64,
+    next_target_seq_num: u64,
+    creation_time: SystemTime,
+    fsync_policy: FsyncPolicy,
+    last_sync: Instant,
+}
+
+impl FileMessageStore {
+    /// Opens the files `<prefix>.body`, `<prefix>.header`, `<prefix>.seqnums` and
+    /// `<prefix>.session`, creating them if needed.
+    /// The prefix usually names the session: `store/FIX.4.4-CLIENT-VENUE`.
+    pub fn open(prefix: impl AsRef<Path>) -> io::Result<Self> {
+        let prefix = prefix.as_ref();
+        let body_path = add_extension(prefix, "body");
+        let header_path = add_extension(prefix, "header");
+        let seqnums_path = add_extension(prefix, "seqnums");
+        let session_path = add_extension(prefix, "session");
+
+        let body = open_log_file(&body_path)?;
+        let mut header = open_log_file(&header_path)?;
+        let mut seqnums = open_file(&seqnums_path)?;
+        let mut session = open_file(&session_path)?;
+
+        let mut content = Vec::new();
+        header.read_to_end(&mut content)?;
+
+        let body_len = body.metadata()?.len();
+        let (index, header_len) = read_index(&content, body_len).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid entries in {}", header_path.display()),
+            )
+        })?;
+
+        if header_len < content.len() as u64 {
+            header.set_len(header_len)?;
+        }
+
+        let body_end = index
+            .values()
+            .map(|(offset, size)| offset + *size as u64)
+            .max()
+            .unwrap_or(0);
+
+        if body_end < body_len {
+            body.set_len(body_end)?;
+        }
+
+        let last_stored = index.keys().next_back().copied().unwrap_or(0);
+
+        let (next_sender_seq_num, next_target_seq_num) = match read_seqnums(&mut seqnums)? {
+            Some((sender, target)) => (sender.max(last_stored + 1), target),
+            // Counters are lost, but the stored messages show what was sent
+            None => (last_stored + 1, 1),
+        };
+
+        let creation_time = match read_creation_time(&mut session)? {
+            Some(creation_time) => creation_time,
+            None => {
+                let now = SystemTime::now();
+                write_creation_time(&mut session, now)?;
+                now
+            }
+        };
+
+        let mut result = Self {
+            body_path,
+            header_path,
+            seqnums_path,
+            session_path,
+            body,
+            header,
+            seqnums,
+            session,
+            index,
+            next_sender_seq_num,
+            next_target_seq_num,
+            creation_time,
+            fsync_policy: FsyncPolicy::default(),
+            last_sync: Instant::now(),
+        };
+
+        result.write_seqnums()?;
+        Ok(result)
+    }
+
+    pub fn with_fsync_policy(mut self, fsync_policy: FsyncPolicy) -> Self {
+        self.fsync_policy = fsync_policy;
+        self
+    }
+
+    pub fn get_fsync_policy(&self) -> FsyncPolicy {
+        self.fsync_policy
+    }
+
+    pub fn get_body_path(&self) -> &Path {
+        &self.body_path
+    }
+
+    pub fn get_header_path(&self) -> &Path {
+        &self.header_path
+    }
+
+    pub fn get_seqnums_path(&self) -> &Path {
+        &self.seqnums_path
+    }
+
+    pub fn get_session_path(&self) -> &Path {
+        &self.session_path
+    }
+
+    /// Flushes all the files to the disk regardless of the policy
+    pub fn sync(&mut self) -> io::Result<()> {
+        self.body.sync_data()?;
+        self.header.sync_data()?;
+        self.seqnums.sync_data()?;
+        self.session.sync_data()?;
+        self.last_sync = Instant::now();
+        Ok(())
+    }
+
+    fn sync_by_policy(&mut self) -> io::Result<()> {
+        match self.fsync_policy {
+            FsyncPolicy::Never => Ok(()),
+            FsyncPolicy::Always => self.sync(),
+            FsyncPolicy::Interval(interval) => {
+                if self.last_sync.elapsed() >= interval {
+                    self.sync()
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    // Numbers are rewritten in place, zero padding keeps the size of the file
+    fn write_seqnums(&mut self) -> io::Result<()> {
+        let content = format!(
+            "{:010} : {:010}",
+            self.next_sender_seq_num, self.next_target_seq_num
+        );
+
+        overwrite_file(&mut self.seqnums, content.as_bytes())
+    }
+}
+
+impl MessageStore for FileMessageStore {
+    fn set(&mut self, seq_num: u64, message: &[u8]) -> io::Result<()> {
+        let offset = self.body.seek(SeekFrom::End(0))?;
+        self.body.write_all(message)?;
+
+        write!(self.header, "{},{},{} ", seq_num, offset, message.len())?;
+
+        self.index.insert(seq_num, (offset, message.len()));
+        self.sync_by_policy()
+    }
+
+    fn get_range(&self, begin: u64, end: u64) -> io::Result<Vec<(u64, Vec<u8>)>> {
+        let mut result = Vec::new();
+
+        if begin > end {
+            return Ok(result);
+        }
+
+        let mut body = &self.body;
+
+        for (seq_num, (offset, size)) in self.index.range(begin..=end) {
+            let mut message = vec![0u8; *size];
+            body.seek(SeekFrom::Start(*offset))?;
+            body.read_exact(&mut message)?;
+            result.push((*seq_num, message));
+        }
+
+        Ok(result)
+    }
+
+    fn get_next_sender_seq_num(&self) -> u64 {
+        self.next_sender_seq_num
+    }
+
+    fn get_next_target_seq_num(&self) -> u64 {
+        self.next_target_seq_num
+    }
+
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> io::Result<()> {
+        self.next_sender_seq_num = seq_num;
+        self.write_seqnums()?;
+        self.sync_by_policy()
+    }
+
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> io::Result<()> {
+        self.next_target_seq_num = seq_num;
+        self.write_seqnums()?;
+        self.sync_by_policy()
+    }
+
+    fn get_creation_time(&self) -> SystemTime {
+        self.creation_time
+    }
+
+    fn reset(&mut self, creation_time: SystemTime) -> io::Result<()> {
+        self.body.set_len(0)?;
+        self.header.set_len(0)?;
+        self.index.clear();
+
+        self.next_sender_seq_num = 1;
+        self.next_target_seq_num = 1;
+        self.write_seqnums()?;
+
+        write_creation_time(&mut self.session, creation_time)?;
+        self.creation_time = creation_time;
+
+        self.sync_by_policy()
+    }
+}
+
+// Body and header are only appended
+fn open_log_file(path: &Path) -> io::Result<File> {
+    OpenOptions::new()
+        .read(true)
+        .append(true)
+        .create(true)
+        .open(path)
+}
+
+// Seqnums and session are rewritten
+fn open_file(path: &Path) -> io::Result<File> {
+    OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+fn overwrite_file(file: &mut File, content: &[u8]) -> io::Result<()> {
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(content)?;
+    file.set_len(content.len() as u64)
+}
+
+fn add_extension(prefix: &Path, extension: &str) -> PathBuf {
+    let mut result = prefix.as_os_str().to_os_string();
+    result.push(".");
+    result.push(extension);
+    PathBuf::from(result)
+}
+
+// Returns the index and the length of the header which holds valid entries.
+// Only the last entry may be broken, otherwise the files are corrupted.
+fn read_index(content: &[u8], body_len: u64) -> Option<(Index, u64)> {
+    let mut index = BTreeMap::new();
+    let mut valid_len = 0;
+
+    // Entry without the trailing space is not complete
+    let complete_len = content
+        .iter()
+        .rposition(|b| *b == b' ')
+        .map_or(0, |position| position + 1);
+
+    let mut entries = content[..complete_len]
+        .split_inclusive(|b| *b == b' ')
+        .peekable();
+
+    while let Some(entry) = entries.next() {
+        let parsed = std::str::from_utf8(&entry[..entry.len() - 1])
+            .ok()
+            .and_then(parse_header_entry)
+            .filter(|(_, offset, size)| offset + *size as u64 <= body_len);
+
+        match parsed {
+            Some((seq_num, offset, size)) => {
+                index.insert(seq_num, (offset, size));
+                valid_len += entry.len();
+            }
+            None if entries.peek().is_none() => break,
+            None => return None,
+        }
+    }
+
+    Some((index, valid_len as u64))
+}
+
+fn parse_header_entry(entry: &str) -> Option<(u64, u64, usize)> {
+    let mut parts = entry.split(',');
+    let seq_num = parts.next()?.parse().ok()?;
+    let offset = parts.next()?.parse().ok()?;
+    let size = parts.next()?.parse().ok()?;
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some((seq_num, offset, size))
+}
+
+// `None` if the file is new or its content is torn
+fn read_seqnums(file: &mut File) -> io::Result<Option<(u64, u64)>> {
+    let mut content = String::new();
+    file.seek(SeekFrom::Start(0))?;
+    if file.read_to_string(&mut content).is_err() {
+        return Ok(None);
+    }
+
+    let Some((sender, target)) = content.split_once(" : ") else {
+        return Ok(None);
+    };
+
+    match (sender.trim().parse(), target.trim().parse()) {
+        (Ok(sender), Ok(target)) if sender > 0 && target > 0 => Ok(Some((sender, target))),
+        _ => Ok(None),
+    }
+}
+
+fn read_creation_time(file: &mut File) -> io::Result<Option<SystemTime>> {
+    let mut content = String::new();
+    file.seek(SeekFrom::Start(0))?;
+    if file.read_to_string(&mut content).is_err() {
+        return Ok(None);
+    }
+
+    Ok(UtcTimestamp::parse(content.trim()).map(|value| value.to_system_time()))
+}
+
+fn write_creation_time(file: &mut File, creation_time: SystemTime) -> io::Result<()> {
+    let timestamp = UtcTimestamp::from_system_time(creation_time).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Creation time is out of FIX range",
+        )
+    })?;
+
+    overwrite_file(file, timestamp.to_string().as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs::OpenOptions,
+        io::Write,
+        path::PathBuf,
+        time::{Duration, SystemTime},
+    };
+
+    use crate::{FileMessageStore, FsyncPolicy, MessageStore};
+
+    fn get_prefix(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rust-fix-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let prefix = dir.join(name);
+        for extension in ["body", "header", "seqnums", "session"] {
+            let _ = std::fs::remove_file(prefix.with_extension(extension));
+        }
+        prefix
+    }
+
+    fn append(path: &std::path::Path, data: &[u8]) {
+        let mut file = OpenOptions::new().append(true).open(path).unwrap();
+        file.write_all(data).unwrap();
+    }
+
+    #[test]
+    fn test_messages_survive_reopen() {
+        let prefix = get_prefix("reopen");
+
+        {
+            let mut store = FileMessageStore::open(&prefix).unwrap();
+            store
+                .set(1, b"8=FIX.4.4\x019=5\x0135=0\x0110=163\x01")
+                .unwrap();
+            store.set(2, b"second").unwrap();
+        }
+
+        let mut store = FileMessageStore::open(&prefix).unwrap();
+        assert_eq!(
+            vec![(2, b"second".to_vec())],
+            store.get_range(2, 2).unwrap()
+        );
+
+        store.set(3, b"third").unwrap();
+        assert_eq!(3, store.get_range(1, 3).unwrap().len());
+
+        store.reset(SystemTime::now()).unwrap();
+        assert!(store.get_range(1, 3).unwrap().is_empty());
+
+        let store = FileMessageStore::open(&prefix).unwrap();
+        assert!(store.get_range(1, 3).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_seq_nums_and_creation_time_survive_reopen() {
+        let prefix = get_prefix("seqnums");
+        let creation_time = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+
+        {
+            let mut store = FileMessageStore::open(&prefix)
+                .unwrap()
+                .with_fsync_policy(FsyncPolicy::Always);
+
+            assert_eq!(1, store.get_next_sender_seq_num());
+            assert_eq!(1, store.get_next_target_seq_num());
+
+            store.reset(creation_time).unwrap();
+            store.set_next_sender_seq_num(12).unwrap();
+            store.set_next_target_seq_num(34).unwrap();
+        }
+
+        let store = FileMessageStore::open(&prefix).unwrap();
+        assert_eq!(12, store.get_next_sender_seq_num());
+        assert_eq!(34, store.get_next_target_seq_num());
+        assert_eq!(creation_time, store.get_creation_time());
+        assert_eq!(
+            "0000000012 : 0000000034",
+            std::fs::read_to_string(store.get_seqnums_path()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_torn_last_write_is_dropped() {
+        let prefix = get_prefix("torn");
+
+        let (body_path, header_path) = {
+            let mut store = FileMessageStore::open(&prefix).unwrap();
+            store.set(1, b"first").unwrap();
+            store.set(2, b"second").unwrap();
+            store.set_next_sender_seq_num(3).unwrap();
+
+            (
+                store.get_body_path().to_path_buf(),
+                store.get_header_path().to_path_buf(),
+            )
+        };
+
+        // Crash in the middle of writing the third message
+        append(&body_path, b"thi");
+        append(&header_path, b"3,11,");
+
+        let mut store = FileMessageStore::open(&prefix).unwrap();
+        assert_eq!(2, store.get_range(1, 10).unwrap().len());
+        assert_eq!(3, store.get_next_sender_seq_num());
+        assert_eq!(11, std::fs::metadata(&body_path).unwrap().len());
+
+        store.set(3, b"third").unwrap();
+
+        let store = FileMessageStore::open(&prefix).unwrap();
+        assert_eq!(vec![(3, b"third".to_vec())], store.get_range(3, 3).unwrap());
+    }
+
+    #[test]
+    fn test_torn_seq_nums_are_recovered_from_header() {
+        let prefix = get_prefix("torn-seqnums");
+
+        let seqnums_path = {
+            let mut store = FileMessageStore::open(&prefix).unwrap();
+            store.set(1, b"first").unwrap();
+            store.set(2, b"second").unwrap();
+            store.get_seqnums_path().to_path_buf()
+        };
+
+        std::fs::write(&seqnums_path, b"00000").unwrap();
+
+        let store = FileMessageStore::open(&prefix).unwrap();
+        assert_eq!(3, store.get_next_sender_seq_num());
+        assert_eq!(1, store.get_next_target_seq_num());
+    }
+
+    #[test]
+    fn test_broken_entry_in_the_middle_is_an_error() {
+        let prefix = get_prefix("corrupted");
+
+        let header_path = {
+            let store = FileMessageStore::open(&prefix).unwrap();
+            store.get_header_path().to_path_buf()
+        };
+
+        std::fs::write(&header_path, b"1,0,5 garbage 2,5,6 ").unwrap();
+
+        assert!(FileMessageStore::open(&prefix).is_err());
+    }
+}
@@ -0,0 +1,496 @@
+use std::{
+    collections::HashMap,
+    io,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use tokio::{
+    io::{AsyncRead, AsyncReadExt},
+    net::{TcpListener, TcpStream, ToSocketAddrs},
+    sync::mpsc,
+    task::JoinHandle,
+};
+
+use crate::{
+    fix_connection::{self, AsyncStream, FixCommand},
+    fix_session_config,
+    fix_transport::{self, FirstFrame},
+    FixFrameDecoder, FixSender, FixSession, FixSessionConfig, FixSessionEvent, FixSessionId,
+    MessageStore, SessionClock,
+};
+
+type Connection = (Box<dyn AsyncStream>, FixFrameDecoder);
+
+// Pause before accepting again after a failed accept
+const ACCEPT_ERROR_DELAY: Duration = Duration::from_millis(100);
+
+/// Accepts the connections of many counterparties on one port.
+///
+/// Every [`FixSession`] runs in its own task for the whole life of the acceptor, so the
+/// sequence numbers are kept between connections. A connection is assigned to the session
+/// by BeginString, SenderCompID and TargetCompID of its first message. Connections of
+/// unknown sessions and second connections of a session which is already connected are
+/// closed without an answer. With the `tls` feature the connections can be required to use
+/// TLS, see [`Self::with_tls`].
+pub struct FixAcceptor<C: SessionClock, S: MessageStore> {
+    sessions: Vec<FixSession<C, S>>,
+    logon_timeout: Duration,
+    handshake: Handshake,
+}
+
+impl<C, S> FixAcceptor<C, S>
+where
+    C: SessionClock + Send + 'static,
+    S: MessageStore + Send + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            sessions: Vec::new(),
+            logon_timeout: FixSessionConfig::DEFAULT_LOGON_TIMEOUT,
+            handshake: Handshake::default(),
+        }
+    }
+
+    /// Adds the session the counterparty can log on to. The session has to be an acceptor.
+    pub fn with_session(mut self, session: FixSession<C, S>) -> Self {
+        self.sessions.push(session);
+        self
+    }
+
+    /// How long a new connection may stay without a complete first message. The TLS
+    /// handshake counts towards it.
+    pub fn with_logon_timeout(mut self, timeout: Duration) -> Self {
+        self.logon_timeout = timeout;
+        self
+    }
+
+    /// Accepts only TLS connections
+    #[cfg(feature = "tls")]
+    pub fn with_tls(mut self, tls: crate::TlsServerConfig) -> Self {
+        self.handshake.tls = Some(tls);
+        self
+    }
+
+    /// Starts to listen and spawns the tasks of the sessions. Must be called within a
+    /// tokio runtime. Fails with `InvalidInput` if a session is an initiator or two sessions
+    /// have the same id.
+    pub async fn bind(self, addr: impl ToSocketAddrs) -> io::Result<FixAcceptorHandle> {
+        fix_session_config::check_acceptor_sessions(
+            self.sessions.iter().map(|session| session.get_config()),
+        )?;
+
+        let listener = TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+
+        let (events, events_receiver) = mpsc::unbounded_channel();
+        let mut senders = HashMap::new();
+        let mut slots = HashMap::new();
+
+        for session in self.sessions {
+            let session_id = session.get_config().get_session_id();
+            let (sender, commands) = fix_connection::command_channel();
+            let (connections_sender, connections) = mpsc::unbounded_channel();
+            let connected = Arc::new(AtomicBool::new(false));
+
+            tokio::spawn(run_session(
+                session,
+                connections,
+                commands,
+                connected.clone(),
+                events.clone(),
+            ));
+
+            senders.insert(session_id.clone(), sender);
+            slots.insert(
+                session_id,
+                SessionSlot {
+                    connected,
+                    connections: connections_sender,
+                },
+            );
+        }
+
+        let listener = tokio::spawn(listen(
+            listener,
+            Arc::new(slots),
+            self.logon_timeout,
+            self.handshake,
+        ));
+
+        Ok(FixAcceptorHandle {
+            local_addr,
+            senders,
+            events: events_receiver,
+            listener,
+        })
+    }
+}
+
+impl<C, S> Default for FixAcceptor<C, S>
+where
+    C: SessionClock + Send + 'static,
+    S: MessageStore + Send + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Running [`FixAcceptor`]. Events of all the sessions are received together with the id
+/// of the session. Dropping the handle stops accepting new connections.
+#[derive(Debug)]
+pub struct FixAcceptorHandle {
+    local_addr: SocketAddr,
+    senders: HashMap<FixSessionId, FixSender>,
+    events: mpsc::UnboundedReceiver<(FixSessionId, FixSessionEvent)>,
+    listener: JoinHandle<()>,
+}
+
+impl FixAcceptorHandle {
+    pub fn get_local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    pub fn get_sender(&self, session_id: &FixSessionId) -> Option<&FixSender> {
+        self.senders.get(session_id)
+    }
+
+    pub fn get_session_ids(&self) -> impl Iterator<Item = &FixSessionId> {
+        self.senders.keys()
+    }
+
+    /// Logon and logout, inbound application messages and disconnects of the sessions
+    pub async fn recv(&mut self) -> Option<(FixSessionId, FixSessionEvent)> {
+        self.events.recv().await
+    }
+}
+
+impl Drop for FixAcceptorHandle {
+    fn drop(&mut self) {
+        self.listener.abort();
+    }
+}
+
+// Wraps the accepted connection with TLS if it is configured
+#[derive(Clone, Default)]
+struct Handshake {
+    #[cfg(feature = "tls")]
+    tls: Option<crate::TlsServerConfig>,
+}
+
+impl Handshake {
+    async fn open(&self, stream: TcpStream) -> io::Result<Box<dyn AsyncStream>> {
+        #[cfg(feature = "tls")]
+        if let Some(tls) = &self.tls {
+            return Ok(Box::new(tls.accept(stream).await?));
+        }
+
+        Ok(Box::new(stream))
+    }
+}
+
+struct SessionSlot {
+    connected: Arc<AtomicBool>,
+    connections: mpsc::UnboundedSender<Connection>,
+}
+
+async fn listen(
+    listener: TcpListener,
+    slots: Arc<HashMap<FixSessionId, SessionSlot>>,
+    logon_timeout: Duration,
+    handshake: Handshake,
+) {
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(_) => {
+                // Errors like EMFILE last until connections are closed, retrying at once spins
+                tokio::time::sleep(ACCEPT_ERROR_DELAY).await;
+                continue;
+            }
+        };
+
+        let _ = stream.set_nodelay(true);
+        tokio::spawn(assign_connection(
+            stream,
+            slots.clone(),
+            logon_timeout,
+            handshake.clone(),
+        ));
+    }
+}
+
+async fn assign_connection(
+    stream: TcpStream,
+    slots: Arc<HashMap<FixSessionId, SessionSlot>>,
+    logon_timeout: Duration,
+    handshake: Handshake,
+) {
+    let read = tokio::time::timeout(logon_timeout, async {
+        let mut stream = handshake.open(stream).await.ok()?;
+        let (session_id, received) = read_session_id(&mut stream).await?;
+        Some((stream, session_id, received))
+    })
+    .await;
+
+    let Ok(Some((stream, session_id, received))) = read else {
+        return;
+    };
+
+    // Unknown CompIDs
+    let Some(slot) = slots.get(&session_id) else {
+        return;
+    };
+
+    // One connection per session
+    if slot.connected.swap(true, Ordering::AcqRel) {
+        return;
+    }
+
+    let mut decoder = FixFrameDecoder::new();
+    decoder.append(&received);
+
+    if slot.connections.send((stream, decoder)).is_err() {
+        slot.connected.store(false, Ordering::Release);
+    }
+}
+
+// Reads up to the first complete message. Returns the session it belongs to and all the
+// bytes read, so the session receives them too.
+async fn read_session_id(stream: &mut (impl AsyncRead + Unpin)) -> Option<(FixSessionId, Vec<u8>)> {
+    let mut decoder = FixFrameDecoder::new();
+    let mut received = Vec::new();
+    let mut buffer = [0u8; 4096];
+
+    loop {
+        match fix_transport::read_first_frame(&mut decoder) {
+            FirstFrame::Incomplete => {}
+            FirstFrame::Session(session_id) => return Some((session_id, received)),
+            FirstFrame::Invalid => return None,
+        }
+
+        let size = stream.read(&mut buffer).await.ok()?;
+        if size == 0 {
+            return None;
+        }
+
+        decoder.append(&buffer[..size]);
+        received.extend_from_slice(&buffer[..size]);
+    }
+}
+
+async fn run_session<C: SessionClock, S: MessageStore>(
+    mut session: FixSession<C, S>,
+    mut connections: mpsc::UnboundedReceiver<Connection>,
+    mut commands: mpsc::UnboundedReceiver<FixCommand>,
+    connected: Arc<AtomicBool>,
+    events: mpsc::UnboundedSender<(FixSessionId, FixSessionEvent)>,
+) {
+    let session_id = session.get_config().get_session_id();
+    let mut emit = |event| {
+        let _ = events.send((session_id.clone(), event));
+    };
+    let mut commands_open = true;
+
+    loop {
+        tokio::select! {
+            connection = connections.recv() => {
+                let Some((stream, decoder)) = connection else {
+                    return;
+                };
+
+                fix_connection::run_connection(
+                    &mut session,
+                    stream,
+                    decoder,
+                    &mut commands,
+                    &mut emit,
+                )
+                .await;
+
+                connected.store(false, Ordering::Release);
+            }
+            command = commands.recv(), if commands_open => match command {
+                Some(FixCommand::Send(writer, reply)) => {
+                    let _ = reply.send(session.send(writer));
+                }
+                // Not connected, nothing to log out
+                Some(FixCommand::Logout(_)) => {}
+                None => commands_open = false,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpStream,
+    };
+
+    use crate::{
+        tags, FixAcceptor, FixAcceptorHandle, FixInitiator, FixMessageReader, FixMessageWriter,
+        FixSession, FixSessionConfig, FixSessionEvent, FixSessionId, SessionRole,
+    };
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    async fn start_acceptor() -> FixAcceptorHandle {
+        let session = |target_comp_id: &str| {
+            FixSession::new(FixSessionConfig::new(
+                SessionRole::Acceptor,
+                "FIX.4.4",
+                "VENUE",
+                target_comp_id,
+            ))
+        };
+
+        FixAcceptor::new()
+            .with_session(session("CLIENT1"))
+            .with_session(session("CLIENT2"))
+            .bind("127.0.0.1:0")
+            .await
+            .unwrap()
+    }
+
+    async fn recv(acceptor: &mut FixAcceptorHandle) -> (FixSessionId, FixSessionEvent) {
+        tokio::time::timeout(TIMEOUT, acceptor.recv())
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    fn logon(sender_comp_id: &str) -> Vec<u8> {
+        let mut writer = FixMessageWriter::new("FIX.4.4", "A");
+        writer.with_value(tags::SENDER_COMP_ID, sender_comp_id);
+        writer.with_value(tags::TARGET_COMP_ID, "VENUE");
+        writer.with_value(tags::MSG_SEQ_NUM, 1);
+        writer.with_value(tags::ENCRYPT_METHOD, 0);
+        writer.with_value(tags::HEART_BT_INT, 30);
+        writer.compile_message()
+    }
+
+    // Connection closed by the acceptor reads as EOF
+    async fn assert_closed(stream: &mut TcpStream) {
+        let mut buffer = [0u8; 1024];
+        let read = tokio::time::timeout(TIMEOUT, stream.read(&mut buffer))
+            .await
+            .unwrap();
+        assert!(matches!(read, Ok(0) | Err(_)));
+    }
+
+    #[tokio::test]
+    async fn test_sessions_are_dispatched_by_comp_id() {
+        let mut acceptor = start_acceptor().await;
+        let port = acceptor.get_local_addr().port();
+
+        let config = FixSessionConfig::new(SessionRole::Initiator, "FIX.4.4", "CLIENT2", "VENUE");
+        let (client, mut client_events) =
+            FixInitiator::new(FixSession::new(config), "127.0.0.1", port).start();
+
+        let session_id = FixSessionId::new("FIX.4.4", "VENUE", "CLIENT2");
+        assert_eq!(
+            (session_id.clone(), FixSessionEvent::LoggedOn),
+            recv(&mut acceptor).await
+        );
+        assert_eq!(Some(FixSessionEvent::LoggedOn), client_events.recv().await);
+
+        let mut order = FixMessageWriter::new("FIX.4.4", "D");
+        order.with_value(tags::CL_ORD_ID, "order-1");
+        client.send(order).await.unwrap();
+
+        let (id, FixSessionEvent::Application(message)) = recv(&mut acceptor).await else {
+            panic!("Application message expected");
+        };
+        assert_eq!(session_id, id);
+        assert_eq!(
+            Some("order-1"),
+            FixMessageReader::from_bytes(&message)
+                .get_value(tags::CL_ORD_ID)
+                .unwrap()
+        );
+
+        let venue = acceptor.get_sender(&session_id).unwrap();
+        assert_eq!(
+            2,
+            venue
+                .send(FixMessageWriter::new("FIX.4.4", "8"))
+                .await
+                .unwrap()
+        );
+        let Some(FixSessionEvent::Application(_)) = client_events.recv().await else {
+            panic!("Application message expected");
+        };
+    }
+
+    #[tokio::test]
+    async fn test_unknown_comp_id_is_closed() {
+        let acceptor = start_acceptor().await;
+
+        let mut stream = TcpStream::connect(acceptor.get_local_addr()).await.unwrap();
+        stream.write_all(&logon("UNKNOWN")).await.unwrap();
+
+        assert_closed(&mut stream).await;
+    }
+
+    #[tokio::test]
+    async fn test_one_connection_per_session() {
+        let mut acceptor = start_acceptor().await;
+
+        let mut first = TcpStream::connect(acceptor.get_local_addr()).await.unwrap();
+        first.write_all(&logon("CLIENT1")).await.unwrap();
+
+        let (_, event) = recv(&mut acceptor).await;
+        assert_eq!(FixSessionEvent::LoggedOn, event);
+
+        let mut second = TcpStream::connect(acceptor.get_local_addr()).await.unwrap();
+        second.write_all(&logon("CLIENT1")).await.unwrap();
+        assert_closed(&mut second).await;
+
+        // First connection is still served
+        let mut buffer = [0u8; 1024];
+        let size = tokio::time::timeout(TIMEOUT, first.read(&mut buffer))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(size > 0);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_sessions_are_rejected() {
+        let session = |role, target_comp_id: &str| {
+            FixSession::new(FixSessionConfig::new(
+                role,
+                "FIX.4.4",
+                "VENUE",
+                target_comp_id,
+            ))
+        };
+
+        let err = FixAcceptor::new()
+            .with_session(session(SessionRole::Initiator, "CLIENT1"))
+            .bind("127.0.0.1:0")
+            .await
+            .unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+        assert!(err.to_string().contains("FIX.4.4:VENUE->CLIENT1"));
+
+        let err = FixAcceptor::new()
+            .with_session(session(SessionRole::Acceptor, "CLIENT1"))
+            .with_session(session(SessionRole::Acceptor, "CLIENT2"))
+            .with_session(session(SessionRole::Acceptor, "CLIENT1"))
+            .bind("127.0.0.1:0")
+            .await
+            .unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+        assert!(err.to_string().contains("FIX.4.4:VENUE->CLIENT1"));
+    }
+}
@@ -0,0 +1,229 @@
+use std::time::Duration;
+
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    sync::{mpsc, oneshot},
+    time::MissedTickBehavior,
+};
+
+use crate::{
+    fix_transport, DisconnectReason, FixFrameDecoder, FixMessageWriter, FixSession,
+    FixSessionError, FixSessionEvent, MessageStore, SessionClock,
+};
+
+const READ_BUFFER_SIZE: usize = 8 * 1024;
+const TIMER_INTERVAL: Duration = Duration::from_secs(1);
+// How often a disconnected initiator checks whether the session schedule has started
+const SCHEDULE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Transport the session runs over: plain TCP or TLS
+pub(crate) trait AsyncStream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> AsyncStream for T {}
+
+pub(crate) enum FixCommand {
+    Send(
+        FixMessageWriter,
+        oneshot::Sender<Result<u64, FixSessionError>>,
+    ),
+    Logout(Option<String>),
+}
+
+/// Handle which passes outbound application messages to the task running the session.
+/// Clones send to the same session.
+#[derive(Debug, Clone)]
+pub struct FixSender {
+    commands: mpsc::UnboundedSender<FixCommand>,
+}
+
+impl FixSender {
+    /// Stamps the header of the application message and writes it to the connection.
+    /// Returns MsgSeqNum (34) of the message.
+    pub async fn send(&self, writer: FixMessageWriter) -> Result<u64, FixSessionError> {
+        let (reply, result) = oneshot::channel();
+
+        self.commands
+            .send(FixCommand::Send(writer, reply))
+            .map_err(|_| FixSessionError::Closed)?;
+
+        result.await.map_err(|_| FixSessionError::Closed)?
+    }
+
+    /// Starts the graceful logout. The session is not connected again after it.
+    pub fn logout(&self, text: Option<&str>) -> Result<(), FixSessionError> {
+        self.commands
+            .send(FixCommand::Logout(text.map(|text| text.to_string())))
+            .map_err(|_| FixSessionError::Closed)
+    }
+
+    /// Task running the session is stopped
+    pub fn is_closed(&self) -> bool {
+        self.commands.is_closed()
+    }
+}
+
+/// Events of the session: logon and logout, inbound application messages and disconnects.
+/// `FixSessionEvent::Send` is written to the connection and is never received here.
+#[derive(Debug)]
+pub struct FixReceiver {
+    events: mpsc::UnboundedReceiver<FixSessionEvent>,
+}
+
+impl FixReceiver {
+    /// Returns `None` when the task running the session is stopped
+    pub async fn recv(&mut self) -> Option<FixSessionEvent> {
+        self.events.recv().await
+    }
+}
+
+pub(crate) fn command_channel() -> (FixSender, mpsc::UnboundedReceiver<FixCommand>) {
+    let (commands, receiver) = mpsc::unbounded_channel();
+    (FixSender { commands }, receiver)
+}
+
+pub(crate) fn event_channel() -> (mpsc::UnboundedSender<FixSessionEvent>, FixReceiver) {
+    let (sender, events) = mpsc::unbounded_channel();
+    (sender, FixReceiver { events })
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ConnectionEnd {
+    pub logged_on: bool,
+    /// Logout is requested or every [`FixSender`] is dropped
+    pub stop: bool,
+}
+
+/// Runs the session over the connection until it is disconnected.
+///
+/// `decoder` may already hold the bytes which were read to identify the session. Every
+/// event except `FixSessionEvent::Send` is passed to `emit`.
+pub(crate) async fn run_connection<C, S, IO>(
+    session: &mut FixSession<C, S>,
+    mut io: IO,
+    mut decoder: FixFrameDecoder,
+    commands: &mut mpsc::UnboundedReceiver<FixCommand>,
+    emit: &mut impl FnMut(FixSessionEvent),
+) -> ConnectionEnd
+where
+    C: SessionClock,
+    S: MessageStore,
+    IO: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut result = ConnectionEnd::default();
+    let mut buffer = vec![0u8; READ_BUFFER_SIZE];
+
+    let mut timer = tokio::time::interval(TIMER_INTERVAL);
+    timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    session.on_connected();
+    fix_transport::process_frames(session, &mut decoder);
+
+    loop {
+        while let Some(event) = session.poll_event() {
+            match event {
+                FixSessionEvent::Send(message) => {
+                    if let Err(err) = io.write_all(&message).await {
+                        return close(session, emit, result, err.to_string());
+                    }
+                }
+                FixSessionEvent::Disconnect(reason) => {
+                    let _ = io.shutdown().await;
+                    emit(FixSessionEvent::Disconnect(reason));
+                    return result;
+                }
+                event => {
+                    if event == FixSessionEvent::LoggedOn {
+                        result.logged_on = true;
+                    }
+                    emit(event);
+                }
+            }
+        }
+
+        tokio::select! {
+            read = io.read(&mut buffer) => match read {
+                Ok(0) => {
+                    return close(session, emit, result, "Connection is closed".to_string());
+                }
+                Ok(size) => {
+                    decoder.append(&buffer[..size]);
+                    fix_transport::process_frames(session, &mut decoder);
+                }
+                Err(err) => return close(session, emit, result, err.to_string()),
+            },
+            _ = timer.tick() => session.on_timer(),
+            command = commands.recv(), if !result.stop => match command {
+                Some(FixCommand::Send(writer, reply)) => {
+                    let _ = reply.send(session.send(writer));
+                }
+                Some(FixCommand::Logout(text)) => {
+                    result.stop = true;
+                    session.logout(text.as_deref());
+                }
+                None => {
+                    result.stop = true;
+                    session.logout(None);
+                }
+            },
+        }
+    }
+}
+
+/// Answers the commands while the session is disconnected.
+/// Returns `false` if the session has to be stopped.
+pub(crate) async fn wait_disconnected<C, S>(
+    session: &mut FixSession<C, S>,
+    commands: &mut mpsc::UnboundedReceiver<FixCommand>,
+    duration: Duration,
+) -> bool
+where
+    C: SessionClock,
+    S: MessageStore,
+{
+    let sleep = tokio::time::sleep(duration);
+    tokio::pin!(sleep);
+
+    loop {
+        tokio::select! {
+            _ = &mut sleep => return true,
+            command = commands.recv() => match command {
+                Some(FixCommand::Send(writer, reply)) => {
+                    let _ = reply.send(session.send(writer));
+                }
+                Some(FixCommand::Logout(_)) | None => return false,
+            },
+        }
+    }
+}
+
+/// Waits without a connection until the schedule of the session allows to log on, serving
+/// the commands meanwhile. Returns `false` if the session has to be stopped.
+pub(crate) async fn wait_session_time<C, S>(
+    session: &mut FixSession<C, S>,
+    commands: &mut mpsc::UnboundedReceiver<FixCommand>,
+) -> bool
+where
+    C: SessionClock,
+    S: MessageStore,
+{
+    while !session.is_session_time() {
+        if !wait_disconnected(session, commands, SCHEDULE_CHECK_INTERVAL).await {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn close<C: SessionClock, S: MessageStore>(
+    session: &mut FixSession<C, S>,
+    emit: &mut impl FnMut(FixSessionEvent),
+    result: ConnectionEnd,
+    error: String,
+) -> ConnectionEnd {
+    session.on_disconnected();
+    emit(FixSessionEvent::Disconnect(DisconnectReason::Transport(
+        error,
+    )));
+    result
+}
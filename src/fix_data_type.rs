@@ -0,0 +1,395 @@
+use crate::{LocalMktDate, UtcTimeOnly, UtcTimestamp};
+
+/// Data types of FIX fields as they are named in the specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FixDataType {
+    Int,
+    Length,
+    SeqNum,
+    NumInGroup,
+    TagNum,
+    DayOfMonth,
+    Float,
+    Qty,
+    Price,
+    PriceOffset,
+    Amt,
+    Percentage,
+    Char,
+    Boolean,
+    String,
+    MultipleCharValue,
+    MultipleValueString,
+    Country,
+    Currency,
+    Exchange,
+    Language,
+    MonthYear,
+    UtcTimestamp,
+    UtcTimeOnly,
+    UtcDateOnly,
+    LocalMktDate,
+    TzTimeOnly,
+    TzTimestamp,
+    Data,
+    XmlData,
+}
+
+impl FixDataType {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Int => "INT",
+            Self::Length => "LENGTH",
+            Self::SeqNum => "SEQNUM",
+            Self::NumInGroup => "NUMINGROUP",
+            Self::TagNum => "TAGNUM",
+            Self::DayOfMonth => "DAYOFMONTH",
+            Self::Float => "FLOAT",
+            Self::Qty => "QTY",
+            Self::Price => "PRICE",
+            Self::PriceOffset => "PRICEOFFSET",
+            Self::Amt => "AMT",
+            Self::Percentage => "PERCENTAGE",
+            Self::Char => "CHAR",
+            Self::Boolean => "BOOLEAN",
+            Self::String => "STRING",
+            Self::MultipleCharValue => "MULTIPLECHARVALUE",
+            Self::MultipleValueString => "MULTIPLEVALUESTRING",
+            Self::Country => "COUNTRY",
+            Self::Currency => "CURRENCY",
+            Self::Exchange => "EXCHANGE",
+            Self::Language => "LANGUAGE",
+            Self::MonthYear => "MONTHYEAR",
+            Self::UtcTimestamp => "UTCTIMESTAMP",
+            Self::UtcTimeOnly => "UTCTIMEONLY",
+            Self::UtcDateOnly => "UTCDATEONLY",
+            Self::LocalMktDate => "LOCALMKTDATE",
+            Self::TzTimeOnly => "TZTIMEONLY",
+            Self::TzTimestamp => "TZTIMESTAMP",
+            Self::Data => "DATA",
+            Self::XmlData => "XMLDATA",
+        }
+    }
+
+    /// Parses the type name used by QuickFIX dictionaries, including the names of older versions.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let result = match name {
+            "INT" => Self::Int,
+            "LENGTH" => Self::Length,
+            "SEQNUM" => Self::SeqNum,
+            "NUMINGROUP" => Self::NumInGroup,
+            "TAGNUM" => Self::TagNum,
+            "DAYOFMONTH" => Self::DayOfMonth,
+            "FLOAT" => Self::Float,
+            "QTY" => Self::Qty,
+            "PRICE" => Self::Price,
+            "PRICEOFFSET" => Self::PriceOffset,
+            "AMT" => Self::Amt,
+            "PERCENTAGE" => Self::Percentage,
+            "CHAR" => Self::Char,
+            "BOOLEAN" => Self::Boolean,
+            "STRING" => Self::String,
+            "MULTIPLECHARVALUE" => Self::MultipleCharValue,
+            "MULTIPLEVALUESTRING" | "MULTIPLESTRINGVALUE" => Self::MultipleValueString,
+            "COUNTRY" => Self::Country,
+            "CURRENCY" => Self::Currency,
+            "EXCHANGE" => Self::Exchange,
+            "LANGUAGE" => Self::Language,
+            "MONTHYEAR" => Self::MonthYear,
+            "UTCTIMESTAMP" | "TIME" => Self::UtcTimestamp,
+            "UTCTIMEONLY" => Self::UtcTimeOnly,
+            "UTCDATEONLY" | "UTCDATE" => Self::UtcDateOnly,
+            "LOCALMKTDATE" | "DATE" => Self::LocalMktDate,
+            "TZTIMEONLY" => Self::TzTimeOnly,
+            "TZTIMESTAMP" => Self::TzTimestamp,
+            "DATA" => Self::Data,
+            "XMLDATA" => Self::XmlData,
+            // Types of FIX.5.0 which are restrictions of the basic ones
+            "RESERVED100PLUS" | "RESERVED1000PLUS" | "RESERVED4000PLUS" => Self::Int,
+            "XID" | "XIDREF" | "TENOR" | "LOCALMKTTIME" => Self::String,
+            _ => return None,
+        };
+
+        Some(result)
+    }
+
+    /// Checks the value against the grammar of the data type.
+    pub fn is_valid(&self, value: &str) -> bool {
+        match self {
+            Self::Int => parse_int(value).is_some(),
+            Self::Length | Self::NumInGroup | Self::SeqNum => parse_seq_num(value).is_some(),
+            Self::TagNum => parse_seq_num(value).is_some_and(|value| value > 0),
+            Self::DayOfMonth => parse_seq_num(value).is_some_and(|day| (1..=31).contains(&day)),
+            Self::Float
+            | Self::Qty
+            | Self::Price
+            | Self::PriceOffset
+            | Self::Amt
+            | Self::Percentage => parse_float(value).is_some(),
+            Self::Char => parse_char(value).is_some(),
+            Self::Boolean => parse_bool(value).is_some(),
+            Self::String | Self::Exchange | Self::Data | Self::XmlData => !value.is_empty(),
+            Self::MultipleCharValue => parse_multiple_value_string(value)
+                .is_some_and(|values| values.iter().all(|itm| itm.chars().count() == 1)),
+            Self::MultipleValueString => parse_multiple_value_string(value).is_some(),
+            Self::Country | Self::Language => is_alpha_numeric_code(value, 2),
+            Self::Currency => is_alpha_numeric_code(value, 3),
+            Self::MonthYear => is_month_year(value),
+            Self::UtcTimestamp => UtcTimestamp::parse(value).is_some(),
+            Self::UtcTimeOnly => UtcTimeOnly::parse(value).is_some(),
+            Self::UtcDateOnly | Self::LocalMktDate => LocalMktDate::parse(value).is_some(),
+            Self::TzTimeOnly => is_tz_time_only(value),
+            Self::TzTimestamp => is_tz_timestamp(value),
+        }
+    }
+}
+
+pub(crate) fn parse_int(value: &str) -> Option<i64> {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+
+    if !is_digits(digits) {
+        return None;
+    }
+
+    value.parse().ok()
+}
+
+pub(crate) fn parse_seq_num(value: &str) -> Option<u64> {
+    if !is_digits(value) {
+        return None;
+    }
+
+    value.parse().ok()
+}
+
+pub(crate) fn parse_float(value: &str) -> Option<f64> {
+    let unsigned = value.strip_prefix('-').unwrap_or(value);
+
+    let (int_part, fraction_part) = match unsigned.split_once('.') {
+        Some((int_part, fraction_part)) => (int_part, fraction_part),
+        None => (unsigned, ""),
+    };
+
+    if int_part.is_empty() && fraction_part.is_empty() {
+        return None;
+    }
+
+    if !int_part.bytes().all(|b| b.is_ascii_digit())
+        || !fraction_part.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    value.parse().ok()
+}
+
+pub(crate) fn parse_char(value: &str) -> Option<char> {
+    let mut chars = value.chars();
+    let result = chars.next()?;
+
+    if chars.next().is_some() || result == ' ' {
+        return None;
+    }
+
+    Some(result)
+}
+
+pub(crate) fn parse_bool(value: &str) -> Option<bool> {
+    match value {
+        "Y" => Some(true),
+        "N" => Some(false),
+        _ => None,
+    }
+}
+
+pub(crate) fn parse_multiple_value_string(value: &str) -> Option<Vec<&str>> {
+    let result: Vec<&str> = value.split(' ').collect();
+
+    if result.iter().any(|itm| itm.is_empty()) {
+        return None;
+    }
+
+    Some(result)
+}
+
+pub(crate) fn is_digits(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_alpha_numeric_code(value: &str, len: usize) -> bool {
+    value.len() == len && value.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+// YYYYMM, YYYYMMDD or YYYYMMwN
+fn is_month_year(value: &str) -> bool {
+    if value.len() < 6 || !value.is_ascii() || !is_digits(&value[..6]) {
+        return false;
+    }
+
+    let month: u32 = value[4..6].parse().unwrap_or(0);
+    if !(1..=12).contains(&month) {
+        return false;
+    }
+
+    match &value[6..] {
+        "" => true,
+        "w1" | "w2" | "w3" | "w4" | "w5" => true,
+        day => is_digits(day) && day.len() == 2 && (1..=31).contains(&day.parse().unwrap_or(0)),
+    }
+}
+
+// HH:MM[:SS[.sss]]
+fn is_time_only(value: &str) -> bool {
+    let bytes = value.as_bytes();
+
+    if bytes.len() < 5 || !value.is_ascii() || bytes[2] != b':' {
+        return false;
+    }
+
+    if !is_two_digits_in_range(&value[0..2], 23) || !is_two_digits_in_range(&value[3..5], 59) {
+        return false;
+    }
+
+    if bytes.len() == 5 {
+        return true;
+    }
+
+    if bytes[5] != b':' || bytes.len() < 8 || !is_two_digits_in_range(&value[6..8], 60) {
+        return false;
+    }
+
+    match value[8..].strip_prefix('.') {
+        Some(fraction) => is_digits(fraction) && fraction.len() <= 9,
+        None => value.len() == 8,
+    }
+}
+
+fn is_tz_time_only(value: &str) -> bool {
+    match split_time_zone(value) {
+        Some((time, _)) => is_time_only(time),
+        None => false,
+    }
+}
+
+fn is_tz_timestamp(value: &str) -> bool {
+    if value.len() < 9 || !value.is_ascii() || value.as_bytes()[8] != b'-' {
+        return false;
+    }
+
+    LocalMktDate::parse(&value[..8]).is_some() && is_tz_time_only(&value[9..])
+}
+
+// Splits "HH:MM[:SS]" from the "Z" / "+hh[:mm]" / "-hh[:mm]" suffix
+fn split_time_zone(value: &str) -> Option<(&str, &str)> {
+    if let Some(time) = value.strip_suffix('Z') {
+        return Some((time, "Z"));
+    }
+
+    let index = value.rfind(['+', '-'])?;
+    let (time, zone) = value.split_at(index);
+
+    if !zone.is_ascii() {
+        return None;
+    }
+
+    let offset = &zone[1..];
+    let valid_offset = match offset.len() {
+        2 => is_two_digits_in_range(offset, 14),
+        5 => {
+            is_two_digits_in_range(&offset[..2], 14)
+                && &offset[2..3] == ":"
+                && is_two_digits_in_range(&offset[3..], 59)
+        }
+        _ => false,
+    };
+
+    if !valid_offset {
+        return None;
+    }
+
+    Some((time, zone))
+}
+
+fn is_two_digits_in_range(value: &str, max: u32) -> bool {
+    value.len() == 2 && is_digits(value) && value.parse::<u32>().is_ok_and(|v| v <= max)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FixDataType;
+
+    #[test]
+    fn test_int() {
+        assert!(FixDataType::Int.is_valid("123"));
+        assert!(FixDataType::Int.is_valid("-123"));
+        assert!(FixDataType::Int.is_valid("007"));
+        assert!(!FixDataType::Int.is_valid("+1"));
+        assert!(!FixDataType::Int.is_valid("1.0"));
+        assert!(!FixDataType::Int.is_valid(""));
+        assert!(!FixDataType::SeqNum.is_valid("-1"));
+    }
+
+    #[test]
+    fn test_float() {
+        assert!(FixDataType::Price.is_valid("1.5"));
+        assert!(FixDataType::Price.is_valid("-.5"));
+        assert!(FixDataType::Price.is_valid("15."));
+        assert!(FixDataType::Qty.is_valid("100"));
+        assert!(!FixDataType::Price.is_valid("1e5"));
+        assert!(!FixDataType::Price.is_valid("."));
+        assert!(!FixDataType::Price.is_valid("1.2.3"));
+        assert!(!FixDataType::Price.is_valid("NaN"));
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Some(FixDataType::Price), FixDataType::from_name("PRICE"));
+        assert_eq!(
+            Some(FixDataType::UtcTimestamp),
+            FixDataType::from_name("TIME")
+        );
+        assert_eq!(
+            Some(FixDataType::MultipleValueString),
+            FixDataType::from_name("MULTIPLESTRINGVALUE")
+        );
+        assert_eq!(None, FixDataType::from_name("price"));
+
+        let data_type = FixDataType::MultipleCharValue;
+        assert_eq!(
+            Some(data_type),
+            FixDataType::from_name(data_type.get_name())
+        );
+    }
+
+    #[test]
+    fn test_char_and_boolean() {
+        assert!(FixDataType::Char.is_valid("1"));
+        assert!(!FixDataType::Char.is_valid("12"));
+        assert!(FixDataType::Boolean.is_valid("Y"));
+        assert!(!FixDataType::Boolean.is_valid("y"));
+    }
+
+    #[test]
+    fn test_multiple_values() {
+        assert!(FixDataType::MultipleValueString.is_valid("AA B"));
+        assert!(!FixDataType::MultipleValueString.is_valid("AA  B"));
+        assert!(FixDataType::MultipleCharValue.is_valid("A B"));
+        assert!(!FixDataType::MultipleCharValue.is_valid("AA B"));
+    }
+
+    #[test]
+    fn test_dates_and_times() {
+        assert!(FixDataType::UtcTimestamp.is_valid("20180920-18:24:59.643"));
+        assert!(!FixDataType::UtcTimestamp.is_valid("20180920-24:24:59"));
+        assert!(FixDataType::LocalMktDate.is_valid("20180920"));
+        assert!(!FixDataType::LocalMktDate.is_valid("20180231"));
+        assert!(FixDataType::MonthYear.is_valid("201809"));
+        assert!(FixDataType::MonthYear.is_valid("201809w2"));
+        assert!(!FixDataType::MonthYear.is_valid("201813"));
+        assert!(FixDataType::UtcTimeOnly.is_valid("18:24:59.643"));
+        assert!(FixDataType::TzTimeOnly.is_valid("07:39Z"));
+        assert!(FixDataType::TzTimeOnly.is_valid("02:39-05"));
+        assert!(FixDataType::TzTimestamp.is_valid("20060901-07:39:00+05:30"));
+        assert!(!FixDataType::TzTimestamp.is_valid("20060901-07:39:00"));
+    }
+}
@@ -0,0 +1,386 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+/// Decimal value of Price, Qty, Amt and other FIX float fields.
+///
+/// The value is kept as `mantissa * 10^-scale`, so `1.50` is stored as `150` with scale `2`
+/// and is written back as `1.50`. A parsed value keeps its wire form, so `007`, `.5`, `15.`
+/// and `-0` are written back unchanged. Comparison is numeric: `1.5 == 1.50`.
+///
+/// Arithmetic is checked and returns `None` on overflow instead of panicking.
+#[derive(Debug, Clone, Copy)]
+pub struct FixDecimal {
+    mantissa: i64,
+    scale: u8,
+    form: WireForm,
+}
+
+// How the integer part, the decimal point and the sign are written. Calculated values use
+// the plain form.
+#[derive(Debug, Clone, Copy)]
+struct WireForm {
+    // Digits of the integer part including the leading zeros, `0` for `.5`
+    int_width: u8,
+    // `15.`
+    trailing_point: bool,
+    // `-0` or `-0.00`
+    negative_zero: bool,
+}
+
+impl WireForm {
+    const PLAIN: Self = Self {
+        int_width: 1,
+        trailing_point: false,
+        negative_zero: false,
+    };
+}
+
+impl FixDecimal {
+    pub const MAX_SCALE: u8 = 18;
+
+    pub const ZERO: Self = Self {
+        mantissa: 0,
+        scale: 0,
+        form: WireForm::PLAIN,
+    };
+
+    pub fn new(mantissa: i64, scale: u8) -> Option<Self> {
+        if scale > Self::MAX_SCALE {
+            return None;
+        }
+
+        Some(Self::plain(mantissa, scale))
+    }
+
+    pub fn parse(src: &str) -> Option<Self> {
+        let (negative, unsigned) = match src.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, src),
+        };
+
+        let (int_part, fraction_part) = match unsigned.split_once('.') {
+            Some((int_part, fraction_part)) => (int_part, fraction_part),
+            None => (unsigned, ""),
+        };
+
+        if int_part.is_empty() && fraction_part.is_empty() {
+            return None;
+        }
+
+        if fraction_part.len() > Self::MAX_SCALE as usize {
+            return None;
+        }
+
+        let mut mantissa: i64 = 0;
+
+        for b in int_part.bytes().chain(fraction_part.bytes()) {
+            if !b.is_ascii_digit() {
+                return None;
+            }
+
+            let digit = (b - b'0') as i64;
+            mantissa = mantissa.checked_mul(10)?;
+
+            mantissa = if negative {
+                mantissa.checked_sub(digit)?
+            } else {
+                mantissa.checked_add(digit)?
+            };
+        }
+
+        Some(Self {
+            mantissa,
+            scale: fraction_part.len() as u8,
+            form: WireForm {
+                int_width: u8::try_from(int_part.len()).ok()?,
+                trailing_point: fraction_part.is_empty() && unsigned.ends_with('.'),
+                negative_zero: negative && mantissa == 0,
+            },
+        })
+    }
+
+    pub fn get_mantissa(&self) -> i64 {
+        self.mantissa
+    }
+
+    pub fn get_scale(&self) -> u8 {
+        self.scale
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.mantissa < 0
+    }
+
+    /// Removes trailing zeros of the fraction part: `1.500` becomes `1.5`
+    pub fn normalize(&self) -> Self {
+        let mut result = Self::plain(self.mantissa, self.scale);
+
+        while result.scale > 0 && result.mantissa % 10 == 0 {
+            result.mantissa /= 10;
+            result.scale -= 1;
+        }
+
+        result
+    }
+
+    /// Changes the amount of digits after the decimal point.
+    /// Returns `None` if the value can not be represented exactly with the new scale.
+    pub fn with_scale(&self, scale: u8) -> Option<Self> {
+        if scale > Self::MAX_SCALE {
+            return None;
+        }
+
+        let mantissa = if scale >= self.scale {
+            self.mantissa
+                .checked_mul(10i64.checked_pow((scale - self.scale) as u32)?)?
+        } else {
+            let divider = 10i64.pow((self.scale - scale) as u32);
+
+            if self.mantissa % divider != 0 {
+                return None;
+            }
+
+            self.mantissa / divider
+        };
+
+        Some(Self::plain(mantissa, scale))
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let scale = self.scale.max(other.scale);
+        let mantissa = self.scaled_mantissa(scale) + other.scaled_mantissa(scale);
+
+        Self::from_wide(mantissa, scale)
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        let scale = self.scale.max(other.scale);
+        let mantissa = self.scaled_mantissa(scale) - other.scaled_mantissa(scale);
+
+        Self::from_wide(mantissa, scale)
+    }
+
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        let mut mantissa = self.mantissa as i128 * other.mantissa as i128;
+        let mut scale = self.scale + other.scale;
+
+        // Drop the trailing zeros which do not fit into MAX_SCALE
+        while scale > Self::MAX_SCALE {
+            if mantissa % 10 != 0 {
+                return None;
+            }
+
+            mantissa /= 10;
+            scale -= 1;
+        }
+
+        Self::from_wide(mantissa, scale)
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self::plain(self.mantissa.checked_neg()?, self.scale))
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.mantissa as f64 / 10f64.powi(self.scale as i32)
+    }
+
+    fn scaled_mantissa(&self, scale: u8) -> i128 {
+        self.mantissa as i128 * 10i128.pow((scale - self.scale) as u32)
+    }
+
+    fn from_wide(mantissa: i128, scale: u8) -> Option<Self> {
+        Some(Self::plain(i64::try_from(mantissa).ok()?, scale))
+    }
+
+    fn plain(mantissa: i64, scale: u8) -> Self {
+        Self {
+            mantissa,
+            scale,
+            form: WireForm::PLAIN,
+        }
+    }
+}
+
+impl Default for FixDecimal {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<i64> for FixDecimal {
+    fn from(value: i64) -> Self {
+        Self::plain(value, 0)
+    }
+}
+
+impl From<i32> for FixDecimal {
+    fn from(value: i32) -> Self {
+        Self::from(value as i64)
+    }
+}
+
+impl From<u32> for FixDecimal {
+    fn from(value: u32) -> Self {
+        Self::from(value as i64)
+    }
+}
+
+impl fmt::Display for FixDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let divider = 10u64.pow(self.scale as u32);
+        let abs = self.mantissa.unsigned_abs();
+        let int_part = abs / divider;
+
+        if self.mantissa < 0 || self.form.negative_zero {
+            write!(f, "-")?;
+        }
+
+        if int_part != 0 || self.form.int_width > 0 {
+            write!(
+                f,
+                "{:0width$}",
+                int_part,
+                width = self.form.int_width as usize
+            )?;
+        }
+
+        if self.scale > 0 {
+            write!(f, ".{:0width$}", abs % divider, width = self.scale as usize)?;
+        } else if self.form.trailing_point {
+            write!(f, ".")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl PartialEq for FixDecimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for FixDecimal {}
+
+impl PartialOrd for FixDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FixDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let scale = self.scale.max(other.scale);
+        self.scaled_mantissa(scale)
+            .cmp(&other.scaled_mantissa(scale))
+    }
+}
+
+impl Hash for FixDecimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let normalized = self.normalize();
+        normalized.mantissa.hash(state);
+        normalized.scale.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FixDecimal;
+
+    #[test]
+    fn test_parse_keeps_wire_representation() {
+        for src in ["0.1", "1.50", "-0.0050", "100", "123456789.123456789", "0"] {
+            assert_eq!(src, FixDecimal::parse(src).unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn test_parse_keeps_short_and_padded_forms() {
+        for src in [".5", "-.5", "15.", "007", "007.50", "-0", "-0.00", "00"] {
+            assert_eq!(src, FixDecimal::parse(src).unwrap().to_string());
+        }
+
+        assert_eq!(FixDecimal::parse("0.5"), FixDecimal::parse(".5"));
+        assert_eq!(FixDecimal::parse("7"), FixDecimal::parse("007"));
+        assert_eq!(FixDecimal::ZERO, FixDecimal::parse("-0").unwrap());
+        assert!(!FixDecimal::parse("-0").unwrap().is_negative());
+
+        // Calculated values use the plain form
+        let value = FixDecimal::parse("007.50").unwrap();
+        assert_eq!("7.5", value.normalize().to_string());
+        assert_eq!(
+            "8.50",
+            value.checked_add(&FixDecimal::from(1)).unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for src in [
+            "",
+            ".",
+            "-",
+            "1e5",
+            "1.2.3",
+            "+1",
+            " 1",
+            "NaN",
+            "99999999999999999999",
+        ] {
+            assert!(FixDecimal::parse(src).is_none(), "{}", src);
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = FixDecimal::parse("0.1").unwrap();
+        let b = FixDecimal::parse("0.2").unwrap();
+
+        assert_eq!("0.3", a.checked_add(&b).unwrap().to_string());
+        assert_eq!("-0.1", a.checked_sub(&b).unwrap().to_string());
+        assert_eq!("0.02", a.checked_mul(&b).unwrap().to_string());
+        assert_eq!("-0.1", a.checked_neg().unwrap().to_string());
+
+        let price = FixDecimal::parse("1.25").unwrap();
+        let qty = FixDecimal::from(100);
+        assert_eq!("125.00", price.checked_mul(&qty).unwrap().to_string());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = FixDecimal::from(i64::MAX);
+        assert!(max.checked_add(&FixDecimal::from(1)).is_none());
+        assert!(max.checked_mul(&FixDecimal::from(2)).is_none());
+        assert!(FixDecimal::from(i64::MIN).checked_neg().is_none());
+    }
+
+    #[test]
+    fn test_comparison() {
+        let a = FixDecimal::parse("1.5").unwrap();
+        let b = FixDecimal::parse("1.50").unwrap();
+        let c = FixDecimal::parse("1.49").unwrap();
+
+        assert_eq!(a, b);
+        assert!(c < a);
+        assert!(FixDecimal::parse("-2").unwrap() < c);
+    }
+
+    #[test]
+    fn test_scale() {
+        let value = FixDecimal::parse("1.500").unwrap();
+
+        assert_eq!("1.5", value.normalize().to_string());
+        assert_eq!("1.50000", value.with_scale(5).unwrap().to_string());
+        assert_eq!("1.5", value.with_scale(1).unwrap().to_string());
+        assert!(value.with_scale(0).is_none());
+    }
+}
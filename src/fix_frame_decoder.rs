@@ -0,0 +1,369 @@
+use crate::{utils::FIX_DELIMITER, FixSerializeError};
+
+pub const DEFAULT_MAX_BODY_LEN: usize = 1024 * 1024;
+
+const BEGIN_STRING_PREFIX: &[u8] = b"8=FIX";
+const BODY_LEN_PREFIX: &[u8] = b"9=";
+const CHECK_SUM_PREFIX: &[u8] = b"10=";
+
+// 8=FIXT.1.1 is the longest standard BeginString, but leave some room for custom ones
+const MAX_BEGIN_STRING_LEN: usize = 32;
+const MAX_BODY_LEN_DIGITS: usize = 10;
+// 10=xxx<SOH>
+const CHECK_SUM_FIELD_LEN: usize = 7;
+
+/// Splits a byte stream into complete FIX messages.
+///
+/// Bytes are appended as they arrive from the transport. Every complete message is located
+/// using the BeginString, BodyLength and CheckSum fields; a partial tail stays in the buffer
+/// until the rest of the message is appended.
+pub struct FixFrameDecoder {
+    buffer: Vec<u8>,
+    read_pos: usize,
+    max_body_len: usize,
+}
+
+impl FixFrameDecoder {
+    pub fn new() -> Self {
+        Self::with_max_body_len(DEFAULT_MAX_BODY_LEN)
+    }
+
+    pub fn with_max_body_len(max_body_len: usize) -> Self {
+        Self {
+            buffer: Vec::new(),
+            read_pos: 0,
+            max_body_len,
+        }
+    }
+
+    pub fn append(&mut self, data: &[u8]) {
+        if self.read_pos > 0 {
+            self.buffer.drain(..self.read_pos);
+            self.read_pos = 0;
+        }
+
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Number of buffered bytes which are not yet returned as a frame.
+    pub fn pending_len(&self) -> usize {
+        self.buffer.len() - self.read_pos
+    }
+
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.read_pos = 0;
+    }
+
+    /// Returns the next complete message or `Ok(None)` if more bytes are required.
+    ///
+    /// On error the offending bytes are already skipped, so the caller may log the error
+    /// and keep calling `next_frame` to resynchronize with the stream.
+    pub fn next_frame(&mut self) -> Result<Option<&[u8]>, FixSerializeError> {
+        let data = &self.buffer[self.read_pos..];
+
+        let Some(start) = find_begin_string(data) else {
+            let skipped = data.len() - partial_begin_string_len(data);
+            self.read_pos += skipped;
+
+            if skipped > 0 {
+                return Err(FixSerializeError::GarbageBeforeHeader { skipped });
+            }
+
+            return Ok(None);
+        };
+
+        if start > 0 {
+            self.read_pos += start;
+            return Err(FixSerializeError::GarbageBeforeHeader { skipped: start });
+        }
+
+        let frame_len = match self.read_frame_len(data) {
+            Ok(Some(frame_len)) => frame_len,
+            Ok(None) => return Ok(None),
+            Err(err) => {
+                // Skip the BeginString prefix so the next call searches for a new header
+                self.read_pos += BEGIN_STRING_PREFIX.len();
+                return Err(err);
+            }
+        };
+
+        let start = self.read_pos;
+        self.read_pos += frame_len;
+
+        Ok(Some(&self.buffer[start..start + frame_len]))
+    }
+
+    fn read_frame_len(&self, data: &[u8]) -> Result<Option<usize>, FixSerializeError> {
+        let Some(begin_string_end) = find_delimiter(data, 0, MAX_BEGIN_STRING_LEN)? else {
+            return Ok(None);
+        };
+
+        let body_len_start = begin_string_end + 1;
+
+        let prefix_end = (body_len_start + BODY_LEN_PREFIX.len()).min(data.len());
+        if !BODY_LEN_PREFIX.starts_with(&data[body_len_start..prefix_end]) {
+            return Err(FixSerializeError::BodyLenTagNotFound);
+        }
+
+        let value_start = body_len_start + BODY_LEN_PREFIX.len();
+
+        if data.len() < value_start {
+            return Ok(None);
+        }
+
+        let Some(body_len_end) = find_delimiter(data, value_start, MAX_BODY_LEN_DIGITS + 1)? else {
+            return Ok(None);
+        };
+
+        let body_len = parse_body_len(&data[value_start..body_len_end])?;
+
+        if body_len > self.max_body_len {
+            return Err(FixSerializeError::BodyLenTooLarge {
+                declared: body_len,
+                max: self.max_body_len,
+            });
+        }
+
+        let check_sum_start = body_len_end + 1 + body_len;
+        let frame_len = check_sum_start + CHECK_SUM_FIELD_LEN;
+
+        if data.len() < frame_len {
+            return Ok(None);
+        }
+
+        if !is_check_sum_field(&data[check_sum_start..frame_len]) {
+            return Err(FixSerializeError::CheckSumTagNotFound);
+        }
+
+        Ok(Some(frame_len))
+    }
+}
+
+impl Default for FixFrameDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn find_begin_string(data: &[u8]) -> Option<usize> {
+    data.windows(BEGIN_STRING_PREFIX.len())
+        .position(|window| window == BEGIN_STRING_PREFIX)
+}
+
+// Length of the tail which may turn into a BeginString once more bytes arrive
+fn partial_begin_string_len(data: &[u8]) -> usize {
+    for len in (1..BEGIN_STRING_PREFIX.len()).rev() {
+        if data.ends_with(&BEGIN_STRING_PREFIX[..len]) {
+            return len;
+        }
+    }
+
+    0
+}
+
+fn find_delimiter(
+    data: &[u8],
+    from: usize,
+    max_len: usize,
+) -> Result<Option<usize>, FixSerializeError> {
+    let to = (from + max_len).min(data.len());
+
+    if let Some(index) = data[from..to].iter().position(|b| *b == FIX_DELIMITER) {
+        return Ok(Some(from + index));
+    }
+
+    if to - from >= max_len {
+        return Err(FixSerializeError::FixDelimiterNotFound { offset: from });
+    }
+
+    Ok(None)
+}
+
+fn parse_body_len(src: &[u8]) -> Result<usize, FixSerializeError> {
+    if src.is_empty() || !src.iter().all(|b| b.is_ascii_digit()) {
+        return Err(FixSerializeError::InvalidBodyLenValue);
+    }
+
+    let mut result = 0usize;
+    for b in src {
+        result = result * 10 + (b - b'0') as usize;
+    }
+
+    Ok(result)
+}
+
+fn is_check_sum_field(src: &[u8]) -> bool {
+    src.starts_with(CHECK_SUM_PREFIX)
+        && src[CHECK_SUM_PREFIX.len()..CHECK_SUM_FIELD_LEN - 1]
+            .iter()
+            .all(|b| b.is_ascii_digit())
+        && src[CHECK_SUM_FIELD_LEN - 1] == FIX_DELIMITER
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tags, FixFrameDecoder, FixMessageReader, FixMessageWriter, FixSerializeError};
+
+    const MESSAGE: &[u8] = b"8=FIX.4.4\x019=75\x0135=A\x0134=1092\x0149=TESTBUY1\x0152=20180920-18:24:59.643\x0156=TESTSELL1\x0198=0\x01108=60\x0110=178\x01";
+
+    #[test]
+    fn test_single_message() {
+        let mut decoder = FixFrameDecoder::new();
+        decoder.append(MESSAGE);
+
+        let frame = decoder.next_frame().unwrap().unwrap();
+        assert_eq!(MESSAGE, frame);
+
+        FixMessageReader::from_bytes(frame).check_payload().unwrap();
+
+        assert!(decoder.next_frame().unwrap().is_none());
+        assert_eq!(0, decoder.pending_len());
+    }
+
+    #[test]
+    fn test_message_split_byte_by_byte() {
+        let mut decoder = FixFrameDecoder::new();
+
+        for (index, byte) in MESSAGE.iter().enumerate() {
+            decoder.append(&[*byte]);
+
+            let frame = decoder.next_frame().unwrap();
+
+            if index == MESSAGE.len() - 1 {
+                assert_eq!(MESSAGE, frame.unwrap());
+            } else {
+                assert!(frame.is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn test_merged_messages_with_partial_tail() {
+        let mut src = MESSAGE.to_vec();
+        src.extend_from_slice(MESSAGE);
+        src.extend_from_slice(&MESSAGE[..20]);
+
+        let mut decoder = FixFrameDecoder::new();
+        decoder.append(&src);
+
+        assert_eq!(MESSAGE, decoder.next_frame().unwrap().unwrap());
+        assert_eq!(MESSAGE, decoder.next_frame().unwrap().unwrap());
+        assert!(decoder.next_frame().unwrap().is_none());
+        assert_eq!(20, decoder.pending_len());
+
+        decoder.append(&MESSAGE[20..]);
+        assert_eq!(MESSAGE, decoder.next_frame().unwrap().unwrap());
+    }
+
+    #[test]
+    fn test_garbage_before_header() {
+        let mut src = b"garbage".to_vec();
+        src.extend_from_slice(MESSAGE);
+
+        let mut decoder = FixFrameDecoder::new();
+        decoder.append(&src);
+
+        match decoder.next_frame() {
+            Err(FixSerializeError::GarbageBeforeHeader { skipped }) => assert_eq!(7, skipped),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+
+        assert_eq!(MESSAGE, decoder.next_frame().unwrap().unwrap());
+    }
+
+    #[test]
+    fn test_garbage_keeps_possible_header_start() {
+        let mut decoder = FixFrameDecoder::new();
+        decoder.append(b"garbage8=FI");
+
+        match decoder.next_frame() {
+            Err(FixSerializeError::GarbageBeforeHeader { skipped }) => assert_eq!(7, skipped),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+
+        assert!(decoder.next_frame().unwrap().is_none());
+
+        decoder.append(&MESSAGE[4..]);
+        assert_eq!(MESSAGE, decoder.next_frame().unwrap().unwrap());
+    }
+
+    #[test]
+    fn test_body_len_too_large() {
+        let mut src = b"8=FIX.4.4\x019=999999\x0135=A\x01".to_vec();
+        src.extend_from_slice(MESSAGE);
+
+        let mut decoder = FixFrameDecoder::with_max_body_len(4096);
+        decoder.append(&src);
+
+        match decoder.next_frame() {
+            Err(FixSerializeError::BodyLenTooLarge { declared, max }) => {
+                assert_eq!(999999, declared);
+                assert_eq!(4096, max);
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+
+        assert!(decoder.next_frame().unwrap_err().is_garbage_before_header());
+        assert_eq!(MESSAGE, decoder.next_frame().unwrap().unwrap());
+    }
+
+    #[test]
+    fn test_missing_body_len() {
+        let mut decoder = FixFrameDecoder::new();
+        decoder.append(b"8=FIX.4.4\x0135=A\x01");
+
+        assert!(decoder
+            .next_frame()
+            .unwrap_err()
+            .is_body_len_tag_not_found());
+    }
+
+    #[test]
+    fn test_body_len_does_not_point_to_check_sum() {
+        let mut src = MESSAGE.to_vec();
+        src[13] = b'4';
+
+        let mut decoder = FixFrameDecoder::new();
+        decoder.append(&src);
+
+        assert!(decoder
+            .next_frame()
+            .unwrap_err()
+            .is_check_sum_tag_not_found());
+    }
+
+    #[test]
+    fn test_begin_string_without_delimiter() {
+        let mut decoder = FixFrameDecoder::new();
+        decoder.append(&[b'8', b'=', b'F', b'I', b'X'].repeat(10));
+
+        match decoder.next_frame() {
+            Err(FixSerializeError::FixDelimiterNotFound { offset }) => assert_eq!(0, offset),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_data_field_with_delimiter_and_check_sum() {
+        // RawData looks like the end of the message, the frame is found by BodyLength
+        let mut writer = FixMessageWriter::new("FIX.4.4", "B");
+        writer.with_data(tags::RAW_DATA_LENGTH, tags::RAW_DATA, b"a\x0110=123\x01");
+        let message = writer.compile_message();
+
+        let mut decoder = FixFrameDecoder::new();
+        decoder.append(&message);
+        decoder.append(MESSAGE);
+
+        let frame = decoder.next_frame().unwrap().unwrap();
+        assert_eq!(message.as_slice(), frame);
+
+        let reader = FixMessageReader::from_bytes(frame).check_payload().unwrap();
+        assert_eq!(
+            Some("a\x0110=123\x01"),
+            reader.get_value(tags::RAW_DATA).unwrap()
+        );
+
+        assert_eq!(MESSAGE, decoder.next_frame().unwrap().unwrap());
+    }
+}
@@ -0,0 +1,348 @@
+use crate::{
+    fix_data_type::parse_seq_num, AsFixTag, FixDataType, FixMessageItem, FixSerializeError, Tag,
+};
+
+/// Layout of a repeating group: the NumInGroup tag, the delimiter which starts every entry,
+/// the other tags of the entry and the nested groups.
+#[derive(Debug, Clone)]
+pub struct FixGroupDefinition {
+    count_tag: Tag,
+    delimiter_tag: Tag,
+    tags: Vec<Tag>,
+    groups: Vec<FixGroupDefinition>,
+}
+
+impl FixGroupDefinition {
+    pub fn new(count_tag: Tag, delimiter_tag: Tag) -> Self {
+        Self {
+            count_tag,
+            delimiter_tag,
+            tags: Vec::new(),
+            groups: Vec::new(),
+        }
+    }
+
+    pub fn with_tags(mut self, tags: &[Tag]) -> Self {
+        self.tags.extend_from_slice(tags);
+        self
+    }
+
+    pub fn with_group(mut self, group: FixGroupDefinition) -> Self {
+        self.groups.push(group);
+        self
+    }
+
+    pub fn get_count_tag(&self) -> Tag {
+        self.count_tag
+    }
+
+    pub fn get_delimiter_tag(&self) -> Tag {
+        self.delimiter_tag
+    }
+
+    pub fn get_tags(&self) -> &[Tag] {
+        self.tags.as_slice()
+    }
+
+    pub fn get_groups(&self) -> &[FixGroupDefinition] {
+        self.groups.as_slice()
+    }
+
+    fn get_nested_group(&self, count_tag: Tag) -> Option<&FixGroupDefinition> {
+        self.groups
+            .iter()
+            .find(|group| group.count_tag == count_tag)
+    }
+}
+
+/// Single instance of a repeating group read from a message, or the fields of the message
+/// read by `FixMessageReader::read_fields`
+#[derive(Debug, Clone)]
+pub struct FixGroupEntry<'s> {
+    pub(crate) items: Vec<FixMessageItem<'s>>,
+    pub(crate) groups: Vec<(Tag, Vec<FixGroupEntry<'s>>)>,
+}
+
+impl<'s> FixGroupEntry<'s> {
+    pub fn get_value(&self, key: impl AsFixTag) -> Option<&'s str> {
+        self.items
+            .iter()
+            .find(|itm| key.is_same_tag(itm.key))
+            .map(|itm| itm.value)
+    }
+
+    pub fn get_values(&self, key: impl AsFixTag) -> Vec<&'s str> {
+        self.items
+            .iter()
+            .filter(|itm| key.is_same_tag(itm.key))
+            .map(|itm| itm.value)
+            .collect()
+    }
+
+    /// Value of a data field as it is in the message. It may be binary.
+    pub fn get_data(&self, key: impl AsFixTag) -> Option<&'s [u8]> {
+        self.items
+            .iter()
+            .find(|itm| key.is_same_tag(itm.key))
+            .map(|itm| itm.data)
+    }
+
+    /// Entries of the nested group. Empty if the group is not present in this entry.
+    pub fn get_group(&self, count_tag: Tag) -> &[FixGroupEntry<'s>] {
+        self.groups
+            .iter()
+            .find(|(tag, _)| *tag == count_tag)
+            .map(|(_, entries)| entries.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Fields of the entry without the fields of the nested groups
+    pub fn get_items(&self) -> &[FixMessageItem<'s>] {
+        self.items.as_slice()
+    }
+}
+
+/// Reads the group which NumInGroup field is `items[start]`.
+/// Returns the entries and the index of the first item after the group.
+pub(crate) fn read_group<'s>(
+    items: &[FixMessageItem<'s>],
+    start: usize,
+    definition: &FixGroupDefinition,
+) -> Result<(Vec<FixGroupEntry<'s>>, usize), FixSerializeError> {
+    let count_item = &items[start];
+
+    let Some(declared) = parse_seq_num(count_item.value) else {
+        return Err(FixSerializeError::InvalidFieldValue {
+            tag: definition.count_tag,
+            value: count_item.value.to_string(),
+            data_type: FixDataType::NumInGroup,
+        });
+    };
+
+    let mut entries: Vec<FixGroupEntry<'s>> = Vec::new();
+    let mut index = start + 1;
+
+    while index < items.len() {
+        let itm = items[index];
+        let tag = itm.get_tag()?;
+
+        if tag == definition.delimiter_tag {
+            entries.push(FixGroupEntry {
+                items: vec![itm],
+                groups: Vec::new(),
+            });
+            index += 1;
+            continue;
+        }
+
+        let nested_group = definition.get_nested_group(tag);
+
+        if nested_group.is_none() && !definition.tags.contains(&tag) {
+            break;
+        }
+
+        // Field of the group which comes before the delimiter
+        let Some(entry) = entries.last_mut() else {
+            return Err(FixSerializeError::GroupFieldOutOfOrder { tag });
+        };
+
+        // Only the delimiter starts a new entry, the other fields appear once per entry
+        if entry.items.iter().any(|itm| tag.is_same_tag(itm.key))
+            || entry.groups.iter().any(|(count_tag, _)| *count_tag == tag)
+        {
+            return Err(FixSerializeError::GroupFieldRepeated { tag });
+        }
+
+        match nested_group {
+            Some(nested_group) => {
+                let (nested_entries, next_index) = read_group(items, index, nested_group)?;
+                entry.groups.push((tag, nested_entries));
+                index = next_index;
+            }
+            None => {
+                entry.items.push(itm);
+                index += 1;
+            }
+        }
+    }
+
+    if entries.len() as u64 != declared {
+        return Err(FixSerializeError::GroupCountMismatch {
+            tag: definition.count_tag,
+            declared: declared as usize,
+            actual: entries.len(),
+        });
+    }
+
+    Ok((entries, index))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tags, FixGroupDefinition, FixGroupWriter, FixMessageReader, FixMessageWriter};
+
+    fn parties_definition() -> FixGroupDefinition {
+        FixGroupDefinition::new(tags::NO_PARTY_IDS, tags::PARTY_ID)
+            .with_tags(&[tags::PARTY_ID_SOURCE, tags::PARTY_ROLE])
+    }
+
+    fn legs_definition() -> FixGroupDefinition {
+        FixGroupDefinition::new(tags::NO_LEGS, tags::LEG_SYMBOL)
+            .with_tags(&[tags::LEG_SIDE, tags::LEG_RATIO_QTY])
+            .with_group(
+                FixGroupDefinition::new(tags::NO_LEG_SECURITY_ALT_ID, tags::LEG_SECURITY_ALT_ID)
+                    .with_tags(&[tags::LEG_SECURITY_ALT_ID_SOURCE]),
+            )
+    }
+
+    #[test]
+    fn test_read_group() {
+        let fix_string =
+            "8=FIX.4.4|9=63|35=D|11=ORDER1|453=2|448=BROKER1|452=1|448=TRADER1|452=11|54=1|10=006|";
+        let reader = FixMessageReader::from_str(fix_string);
+
+        let parties = reader.get_group(&parties_definition()).unwrap();
+
+        assert_eq!(2, parties.len());
+        assert_eq!(Some("BROKER1"), parties[0].get_value(tags::PARTY_ID));
+        assert_eq!(Some("1"), parties[0].get_value(tags::PARTY_ROLE));
+        assert_eq!(Some("TRADER1"), parties[1].get_value(tags::PARTY_ID));
+        assert_eq!(Some("11"), parties[1].get_value(tags::PARTY_ROLE));
+        assert_eq!(None, parties[1].get_value(tags::SIDE));
+    }
+
+    #[test]
+    fn test_missing_group() {
+        let reader = FixMessageReader::from_str("8=FIX.4.4|9=20|35=D|11=ORDER1|10=000|");
+        assert!(reader.get_group(&parties_definition()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_read_nested_groups() {
+        let mut alt_ids = FixGroupWriter::new(tags::NO_LEG_SECURITY_ALT_ID);
+        alt_ids
+            .add_entry()
+            .with_value(tags::LEG_SECURITY_ALT_ID, "US0378331005")
+            .with_value(tags::LEG_SECURITY_ALT_ID_SOURCE, "4");
+        alt_ids
+            .add_entry()
+            .with_value(tags::LEG_SECURITY_ALT_ID, "2046251")
+            .with_value(tags::LEG_SECURITY_ALT_ID_SOURCE, "2");
+
+        let mut legs = FixGroupWriter::new(tags::NO_LEGS);
+        legs.add_entry()
+            .with_value(tags::LEG_SYMBOL, "AAPL")
+            .with_group(&alt_ids)
+            .with_value(tags::LEG_SIDE, '1');
+        legs.add_entry()
+            .with_value(tags::LEG_SYMBOL, "MSFT")
+            .with_value(tags::LEG_SIDE, '2');
+
+        let mut writer = FixMessageWriter::new("FIX.4.4", "AB");
+        writer.with_value(tags::CL_ORD_ID, "ORDER1");
+        writer.with_group(&legs);
+        writer.with_value(tags::SIDE, '1');
+
+        let fix_message = writer.compile_message();
+        let reader = FixMessageReader::from_bytes(&fix_message);
+
+        let legs = reader.get_group(&legs_definition()).unwrap();
+        assert_eq!(2, legs.len());
+
+        assert_eq!(Some("AAPL"), legs[0].get_value(tags::LEG_SYMBOL));
+        assert_eq!(Some("1"), legs[0].get_value(tags::LEG_SIDE));
+
+        let alt_ids = legs[0].get_group(tags::NO_LEG_SECURITY_ALT_ID);
+        assert_eq!(2, alt_ids.len());
+        assert_eq!(
+            Some("2046251"),
+            alt_ids[1].get_value(tags::LEG_SECURITY_ALT_ID)
+        );
+
+        assert_eq!(Some("MSFT"), legs[1].get_value(tags::LEG_SYMBOL));
+        assert!(legs[1].get_group(tags::NO_LEG_SECURITY_ALT_ID).is_empty());
+
+        assert_eq!(Some("1"), reader.get_value(tags::SIDE).unwrap());
+    }
+
+    #[test]
+    fn test_read_data_field() {
+        let mut lines = FixGroupWriter::new(tags::NO_LINES_OF_TEXT);
+        lines.add_entry().with_value(tags::TEXT, "first").with_data(
+            tags::ENCODED_TEXT_LEN,
+            tags::ENCODED_TEXT,
+            [0xff, 0x01, 0x80],
+        );
+        lines.add_entry().with_value(tags::TEXT, "second");
+
+        let mut writer = FixMessageWriter::new("FIX.4.4", "B");
+        writer.with_group(&lines);
+        let message = writer.compile_message();
+
+        let definition = FixGroupDefinition::new(tags::NO_LINES_OF_TEXT, tags::TEXT)
+            .with_tags(&[tags::ENCODED_TEXT_LEN, tags::ENCODED_TEXT]);
+        let reader = FixMessageReader::from_bytes(&message);
+        let lines = reader.get_group(&definition).unwrap();
+
+        assert_eq!(2, lines.len());
+        assert_eq!(
+            Some([0xff, 0x01, 0x80].as_slice()),
+            lines[0].get_data(tags::ENCODED_TEXT)
+        );
+        assert_eq!(None, lines[1].get_data(tags::ENCODED_TEXT));
+    }
+
+    #[test]
+    fn test_group_count_mismatch() {
+        let fix_string = "8=FIX.4.4|9=20|35=D|453=3|448=BROKER1|452=1|448=TRADER1|54=1|10=000|";
+        let reader = FixMessageReader::from_str(fix_string);
+
+        match reader.get_group(&parties_definition()) {
+            Err(crate::FixSerializeError::GroupCountMismatch {
+                tag,
+                declared,
+                actual,
+            }) => {
+                assert_eq!(tags::NO_PARTY_IDS, tag);
+                assert_eq!(3, declared);
+                assert_eq!(2, actual);
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_group_field_before_delimiter() {
+        let fix_string = "8=FIX.4.4|9=20|35=D|453=1|452=1|448=BROKER1|10=000|";
+        let reader = FixMessageReader::from_str(fix_string);
+
+        assert!(reader
+            .get_group(&parties_definition())
+            .unwrap_err()
+            .is_group_field_out_of_order());
+    }
+
+    #[test]
+    fn test_group_field_repeated() {
+        let fix_string = "8=FIX.4.4|9=20|35=D|453=1|448=BROKER1|452=1|452=3|10=000|";
+        let reader = FixMessageReader::from_str(fix_string);
+
+        match reader.get_group(&parties_definition()) {
+            Err(crate::FixSerializeError::GroupFieldRepeated { tag }) => {
+                assert_eq!(tags::PARTY_ROLE, tag)
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_invalid_group_count() {
+        let fix_string = "8=FIX.4.4|9=20|35=D|453=X|448=BROKER1|10=000|";
+        let reader = FixMessageReader::from_str(fix_string);
+
+        assert!(reader
+            .get_group(&parties_definition())
+            .unwrap_err()
+            .is_invalid_field_value());
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{AsFixTag, FixMessageBodyBuilder, FixValue, Tag};
+
+/// Repeating group written as the NumInGroup field followed by the entries.
+///
+/// The first field of every entry is the delimiter of the group, so it must be
+/// the same tag for all the entries.
+pub struct FixGroupWriter {
+    count_tag: Tag,
+    entries: Vec<FixGroupEntryWriter>,
+}
+
+impl FixGroupWriter {
+    pub fn new(count_tag: Tag) -> Self {
+        Self {
+            count_tag,
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn add_entry(&mut self) -> &mut FixGroupEntryWriter {
+        self.entries.push(FixGroupEntryWriter {
+            body: FixMessageBodyBuilder::new(),
+        });
+
+        self.entries.last_mut().unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Group without entries is not written at all
+    pub(crate) fn write_to(&self, body: &mut FixMessageBodyBuilder) {
+        if self.entries.is_empty() {
+            return;
+        }
+
+        body.append(self.count_tag, self.entries.len());
+
+        for entry in &self.entries {
+            body.append_raw(entry.body.as_slice());
+        }
+    }
+}
+
+pub struct FixGroupEntryWriter {
+    body: FixMessageBodyBuilder,
+}
+
+impl FixGroupEntryWriter {
+    pub fn with_value(&mut self, key: impl AsFixTag, value: impl FixValue) -> &mut Self {
+        self.body.append(key, value);
+        self
+    }
+
+    /// Writes the data field preceded by its length field.
+    /// The value may be binary and contain the delimiter.
+    pub fn with_data(
+        &mut self,
+        length_tag: Tag,
+        data_tag: Tag,
+        value: impl AsRef<[u8]>,
+    ) -> &mut Self {
+        let value = value.as_ref();
+        self.body.append(length_tag, value.len());
+        self.body.append_data(data_tag, value);
+        self
+    }
+
+    /// Writes a nested group at the current position of the entry
+    pub fn with_group(&mut self, group: &FixGroupWriter) -> &mut Self {
+        group.write_to(&mut self.body);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tags, FixGroupWriter, FixMessageWriter};
+
+    #[test]
+    fn test_write_group() {
+        let mut parties = FixGroupWriter::new(tags::NO_PARTY_IDS);
+        parties
+            .add_entry()
+            .with_value(tags::PARTY_ID, "BROKER1")
+            .with_value(tags::PARTY_ROLE, 1);
+        parties
+            .add_entry()
+            .with_value(tags::PARTY_ID, "TRADER1")
+            .with_value(tags::PARTY_ROLE, 11);
+
+        let mut writer = FixMessageWriter::new("FIX.4.4", "D");
+        writer.with_value(tags::CL_ORD_ID, "ORDER1");
+        writer.with_group(&parties);
+        writer.with_value(tags::SIDE, '1');
+
+        assert_eq!(
+            "8=FIX.4.4|9=63|35=D|11=ORDER1|453=2|448=BROKER1|452=1|448=TRADER1|452=11|54=1|10=006|",
+            writer.to_string()
+        );
+    }
+
+    #[test]
+    fn test_empty_group_is_not_written() {
+        let mut writer = FixMessageWriter::new("FIX.4.4", "D");
+        writer.with_group(&FixGroupWriter::new(tags::NO_PARTY_IDS));
+
+        assert_eq!("8=FIX.4.4|9=5|35=D|10=183|", writer.to_string());
+    }
+}
@@ -0,0 +1,350 @@
+use std::{io, time::Duration};
+
+use tokio::net::TcpStream;
+
+use crate::{
+    fix_connection::{self, AsyncStream, FixCommand},
+    DisconnectReason, FixFrameDecoder, FixReceiver, FixSender, FixSession, FixSessionEvent,
+    MessageStore, SessionClock,
+};
+
+/// Connects the session to the counterparty over TCP and keeps it connected.
+///
+/// `start` spawns a task which runs the [`FixSession`] and returns the handles of the
+/// application: [`FixSender`] for outbound messages and [`FixReceiver`] for inbound
+/// messages and session events. When the connection is lost or can not be established,
+/// the task reports `DisconnectReason::Transport` and connects again after the reconnect
+/// interval, which doubles after each attempt up to the maximum and drops back to the
+/// minimum after a successful Logon. The sequence numbers are kept between connections.
+/// Outside of the session schedule the task does not connect and waits for the session
+/// to start.
+///
+/// With the `tls` feature the connection can be wrapped with TLS, see [`Self::with_tls`].
+///
+/// The task stops after the logout requested by `FixSender::logout` or when every
+/// [`FixSender`] is dropped.
+pub struct FixInitiator<C: SessionClock, S: MessageStore> {
+    session: FixSession<C, S>,
+    endpoint: Endpoint,
+    min_reconnect_interval: Duration,
+    max_reconnect_interval: Duration,
+}
+
+impl<C, S> FixInitiator<C, S>
+where
+    C: SessionClock + Send + 'static,
+    S: MessageStore + Send + 'static,
+{
+    pub const DEFAULT_MIN_RECONNECT_INTERVAL: Duration = Duration::from_secs(1);
+    pub const DEFAULT_MAX_RECONNECT_INTERVAL: Duration = Duration::from_secs(30);
+
+    pub fn new(session: FixSession<C, S>, host: impl Into<String>, port: u16) -> Self {
+        Self {
+            session,
+            endpoint: Endpoint {
+                host: host.into(),
+                port,
+                #[cfg(feature = "tls")]
+                tls: None,
+            },
+            min_reconnect_interval: Self::DEFAULT_MIN_RECONNECT_INTERVAL,
+            max_reconnect_interval: Self::DEFAULT_MAX_RECONNECT_INTERVAL,
+        }
+    }
+
+    /// Delay before the first reconnect attempt and the limit it doubles up to
+    pub fn with_reconnect_interval(mut self, min: Duration, max: Duration) -> Self {
+        self.min_reconnect_interval = min;
+        self.max_reconnect_interval = max.max(min);
+        self
+    }
+
+    /// Performs the TLS handshake after connecting. A failed handshake is reported as
+    /// `DisconnectReason::Transport` and retried like a failed connect.
+    #[cfg(feature = "tls")]
+    pub fn with_tls(mut self, tls: crate::TlsClientConfig) -> Self {
+        self.endpoint.tls = Some(tls);
+        self
+    }
+
+    pub fn get_host(&self) -> &str {
+        &self.endpoint.host
+    }
+
+    pub fn get_port(&self) -> u16 {
+        self.endpoint.port
+    }
+
+    /// Spawns the task running the session. Must be called within a tokio runtime.
+    pub fn start(self) -> (FixSender, FixReceiver) {
+        let (sender, commands) = fix_connection::command_channel();
+        let (events, receiver) = fix_connection::event_channel();
+        tokio::spawn(self.run(commands, events));
+        (sender, receiver)
+    }
+
+    async fn run(
+        mut self,
+        mut commands: tokio::sync::mpsc::UnboundedReceiver<FixCommand>,
+        events: tokio::sync::mpsc::UnboundedSender<FixSessionEvent>,
+    ) {
+        let mut reconnect_interval = self.min_reconnect_interval;
+        let mut emit = |event| {
+            let _ = events.send(event);
+        };
+
+        loop {
+            if !self.session.is_session_time() {
+                if !fix_connection::wait_session_time(&mut self.session, &mut commands).await {
+                    return;
+                }
+
+                reconnect_interval = self.min_reconnect_interval;
+            }
+
+            match self.endpoint.connect().await {
+                Ok(stream) => {
+                    let end = fix_connection::run_connection(
+                        &mut self.session,
+                        stream,
+                        FixFrameDecoder::new(),
+                        &mut commands,
+                        &mut emit,
+                    )
+                    .await;
+
+                    if end.stop {
+                        return;
+                    }
+
+                    if end.logged_on {
+                        reconnect_interval = self.min_reconnect_interval;
+                    }
+                }
+                Err(err) => {
+                    let reason = DisconnectReason::Transport(err.to_string());
+                    emit(FixSessionEvent::Disconnect(reason));
+                }
+            }
+
+            let keep_running = fix_connection::wait_disconnected(
+                &mut self.session,
+                &mut commands,
+                reconnect_interval,
+            )
+            .await;
+
+            if !keep_running {
+                return;
+            }
+
+            reconnect_interval = (reconnect_interval * 2).min(self.max_reconnect_interval);
+        }
+    }
+}
+
+// Where to connect to. Kept apart from the session, so connecting does not borrow it.
+struct Endpoint {
+    host: String,
+    port: u16,
+    #[cfg(feature = "tls")]
+    tls: Option<crate::TlsClientConfig>,
+}
+
+impl Endpoint {
+    async fn connect(&self) -> io::Result<Box<dyn AsyncStream>> {
+        let stream = TcpStream::connect((self.host.as_str(), self.port)).await?;
+        let _ = stream.set_nodelay(true);
+
+        #[cfg(feature = "tls")]
+        if let Some(tls) = &self.tls {
+            return Ok(Box::new(tls.connect(&self.host, stream).await?));
+        }
+
+        Ok(Box::new(stream))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+
+    use crate::{
+        tags, utils, DisconnectReason, FixFrameDecoder, FixInitiator, FixMessageReader,
+        FixMessageWriter, FixReceiver, FixSession, FixSessionConfig, FixSessionEvent, ManualClock,
+        SessionRole, SessionSchedule, UtcTimeOnly, UtcTimestamp,
+    };
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    async fn read_message(stream: &mut TcpStream, decoder: &mut FixFrameDecoder) -> String {
+        let mut buffer = [0u8; 1024];
+
+        loop {
+            if let Some(frame) = decoder.next_frame().unwrap() {
+                return utils::bytes_to_fix_string(frame);
+            }
+
+            let size = stream.read(&mut buffer).await.unwrap();
+            assert!(size > 0, "Connection is closed");
+            decoder.append(&buffer[..size]);
+        }
+    }
+
+    fn outbound(msg_type: &str, seq_num: u64) -> Vec<u8> {
+        let mut writer = FixMessageWriter::new("FIX.4.4", msg_type);
+        writer.with_value(tags::SENDER_COMP_ID, "VENUE");
+        writer.with_value(tags::TARGET_COMP_ID, "CLIENT");
+        writer.with_value(tags::MSG_SEQ_NUM, seq_num);
+        if msg_type == "A" {
+            writer.with_value(tags::ENCRYPT_METHOD, 0);
+            writer.with_value(tags::HEART_BT_INT, 30);
+        }
+        writer.compile_message()
+    }
+
+    async fn recv(receiver: &mut FixReceiver) -> FixSessionEvent {
+        tokio::time::timeout(TIMEOUT, receiver.recv())
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    async fn start_initiator(listener: &TcpListener) -> (crate::FixSender, FixReceiver) {
+        let port = listener.local_addr().unwrap().port();
+        let config = FixSessionConfig::new(SessionRole::Initiator, "FIX.4.4", "CLIENT", "VENUE");
+
+        FixInitiator::new(FixSession::new(config), "127.0.0.1", port)
+            .with_reconnect_interval(Duration::from_millis(10), Duration::from_millis(100))
+            .start()
+    }
+
+    #[tokio::test]
+    async fn test_logon_and_exchange_messages() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let (sender, mut receiver) = start_initiator(&listener).await;
+
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut decoder = FixFrameDecoder::new();
+
+        let logon = read_message(&mut stream, &mut decoder).await;
+        assert!(logon.contains("|35=A|"));
+        assert!(logon.contains("|34=1|"));
+
+        stream.write_all(&outbound("A", 1)).await.unwrap();
+        assert_eq!(FixSessionEvent::LoggedOn, recv(&mut receiver).await);
+
+        let mut order = FixMessageWriter::new("FIX.4.4", "D");
+        order.with_value(tags::CL_ORD_ID, "order-1");
+        assert_eq!(2, sender.send(order).await.unwrap());
+
+        let order = read_message(&mut stream, &mut decoder).await;
+        assert!(order.contains("|35=D|"));
+        assert!(order.contains("|11=order-1|"));
+
+        let execution_report = outbound("8", 2);
+        stream.write_all(&execution_report).await.unwrap();
+        let FixSessionEvent::Application(message) = recv(&mut receiver).await else {
+            panic!("Application message expected");
+        };
+        assert_eq!(
+            "8",
+            FixMessageReader::from_bytes(&message)
+                .get_message_type()
+                .unwrap()
+        );
+
+        sender.logout(None).unwrap();
+        let logout = read_message(&mut stream, &mut decoder).await;
+        assert!(logout.contains("|35=5|"));
+
+        stream.write_all(&outbound("5", 3)).await.unwrap();
+        assert_eq!(FixSessionEvent::LoggedOut, recv(&mut receiver).await);
+        assert_eq!(
+            FixSessionEvent::Disconnect(DisconnectReason::LoggedOut),
+            recv(&mut receiver).await
+        );
+
+        // Task is stopped after the logout
+        assert!(tokio::time::timeout(TIMEOUT, receiver.recv())
+            .await
+            .unwrap()
+            .is_none());
+        assert!(sender
+            .send(FixMessageWriter::new("FIX.4.4", "D"))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_after_connection_is_lost() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let (sender, mut receiver) = start_initiator(&listener).await;
+
+        {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let logon = read_message(&mut stream, &mut FixFrameDecoder::new()).await;
+            assert!(logon.contains("|34=1|"));
+        }
+
+        let FixSessionEvent::Disconnect(DisconnectReason::Transport(_)) = recv(&mut receiver).await
+        else {
+            panic!("Transport disconnect expected");
+        };
+
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let logon = read_message(&mut stream, &mut FixFrameDecoder::new()).await;
+        assert!(logon.contains("|35=A|"));
+        assert!(logon.contains("|34=2|"));
+
+        let err = sender
+            .send(FixMessageWriter::new("FIX.4.4", "D"))
+            .await
+            .unwrap_err();
+        assert!(err.is_not_logged_on());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_session_time() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let schedule = SessionSchedule::daily(
+            UtcTimeOnly::new(8, 0, 0, 0).unwrap(),
+            UtcTimeOnly::new(17, 0, 0, 0).unwrap(),
+        );
+        let config = FixSessionConfig::new(SessionRole::Initiator, "FIX.4.4", "CLIENT", "VENUE")
+            .with_schedule(schedule);
+        let at = |hour| {
+            UtcTimestamp::new(2024, 1, 2, hour, 0, 0, 0)
+                .unwrap()
+                .to_system_time()
+        };
+        let clock = ManualClock::new(at(7));
+
+        let (_sender, _receiver) = FixInitiator::new(
+            FixSession::with_clock(config, clock.clone()),
+            "127.0.0.1",
+            port,
+        )
+        .with_reconnect_interval(Duration::from_millis(10), Duration::from_millis(100))
+        .start();
+
+        // No connection before the session starts
+        let accept = tokio::time::timeout(Duration::from_millis(1500), listener.accept()).await;
+        assert!(accept.is_err());
+
+        clock.set(at(8));
+        let (mut stream, _) = tokio::time::timeout(TIMEOUT, listener.accept())
+            .await
+            .unwrap()
+            .unwrap();
+        let logon = read_message(&mut stream, &mut FixFrameDecoder::new()).await;
+        assert!(logon.contains("|35=A|"));
+    }
+}
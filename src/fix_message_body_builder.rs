@@ -1,4 +1,7 @@
-use crate::utils::{FIX_BODY_LEN, FIX_VERSION};
+use crate::{
+    utils::{FIX_BODY_LEN, FIX_DELIMITER, FIX_EQUALS, FIX_VERSION},
+    AsFixTag, FixValue,
+};
 
 #[derive(Clone)]
 pub struct FixMessageBodyBuilder {
@@ -9,8 +12,49 @@ impl FixMessageBodyBuilder {
     pub fn new() -> Self {
         Self { data: Vec::new() }
     }
-    pub fn append(&mut self, key: &str, value: &str) {
-        crate::utils::write_fix_chunk(&mut self.data, key, value);
+    pub fn append(&mut self, key: impl AsFixTag, value: impl FixValue) {
+        key.write_fix_tag(&mut self.data);
+        self.data.push(FIX_EQUALS);
+        value.write_fix_value(&mut self.data);
+        self.data.push(FIX_DELIMITER);
+    }
+
+    pub(crate) fn append_raw(&mut self, data: &[u8]) {
+        self.data.extend_from_slice(data);
+    }
+
+    /// Value of DATA fields which is written as is
+    pub(crate) fn append_data(&mut self, key: impl AsFixTag, value: &[u8]) {
+        key.write_fix_tag(&mut self.data);
+        self.data.push(FIX_EQUALS);
+        self.data.extend_from_slice(value);
+        self.data.push(FIX_DELIMITER);
+    }
+
+    /// Inserts the fields after the first one and the fields right after it whose keys
+    /// match `skip`
+    pub(crate) fn insert_after_first_field(&mut self, data: &[u8], skip: impl Fn(&[u8]) -> bool) {
+        let mut position = self.get_field_end(0);
+
+        while let Some(key_len) = self.data[position..].iter().position(|b| *b == FIX_EQUALS) {
+            if !skip(&self.data[position..position + key_len]) {
+                break;
+            }
+
+            position = self.get_field_end(position);
+        }
+
+        self.data.splice(position..position, data.iter().copied());
+    }
+
+    fn get_field_end(&self, position: usize) -> usize {
+        match self.data[position..]
+            .iter()
+            .position(|b| *b == FIX_DELIMITER)
+        {
+            Some(index) => position + index + 1,
+            None => self.data.len(),
+        }
     }
 
     pub fn get_checksum(&self, fix_version: &str) -> String {
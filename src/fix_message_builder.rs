@@ -1,6 +1,6 @@
 use crate::{
     utils::{bytes_to_fix_string, calculate_check_sum, compile_fix_chunk, split_fix_to_tags},
-    FixSerializeError,
+    AsFixTag, FixSerializeError, FixValue,
 };
 
 #[derive(Clone)]
@@ -18,12 +18,6 @@ impl FixMessageBuilder {
         let tags = split_fix_to_tags(payload);
 
         let Some(version) = tags.get(crate::utils::FIX_VERSION.as_bytes()) else {
-            println!(
-                "Tag not found: {:?}. Str: {}",
-                payload,
-                std::str::from_utf8(payload).unwrap()
-            );
-
             return Err(FixSerializeError::VersionTagNotFound);
         };
 
@@ -136,9 +130,9 @@ impl FixMessageBuilder {
         return result;
     }
 
-    pub fn get_value_string(&self, key: &str) -> Option<String> {
+    pub fn get_value_string(&self, key: impl AsFixTag) -> Option<String> {
         for (inner_key, value) in &self.data {
-            if inner_key == &key.as_bytes() {
+            if is_same_tag(&key, inner_key) {
                 return Some(String::from_utf8(value.clone()).unwrap());
             }
         }
@@ -146,10 +140,10 @@ impl FixMessageBuilder {
         return None;
     }
 
-    pub fn get_values_string(&self, key: &str) -> Vec<String> {
+    pub fn get_values_string(&self, key: impl AsFixTag) -> Vec<String> {
         let mut result = vec![];
         for (inner_key, value) in &self.data {
-            if inner_key == &key.as_bytes() {
+            if is_same_tag(&key, inner_key) {
                 result.push(String::from_utf8(value.clone()).unwrap());
             }
         }
@@ -157,11 +151,12 @@ impl FixMessageBuilder {
         return result;
     }
 
-    pub fn with_value(&mut self, key: i32, value: &str) {
-        self.data.push((
-            key.to_string().as_bytes().to_vec(),
-            value.as_bytes().to_vec(),
-        ));
+    pub fn with_value(&mut self, key: impl AsFixTag, value: impl FixValue) {
+        let mut key_bytes = Vec::new();
+        key.write_fix_tag(&mut key_bytes);
+        let mut value_bytes = Vec::new();
+        value.write_fix_value(&mut value_bytes);
+        self.data.push((key_bytes, value_bytes));
     }
 
     fn with_value_as_bytes(&mut self, key: Vec<u8>, value: Vec<u8>) {
@@ -216,6 +211,13 @@ impl FixMessageBuilder {
     }
 }
 
+fn is_same_tag(key: &impl AsFixTag, inner_key: &[u8]) -> bool {
+    match std::str::from_utf8(inner_key) {
+        Ok(inner_key) => key.is_same_tag(inner_key),
+        Err(_) => false,
+    }
+}
+
 impl ToString for FixMessageBuilder {
     fn to_string(&self) -> String {
         let bytes = self.compile_message();
@@ -226,18 +228,19 @@ impl ToString for FixMessageBuilder {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::tags;
 
     #[test]
     fn test_to_fix_string() {
         let fix_string = "8=FIX.4.4|9=75|35=A|34=1092|49=TESTBUY1|52=20180920-18:24:59.643|56=TESTSELL1|98=0|108=60|10=178|";
 
         let mut fix_builder = FixMessageBuilder::new("FIX.4.4", "A");
-        fix_builder.with_value(34, &"1092".to_string());
-        fix_builder.with_value(49, &"TESTBUY1".to_string());
-        fix_builder.with_value(52, &"20180920-18:24:59.643".to_string());
-        fix_builder.with_value(56, &"TESTSELL1".to_string());
-        fix_builder.with_value(98, &"0".to_string());
-        fix_builder.with_value(108, &"60".to_string());
+        fix_builder.with_value(tags::MSG_SEQ_NUM, &"1092".to_string());
+        fix_builder.with_value(tags::SENDER_COMP_ID, &"TESTBUY1".to_string());
+        fix_builder.with_value(tags::SENDING_TIME, &"20180920-18:24:59.643".to_string());
+        fix_builder.with_value(tags::TARGET_COMP_ID, &"TESTSELL1".to_string());
+        fix_builder.with_value(tags::ENCRYPT_METHOD, &"0".to_string());
+        fix_builder.with_value(tags::HEART_BT_INT, &"60".to_string());
 
         let fix_to_assert: String = fix_builder.to_string();
 
@@ -252,10 +255,7 @@ mod test {
         let builder = FixMessageBuilder::from_bytes(fix_string, true);
 
         assert_eq!(true, builder.is_err());
-        assert_eq!(
-            FixSerializeError::VersionTagNotFound as i32,
-            builder.err().unwrap() as i32
-        );
+        assert!(builder.err().unwrap().is_version_tag_not_found());
     }
 
     #[test]
@@ -265,10 +265,7 @@ mod test {
         let builder = FixMessageBuilder::from_bytes(fix_string, true);
 
         assert_eq!(true, builder.is_err());
-        assert_eq!(
-            FixSerializeError::MessageTypeTagNotFound as i32,
-            builder.err().unwrap() as i32
-        );
+        assert!(builder.err().unwrap().is_message_type_tag_not_found());
     }
 
     #[test]
@@ -278,10 +275,7 @@ mod test {
         let builder = FixMessageBuilder::from_bytes(fix_string, true);
 
         assert_eq!(true, builder.is_err());
-        assert_eq!(
-            FixSerializeError::CheckSumTagNotFound as i32,
-            builder.err().unwrap() as i32
-        );
+        assert!(builder.err().unwrap().is_check_sum_tag_not_found());
     }
 
     #[test]
@@ -306,10 +300,7 @@ mod test {
         let builder = FixMessageBuilder::from_bytes(fix_string, true);
 
         assert_eq!(true, builder.is_err());
-        assert_eq!(
-            FixSerializeError::InvalidCheckSum as i32,
-            builder.err().unwrap() as i32
-        );
+        assert!(builder.err().unwrap().is_invalid_check_sum());
     }
 
     #[test]
@@ -317,12 +308,12 @@ mod test {
         let fix_string = b"8=FIX.4.49=7535=A34=109249=TESTBUY152=20180920-18:24:59.64356=TESTSELL198=0108=6010=178";
 
         let mut fix_builder = FixMessageBuilder::new("FIX.4.4", "A");
-        fix_builder.with_value(34, &"1092".to_string());
-        fix_builder.with_value(49, &"TESTBUY1".to_string());
-        fix_builder.with_value(52, &"20180920-18:24:59.643".to_string());
-        fix_builder.with_value(56, &"TESTSELL1".to_string());
-        fix_builder.with_value(98, &"0".to_string());
-        fix_builder.with_value(108, &"60".to_string());
+        fix_builder.with_value(tags::MSG_SEQ_NUM, &"1092".to_string());
+        fix_builder.with_value(tags::SENDER_COMP_ID, &"TESTBUY1".to_string());
+        fix_builder.with_value(tags::SENDING_TIME, &"20180920-18:24:59.643".to_string());
+        fix_builder.with_value(tags::TARGET_COMP_ID, &"TESTSELL1".to_string());
+        fix_builder.with_value(tags::ENCRYPT_METHOD, &"0".to_string());
+        fix_builder.with_value(tags::HEART_BT_INT, &"60".to_string());
 
         let fix_to_assert = fix_builder.as_bytes();
 
@@ -334,13 +325,13 @@ mod test {
         let fix_string = b"8=FIX.4.49=8735=A34=109249=TESTBUY149=TESTBUY252=20180920-18:24:59.64356=TESTSELL198=0108=6010=194";
 
         let mut fix_builder = FixMessageBuilder::new("FIX.4.4", "A");
-        fix_builder.with_value(34, &"1092".to_string());
-        fix_builder.with_value(49, &"TESTBUY1".to_string());
-        fix_builder.with_value(49, &"TESTBUY2".to_string());
-        fix_builder.with_value(52, &"20180920-18:24:59.643".to_string());
-        fix_builder.with_value(56, &"TESTSELL1".to_string());
-        fix_builder.with_value(98, &"0".to_string());
-        fix_builder.with_value(108, &"60".to_string());
+        fix_builder.with_value(tags::MSG_SEQ_NUM, &"1092".to_string());
+        fix_builder.with_value(tags::SENDER_COMP_ID, &"TESTBUY1".to_string());
+        fix_builder.with_value(tags::SENDER_COMP_ID, &"TESTBUY2".to_string());
+        fix_builder.with_value(tags::SENDING_TIME, &"20180920-18:24:59.643".to_string());
+        fix_builder.with_value(tags::TARGET_COMP_ID, &"TESTSELL1".to_string());
+        fix_builder.with_value(tags::ENCRYPT_METHOD, &"0".to_string());
+        fix_builder.with_value(tags::HEART_BT_INT, &"60".to_string());
         let fix_to_assert = fix_builder.as_bytes();
 
         assert_eq!(fix_string, fix_to_assert.as_slice());
@@ -351,17 +342,17 @@ mod test {
         let fix_string = b"8=FIX.4.49=8735=A34=109249=TESTBUY149=TESTBUY252=20180920-18:24:59.64356=TESTSELL198=0108=6010=194";
 
         let mut fix_builder = FixMessageBuilder::new("FIX.4.4", "A");
-        fix_builder.with_value(34, &"1092".to_string());
-        fix_builder.with_value(49, &"TESTBUY1".to_string());
-        fix_builder.with_value(49, &"TESTBUY2".to_string());
-        fix_builder.with_value(52, &"20180920-18:24:59.643".to_string());
-        fix_builder.with_value(56, &"TESTSELL1".to_string());
-        fix_builder.with_value(98, &"0".to_string());
-        fix_builder.with_value(108, &"60".to_string());
+        fix_builder.with_value(tags::MSG_SEQ_NUM, &"1092".to_string());
+        fix_builder.with_value(tags::SENDER_COMP_ID, &"TESTBUY1".to_string());
+        fix_builder.with_value(tags::SENDER_COMP_ID, &"TESTBUY2".to_string());
+        fix_builder.with_value(tags::SENDING_TIME, &"20180920-18:24:59.643".to_string());
+        fix_builder.with_value(tags::TARGET_COMP_ID, &"TESTSELL1".to_string());
+        fix_builder.with_value(tags::ENCRYPT_METHOD, &"0".to_string());
+        fix_builder.with_value(tags::HEART_BT_INT, &"60".to_string());
         let fix_to_assert = fix_builder.as_bytes();
 
         assert_eq!(fix_string, fix_to_assert.as_slice());
-        let tag49 = fix_builder.get_values_string("49");
+        let tag49 = fix_builder.get_values_string(tags::SENDER_COMP_ID);
         assert_eq!(2, tag49.len());
         assert_eq!("TESTBUY1", tag49[0]);
         assert_eq!("TESTBUY2", tag49[1]);
@@ -1,65 +1,109 @@
-#[derive(Debug)]
+use crate::{
+    utils::{FIX_DELIMITER, FIX_DELIMITER_STR, FIX_EQUALS},
+    FixSerializeError, Tag,
+};
+
+#[derive(Debug, Clone, Copy)]
 pub struct FixMessageItem<'s> {
     pub key: &'s str,
+    /// Empty if the value of a data field is not UTF-8. Such values are read from `data`.
     pub value: &'s str,
+    /// Value as it is in the message
+    pub data: &'s [u8],
 }
 
 impl<'s> FixMessageItem<'s> {
-    pub fn from_str(src: &'s str) -> Self {
-        let index = src.find('=');
+    pub fn from_str(src: &'s str) -> Result<Self, FixSerializeError> {
+        Self::parse(src.as_bytes(), 0, FIX_DELIMITER_STR as u8)
+    }
 
-        if index.is_none() {
-            panic!("Invalid fix item: {}", src);
-        }
+    pub fn from_slice(src: &'s [u8]) -> Result<Self, FixSerializeError> {
+        Self::parse(src, 0, FIX_DELIMITER)
+    }
 
-        let index = index.unwrap();
+    pub fn get_tag(&self) -> Result<Tag, FixSerializeError> {
+        self.key.parse()
+    }
 
-        let key = &src[..index];
-        let mut value = &src[index + 1..];
+    /// Value of a data field which is not UTF-8
+    pub fn is_binary(&self) -> bool {
+        self.value.len() != self.data.len()
+    }
 
-        if *value.as_bytes().last().unwrap() == b'|' {
-            value = &value[..value.len() - 1];
-        }
-        Self { key, value }
+    /// Parses a single `tag=value` item. `offset` is the position of `src` inside the message
+    /// and is used to report where the problem is.
+    pub(crate) fn parse(
+        src: &'s [u8],
+        offset: usize,
+        delimiter: u8,
+    ) -> Result<Self, FixSerializeError> {
+        Self::parse_item(src, offset, delimiter, false)
     }
 
-    pub fn from_slice(src: &'s [u8]) -> Self {
-        let index = find_index(src);
+    /// Parses the item of a data field. Its value may be binary, so it is not checked to be UTF-8.
+    pub(crate) fn parse_data(
+        src: &'s [u8],
+        offset: usize,
+        delimiter: u8,
+    ) -> Result<Self, FixSerializeError> {
+        Self::parse_item(src, offset, delimiter, true)
+    }
 
-        let index = index.unwrap();
+    fn parse_item(
+        src: &'s [u8],
+        offset: usize,
+        delimiter: u8,
+        is_data: bool,
+    ) -> Result<Self, FixSerializeError> {
+        let src = match src.last() {
+            Some(last) if *last == delimiter => &src[..src.len() - 1],
+            _ => src,
+        };
+
+        let Some(index) = src.iter().position(|b| *b == FIX_EQUALS) else {
+            return Err(FixSerializeError::MissingEquals { offset });
+        };
 
         let key = &src[..index];
-        let mut value = &src[index + 1..];
 
-        if *value.last().unwrap() == 1 {
-            value = &value[..value.len() - 1];
-        }
-        Self {
-            key: std::str::from_utf8(key).unwrap(),
-            value: std::str::from_utf8(value).unwrap(),
+        if key.is_empty() {
+            return Err(FixSerializeError::EmptyTag { offset });
         }
-    }
-}
 
-fn find_index(src: &[u8]) -> Option<usize> {
-    for (index, byte) in src.iter().enumerate() {
-        if *byte == b'=' {
-            return Some(index);
+        if !key.iter().all(|b| b.is_ascii_digit()) {
+            return Err(FixSerializeError::NonNumericTag { offset });
         }
+
+        let data = &src[index + 1..];
+        let value = match std::str::from_utf8(data) {
+            Ok(value) => value,
+            Err(_) if is_data => "",
+            Err(err) => {
+                return Err(FixSerializeError::InvalidUtf8 {
+                    offset: offset + index + 1 + err.valid_up_to(),
+                })
+            }
+        };
+
+        Ok(Self {
+            // Tag contains ASCII digits only
+            key: std::str::from_utf8(key).unwrap_or_default(),
+            value,
+            data,
+        })
     }
-    None
 }
 
 #[cfg(test)]
 mod test {
-    use crate::FixMessageItem;
+    use crate::{FixMessageItem, FixSerializeError};
 
     #[test]
     fn test_parsing_from_bytes_with_ending() {
         let mut src = "9=123".as_bytes().to_vec();
         src.push(1);
 
-        let item = FixMessageItem::from_slice(src.as_slice());
+        let item = FixMessageItem::from_slice(src.as_slice()).unwrap();
 
         assert_eq!(item.key, "9");
         assert_eq!(item.value, "123");
@@ -69,7 +113,7 @@ mod test {
     fn test_parsing_from_bytes_with_no_ending() {
         let src = "9=123".as_bytes().to_vec();
 
-        let item = FixMessageItem::from_slice(src.as_slice());
+        let item = FixMessageItem::from_slice(src.as_slice()).unwrap();
 
         assert_eq!(item.key, "9");
         assert_eq!(item.value, "123");
@@ -80,7 +124,7 @@ mod test {
         let mut src = "9=123".to_string();
         src.push('|');
 
-        let item = FixMessageItem::from_str(src.as_str());
+        let item = FixMessageItem::from_str(src.as_str()).unwrap();
 
         assert_eq!(item.key, "9");
         assert_eq!(item.value, "123");
@@ -90,9 +134,58 @@ mod test {
     fn test_parsing_from_str_with_no_ending() {
         let src = "9=123";
 
-        let item = FixMessageItem::from_str(src);
+        let item = FixMessageItem::from_str(src).unwrap();
 
         assert_eq!(item.key, "9");
         assert_eq!(item.value, "123");
     }
+
+    #[test]
+    fn test_missing_equals() {
+        match FixMessageItem::from_str("9123|") {
+            Err(FixSerializeError::MissingEquals { offset }) => assert_eq!(0, offset),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_empty_tag() {
+        match FixMessageItem::from_slice(b"=123") {
+            Err(FixSerializeError::EmptyTag { offset }) => assert_eq!(0, offset),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_non_numeric_tag() {
+        match FixMessageItem::from_slice(b"9a=123") {
+            Err(FixSerializeError::NonNumericTag { offset }) => assert_eq!(0, offset),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_invalid_utf8_value() {
+        match FixMessageItem::from_slice(&[b'5', b'8', b'=', b'a', 0xff, 1]) {
+            Err(FixSerializeError::InvalidUtf8 { offset }) => assert_eq!(4, offset),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_binary_data_value() {
+        let item = FixMessageItem::parse_data(&[b'9', b'6', b'=', 0xff, 1, 0x80, 1], 0, 1).unwrap();
+
+        assert_eq!(item.key, "96");
+        assert_eq!(item.data, &[0xff, 1, 0x80]);
+        assert!(item.is_binary());
+    }
+
+    #[test]
+    fn test_empty_value() {
+        let item = FixMessageItem::from_slice(b"58=").unwrap();
+
+        assert_eq!(item.key, "58");
+        assert_eq!(item.value, "");
+    }
 }
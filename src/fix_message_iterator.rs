@@ -1,27 +1,160 @@
-use crate::{FixMessageItem, FixSerializeError};
+use crate::{tags, utils::FIX_EQUALS, FixMessageItem, FixSerializeError, Tag};
+
+/// Length and data tag pairs of the standard FIX 4.x / 5.0SP2 fields. Values of the data fields
+/// may contain the delimiter, so they are read by the length which precedes them.
+pub const STANDARD_DATA_FIELDS: &[(Tag, Tag)] = &[
+    (tags::SECURE_DATA_LEN, tags::SECURE_DATA),
+    (tags::SIGNATURE_LENGTH, tags::SIGNATURE),
+    (tags::RAW_DATA_LENGTH, tags::RAW_DATA),
+    (tags::XML_DATA_LEN, tags::XML_DATA),
+    (tags::ENCODED_ISSUER_LEN, tags::ENCODED_ISSUER),
+    (tags::ENCODED_SECURITY_DESC_LEN, tags::ENCODED_SECURITY_DESC),
+    (
+        tags::ENCODED_LIST_EXEC_INST_LEN,
+        tags::ENCODED_LIST_EXEC_INST,
+    ),
+    (tags::ENCODED_TEXT_LEN, tags::ENCODED_TEXT),
+    (tags::ENCODED_SUBJECT_LEN, tags::ENCODED_SUBJECT),
+    (tags::ENCODED_HEADLINE_LEN, tags::ENCODED_HEADLINE),
+    (tags::ENCODED_ALLOC_TEXT_LEN, tags::ENCODED_ALLOC_TEXT),
+    (
+        tags::ENCODED_UNDERLYING_ISSUER_LEN,
+        tags::ENCODED_UNDERLYING_ISSUER,
+    ),
+    (
+        tags::ENCODED_UNDERLYING_SECURITY_DESC_LEN,
+        tags::ENCODED_UNDERLYING_SECURITY_DESC,
+    ),
+    (
+        tags::ENCODED_LIST_STATUS_TEXT_LEN,
+        tags::ENCODED_LIST_STATUS_TEXT,
+    ),
+    (tags::ENCODED_LEG_ISSUER_LEN, tags::ENCODED_LEG_ISSUER),
+    (
+        tags::ENCODED_LEG_SECURITY_DESC_LEN,
+        tags::ENCODED_LEG_SECURITY_DESC,
+    ),
+    (tags::SECURITY_XML_LEN, tags::SECURITY_XML),
+    (
+        tags::DERIVATIVE_ENCODED_ISSUER_LEN,
+        tags::DERIVATIVE_ENCODED_ISSUER,
+    ),
+    (
+        tags::DERIVATIVE_ENCODED_SECURITY_DESC_LEN,
+        tags::DERIVATIVE_ENCODED_SECURITY_DESC,
+    ),
+    (
+        tags::DERIVATIVE_SECURITY_XML_LEN,
+        tags::DERIVATIVE_SECURITY_XML,
+    ),
+    (tags::ENCODED_SYMBOL_LEN, tags::ENCODED_SYMBOL),
+    (tags::ENCODED_MKT_SEGM_DESC_LEN, tags::ENCODED_MKT_SEGM_DESC),
+    (tags::ENCRYPTED_PASSWORD_LEN, tags::ENCRYPTED_PASSWORD),
+    (
+        tags::ENCRYPTED_NEW_PASSWORD_LEN,
+        tags::ENCRYPTED_NEW_PASSWORD,
+    ),
+    (
+        tags::ENCODED_SECURITY_LIST_DESC_LEN,
+        tags::ENCODED_SECURITY_LIST_DESC,
+    ),
+    (
+        tags::RELATIONSHIP_RISK_ENCODED_SECURITY_DESC_LEN,
+        tags::RELATIONSHIP_RISK_ENCODED_SECURITY_DESC,
+    ),
+    (
+        tags::RISK_ENCODED_SECURITY_DESC_LEN,
+        tags::RISK_ENCODED_SECURITY_DESC,
+    ),
+];
 
 pub struct FixMessageIterator<'s> {
     data: &'s [u8],
     current_index: usize,
     delimiter: u8,
+    data_fields: &'s [(Tag, Tag)],
+    // Data tag and length announced by the previous item
+    data_len: Option<(Tag, usize)>,
 }
 
 impl<'s> FixMessageIterator<'s> {
     pub fn from_str(data: &'s str) -> Self {
-        Self {
-            data: data.as_bytes(),
-            current_index: 0,
-            delimiter: b'|',
-        }
+        Self::new(data.as_bytes(), b'|')
     }
 
     pub fn from_slice(data: &'s [u8]) -> Self {
+        Self::new(data, 1)
+    }
+
+    fn new(data: &'s [u8], delimiter: u8) -> Self {
         Self {
             data,
             current_index: 0,
-            delimiter: 1,
+            delimiter,
+            data_fields: STANDARD_DATA_FIELDS,
+            data_len: None,
         }
     }
+
+    /// Length and data tag pairs to use instead of [`STANDARD_DATA_FIELDS`],
+    /// for example [`crate::DataDictionary::get_data_fields`]
+    pub fn with_data_fields(mut self, data_fields: &'s [(Tag, Tag)]) -> Self {
+        self.data_fields = data_fields;
+        self
+    }
+
+    /// Offset of the next item inside the message
+    pub fn get_position(&self) -> usize {
+        self.current_index
+    }
+
+    // Data field right after its length: the value is exactly `len` bytes long.
+    // Returns `None` if the next item is not the announced data field.
+    fn read_data_field(
+        &mut self,
+        start: usize,
+        data_tag: Tag,
+        len: usize,
+    ) -> Option<Result<FixMessageItem<'s>, FixSerializeError>> {
+        let data = &self.data[start..];
+        let key_len = data.iter().position(|b| *b == FIX_EQUALS)?;
+
+        let tag: Tag = std::str::from_utf8(&data[..key_len]).ok()?.parse().ok()?;
+        if tag != data_tag {
+            return None;
+        }
+
+        // The length comes from the wire, so it is checked before it is added to anything
+        let value_len = data.len() - key_len - 1;
+
+        if len >= value_len || data[key_len + 1 + len] != self.delimiter {
+            self.current_index = self.data.len();
+            return Some(Err(FixSerializeError::DataLengthMismatch {
+                tag: data_tag,
+                offset: start,
+            }));
+        }
+
+        let value_end = key_len + 1 + len;
+        self.current_index = start + value_end + 1;
+
+        let result = FixMessageItem::parse_data(&data[..=value_end], start, self.delimiter);
+        if result.is_err() {
+            self.current_index = self.data.len();
+        }
+
+        Some(result)
+    }
+
+    fn get_data_len(&self, itm: &FixMessageItem) -> Option<(Tag, usize)> {
+        let tag = itm.get_tag().ok()?;
+        let (_, data_tag) = self
+            .data_fields
+            .iter()
+            .find(|(length_tag, _)| *length_tag == tag)?;
+
+        Some((*data_tag, itm.value.parse().ok()?))
+    }
 }
 
 impl<'s> Iterator for FixMessageIterator<'s> {
@@ -34,31 +167,36 @@ impl<'s> Iterator for FixMessageIterator<'s> {
 
         let start = self.current_index;
 
-        while self.data[self.current_index] != self.delimiter {
-            self.current_index += 1;
-
-            if self.current_index >= self.data.len() {
-                return Some(Err(FixSerializeError::FixDelimiterNotFound));
+        if let Some((data_tag, len)) = self.data_len.take() {
+            if let Some(result) = self.read_data_field(start, data_tag, len) {
+                return Some(result);
             }
         }
 
-        let item = &self.data[start..self.current_index];
-
-        let result = if self.delimiter == 1 {
-            FixMessageItem::from_slice(item)
-        } else {
-            FixMessageItem::from_str(std::str::from_utf8(item).unwrap())
+        let Some(len) = self.data[start..].iter().position(|b| *b == self.delimiter) else {
+            self.current_index = self.data.len();
+            return Some(Err(FixSerializeError::FixDelimiterNotFound {
+                offset: start,
+            }));
         };
 
-        self.current_index += 1;
+        self.current_index = start + len + 1;
+
+        let result = FixMessageItem::parse(&self.data[start..start + len], start, self.delimiter);
 
-        Some(Ok(result))
+        match &result {
+            Ok(itm) => self.data_len = self.get_data_len(itm),
+            // Stop iterating - there is no reliable way to find the next item
+            Err(_) => self.current_index = self.data.len(),
+        }
+
+        Some(result)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::FixMessageIterator;
+    use crate::{tags, FixMessageIterator, FixSerializeError, Tag};
 
     #[test]
     fn test_iterator() {
@@ -109,4 +247,139 @@ mod tests {
         let itm = iterator.next();
         assert_eq!(true, itm.is_none());
     }
+
+    #[test]
+    fn test_iterator_reports_error_offset() {
+        let mut iterator = FixMessageIterator::from_str("8=FIX.4.4|9=75|3a=A|34=1092|");
+
+        assert!(iterator.next().unwrap().is_ok());
+        assert!(iterator.next().unwrap().is_ok());
+
+        match iterator.next().unwrap() {
+            Err(FixSerializeError::NonNumericTag { offset }) => assert_eq!(15, offset),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+
+        assert!(iterator.next().is_none());
+    }
+
+    #[test]
+    fn test_iterator_with_empty_items() {
+        let mut iterator = FixMessageIterator::from_slice(b"8=FIX.4.4\x01\x019=75\x01");
+
+        assert!(iterator.next().unwrap().is_ok());
+
+        match iterator.next().unwrap() {
+            Err(FixSerializeError::MissingEquals { offset }) => assert_eq!(10, offset),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+
+        assert!(iterator.next().is_none());
+    }
+
+    #[test]
+    fn test_iterator_with_no_last_delimiter() {
+        let mut iterator = FixMessageIterator::from_str("8=FIX.4.4|9=75");
+
+        assert!(iterator.next().unwrap().is_ok());
+
+        match iterator.next().unwrap() {
+            Err(FixSerializeError::FixDelimiterNotFound { offset }) => assert_eq!(10, offset),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+
+        assert!(iterator.next().is_none());
+    }
+
+    #[test]
+    fn test_data_field_is_read_by_length() {
+        let mut iterator =
+            FixMessageIterator::from_str("8=FIX.4.4|9=30|35=B|95=7|96=a|b=c|d|58=X|10=000|");
+
+        let items = iterator
+            .by_ref()
+            .map(|itm| itm.unwrap())
+            .map(|itm| (itm.key, itm.value))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                ("8", "FIX.4.4"),
+                ("9", "30"),
+                ("35", "B"),
+                ("95", "7"),
+                ("96", "a|b=c|d"),
+                ("58", "X"),
+                ("10", "000"),
+            ],
+            items
+        );
+    }
+
+    #[test]
+    fn test_data_length_mismatch() {
+        let mut iterator = FixMessageIterator::from_str("35=B|95=9|96=a|b|58=X|10=000|");
+
+        assert!(iterator.next().unwrap().is_ok());
+        assert!(iterator.next().unwrap().is_ok());
+
+        match iterator.next().unwrap() {
+            Err(FixSerializeError::DataLengthMismatch { tag, offset }) => {
+                assert_eq!(tags::RAW_DATA, tag);
+                assert_eq!(10, offset);
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+
+        assert!(iterator.next().is_none());
+    }
+
+    #[test]
+    fn test_data_length_larger_than_message() {
+        let mut iterator =
+            FixMessageIterator::from_str("35=B|95=18446744073709551615|96=a|10=000|");
+
+        assert!(iterator.next().unwrap().is_ok());
+        assert!(iterator.next().unwrap().is_ok());
+
+        match iterator.next().unwrap() {
+            Err(FixSerializeError::DataLengthMismatch { tag, offset }) => {
+                assert_eq!(tags::RAW_DATA, tag);
+                assert_eq!(29, offset);
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+
+        assert!(iterator.next().is_none());
+    }
+
+    #[test]
+    fn test_binary_data_field() {
+        let items = FixMessageIterator::from_slice(b"95=3\x0196=\xff\x01\x80\x0158=X\x01")
+            .map(|itm| itm.unwrap())
+            .map(|itm| (itm.key, itm.data))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                ("95", &b"3"[..]),
+                ("96", &b"\xff\x01\x80"[..]),
+                ("58", &b"X"[..]),
+            ],
+            items
+        );
+    }
+
+    #[test]
+    fn test_custom_data_fields() {
+        let data_fields = [(Tag(1401), Tag(1402))];
+
+        let values = FixMessageIterator::from_str("1401=3|1402=a|b|95=1|96=c|")
+            .with_data_fields(&data_fields)
+            .map(|itm| itm.unwrap().value)
+            .collect::<Vec<_>>();
+
+        // RawData is not in the table, so it is split by the delimiter
+        assert_eq!(vec!["3", "a|b", "1", "c"], values);
+    }
 }
@@ -1,6 +1,17 @@
 use crate::{
-    utils::{FIX_BODY_LEN, FIX_CHECK_SUM, FIX_MESSAGE_TYPE, FIX_VERSION},
-    FixMessageBodyBuilder, FixMessageIterator, FixSerializeError,
+    fix_data_type::{
+        parse_bool, parse_char, parse_int, parse_multiple_value_string, parse_seq_num,
+    },
+    fix_group_reader::read_group,
+    fixt11_messages::ApplVerID,
+    tags,
+    utils::{
+        calculate_check_sum, FIX_BODY_LEN, FIX_CHECK_SUM, FIX_DELIMITER, FIX_MESSAGE_TYPE,
+        FIX_VERSION,
+    },
+    AsFixTag, FixDataType, FixDecimal, FixEnum, FixGroupDefinition, FixGroupEntry, FixMessageItem,
+    FixMessageIterator, FixSerializeError, LocalMktDate, Tag, UtcDateOnly, UtcTimeOnly,
+    UtcTimestamp,
 };
 
 #[derive(Debug)]
@@ -18,6 +29,13 @@ impl<'s> FixMessageReader<'s> {
         Self::AsBytes(data)
     }
 
+    pub fn as_bytes(&self) -> &'s [u8] {
+        match self {
+            Self::AsStr(src) => src.as_bytes(),
+            Self::AsBytes(src) => src,
+        }
+    }
+
     pub fn iter(&'s self) -> FixMessageIterator<'s> {
         match &self {
             Self::AsStr(src) => FixMessageIterator::from_str(src),
@@ -32,47 +50,88 @@ impl<'s> FixMessageReader<'s> {
 
         let mut fix_message_type = None;
 
-        let mut body_builder = FixMessageBodyBuilder::new();
+        let mut body_start = 0;
+        let mut body_end = 0;
+        let mut items_amount = 0;
+
+        let mut iterator = self.iter();
+
+        loop {
+            let item_offset = iterator.get_position();
+
+            let Some(itm) = iterator.next() else {
+                break;
+            };
 
-        for itm in self.iter() {
             let itm = itm?;
+            let position = items_amount;
+            items_amount += 1;
 
             match itm.key {
-                FIX_VERSION => fix_version = Some(itm),
-                FIX_BODY_LEN => fix_body_len = Some(itm),
-                FIX_CHECK_SUM => fix_check_sum = Some(itm),
-                FIX_MESSAGE_TYPE => {
-                    body_builder.append(itm.key, itm.value);
-                    fix_message_type = Some(itm);
+                FIX_VERSION => fix_version = Some((position, itm)),
+                FIX_BODY_LEN => {
+                    body_start = iterator.get_position();
+                    fix_body_len = Some((position, itm));
+                }
+                FIX_CHECK_SUM => {
+                    body_end = item_offset;
+                    fix_check_sum = Some((position, itm));
                 }
-                _ => body_builder.append(itm.key, itm.value),
+                FIX_MESSAGE_TYPE => fix_message_type = Some((position, itm)),
+                _ => {}
             }
         }
 
-        if fix_body_len.is_none() {
+        let Some((body_len_position, fix_body_len)) = fix_body_len else {
             return Err(FixSerializeError::BodyLenTagNotFound);
-        }
-
-        //        let fix_body_len = fix_body_len.unwrap();
+        };
 
-        if fix_message_type.is_none() {
+        let Some((message_type_position, _)) = fix_message_type else {
             return Err(FixSerializeError::MessageTypeTagNotFound);
-        }
+        };
 
-        //let fix_message_type = fix_message_type.unwrap();
-
-        if fix_check_sum.is_none() {
+        let Some((check_sum_position, fix_check_sum)) = fix_check_sum else {
             return Err(FixSerializeError::CheckSumTagNotFound);
-        }
+        };
 
-        if fix_version.is_none() {
+        let Some((version_position, _)) = fix_version else {
             return Err(FixSerializeError::VersionTagNotFound);
+        };
+
+        check_tag_position(tags::BEGIN_STRING, 0, version_position)?;
+        check_tag_position(tags::BODY_LENGTH, 1, body_len_position)?;
+        check_tag_position(tags::MSG_TYPE, 2, message_type_position)?;
+        check_tag_position(tags::CHECK_SUM, items_amount - 1, check_sum_position)?;
+
+        if !fix_body_len.value.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(FixSerializeError::InvalidBodyLenValue);
         }
 
-        let fix_check_sum = fix_check_sum.unwrap();
-        let fix_version = fix_version.unwrap();
+        let declared: usize = match fix_body_len.value.parse() {
+            Ok(value) => value,
+            Err(_) => return Err(FixSerializeError::InvalidBodyLenValue),
+        };
 
-        let check_sum = body_builder.get_checksum(fix_version.value);
+        let actual = body_end - body_start;
+
+        if declared != actual {
+            return Err(FixSerializeError::InvalidBodyLength { declared, actual });
+        }
+
+        // CheckSum covers the received bytes up to `10=`. Text messages use `|` for SOH.
+        let received = &self.as_bytes()[..body_end];
+        let check_sum = match self {
+            Self::AsStr(_) => calculate_check_sum(
+                &received
+                    .iter()
+                    .map(|b| match *b {
+                        b'|' => FIX_DELIMITER,
+                        b => b,
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            Self::AsBytes(_) => calculate_check_sum(received),
+        };
 
         if check_sum.as_str() != fix_check_sum.value {
             return Err(FixSerializeError::InvalidCheckSum);
@@ -81,33 +140,216 @@ impl<'s> FixMessageReader<'s> {
         Ok(self)
     }
 
-    pub fn get_value(&self, key: &str) -> Result<Option<&str>, FixSerializeError> {
+    pub fn get_value(&self, key: impl AsFixTag) -> Result<Option<&str>, FixSerializeError> {
+        match self.find_text_items(&key).next() {
+            Some(itm) => Ok(Some(itm?.value)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn get_values(&self, key: impl AsFixTag) -> Result<Vec<&str>, FixSerializeError> {
+        self.find_text_items(&key)
+            .map(|itm| itm.map(|itm| itm.value))
+            .collect()
+    }
+
+    /// Reads the value of a data field as it is in the message. It may be binary.
+    pub fn get_data(&self, key: impl AsFixTag) -> Result<Option<&[u8]>, FixSerializeError> {
         for itm in self.iter() {
             let itm = itm?;
 
-            if itm.key == key {
-                return Ok(Some(itm.value));
+            if key.is_same_tag(itm.key) {
+                return Ok(Some(itm.data));
             }
         }
 
         Ok(None)
     }
 
-    pub fn get_values(&self, key: &str) -> Result<Vec<&str>, FixSerializeError> {
-        let mut result = Vec::new();
-        for itm in self.iter() {
-            let itm = itm?;
+    /// Reads the entries of the repeating group. Empty if the NumInGroup field is not present.
+    pub fn get_group(
+        &self,
+        definition: &FixGroupDefinition,
+    ) -> Result<Vec<FixGroupEntry<'_>>, FixSerializeError> {
+        let items = self.iter().collect::<Result<Vec<_>, _>>()?;
+
+        for (index, itm) in items.iter().enumerate() {
+            if definition.get_count_tag().is_same_tag(itm.key) {
+                let (entries, _) = read_group(&items, index, definition)?;
+                return Ok(entries);
+            }
+        }
+
+        Ok(Vec::new())
+    }
 
-            if itm.key == key {
-                result.push(itm.value);
+    /// Reads the message in a single pass and splits it into the fields of the message and
+    /// the entries of the groups. A group field is not mistaken for the message field with
+    /// the same tag. Nested groups are read by their definitions.
+    pub fn read_fields(
+        &self,
+        groups: &[FixGroupDefinition],
+    ) -> Result<FixGroupEntry<'_>, FixSerializeError> {
+        let items = self.iter().collect::<Result<Vec<_>, _>>()?;
+        let mut result = FixGroupEntry {
+            items: Vec::new(),
+            groups: Vec::new(),
+        };
+        let mut index = 0;
+
+        while index < items.len() {
+            let tag = items[index].get_tag()?;
+
+            match groups.iter().find(|group| group.get_count_tag() == tag) {
+                Some(group) => {
+                    let (entries, next_index) = read_group(&items, index, group)?;
+                    result.groups.push((tag, entries));
+                    index = next_index;
+                }
+                None => {
+                    result.items.push(items[index]);
+                    index += 1;
+                }
             }
         }
 
         Ok(result)
     }
 
+    pub fn get_int(&self, key: impl AsFixTag) -> Result<Option<i64>, FixSerializeError> {
+        self.get_typed_value(key, FixDataType::Int, parse_int)
+    }
+
+    pub fn get_seq_num(&self, key: impl AsFixTag) -> Result<Option<u64>, FixSerializeError> {
+        self.get_typed_value(key, FixDataType::SeqNum, parse_seq_num)
+    }
+
+    pub fn get_qty(&self, key: impl AsFixTag) -> Result<Option<FixDecimal>, FixSerializeError> {
+        self.get_typed_value(key, FixDataType::Qty, FixDecimal::parse)
+    }
+
+    pub fn get_price(&self, key: impl AsFixTag) -> Result<Option<FixDecimal>, FixSerializeError> {
+        self.get_typed_value(key, FixDataType::Price, FixDecimal::parse)
+    }
+
+    pub fn get_amt(&self, key: impl AsFixTag) -> Result<Option<FixDecimal>, FixSerializeError> {
+        self.get_typed_value(key, FixDataType::Amt, FixDecimal::parse)
+    }
+
+    pub fn get_char(&self, key: impl AsFixTag) -> Result<Option<char>, FixSerializeError> {
+        self.get_typed_value(key, FixDataType::Char, parse_char)
+    }
+
+    /// Reads Boolean field where `Y` is `true` and `N` is `false`
+    pub fn get_bool(&self, key: impl AsFixTag) -> Result<Option<bool>, FixSerializeError> {
+        self.get_typed_value(key, FixDataType::Boolean, parse_bool)
+    }
+
+    pub fn get_utc_timestamp(
+        &self,
+        key: impl AsFixTag,
+    ) -> Result<Option<UtcTimestamp>, FixSerializeError> {
+        self.get_typed_value(key, FixDataType::UtcTimestamp, UtcTimestamp::parse)
+    }
+
+    pub fn get_utc_time_only(
+        &self,
+        key: impl AsFixTag,
+    ) -> Result<Option<UtcTimeOnly>, FixSerializeError> {
+        self.get_typed_value(key, FixDataType::UtcTimeOnly, UtcTimeOnly::parse)
+    }
+
+    pub fn get_utc_date_only(
+        &self,
+        key: impl AsFixTag,
+    ) -> Result<Option<UtcDateOnly>, FixSerializeError> {
+        self.get_typed_value(key, FixDataType::UtcDateOnly, UtcDateOnly::parse)
+    }
+
+    pub fn get_local_mkt_date(
+        &self,
+        key: impl AsFixTag,
+    ) -> Result<Option<LocalMktDate>, FixSerializeError> {
+        self.get_typed_value(key, FixDataType::LocalMktDate, LocalMktDate::parse)
+    }
+
+    /// Reads the field of the code set. Values which are not in the dictionary are `Other`
+    pub fn get_enum<T: FixEnum>(&self) -> Result<Option<T>, FixSerializeError> {
+        self.get_typed_value(T::TAG, T::DATA_TYPE, |value| {
+            if T::DATA_TYPE.is_valid(value) {
+                T::from_fix_value(value)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Reads MultipleValueString field as a list of space separated values
+    pub fn get_multiple_value_string(
+        &self,
+        key: impl AsFixTag,
+    ) -> Result<Option<Vec<&str>>, FixSerializeError> {
+        self.get_typed_value(
+            key,
+            FixDataType::MultipleValueString,
+            parse_multiple_value_string,
+        )
+    }
+
+    fn get_typed_value<'r, T>(
+        &'r self,
+        key: impl AsFixTag,
+        data_type: FixDataType,
+        parse: impl Fn(&'r str) -> Option<T>,
+    ) -> Result<Option<T>, FixSerializeError> {
+        let Some(itm) = self.find_text_items(&key).next() else {
+            return Ok(None);
+        };
+
+        let itm = itm?;
+
+        match parse(itm.value) {
+            Some(result) => Ok(Some(result)),
+            None => Err(FixSerializeError::InvalidFieldValue {
+                tag: itm.get_tag()?,
+                value: itm.value.to_string(),
+                data_type,
+            }),
+        }
+    }
+
+    // Items of the tag. Binary values of data fields can not be read as text
+    // and are reported as not UTF-8.
+    fn find_text_items<'r: 'k, 'k>(
+        &'r self,
+        key: &'k impl AsFixTag,
+    ) -> impl Iterator<Item = Result<FixMessageItem<'r>, FixSerializeError>> + 'k {
+        let mut iterator = self.iter();
+
+        std::iter::from_fn(move || loop {
+            let start = iterator.get_position();
+
+            let itm = match iterator.next()? {
+                Ok(itm) => itm,
+                Err(err) => return Some(Err(err)),
+            };
+
+            if !key.is_same_tag(itm.key) {
+                continue;
+            }
+
+            if let Err(err) = std::str::from_utf8(itm.data) {
+                return Some(Err(FixSerializeError::InvalidUtf8 {
+                    offset: start + itm.key.len() + 1 + err.valid_up_to(),
+                }));
+            }
+
+            return Some(Ok(itm));
+        })
+    }
+
     pub fn get_message_type(&self) -> Result<&str, FixSerializeError> {
-        let value = self.get_value(FIX_MESSAGE_TYPE)?;
+        let value = self.get_value(tags::MSG_TYPE)?;
 
         if value.is_none() {
             return Err(FixSerializeError::MessageTypeTagNotFound);
@@ -116,6 +358,12 @@ impl<'s> FixMessageReader<'s> {
         Ok(value.unwrap())
     }
 
+    /// ApplVerID (1128) of a FIXT.1.1 message. Messages without it are of the
+    /// DefaultApplVerID (1137) negotiated at Logon.
+    pub fn get_appl_ver_id(&self) -> Result<Option<ApplVerID>, FixSerializeError> {
+        self.get_enum()
+    }
+
     pub fn to_string(&self) -> String {
         match self {
             FixMessageReader::AsStr(src) => src.to_string(),
@@ -126,9 +374,24 @@ impl<'s> FixMessageReader<'s> {
     }
 }
 
+fn check_tag_position(tag: Tag, expected: usize, actual: usize) -> Result<(), FixSerializeError> {
+    if expected != actual {
+        return Err(FixSerializeError::TagOutOfOrder {
+            tag,
+            expected,
+            actual,
+        });
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::FixMessageReader;
+    use crate::{
+        tags, FixDataType, FixGroupDefinition, FixMessageReader, FixMessageWriter,
+        FixSerializeError,
+    };
 
     #[test]
     fn test_check_sum() {
@@ -154,6 +417,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_check_sum_of_received_bytes() {
+        // Leading zeros of a tag are a part of the checksum as they are received
+        let body = b"8=FIX.4.4\x019=12\x0135=0\x010049=X\x01".to_vec();
+        let mut message = body.clone();
+        message.extend_from_slice(b"10=");
+        message.extend_from_slice(crate::utils::calculate_check_sum(&body).as_bytes());
+        message.push(1);
+
+        assert!(FixMessageReader::from_bytes(&message)
+            .check_payload()
+            .is_ok());
+
+        let index = message.iter().position(|b| *b == b'X').unwrap();
+        message[index] = b'Y';
+        assert!(FixMessageReader::from_bytes(&message)
+            .check_payload()
+            .unwrap_err()
+            .is_invalid_check_sum());
+    }
+
     #[test]
     fn test_invalid_fix_no_version() {
         let fix_string =
@@ -193,4 +477,175 @@ mod tests {
         assert!(builder.is_err());
         assert!(builder.err().unwrap().is_invalid_check_sum());
     }
+
+    #[test]
+    fn test_body_len_is_less_than_actual() {
+        let fix_string = "8=FIX.4.4|9=74|35=A|34=1092|49=TESTBUY1|52=20180920-18:24:59.643|56=TESTSELL1|98=0|108=60|10=177|";
+
+        match FixMessageReader::from_str(fix_string).check_payload() {
+            Err(FixSerializeError::InvalidBodyLength { declared, actual }) => {
+                assert_eq!(74, declared);
+                assert_eq!(75, actual);
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_body_len_is_not_a_number() {
+        let fix_string = "8=FIX.4.4|9=7x|35=A|34=1092|49=TESTBUY1|52=20180920-18:24:59.643|56=TESTSELL1|98=0|108=60|10=178|";
+
+        let result = FixMessageReader::from_str(fix_string).check_payload();
+
+        assert!(matches!(
+            result,
+            Err(FixSerializeError::InvalidBodyLenValue)
+        ));
+    }
+
+    #[test]
+    fn test_version_is_not_first() {
+        let fix_string = "9=75|8=FIX.4.4|35=A|34=1092|49=TESTBUY1|52=20180920-18:24:59.643|56=TESTSELL1|98=0|108=60|10=178|";
+
+        match FixMessageReader::from_str(fix_string).check_payload() {
+            Err(FixSerializeError::TagOutOfOrder {
+                tag,
+                expected,
+                actual,
+            }) => {
+                assert_eq!(tags::BEGIN_STRING, tag);
+                assert_eq!(0, expected);
+                assert_eq!(1, actual);
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_message_type_is_not_third() {
+        let fix_string = "8=FIX.4.4|9=75|34=1092|35=A|49=TESTBUY1|52=20180920-18:24:59.643|56=TESTSELL1|98=0|108=60|10=178|";
+
+        match FixMessageReader::from_str(fix_string).check_payload() {
+            Err(FixSerializeError::TagOutOfOrder { tag, .. }) => assert_eq!(tags::MSG_TYPE, tag),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_check_sum_is_not_last() {
+        let fix_string = "8=FIX.4.4|9=75|35=A|34=1092|49=TESTBUY1|52=20180920-18:24:59.643|56=TESTSELL1|98=0|10=178|108=60|";
+
+        match FixMessageReader::from_str(fix_string).check_payload() {
+            Err(FixSerializeError::TagOutOfOrder { tag, .. }) => assert_eq!(tags::CHECK_SUM, tag),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_typed_values() {
+        let fix_string = "8=FIX.4.4|9=145|35=D|34=1092|43=N|49=TESTBUY1|52=20180920-18:24:59.643|56=TESTSELL1|11=ORDER1|38=100|40=2|44=1.25|54=1|59=0|75=20180920|18=G 1|60=20180920-18:24:59|10=000|";
+        let reader = FixMessageReader::from_str(fix_string);
+
+        assert_eq!(Some(1092), reader.get_seq_num(tags::MSG_SEQ_NUM).unwrap());
+        assert_eq!(Some(2), reader.get_int(tags::ORD_TYPE).unwrap());
+        assert_eq!(
+            "100",
+            reader
+                .get_qty(tags::ORDER_QTY)
+                .unwrap()
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "1.25",
+            reader.get_price(tags::PRICE).unwrap().unwrap().to_string()
+        );
+        assert_eq!(Some('1'), reader.get_char(tags::SIDE).unwrap());
+        assert_eq!(Some(false), reader.get_bool(tags::POSS_DUP_FLAG).unwrap());
+        assert_eq!(
+            Some(vec!["G", "1"]),
+            reader.get_multiple_value_string(tags::EXEC_INST).unwrap()
+        );
+
+        let sending_time = reader
+            .get_utc_timestamp(tags::SENDING_TIME)
+            .unwrap()
+            .unwrap();
+        assert_eq!(643_000_000, sending_time.get_nanos());
+
+        let trade_date = reader
+            .get_local_mkt_date(tags::TRADE_DATE)
+            .unwrap()
+            .unwrap();
+        assert_eq!(2018, trade_date.get_year());
+
+        assert_eq!(None, reader.get_price(tags::STOP_PX).unwrap());
+    }
+
+    #[test]
+    fn test_read_fields() {
+        let fix_string =
+            "8=FIX.4.4|9=60|35=D|11=ORDER1|453=2|448=BROKER|58=first|448=DESK|58=second|10=000|";
+        let reader = FixMessageReader::from_str(fix_string);
+        let parties =
+            FixGroupDefinition::new(tags::NO_PARTY_IDS, tags::PARTY_ID).with_tags(&[tags::TEXT]);
+
+        let fields = reader.read_fields(&[parties]).unwrap();
+        assert_eq!(Some("ORDER1"), fields.get_value(tags::CL_ORD_ID));
+        assert_eq!(None, fields.get_value(tags::TEXT));
+        assert_eq!(None, fields.get_value(tags::PARTY_ID));
+        assert_eq!(Some("first"), reader.get_value(tags::TEXT).unwrap());
+
+        let entries = fields.get_group(tags::NO_PARTY_IDS);
+        assert_eq!(2, entries.len());
+        assert_eq!(Some("first"), entries[0].get_value(tags::TEXT));
+        assert_eq!(Some("second"), entries[1].get_value(tags::TEXT));
+    }
+
+    #[test]
+    fn test_binary_data_field() {
+        let mut writer = FixMessageWriter::new("FIX.4.4", "B");
+        writer.with_data(tags::RAW_DATA_LENGTH, tags::RAW_DATA, [0xff, 0x01, 0x80]);
+        writer.with_value(tags::HEADLINE, "H");
+
+        let message = writer.compile_message();
+        let reader = FixMessageReader::from_bytes(&message)
+            .check_payload()
+            .unwrap();
+
+        assert_eq!(
+            Some(&[0xff, 0x01, 0x80][..]),
+            reader.get_data(tags::RAW_DATA).unwrap()
+        );
+        assert_eq!(Some("H"), reader.get_value(tags::HEADLINE).unwrap());
+
+        match reader.get_value(tags::RAW_DATA) {
+            Err(FixSerializeError::InvalidUtf8 { offset }) => assert_eq!(28, offset),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_invalid_typed_value() {
+        let fix_string = "8=FIX.4.4|9=20|35=D|34=1O92|43=X|10=000|";
+        let reader = FixMessageReader::from_str(fix_string);
+
+        match reader.get_seq_num(tags::MSG_SEQ_NUM) {
+            Err(FixSerializeError::InvalidFieldValue {
+                tag,
+                value,
+                data_type,
+            }) => {
+                assert_eq!(tags::MSG_SEQ_NUM, tag);
+                assert_eq!("1O92", value);
+                assert_eq!(FixDataType::SeqNum, data_type);
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+
+        assert!(reader
+            .get_bool(tags::POSS_DUP_FLAG)
+            .unwrap_err()
+            .is_invalid_field_value());
+    }
 }
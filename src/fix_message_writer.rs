@@ -1,25 +1,37 @@
-use crate::FixMessageBodyBuilder;
+use std::borrow::Cow;
+
+use crate::{
+    fixt11_messages::ApplVerID, tags, AsFixTag, FixEnum, FixGroupWriter, FixMessageBodyBuilder,
+    FixValue, StandardTrailer, Tag, TimestampPrecision, UtcTimeOnly, UtcTimestamp,
+};
 
 //pub const FIX_VERSION: &str = "8";
 //pub const FIX_BODY_LEN: &str = "9";
 //pub const FIX_CHECK_SUM: &str = "10";
 //pub const FIX_MESSAGE_TYPE: &str = "35";
 
+// Header fields of FIXT.1.1 which follow MsgType (35): ApplVerID, ApplExtID and CstmApplVerID
+const APPL_VERSION_TAGS: [&[u8]; 3] = [b"1128", b"1156", b"1129"];
+
 #[derive(Clone)]
 pub struct FixMessageWriter {
-    fix_version: &'static str,
+    fix_version: String,
     body: FixMessageBodyBuilder,
+    trailer: StandardTrailer,
+    timestamp_precision: TimestampPrecision,
 }
 
 impl FixMessageWriter {
-    pub fn new(fix_version: &'static str, message_type: &str) -> Self {
+    pub fn new(fix_version: impl Into<String>, message_type: &str) -> Self {
         let mut body = FixMessageBodyBuilder::new();
-        body.append(
-            crate::utils::FIX_MESSAGE_TYPE,
-            message_type.to_string().as_str(),
-        );
-
-        return Self { fix_version, body };
+        body.append(tags::MSG_TYPE, message_type);
+
+        return Self {
+            fix_version: fix_version.into(),
+            body,
+            trailer: StandardTrailer::default(),
+            timestamp_precision: TimestampPrecision::default(),
+        };
     }
 
     /*
@@ -90,23 +102,107 @@ impl FixMessageWriter {
            return result;
        }
     */
-    pub fn with_value(&mut self, key: &str, value: &str) {
+    pub fn with_value(&mut self, key: impl AsFixTag, value: impl FixValue) {
         self.body.append(key, value);
     }
 
+    /// Writes the data field preceded by its length field, for example a pair of
+    /// [`crate::STANDARD_DATA_FIELDS`] or [`crate::DataDictionary::get_data_fields`].
+    /// The value may be binary and contain the delimiter.
+    pub fn with_data(&mut self, length_tag: Tag, data_tag: Tag, value: impl AsRef<[u8]>) {
+        let value = value.as_ref();
+        self.body.append(length_tag, value.len());
+        self.body.append_data(data_tag, value);
+    }
+
+    /// Writer over the fields which are already encoded, starting with MsgType (35)
+    pub(crate) fn from_body(fix_version: impl Into<String>, body: FixMessageBodyBuilder) -> Self {
+        Self {
+            fix_version: fix_version.into(),
+            body,
+            trailer: StandardTrailer::default(),
+            timestamp_precision: TimestampPrecision::default(),
+        }
+    }
+
+    pub fn get_fix_version(&self) -> &str {
+        &self.fix_version
+    }
+
+    /// Inserts the header fields right after MsgType (35) and the application version fields,
+    /// so they are placed before the fields which are already written
+    pub(crate) fn insert_header(&mut self, header: &FixMessageBodyBuilder) {
+        self.body
+            .insert_after_first_field(header.as_slice(), |key| APPL_VERSION_TAGS.contains(&key));
+    }
+
+    /// ApplVerID (1128) of a FIXT.1.1 message which is not of the default application version
+    /// of the session. It is placed into the header right after MsgType (35).
+    pub fn with_appl_ver_id(&mut self, appl_ver_id: &ApplVerID) {
+        let mut header = FixMessageBodyBuilder::new();
+        header.append(ApplVerID::TAG, appl_ver_id);
+        self.body
+            .insert_after_first_field(header.as_slice(), |_| false);
+    }
+
+    /// Writes the value of the code set into its field
+    pub fn with_enum<T: FixEnum>(&mut self, value: &T) {
+        self.body.append(T::TAG, value);
+    }
+
+    pub fn with_group(&mut self, group: &FixGroupWriter) {
+        group.write_to(&mut self.body);
+    }
+
+    /// Trailer fields written after all the other fields
+    pub fn set_trailer(&mut self, trailer: StandardTrailer) {
+        self.trailer = trailer;
+    }
+
+    pub fn get_trailer(&self) -> &StandardTrailer {
+        &self.trailer
+    }
+
+    /// Precision of the values written by `with_utc_timestamp` and `with_utc_time_only`
+    pub fn set_timestamp_precision(&mut self, precision: TimestampPrecision) {
+        self.timestamp_precision = precision;
+    }
+
+    pub fn get_timestamp_precision(&self) -> TimestampPrecision {
+        self.timestamp_precision
+    }
+
+    pub fn with_utc_timestamp(&mut self, key: impl AsFixTag, value: UtcTimestamp) {
+        self.body
+            .append(key, value.with_precision(self.timestamp_precision));
+    }
+
+    pub fn with_utc_time_only(&mut self, key: impl AsFixTag, value: UtcTimeOnly) {
+        self.body
+            .append(key, value.with_precision(self.timestamp_precision));
+    }
+
     /*
           fn with_value_as_bytes(&mut self, key: Vec<u8>, value: Vec<u8>) {
               self.data.push((key, value));
           }
     */
     pub fn compile_message(&self) -> Vec<u8> {
+        let body = if self.trailer.is_empty() {
+            Cow::Borrowed(&self.body)
+        } else {
+            let mut body = self.body.clone();
+            self.trailer.write_to(&mut body);
+            Cow::Owned(body)
+        };
+
         let mut result = Vec::new();
-        crate::utils::write_fix_chunk(&mut result, crate::utils::FIX_VERSION, self.fix_version);
+        crate::utils::write_fix_chunk(&mut result, crate::utils::FIX_VERSION, &self.fix_version);
 
-        crate::utils::write_body_len(&mut result, self.body.len());
-        result.extend_from_slice(self.body.as_slice());
+        crate::utils::write_body_len(&mut result, body.len());
+        result.extend_from_slice(body.as_slice());
 
-        let check_sum = self.body.get_checksum(&self.fix_version);
+        let check_sum = body.get_checksum(&self.fix_version);
 
         crate::utils::write_fix_chunk(&mut result, crate::utils::FIX_CHECK_SUM, check_sum.as_str());
 
@@ -140,7 +236,7 @@ impl ToString for FixMessageWriter {
 
 #[cfg(test)]
 mod test {
-    use crate::FixMessageReader;
+    use crate::{tags, FixDecimal, FixMessageReader, TimestampPrecision, UtcTimestamp};
 
     use super::*;
 
@@ -149,12 +245,12 @@ mod test {
         let fix_string = "8=FIX.4.4|9=75|35=A|34=1092|49=TESTBUY1|52=20180920-18:24:59.643|56=TESTSELL1|98=0|108=60|10=178|";
 
         let mut fix_builder = FixMessageWriter::new("FIX.4.4", "A");
-        fix_builder.with_value("34", &"1092".to_string());
-        fix_builder.with_value("49", &"TESTBUY1".to_string());
-        fix_builder.with_value("52", &"20180920-18:24:59.643".to_string());
-        fix_builder.with_value("56", &"TESTSELL1".to_string());
-        fix_builder.with_value("98", &"0".to_string());
-        fix_builder.with_value("108", &"60".to_string());
+        fix_builder.with_value(tags::MSG_SEQ_NUM, &"1092".to_string());
+        fix_builder.with_value(tags::SENDER_COMP_ID, &"TESTBUY1".to_string());
+        fix_builder.with_value(tags::SENDING_TIME, &"20180920-18:24:59.643".to_string());
+        fix_builder.with_value(tags::TARGET_COMP_ID, &"TESTSELL1".to_string());
+        fix_builder.with_value(tags::ENCRYPT_METHOD, &"0".to_string());
+        fix_builder.with_value(tags::HEART_BT_INT, &"60".to_string());
 
         let fix_to_assert: String = fix_builder.to_string();
 
@@ -166,12 +262,12 @@ mod test {
         let fix_string = b"8=FIX.4.49=7535=A34=109249=TESTBUY152=20180920-18:24:59.64356=TESTSELL198=0108=6010=178";
 
         let mut fix_builder = FixMessageWriter::new("FIX.4.4", "A");
-        fix_builder.with_value("34", "1092");
-        fix_builder.with_value("49", "TESTBUY1");
-        fix_builder.with_value("52", "20180920-18:24:59.643");
-        fix_builder.with_value("56", "TESTSELL1");
-        fix_builder.with_value("98", "0");
-        fix_builder.with_value("108", "60");
+        fix_builder.with_value(tags::MSG_SEQ_NUM, "1092");
+        fix_builder.with_value(tags::SENDER_COMP_ID, "TESTBUY1");
+        fix_builder.with_value(tags::SENDING_TIME, "20180920-18:24:59.643");
+        fix_builder.with_value(tags::TARGET_COMP_ID, "TESTSELL1");
+        fix_builder.with_value(tags::ENCRYPT_METHOD, "0");
+        fix_builder.with_value(tags::HEART_BT_INT, "60");
 
         let fix_to_assert = fix_builder.compile_message();
 
@@ -183,13 +279,13 @@ mod test {
         let fix_string = b"8=FIX.4.49=8735=A34=109249=TESTBUY149=TESTBUY252=20180920-18:24:59.64356=TESTSELL198=0108=6010=194";
 
         let mut fix_builder = FixMessageWriter::new("FIX.4.4", "A");
-        fix_builder.with_value("34", "1092");
-        fix_builder.with_value("49", "TESTBUY1");
-        fix_builder.with_value("49", "TESTBUY2");
-        fix_builder.with_value("52", "20180920-18:24:59.643");
-        fix_builder.with_value("56", "TESTSELL1");
-        fix_builder.with_value("98", "0");
-        fix_builder.with_value("108", "60");
+        fix_builder.with_value(tags::MSG_SEQ_NUM, "1092");
+        fix_builder.with_value(tags::SENDER_COMP_ID, "TESTBUY1");
+        fix_builder.with_value(tags::SENDER_COMP_ID, "TESTBUY2");
+        fix_builder.with_value(tags::SENDING_TIME, "20180920-18:24:59.643");
+        fix_builder.with_value(tags::TARGET_COMP_ID, "TESTSELL1");
+        fix_builder.with_value(tags::ENCRYPT_METHOD, "0");
+        fix_builder.with_value(tags::HEART_BT_INT, "60");
         let fix_to_assert = fix_builder.compile_message();
 
         assert_eq!(fix_string, fix_to_assert.as_slice());
@@ -200,22 +296,133 @@ mod test {
         let fix_string = b"8=FIX.4.49=8735=A34=109249=TESTBUY149=TESTBUY252=20180920-18:24:59.64356=TESTSELL198=0108=6010=194";
 
         let mut fix_builder = FixMessageWriter::new("FIX.4.4", "A");
-        fix_builder.with_value("34", "1092");
-        fix_builder.with_value("49", "TESTBUY1");
-        fix_builder.with_value("49", "TESTBUY2");
-        fix_builder.with_value("52", "20180920-18:24:59.643");
-        fix_builder.with_value("56", "TESTSELL1");
-        fix_builder.with_value("98", "0");
-        fix_builder.with_value("108", "60");
+        fix_builder.with_value(tags::MSG_SEQ_NUM, "1092");
+        fix_builder.with_value(tags::SENDER_COMP_ID, "TESTBUY1");
+        fix_builder.with_value(tags::SENDER_COMP_ID, "TESTBUY2");
+        fix_builder.with_value(tags::SENDING_TIME, "20180920-18:24:59.643");
+        fix_builder.with_value(tags::TARGET_COMP_ID, "TESTSELL1");
+        fix_builder.with_value(tags::ENCRYPT_METHOD, "0");
+        fix_builder.with_value(tags::HEART_BT_INT, "60");
         let fix_to_assert = fix_builder.compile_message();
 
         assert_eq!(fix_string, fix_to_assert.as_slice());
 
         let fix_reader = FixMessageReader::from_bytes(fix_string);
 
-        let tag49 = fix_reader.get_values("49").unwrap();
+        let tag49 = fix_reader.get_values(tags::SENDER_COMP_ID).unwrap();
         assert_eq!(2, tag49.len());
         assert_eq!("TESTBUY1", tag49[0]);
         assert_eq!("TESTBUY2", tag49[1]);
     }
+
+    #[test]
+    fn test_write_with_tag_constants() {
+        let fix_string = b"8=FIX.4.4\x019=75\x0135=A\x0134=1092\x0149=TESTBUY1\x0152=20180920-18:24:59.643\x0156=TESTSELL1\x0198=0\x01108=60\x0110=178\x01";
+
+        let mut fix_builder = FixMessageWriter::new("FIX.4.4", "A");
+        fix_builder.with_value(tags::MSG_SEQ_NUM, "1092");
+        fix_builder.with_value(tags::SENDER_COMP_ID, "TESTBUY1");
+        fix_builder.with_value(tags::SENDING_TIME, "20180920-18:24:59.643");
+        fix_builder.with_value(tags::TARGET_COMP_ID, "TESTSELL1");
+        fix_builder.with_value(tags::ENCRYPT_METHOD, "0");
+        fix_builder.with_value(tags::HEART_BT_INT, "60");
+
+        let fix_to_assert = fix_builder.compile_message();
+        assert_eq!(fix_string, fix_to_assert.as_slice());
+
+        let fix_reader = FixMessageReader::from_bytes(fix_string);
+        assert_eq!(
+            Some("TESTBUY1"),
+            fix_reader.get_value(tags::SENDER_COMP_ID).unwrap()
+        );
+        assert_eq!(
+            Some("60"),
+            fix_reader.get_value(tags::HEART_BT_INT).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_write_decimal_values() {
+        let price = FixDecimal::parse("1.50").unwrap();
+
+        let mut fix_builder = FixMessageWriter::new("FIX.4.4", "D");
+        fix_builder.with_value(tags::ORDER_QTY, FixDecimal::from(100));
+        fix_builder.with_value(tags::PRICE, price);
+        fix_builder.with_value(
+            tags::STOP_PX,
+            price
+                .checked_mul(&FixDecimal::parse("0.9").unwrap())
+                .unwrap(),
+        );
+
+        let fix_message = fix_builder.compile_message();
+        FixMessageReader::from_bytes(&fix_message)
+            .check_payload()
+            .unwrap();
+
+        let fix_reader = FixMessageReader::from_bytes(&fix_message);
+
+        assert_eq!(Some("1.50"), fix_reader.get_value(tags::PRICE).unwrap());
+        assert_eq!(Some("1.350"), fix_reader.get_value(tags::STOP_PX).unwrap());
+        assert_eq!(Some(price), fix_reader.get_price(tags::PRICE).unwrap());
+        assert_eq!(
+            Some(FixDecimal::from(100)),
+            fix_reader.get_qty(tags::ORDER_QTY).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_write_timestamps_with_precision() {
+        let sending_time = UtcTimestamp::parse("20180920-18:24:59.643001002").unwrap();
+
+        let mut fix_builder = FixMessageWriter::new("FIX.4.4", "0");
+        fix_builder.with_utc_timestamp(tags::SENDING_TIME, sending_time);
+        fix_builder.set_timestamp_precision(TimestampPrecision::Micros);
+        fix_builder.with_utc_timestamp(tags::ORIG_SENDING_TIME, sending_time);
+        fix_builder.set_timestamp_precision(TimestampPrecision::Seconds);
+        fix_builder.with_utc_time_only(tags::MD_ENTRY_TIME, sending_time.get_time());
+
+        let fix_message = fix_builder.compile_message();
+        let fix_reader = FixMessageReader::from_bytes(&fix_message);
+
+        assert_eq!(
+            Some("20180920-18:24:59.643"),
+            fix_reader.get_value(tags::SENDING_TIME).unwrap()
+        );
+        assert_eq!(
+            Some("20180920-18:24:59.643001"),
+            fix_reader.get_value(tags::ORIG_SENDING_TIME).unwrap()
+        );
+        assert_eq!(
+            Some("18:24:59"),
+            fix_reader.get_value(tags::MD_ENTRY_TIME).unwrap()
+        );
+        assert_eq!(
+            sending_time
+                .get_time()
+                .with_precision(TimestampPrecision::Seconds),
+            fix_reader
+                .get_utc_time_only(tags::MD_ENTRY_TIME)
+                .unwrap()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_appl_ver_id_is_written_after_msg_type() {
+        let mut fix_builder = FixMessageWriter::new("FIXT.1.1", "D");
+        fix_builder.with_value(tags::CL_ORD_ID, "A");
+        fix_builder.with_appl_ver_id(&ApplVerID::Fix50sp2);
+
+        let fix_message = fix_builder.compile_message();
+        let fix_reader = FixMessageReader::from_bytes(&fix_message);
+
+        assert!(fix_builder
+            .to_string()
+            .starts_with("8=FIXT.1.1|9=17|35=D|1128=9|11=A|"));
+        assert_eq!(
+            Some(ApplVerID::Fix50sp2),
+            fix_reader.get_appl_ver_id().unwrap()
+        );
+    }
 }
@@ -0,0 +1,2006 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    io,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    fixt11_messages::ApplVerID, tags, FixMessageBodyBuilder, FixMessageReader, FixMessageWriter,
+    FixSessionConfig, FixSessionError, MemoryMessageStore, MessageStore, SessionClock,
+    SessionRejectReason, SessionRole, SystemClock, Tag, UtcTimestamp,
+};
+
+// Same thresholds as QuickFIX: TestRequest after 1.5 and disconnect after 2.4 heartbeat
+// intervals of silence
+const TEST_REQUEST_THRESHOLD: f64 = 1.5;
+const HEARTBEAT_TIMEOUT_THRESHOLD: f64 = 2.4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixSessionState {
+    Disconnected,
+    /// Acceptor is connected and waits for the Logon of the counterparty
+    AwaitingLogon,
+    /// Initiator sent Logon and waits for the answer
+    LogonSent,
+    Active,
+    /// Logout is sent and the session waits for the confirmation
+    LogoutSent,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DisconnectReason {
+    /// Logout exchange is completed
+    LoggedOut,
+    LogonTimeout,
+    LogoutTimeout,
+    /// Nothing is received for too long, even after a TestRequest
+    HeartbeatTimeout,
+    FirstMessageNotLogon,
+    InvalidLogon(String),
+    InvalidBeginString(String),
+    InvalidCompId,
+    MsgSeqNumMissing,
+    /// MsgSeqNum (34) is lower than expected and the message is not a PossDup
+    MsgSeqNumTooLow {
+        expected: u64,
+        received: u64,
+    },
+    /// Outbound message can not be saved to or read from the message store
+    StoreError(String),
+    /// Connection can not be established, is broken or is closed by the counterparty
+    Transport(String),
+    /// Logon outside of the session schedule or the session is over
+    OutsideSessionTime,
+    /// FIXT.1.1 initiator has no DefaultApplVerID (1137) configured, so its Logon would be
+    /// invalid and is not sent
+    MissingDefaultApplVerId,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FixSessionEvent {
+    /// Compiled message which has to be written into the transport
+    Send(Vec<u8>),
+    LoggedOn,
+    /// Application message of the counterparty
+    Application(Vec<u8>),
+    LoggedOut,
+    /// Transport has to be closed
+    Disconnect(DisconnectReason),
+}
+
+/// FIX session state machine which does not depend on the transport.
+///
+/// The transport calls `on_connected`, `on_message` for every inbound frame, `on_timer`
+/// about once a second and `on_disconnected`, then writes or handles everything returned by
+/// `poll_event`. The time is taken from the [`SessionClock`], so tests can use
+/// [`crate::ManualClock`].
+///
+/// Every outbound message is saved to the [`MessageStore`] and is resent from it when the
+/// counterparty sends ResendRequest (2).
+pub struct FixSession<C: SessionClock = SystemClock, S: MessageStore = MemoryMessageStore> {
+    config: FixSessionConfig,
+    clock: C,
+    store: S,
+    state: FixSessionState,
+    heart_bt_int: Duration,
+    last_sent: SystemTime,
+    last_received: SystemTime,
+    state_changed: SystemTime,
+    test_request_id: Option<String>,
+    test_request_counter: u64,
+    queue: BTreeMap<u64, Vec<u8>>,
+    resend_range: Option<(u64, u64)>,
+    target_default_appl_ver_id: Option<ApplVerID>,
+    events: VecDeque<FixSessionEvent>,
+}
+
+impl FixSession<SystemClock> {
+    pub fn new(config: FixSessionConfig) -> Self {
+        Self::with_clock(config, SystemClock)
+    }
+}
+
+impl<C: SessionClock> FixSession<C> {
+    pub fn with_clock(config: FixSessionConfig, clock: C) -> Self {
+        let store = MemoryMessageStore::with_creation_time(clock.now());
+        Self::with_store(config, clock, store)
+    }
+}
+
+impl<C: SessionClock, S: MessageStore> FixSession<C, S> {
+    pub fn with_store(config: FixSessionConfig, clock: C, store: S) -> Self {
+        let now = clock.now();
+
+        Self {
+            heart_bt_int: Duration::from_secs(config.get_heart_bt_int() as u64),
+            config,
+            clock,
+            store,
+            state: FixSessionState::Disconnected,
+            last_sent: now,
+            last_received: now,
+            state_changed: now,
+            test_request_id: None,
+            test_request_counter: 0,
+            queue: BTreeMap::new(),
+            resend_range: None,
+            target_default_appl_ver_id: None,
+            events: VecDeque::new(),
+        }
+    }
+
+    pub fn get_config(&self) -> &FixSessionConfig {
+        &self.config
+    }
+
+    pub fn get_store(&self) -> &S {
+        &self.store
+    }
+
+    pub fn get_store_mut(&mut self) -> &mut S {
+        &mut self.store
+    }
+
+    pub fn get_state(&self) -> FixSessionState {
+        self.state
+    }
+
+    pub fn is_logged_on(&self) -> bool {
+        self.state == FixSessionState::Active
+    }
+
+    /// Negotiated heartbeat interval
+    pub fn get_heart_bt_int(&self) -> Duration {
+        self.heart_bt_int
+    }
+
+    pub fn get_next_sender_seq_num(&self) -> u64 {
+        self.store.get_next_sender_seq_num()
+    }
+
+    pub fn get_next_target_seq_num(&self) -> u64 {
+        self.store.get_next_target_seq_num()
+    }
+
+    pub fn set_next_sender_seq_num(&mut self, seq_num: u64) -> Result<(), FixSessionError> {
+        self.store
+            .set_next_sender_seq_num(seq_num)
+            .map_err(FixSessionError::Store)
+    }
+
+    pub fn set_next_target_seq_num(&mut self, seq_num: u64) -> Result<(), FixSessionError> {
+        self.store
+            .set_next_target_seq_num(seq_num)
+            .map_err(FixSessionError::Store)
+    }
+
+    /// Removes the stored messages and starts both sequences from 1
+    pub fn reset(&mut self) -> Result<(), FixSessionError> {
+        self.store
+            .reset(self.clock.now())
+            .map_err(FixSessionError::Store)
+    }
+
+    /// Session has no schedule or the clock is within it
+    pub fn is_session_time(&self) -> bool {
+        match self.config.get_schedule() {
+            Some(schedule) => schedule.is_session_time(self.clock.now()),
+            None => true,
+        }
+    }
+
+    /// DefaultApplVerID (1137) of the counterparty Logon: the application version of the
+    /// inbound messages without ApplVerID (1128). `None` for FIX.4.x sessions.
+    pub fn get_target_default_appl_ver_id(&self) -> Option<&ApplVerID> {
+        self.target_default_appl_ver_id.as_ref()
+    }
+
+    /// Application version of the inbound message: its ApplVerID (1128) or the version
+    /// negotiated at Logon
+    pub fn get_appl_ver_id(&self, reader: &FixMessageReader) -> Option<ApplVerID> {
+        match reader.get_appl_ver_id() {
+            Ok(Some(appl_ver_id)) => Some(appl_ver_id),
+            _ => self.target_default_appl_ver_id.clone(),
+        }
+    }
+
+    /// Range of MsgSeqNum requested by the last ResendRequest which is not received yet
+    pub fn get_resend_range(&self) -> Option<(u64, u64)> {
+        self.resend_range
+    }
+
+    /// Number of messages received ahead of a gap
+    pub fn get_queued_count(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn poll_event(&mut self) -> Option<FixSessionEvent> {
+        self.events.pop_front()
+    }
+
+    /// Transport is connected. Initiator sends Logon, acceptor starts to wait for it.
+    pub fn on_connected(&mut self) {
+        let now = self.clock.now();
+        self.last_received = now;
+        self.state_changed = now;
+        self.test_request_id = None;
+        self.queue.clear();
+        self.resend_range = None;
+        self.target_default_appl_ver_id = None;
+
+        match self.config.get_role() {
+            SessionRole::Initiator => {
+                if self.config.is_fixt() && self.config.get_default_appl_ver_id().is_none() {
+                    self.disconnect(DisconnectReason::MissingDefaultApplVerId);
+                    return;
+                }
+
+                if !self.start_session(now) {
+                    self.disconnect(DisconnectReason::OutsideSessionTime);
+                    return;
+                }
+
+                self.heart_bt_int = Duration::from_secs(self.config.get_heart_bt_int() as u64);
+
+                let reset = self.config.get_reset_on_logon();
+                if reset {
+                    self.reset_store();
+                }
+
+                self.send_logon(reset);
+                self.state = FixSessionState::LogonSent;
+            }
+            SessionRole::Acceptor => {
+                self.state = FixSessionState::AwaitingLogon;
+            }
+        }
+    }
+
+    /// Transport is closed by the counterparty or by the network
+    pub fn on_disconnected(&mut self) {
+        // Disconnect initiated by the session has already reset the store
+        let reset =
+            self.state != FixSessionState::Disconnected && self.config.get_reset_on_disconnect();
+
+        self.state = FixSessionState::Disconnected;
+        self.test_request_id = None;
+
+        if reset {
+            self.reset_store();
+        }
+    }
+
+    pub fn on_message(&mut self, reader: &FixMessageReader) {
+        if self.state == FixSessionState::Disconnected {
+            return;
+        }
+
+        // Garbled messages are ignored
+        let Ok(msg_type) = reader.get_message_type() else {
+            return;
+        };
+
+        self.last_received = self.clock.now();
+        self.test_request_id = None;
+
+        if !self.check_header(reader) {
+            return;
+        }
+
+        let awaiting_logon = matches!(
+            self.state,
+            FixSessionState::AwaitingLogon | FixSessionState::LogonSent
+        );
+
+        if awaiting_logon && msg_type != "A" {
+            self.disconnect(DisconnectReason::FirstMessageNotLogon);
+            return;
+        }
+
+        if self.state == FixSessionState::AwaitingLogon && !self.start_session(self.last_received) {
+            self.disconnect(DisconnectReason::OutsideSessionTime);
+            return;
+        }
+
+        let Ok(Some(seq_num)) = reader.get_seq_num(tags::MSG_SEQ_NUM) else {
+            self.send_logout(Some("MsgSeqNum(34) is missing"));
+            self.disconnect(DisconnectReason::MsgSeqNumMissing);
+            return;
+        };
+
+        // Counterparty starts both sides from 1
+        if msg_type == "A" && is_flag_set(reader, tags::RESET_SEQ_NUM_FLAG) {
+            if self.state == FixSessionState::AwaitingLogon {
+                self.reset_store();
+            } else {
+                self.update_target_seq_num(1);
+            }
+        }
+
+        // SequenceReset-Reset is processed regardless of MsgSeqNum
+        if msg_type == "4" && !is_flag_set(reader, tags::GAP_FILL_FLAG) {
+            self.on_sequence_reset(reader, seq_num);
+            return;
+        }
+
+        let next_target_seq_num = self.store.get_next_target_seq_num();
+
+        if seq_num > next_target_seq_num {
+            match msg_type {
+                "5" => self.on_logout(),
+                "A" => {
+                    self.on_logon(reader);
+                    self.queue_message(reader.as_bytes().to_vec(), seq_num);
+                }
+                // Both sides may wait for each other, so ResendRequest is answered at once.
+                // Empty placeholder keeps its MsgSeqNum in the queue.
+                "2" => {
+                    self.on_resend_request(reader, seq_num);
+                    self.queue_message(Vec::new(), seq_num);
+                }
+                _ => self.queue_message(reader.as_bytes().to_vec(), seq_num),
+            }
+
+            return;
+        }
+
+        if seq_num < next_target_seq_num {
+            self.on_seq_num_too_low(reader, msg_type, seq_num);
+            return;
+        }
+
+        self.process_message(reader, msg_type, seq_num);
+        self.process_queue();
+    }
+
+    /// Sends heartbeats and test requests and checks the timeouts
+    pub fn on_timer(&mut self) {
+        let now = self.clock.now();
+
+        if !self.is_current_session(now) {
+            match self.state {
+                FixSessionState::Active => {
+                    self.logout(None);
+                    return;
+                }
+                FixSessionState::AwaitingLogon | FixSessionState::LogonSent => {
+                    self.disconnect(DisconnectReason::OutsideSessionTime);
+                    return;
+                }
+                FixSessionState::LogoutSent | FixSessionState::Disconnected => {}
+            }
+        }
+
+        match self.state {
+            FixSessionState::Disconnected => {}
+            FixSessionState::AwaitingLogon | FixSessionState::LogonSent => {
+                if elapsed(now, self.state_changed) >= self.config.get_logon_timeout() {
+                    self.disconnect(DisconnectReason::LogonTimeout);
+                }
+            }
+            FixSessionState::LogoutSent => {
+                if elapsed(now, self.state_changed) >= self.config.get_logout_timeout() {
+                    self.disconnect(DisconnectReason::LogoutTimeout);
+                }
+            }
+            FixSessionState::Active => {
+                // Zero interval means no heartbeats
+                if self.heart_bt_int.is_zero() {
+                    return;
+                }
+
+                let silence = elapsed(now, self.last_received);
+
+                if silence >= self.heart_bt_int.mul_f64(HEARTBEAT_TIMEOUT_THRESHOLD) {
+                    self.disconnect(DisconnectReason::HeartbeatTimeout);
+                    return;
+                }
+
+                if self.test_request_id.is_none()
+                    && silence >= self.heart_bt_int.mul_f64(TEST_REQUEST_THRESHOLD)
+                {
+                    self.test_request_counter += 1;
+                    let test_req_id = format!("TEST{}", self.test_request_counter);
+
+                    let mut test_request = self.new_message("1");
+                    test_request.with_value(tags::TEST_REQ_ID, test_req_id.as_str());
+                    self.send_admin(test_request);
+
+                    self.test_request_id = Some(test_req_id);
+                }
+
+                if elapsed(now, self.last_sent) >= self.heart_bt_int {
+                    let heartbeat = self.new_message("0");
+                    self.send_admin(heartbeat);
+                }
+            }
+        }
+    }
+
+    /// Stamps the header of the application message and queues it.
+    /// Returns MsgSeqNum (34) of the message.
+    pub fn send(&mut self, writer: FixMessageWriter) -> Result<u64, FixSessionError> {
+        if self.state != FixSessionState::Active {
+            return Err(FixSessionError::NotLoggedOn);
+        }
+
+        self.send_message(writer).map_err(FixSessionError::Store)
+    }
+
+    /// Starts the graceful logout. The session is disconnected when the counterparty
+    /// confirms it or after the logout timeout.
+    pub fn logout(&mut self, text: Option<&str>) {
+        match self.state {
+            FixSessionState::Active => {
+                self.send_logout(text);
+                self.state = FixSessionState::LogoutSent;
+                self.state_changed = self.clock.now();
+            }
+            FixSessionState::AwaitingLogon | FixSessionState::LogonSent => {
+                self.disconnect(DisconnectReason::LoggedOut);
+            }
+            FixSessionState::LogoutSent | FixSessionState::Disconnected => {}
+        }
+    }
+
+    // Message with the expected MsgSeqNum
+    fn process_message(&mut self, reader: &FixMessageReader, msg_type: &str, seq_num: u64) {
+        self.update_target_seq_num(seq_num + 1);
+
+        if is_flag_set(reader, tags::POSS_DUP_FLAG)
+            && !self.check_orig_sending_time(reader, seq_num)
+        {
+            return;
+        }
+
+        match msg_type {
+            "A" => self.on_logon(reader),
+            "1" => {
+                let mut heartbeat = self.new_message("0");
+                if let Ok(Some(test_req_id)) = reader.get_value(tags::TEST_REQ_ID) {
+                    heartbeat.with_value(tags::TEST_REQ_ID, test_req_id);
+                }
+                self.send_admin(heartbeat);
+            }
+            "4" => self.on_sequence_reset(reader, seq_num),
+            "5" => self.on_logout(),
+            "2" => self.on_resend_request(reader, seq_num),
+            "0" | "3" => {}
+            _ => {
+                if self.state == FixSessionState::Active {
+                    self.events
+                        .push_back(FixSessionEvent::Application(reader.as_bytes().to_vec()));
+                }
+            }
+        }
+    }
+
+    // Messages received ahead of a gap wait until the gap is filled
+    fn queue_message(&mut self, message: Vec<u8>, seq_num: u64) {
+        if self.state == FixSessionState::Disconnected {
+            return;
+        }
+
+        self.queue.insert(seq_num, message);
+
+        // ResendRequest is open ended, so it already covers this gap
+        if self.resend_range.is_none() {
+            self.send_resend_request(seq_num - 1);
+        }
+    }
+
+    fn send_resend_request(&mut self, end_seq_no: u64) {
+        let begin_seq_no = self.store.get_next_target_seq_num();
+
+        let mut resend_request = self.new_message("2");
+        resend_request.with_value(tags::BEGIN_SEQ_NO, begin_seq_no);
+        // Zero means "up to the last message"
+        resend_request.with_value(tags::END_SEQ_NO, 0);
+        self.send_admin(resend_request);
+
+        self.resend_range = Some((begin_seq_no, end_seq_no));
+    }
+
+    fn process_queue(&mut self) {
+        while self.state != FixSessionState::Disconnected {
+            let seq_num = self.store.get_next_target_seq_num();
+            let Some(message) = self.queue.remove(&seq_num) else {
+                break;
+            };
+
+            if message.is_empty() {
+                self.update_target_seq_num(seq_num + 1);
+                continue;
+            }
+
+            let reader = FixMessageReader::from_bytes(&message);
+            if let Ok(msg_type) = reader.get_message_type() {
+                self.process_message(&reader, msg_type, seq_num);
+            }
+        }
+
+        let next_target_seq_num = self.store.get_next_target_seq_num();
+
+        // GapFill may jump over the queued messages
+        self.queue = self.queue.split_off(&next_target_seq_num);
+
+        if let Some((_, end)) = self.resend_range {
+            if end < next_target_seq_num {
+                self.resend_range = None;
+            }
+        }
+
+        // Counterparty has not resent everything, so there is one more gap
+        if self.resend_range.is_none() && self.state != FixSessionState::Disconnected {
+            if let Some(first_queued) = self.queue.keys().next().copied() {
+                self.send_resend_request(first_queued - 1);
+            }
+        }
+    }
+
+    fn on_seq_num_too_low(&mut self, reader: &FixMessageReader, msg_type: &str, seq_num: u64) {
+        let expected = self.store.get_next_target_seq_num();
+
+        if !is_flag_set(reader, tags::POSS_DUP_FLAG) {
+            let text = format!(
+                "MsgSeqNum too low, expecting {} but received {}",
+                expected, seq_num
+            );
+            self.send_logout(Some(&text));
+            self.disconnect(DisconnectReason::MsgSeqNumTooLow {
+                expected,
+                received: seq_num,
+            });
+            return;
+        }
+
+        // Duplicate which is already processed
+        if msg_type != "4" {
+            self.check_orig_sending_time(reader, seq_num);
+        }
+    }
+
+    // Retransmitted messages must have OrigSendingTime which is not later than SendingTime
+    fn check_orig_sending_time(&mut self, reader: &FixMessageReader, seq_num: u64) -> bool {
+        let msg_type = reader.get_message_type().unwrap_or_default().to_string();
+
+        // SequenceReset-GapFill replaces the messages, so it has no original time
+        if msg_type == "4" {
+            return true;
+        }
+
+        let Ok(Some(orig_sending_time)) = reader.get_utc_timestamp(tags::ORIG_SENDING_TIME) else {
+            self.send_reject(
+                seq_num,
+                &msg_type,
+                Some(tags::ORIG_SENDING_TIME),
+                SessionRejectReason::RequiredTagMissing,
+            );
+            return false;
+        };
+
+        if let Ok(Some(sending_time)) = reader.get_utc_timestamp(tags::SENDING_TIME) {
+            if orig_sending_time > sending_time {
+                self.send_reject(
+                    seq_num,
+                    &msg_type,
+                    Some(tags::ORIG_SENDING_TIME),
+                    SessionRejectReason::SendingTimeAccuracyProblem,
+                );
+                self.logout(None);
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn on_sequence_reset(&mut self, reader: &FixMessageReader, seq_num: u64) {
+        let new_seq_no = match reader.get_seq_num(tags::NEW_SEQ_NO) {
+            Ok(Some(value)) => value,
+            _ => {
+                self.send_reject(
+                    seq_num,
+                    "4",
+                    Some(tags::NEW_SEQ_NO),
+                    SessionRejectReason::RequiredTagMissing,
+                );
+                return;
+            }
+        };
+
+        // Sequence numbers never go back
+        if new_seq_no < self.store.get_next_target_seq_num() {
+            self.send_reject(
+                seq_num,
+                "4",
+                Some(tags::NEW_SEQ_NO),
+                SessionRejectReason::ValueIsIncorrect,
+            );
+            return;
+        }
+
+        self.update_target_seq_num(new_seq_no);
+        self.process_queue();
+    }
+
+    // Application messages are resent from the store, admin and missing ones are replaced
+    // by SequenceReset-GapFill
+    fn on_resend_request(&mut self, reader: &FixMessageReader, seq_num: u64) {
+        let Ok(Some(begin_seq_no)) = reader.get_seq_num(tags::BEGIN_SEQ_NO) else {
+            self.send_reject(
+                seq_num,
+                "2",
+                Some(tags::BEGIN_SEQ_NO),
+                SessionRejectReason::RequiredTagMissing,
+            );
+            return;
+        };
+
+        let last_sent = self.store.get_next_sender_seq_num() - 1;
+
+        // Zero means "up to the last message"
+        let end_seq_no = match reader.get_seq_num(tags::END_SEQ_NO) {
+            Ok(Some(0)) => last_sent,
+            Ok(Some(value)) => value.min(last_sent),
+            _ => {
+                self.send_reject(
+                    seq_num,
+                    "2",
+                    Some(tags::END_SEQ_NO),
+                    SessionRejectReason::RequiredTagMissing,
+                );
+                return;
+            }
+        };
+
+        let begin_seq_no = begin_seq_no.max(1);
+        if begin_seq_no > end_seq_no {
+            return;
+        }
+
+        let messages = match self.store.get_range(begin_seq_no, end_seq_no) {
+            Ok(messages) => messages,
+            Err(err) => {
+                self.disconnect(DisconnectReason::StoreError(err.to_string()));
+                return;
+            }
+        };
+
+        let now = self.clock.now();
+        let mut gap_fill_begin = None;
+        let mut next_seq_num = begin_seq_no;
+
+        for (stored_seq_num, message) in messages {
+            if stored_seq_num > next_seq_num {
+                gap_fill_begin.get_or_insert(next_seq_num);
+            }
+
+            next_seq_num = stored_seq_num + 1;
+
+            let resend = if is_admin_message(&message) {
+                None
+            } else {
+                self.prepare_resend(&message, now)
+            };
+
+            let Some(resend) = resend else {
+                gap_fill_begin.get_or_insert(stored_seq_num);
+                continue;
+            };
+
+            if let Some(gap_fill_begin) = gap_fill_begin.take() {
+                self.send_gap_fill(gap_fill_begin, stored_seq_num);
+            }
+
+            self.events.push_back(FixSessionEvent::Send(resend));
+        }
+
+        if next_seq_num <= end_seq_no {
+            gap_fill_begin.get_or_insert(next_seq_num);
+        }
+
+        if let Some(gap_fill_begin) = gap_fill_begin {
+            self.send_gap_fill(gap_fill_begin, end_seq_no + 1);
+        }
+
+        self.last_sent = now;
+    }
+
+    // Original message with PossDupFlag (43), new SendingTime (52) and the original one in
+    // OrigSendingTime (122). BodyLength and CheckSum are calculated again.
+    fn prepare_resend(&self, message: &[u8], now: SystemTime) -> Option<Vec<u8>> {
+        let sending_time = UtcTimestamp::from_system_time(now)?
+            .with_precision(self.config.get_timestamp_precision());
+
+        let reader = FixMessageReader::from_bytes(message);
+        let mut fix_version = None;
+        let mut body = FixMessageBodyBuilder::new();
+
+        for itm in reader.iter() {
+            let itm = itm.ok()?;
+
+            match itm.get_tag().ok()? {
+                tags::BEGIN_STRING => fix_version = Some(itm.value),
+                tags::BODY_LENGTH | tags::CHECK_SUM | tags::POSS_DUP_FLAG => {}
+                tags::ORIG_SENDING_TIME => {}
+                tags::SENDING_TIME => {
+                    body.append(tags::POSS_DUP_FLAG, true);
+                    body.append(tags::SENDING_TIME, sending_time);
+                    body.append(tags::ORIG_SENDING_TIME, itm.value);
+                }
+                tag => body.append_data(tag, itm.data),
+            }
+        }
+
+        Some(FixMessageWriter::from_body(fix_version?, body).compile_message())
+    }
+
+    fn send_gap_fill(&mut self, seq_num: u64, new_seq_no: u64) {
+        let mut gap_fill = self.new_message("4");
+        gap_fill.with_value(tags::GAP_FILL_FLAG, true);
+        gap_fill.with_value(tags::NEW_SEQ_NO, new_seq_no);
+
+        let now = self.clock.now();
+        self.stamp_header(&mut gap_fill, seq_num, now, true);
+
+        self.events
+            .push_back(FixSessionEvent::Send(gap_fill.compile_message()));
+    }
+
+    fn on_logon(&mut self, reader: &FixMessageReader) {
+        match self.state {
+            FixSessionState::AwaitingLogon => {
+                let heart_bt_int = match reader.get_int(tags::HEART_BT_INT) {
+                    Ok(Some(value)) if value >= 0 => value as u64,
+                    _ => {
+                        self.reject_logon("Invalid HeartBtInt");
+                        return;
+                    }
+                };
+
+                if self.config.is_fixt() {
+                    let Some(appl_ver_id) = get_default_appl_ver_id(reader) else {
+                        self.reject_logon("DefaultApplVerID(1137) is missing");
+                        return;
+                    };
+
+                    self.target_default_appl_ver_id = Some(appl_ver_id);
+                }
+
+                self.heart_bt_int = Duration::from_secs(heart_bt_int);
+
+                // Store is already reset by `on_message`, the answer confirms it
+                let reset = is_flag_set(reader, tags::RESET_SEQ_NUM_FLAG);
+                self.send_logon(reset);
+            }
+            FixSessionState::LogonSent => {
+                // Counterparty which does not answer with its version uses ours
+                if self.config.is_fixt() {
+                    self.target_default_appl_ver_id = get_default_appl_ver_id(reader)
+                        .or_else(|| self.config.get_default_appl_ver_id().cloned());
+                }
+            }
+            // Logon is expected only once per connection
+            _ => return,
+        }
+
+        self.state = FixSessionState::Active;
+        self.events.push_back(FixSessionEvent::LoggedOn);
+    }
+
+    fn reject_logon(&mut self, text: &str) {
+        self.send_logout(Some(text));
+        self.disconnect(DisconnectReason::InvalidLogon(text.to_string()));
+    }
+
+    fn on_logout(&mut self) {
+        if self.state != FixSessionState::LogoutSent {
+            self.send_logout(None);
+        }
+
+        self.events.push_back(FixSessionEvent::LoggedOut);
+        self.disconnect(DisconnectReason::LoggedOut);
+    }
+
+    // BeginString and CompIDs must match the session, otherwise the counterparty is
+    // logged out
+    fn check_header(&mut self, reader: &FixMessageReader) -> bool {
+        let begin_string = reader.get_value(tags::BEGIN_STRING).ok().flatten();
+
+        if begin_string != Some(self.config.get_begin_string()) {
+            let begin_string = begin_string.unwrap_or_default().to_string();
+            self.send_logout(Some("Incorrect BeginString"));
+            self.disconnect(DisconnectReason::InvalidBeginString(begin_string));
+            return false;
+        }
+
+        let sender_comp_id = reader.get_value(tags::SENDER_COMP_ID).ok().flatten();
+        let target_comp_id = reader.get_value(tags::TARGET_COMP_ID).ok().flatten();
+
+        if sender_comp_id != Some(self.config.get_target_comp_id())
+            || target_comp_id != Some(self.config.get_sender_comp_id())
+        {
+            self.send_logout(Some("CompID problem"));
+            self.disconnect(DisconnectReason::InvalidCompId);
+            return false;
+        }
+
+        true
+    }
+
+    fn send_logon(&mut self, reset: bool) {
+        let mut logon = self.new_message("A");
+        logon.with_value(tags::ENCRYPT_METHOD, 0);
+        logon.with_value(tags::HEART_BT_INT, self.heart_bt_int.as_secs());
+        if reset {
+            logon.with_value(tags::RESET_SEQ_NUM_FLAG, true);
+        }
+        if self.config.is_fixt() {
+            // Acceptor without its own version confirms the one of the counterparty
+            let appl_ver_id = self
+                .config
+                .get_default_appl_ver_id()
+                .or(self.target_default_appl_ver_id.as_ref());
+
+            if let Some(appl_ver_id) = appl_ver_id {
+                logon.with_value(tags::DEFAULT_APPL_VER_ID, appl_ver_id);
+            }
+        }
+        self.send_admin(logon);
+    }
+
+    fn send_logout(&mut self, text: Option<&str>) {
+        let mut logout = self.new_message("5");
+        if let Some(text) = text {
+            logout.with_value(tags::TEXT, text);
+        }
+        self.send_admin(logout);
+    }
+
+    fn send_reject(
+        &mut self,
+        ref_seq_num: u64,
+        ref_msg_type: &str,
+        ref_tag_id: Option<Tag>,
+        reason: SessionRejectReason,
+    ) {
+        let mut reject = self.new_message("3");
+        reject.with_value(tags::REF_SEQ_NUM, ref_seq_num);
+        if let Some(ref_tag_id) = ref_tag_id {
+            reject.with_value(tags::REF_TAG_ID, ref_tag_id.get_value());
+        }
+        if !ref_msg_type.is_empty() {
+            reject.with_value(tags::REF_MSG_TYPE, ref_msg_type);
+        }
+        reject.with_value(tags::SESSION_REJECT_REASON, reason.get_code());
+        reject.with_value(tags::TEXT, reason.get_text());
+        self.send_admin(reject);
+    }
+
+    fn disconnect(&mut self, reason: DisconnectReason) {
+        let reset = match &reason {
+            // Failed reset must not be retried
+            DisconnectReason::StoreError(_) => false,
+            DisconnectReason::LoggedOut => {
+                self.config.get_reset_on_logout() || self.config.get_reset_on_disconnect()
+            }
+            _ => self.config.get_reset_on_disconnect(),
+        };
+
+        self.state = FixSessionState::Disconnected;
+        self.test_request_id = None;
+        self.events.push_back(FixSessionEvent::Disconnect(reason));
+
+        if reset {
+            self.reset_store();
+        }
+    }
+
+    fn new_message(&self, msg_type: &str) -> FixMessageWriter {
+        FixMessageWriter::new(self.config.get_begin_string(), msg_type)
+    }
+
+    // Sequence numbers which can not be stored break the session
+    fn update_target_seq_num(&mut self, seq_num: u64) {
+        if let Err(err) = self.store.set_next_target_seq_num(seq_num) {
+            self.disconnect(DisconnectReason::StoreError(err.to_string()));
+        }
+    }
+
+    fn reset_store(&mut self) {
+        if let Err(err) = self.store.reset(self.clock.now()) {
+            self.disconnect(DisconnectReason::StoreError(err.to_string()));
+        }
+    }
+
+    // Resets the store when a new session has started since the store was created.
+    // Returns `false` outside of the session time.
+    fn start_session(&mut self, now: SystemTime) -> bool {
+        let Some(schedule) = self.config.get_schedule() else {
+            return true;
+        };
+
+        let Some(session_start) = schedule.get_session_start(now) else {
+            return false;
+        };
+
+        if self.store.get_creation_time() < session_start {
+            self.reset_store();
+        }
+
+        true
+    }
+
+    // Session time has not ended and no new session has started since the logon
+    fn is_current_session(&self, now: SystemTime) -> bool {
+        let Some(schedule) = self.config.get_schedule() else {
+            return true;
+        };
+
+        schedule
+            .get_session_start(now)
+            .is_some_and(|session_start| self.store.get_creation_time() >= session_start)
+    }
+
+    // Admin message which can not be stored breaks the session
+    fn send_admin(&mut self, writer: FixMessageWriter) {
+        if let Err(err) = self.send_message(writer) {
+            self.disconnect(DisconnectReason::StoreError(err.to_string()));
+        }
+    }
+
+    fn send_message(&mut self, mut writer: FixMessageWriter) -> io::Result<u64> {
+        let now = self.clock.now();
+        let seq_num = self.store.get_next_sender_seq_num();
+
+        self.stamp_header(&mut writer, seq_num, now, false);
+
+        let message = writer.compile_message();
+        self.store.set(seq_num, &message)?;
+        self.store.set_next_sender_seq_num(seq_num + 1)?;
+
+        self.events.push_back(FixSessionEvent::Send(message));
+        self.last_sent = now;
+
+        Ok(seq_num)
+    }
+
+    fn stamp_header(
+        &self,
+        writer: &mut FixMessageWriter,
+        seq_num: u64,
+        now: SystemTime,
+        poss_dup: bool,
+    ) {
+        let sending_time = UtcTimestamp::from_system_time(now)
+            .map(|sending_time| sending_time.with_precision(self.config.get_timestamp_precision()));
+
+        self.config
+            .get_standard_header()
+            .stamp_fields(writer, seq_num, sending_time, poss_dup);
+    }
+}
+
+fn is_admin_message(message: &[u8]) -> bool {
+    let reader = FixMessageReader::from_bytes(message);
+
+    matches!(
+        reader.get_message_type(),
+        Ok("0" | "1" | "2" | "3" | "4" | "5" | "A")
+    )
+}
+
+fn get_default_appl_ver_id(reader: &FixMessageReader) -> Option<ApplVerID> {
+    let value = reader.get_value(tags::DEFAULT_APPL_VER_ID).ok().flatten()?;
+    ApplVerID::try_from(value).ok()
+}
+
+fn is_flag_set(reader: &FixMessageReader, tag: Tag) -> bool {
+    matches!(reader.get_bool(tag), Ok(Some(true)))
+}
+
+fn elapsed(now: SystemTime, since: SystemTime) -> Duration {
+    now.duration_since(since).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        fixt11_messages::ApplVerID, tags, DisconnectReason, FileMessageStore, FixMessageReader,
+        FixMessageWriter, FixSession, FixSessionConfig, FixSessionEvent, FixSessionState,
+        ManualClock, MessageStore, SessionRole, SessionSchedule, Tag, UtcTimeOnly, UtcTimestamp,
+    };
+
+    fn create_session(role: SessionRole) -> (FixSession<ManualClock>, ManualClock) {
+        let start = UtcTimestamp::new(2024, 1, 2, 10, 0, 0, 0)
+            .unwrap()
+            .to_system_time();
+        let clock = ManualClock::new(start);
+
+        let config = FixSessionConfig::new(role, "FIX.4.4", "CLIENT", "VENUE");
+        (FixSession::with_clock(config, clock.clone()), clock)
+    }
+
+    fn inbound(msg_type: &str, seq_num: u64, fields: &[(Tag, &str)]) -> Vec<u8> {
+        inbound_of_version("FIX.4.4", msg_type, seq_num, fields)
+    }
+
+    fn inbound_of_version(
+        begin_string: &str,
+        msg_type: &str,
+        seq_num: u64,
+        fields: &[(Tag, &str)],
+    ) -> Vec<u8> {
+        let mut writer = FixMessageWriter::new(begin_string, msg_type);
+        writer.with_value(tags::SENDER_COMP_ID, "VENUE");
+        writer.with_value(tags::TARGET_COMP_ID, "CLIENT");
+        writer.with_value(tags::MSG_SEQ_NUM, seq_num);
+        for (tag, value) in fields {
+            writer.with_value(*tag, *value);
+        }
+        writer.compile_message()
+    }
+
+    fn receive(session: &mut FixSession<ManualClock>, message: Vec<u8>) {
+        session.on_message(&FixMessageReader::from_bytes(&message));
+    }
+
+    fn drain_events(session: &mut FixSession<ManualClock>) -> Vec<FixSessionEvent> {
+        std::iter::from_fn(|| session.poll_event()).collect()
+    }
+
+    fn sent_types(events: &[FixSessionEvent]) -> Vec<String> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                FixSessionEvent::Send(message) => Some(
+                    FixMessageReader::from_bytes(message)
+                        .get_message_type()
+                        .unwrap()
+                        .to_string(),
+                ),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn logon(session: &mut FixSession<ManualClock>) {
+        session.on_connected();
+        receive(
+            session,
+            inbound(
+                "A",
+                1,
+                &[(tags::ENCRYPT_METHOD, "0"), (tags::HEART_BT_INT, "30")],
+            ),
+        );
+        drain_events(session);
+    }
+
+    #[test]
+    fn test_initiator_logon() {
+        let (mut session, _) = create_session(SessionRole::Initiator);
+        session.on_connected();
+
+        let events = drain_events(&mut session);
+        let FixSessionEvent::Send(logon) = &events[0] else {
+            panic!("Logon expected");
+        };
+
+        assert_eq!(
+            "8=FIX.4.4|9=66|35=A|49=CLIENT|56=VENUE|34=1|52=20240102-10:00:00.000|98=0|108=30|10=032|",
+            FixMessageReader::from_bytes(logon).to_string()
+        );
+        assert_eq!(FixSessionState::LogonSent, session.get_state());
+
+        receive(
+            &mut session,
+            inbound(
+                "A",
+                1,
+                &[(tags::ENCRYPT_METHOD, "0"), (tags::HEART_BT_INT, "30")],
+            ),
+        );
+
+        assert_eq!(vec![FixSessionEvent::LoggedOn], drain_events(&mut session));
+        assert!(session.is_logged_on());
+        assert_eq!(2, session.get_next_target_seq_num());
+    }
+
+    #[test]
+    fn test_acceptor_uses_counterparty_heart_bt_int() {
+        let (mut session, _) = create_session(SessionRole::Acceptor);
+        session.on_connected();
+        assert!(drain_events(&mut session).is_empty());
+
+        receive(
+            &mut session,
+            inbound(
+                "A",
+                1,
+                &[(tags::ENCRYPT_METHOD, "0"), (tags::HEART_BT_INT, "10")],
+            ),
+        );
+
+        let events = drain_events(&mut session);
+        assert_eq!(vec!["A"], sent_types(&events));
+        assert_eq!(FixSessionEvent::LoggedOn, events[1]);
+        assert_eq!(Duration::from_secs(10), session.get_heart_bt_int());
+
+        let FixSessionEvent::Send(logon) = &events[0] else {
+            panic!("Logon expected");
+        };
+        let reader = FixMessageReader::from_bytes(logon);
+        assert_eq!(Some(10), reader.get_int(tags::HEART_BT_INT).unwrap());
+    }
+
+    #[test]
+    fn test_first_message_must_be_logon() {
+        let (mut session, _) = create_session(SessionRole::Acceptor);
+        session.on_connected();
+
+        receive(
+            &mut session,
+            inbound("D", 1, &[(tags::CL_ORD_ID, "ORDER1")]),
+        );
+
+        assert_eq!(
+            vec![FixSessionEvent::Disconnect(
+                DisconnectReason::FirstMessageNotLogon
+            )],
+            drain_events(&mut session)
+        );
+    }
+
+    #[test]
+    fn test_invalid_comp_id() {
+        let (mut session, _) = create_session(SessionRole::Acceptor);
+        session.on_connected();
+
+        let mut writer = FixMessageWriter::new("FIX.4.4", "A");
+        writer.with_value(tags::SENDER_COMP_ID, "OTHER");
+        writer.with_value(tags::TARGET_COMP_ID, "CLIENT");
+        receive(&mut session, writer.compile_message());
+
+        let events = drain_events(&mut session);
+        assert_eq!(vec!["5"], sent_types(&events));
+        assert_eq!(
+            FixSessionEvent::Disconnect(DisconnectReason::InvalidCompId),
+            events[1]
+        );
+    }
+
+    #[test]
+    fn test_logon_timeout() {
+        let (mut session, clock) = create_session(SessionRole::Initiator);
+        session.on_connected();
+        drain_events(&mut session);
+
+        clock.advance(Duration::from_secs(9));
+        session.on_timer();
+        assert!(drain_events(&mut session).is_empty());
+
+        clock.advance(Duration::from_secs(1));
+        session.on_timer();
+        assert_eq!(
+            vec![FixSessionEvent::Disconnect(DisconnectReason::LogonTimeout)],
+            drain_events(&mut session)
+        );
+    }
+
+    #[test]
+    fn test_heartbeat_and_test_request() {
+        let (mut session, clock) = create_session(SessionRole::Initiator);
+        logon(&mut session);
+
+        clock.advance(Duration::from_secs(30));
+        session.on_timer();
+        assert_eq!(vec!["0"], sent_types(&drain_events(&mut session)));
+
+        clock.advance(Duration::from_secs(15));
+        session.on_timer();
+        assert_eq!(vec!["1"], sent_types(&drain_events(&mut session)));
+
+        // TestRequest is sent only once
+        clock.advance(Duration::from_secs(15));
+        session.on_timer();
+        assert!(drain_events(&mut session).is_empty());
+
+        clock.advance(Duration::from_secs(12));
+        session.on_timer();
+        assert_eq!(
+            vec![FixSessionEvent::Disconnect(
+                DisconnectReason::HeartbeatTimeout
+            )],
+            drain_events(&mut session)
+        );
+    }
+
+    #[test]
+    fn test_answer_test_request() {
+        let (mut session, _) = create_session(SessionRole::Initiator);
+        logon(&mut session);
+
+        receive(
+            &mut session,
+            inbound("1", 2, &[(tags::TEST_REQ_ID, "PING")]),
+        );
+
+        let events = drain_events(&mut session);
+        let FixSessionEvent::Send(heartbeat) = &events[0] else {
+            panic!("Heartbeat expected");
+        };
+
+        let reader = FixMessageReader::from_bytes(heartbeat);
+        assert_eq!("0", reader.get_message_type().unwrap());
+        assert_eq!(Some("PING"), reader.get_value(tags::TEST_REQ_ID).unwrap());
+    }
+
+    #[test]
+    fn test_send_application_message() {
+        let (mut session, _) = create_session(SessionRole::Initiator);
+
+        let mut order = FixMessageWriter::new("FIX.4.4", "D");
+        order.with_value(tags::CL_ORD_ID, "ORDER1");
+        assert!(session.send(order.clone()).unwrap_err().is_not_logged_on());
+
+        logon(&mut session);
+
+        assert_eq!(2, session.send(order).unwrap());
+
+        let events = drain_events(&mut session);
+        let FixSessionEvent::Send(message) = &events[0] else {
+            panic!("Message expected");
+        };
+
+        assert_eq!(
+            "8=FIX.4.4|9=64|35=D|49=CLIENT|56=VENUE|34=2|52=20240102-10:00:00.000|11=ORDER1|10=086|",
+            FixMessageReader::from_bytes(message).to_string()
+        );
+    }
+
+    #[test]
+    fn test_application_message_is_passed_through() {
+        let (mut session, _) = create_session(SessionRole::Initiator);
+        logon(&mut session);
+
+        let message = inbound("8", 2, &[(tags::ORDER_ID, "EXEC1")]);
+        receive(&mut session, message.clone());
+
+        assert_eq!(
+            vec![FixSessionEvent::Application(message)],
+            drain_events(&mut session)
+        );
+    }
+
+    #[test]
+    fn test_graceful_logout() {
+        let (mut session, _) = create_session(SessionRole::Initiator);
+        logon(&mut session);
+
+        session.logout(Some("Bye"));
+        assert_eq!(vec!["5"], sent_types(&drain_events(&mut session)));
+        assert_eq!(FixSessionState::LogoutSent, session.get_state());
+
+        receive(&mut session, inbound("5", 2, &[]));
+
+        assert_eq!(
+            vec![
+                FixSessionEvent::LoggedOut,
+                FixSessionEvent::Disconnect(DisconnectReason::LoggedOut)
+            ],
+            drain_events(&mut session)
+        );
+    }
+
+    #[test]
+    fn test_logout_timeout() {
+        let (mut session, clock) = create_session(SessionRole::Initiator);
+        logon(&mut session);
+
+        session.logout(None);
+        drain_events(&mut session);
+
+        clock.advance(Duration::from_secs(2));
+        session.on_timer();
+
+        assert_eq!(
+            vec![FixSessionEvent::Disconnect(DisconnectReason::LogoutTimeout)],
+            drain_events(&mut session)
+        );
+    }
+
+    #[test]
+    fn test_counterparty_logout_is_confirmed() {
+        let (mut session, _) = create_session(SessionRole::Initiator);
+        logon(&mut session);
+
+        receive(&mut session, inbound("5", 2, &[]));
+
+        let events = drain_events(&mut session);
+        assert_eq!(vec!["5"], sent_types(&events));
+        assert_eq!(
+            FixSessionEvent::Disconnect(DisconnectReason::LoggedOut),
+            events[2]
+        );
+    }
+
+    fn sent_messages(events: &[FixSessionEvent]) -> Vec<String> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                FixSessionEvent::Send(message) => {
+                    Some(FixMessageReader::from_bytes(message).to_string())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_gap_is_filled_by_resend() {
+        let (mut session, _) = create_session(SessionRole::Initiator);
+        logon(&mut session);
+
+        let order_4 = inbound("8", 4, &[(tags::ORDER_ID, "EXEC4")]);
+        receive(&mut session, order_4.clone());
+
+        let events = drain_events(&mut session);
+        assert_eq!(vec!["2"], sent_types(&events));
+        assert!(sent_messages(&events)[0].contains("|7=2|16=0|"));
+        assert_eq!(Some((2, 3)), session.get_resend_range());
+        assert_eq!(1, session.get_queued_count());
+
+        // One more message ahead of the gap does not repeat the request
+        let order_5 = inbound("8", 5, &[(tags::ORDER_ID, "EXEC5")]);
+        receive(&mut session, order_5.clone());
+        assert!(drain_events(&mut session).is_empty());
+
+        let order_2 = inbound(
+            "8",
+            2,
+            &[
+                (tags::POSS_DUP_FLAG, "Y"),
+                (tags::SENDING_TIME, "20240102-10:00:01.000"),
+                (tags::ORIG_SENDING_TIME, "20240102-09:59:00.000"),
+                (tags::ORDER_ID, "EXEC2"),
+            ],
+        );
+        receive(&mut session, order_2.clone());
+
+        let gap_fill = inbound(
+            "4",
+            3,
+            &[
+                (tags::POSS_DUP_FLAG, "Y"),
+                (tags::GAP_FILL_FLAG, "Y"),
+                (tags::NEW_SEQ_NO, "4"),
+            ],
+        );
+        receive(&mut session, gap_fill);
+
+        assert_eq!(
+            vec![
+                FixSessionEvent::Application(order_2),
+                FixSessionEvent::Application(order_4),
+                FixSessionEvent::Application(order_5)
+            ],
+            drain_events(&mut session)
+        );
+        assert_eq!(6, session.get_next_target_seq_num());
+        assert_eq!(None, session.get_resend_range());
+        assert_eq!(0, session.get_queued_count());
+    }
+
+    #[test]
+    fn test_seq_num_too_low() {
+        let (mut session, _) = create_session(SessionRole::Initiator);
+        logon(&mut session);
+
+        receive(&mut session, inbound("8", 1, &[(tags::ORDER_ID, "EXEC1")]));
+
+        let events = drain_events(&mut session);
+        assert!(sent_messages(&events)[0]
+            .contains("|58=MsgSeqNum too low, expecting 2 but received 1|"));
+        assert_eq!(
+            FixSessionEvent::Disconnect(DisconnectReason::MsgSeqNumTooLow {
+                expected: 2,
+                received: 1
+            }),
+            events[1]
+        );
+    }
+
+    #[test]
+    fn test_poss_dup_duplicate_is_ignored() {
+        let (mut session, _) = create_session(SessionRole::Initiator);
+        logon(&mut session);
+
+        let duplicate = inbound(
+            "8",
+            1,
+            &[
+                (tags::POSS_DUP_FLAG, "Y"),
+                (tags::SENDING_TIME, "20240102-10:00:01.000"),
+                (tags::ORIG_SENDING_TIME, "20240102-09:59:00.000"),
+            ],
+        );
+        receive(&mut session, duplicate);
+
+        assert!(drain_events(&mut session).is_empty());
+        assert!(session.is_logged_on());
+    }
+
+    #[test]
+    fn test_poss_dup_without_orig_sending_time_is_rejected() {
+        let (mut session, _) = create_session(SessionRole::Initiator);
+        logon(&mut session);
+
+        receive(&mut session, inbound("8", 2, &[(tags::POSS_DUP_FLAG, "Y")]));
+
+        let events = drain_events(&mut session);
+        assert_eq!(1, events.len());
+        assert!(sent_messages(&events)[0].contains("|35=3|"));
+        assert!(sent_messages(&events)[0].contains("|45=2|371=122|372=8|373=1|"));
+        assert_eq!(3, session.get_next_target_seq_num());
+    }
+
+    #[test]
+    fn test_orig_sending_time_after_sending_time() {
+        let (mut session, _) = create_session(SessionRole::Initiator);
+        logon(&mut session);
+
+        let message = inbound(
+            "8",
+            2,
+            &[
+                (tags::POSS_DUP_FLAG, "Y"),
+                (tags::SENDING_TIME, "20240102-10:00:01.000"),
+                (tags::ORIG_SENDING_TIME, "20240102-10:00:02.000"),
+            ],
+        );
+        receive(&mut session, message);
+
+        let events = drain_events(&mut session);
+        assert_eq!(vec!["3", "5"], sent_types(&events));
+        assert!(sent_messages(&events)[0].contains("|373=10|"));
+        assert_eq!(FixSessionState::LogoutSent, session.get_state());
+    }
+
+    #[test]
+    fn test_sequence_reset() {
+        let (mut session, _) = create_session(SessionRole::Initiator);
+        logon(&mut session);
+
+        // Reset mode ignores MsgSeqNum
+        receive(&mut session, inbound("4", 99, &[(tags::NEW_SEQ_NO, "10")]));
+        assert!(drain_events(&mut session).is_empty());
+        assert_eq!(10, session.get_next_target_seq_num());
+
+        receive(&mut session, inbound("4", 10, &[(tags::NEW_SEQ_NO, "5")]));
+        let events = drain_events(&mut session);
+        assert!(sent_messages(&events)[0].contains("|371=36|372=4|373=5|"));
+        assert_eq!(10, session.get_next_target_seq_num());
+    }
+
+    #[test]
+    fn test_logon_with_higher_seq_num_requests_resend() {
+        let (mut session, _) = create_session(SessionRole::Acceptor);
+        session.on_connected();
+
+        receive(
+            &mut session,
+            inbound(
+                "A",
+                5,
+                &[(tags::ENCRYPT_METHOD, "0"), (tags::HEART_BT_INT, "30")],
+            ),
+        );
+
+        let events = drain_events(&mut session);
+        assert_eq!(vec!["A", "2"], sent_types(&events));
+        assert!(sent_messages(&events)[1].contains("|7=1|16=0|"));
+        assert!(session.is_logged_on());
+
+        let gap_fill = inbound(
+            "4",
+            1,
+            &[
+                (tags::POSS_DUP_FLAG, "Y"),
+                (tags::GAP_FILL_FLAG, "Y"),
+                (tags::NEW_SEQ_NO, "5"),
+            ],
+        );
+        receive(&mut session, gap_fill);
+
+        assert!(drain_events(&mut session).is_empty());
+        assert_eq!(6, session.get_next_target_seq_num());
+    }
+
+    #[test]
+    fn test_reset_on_logon() {
+        let start = UtcTimestamp::new(2024, 1, 2, 10, 0, 0, 0)
+            .unwrap()
+            .to_system_time();
+        let config = FixSessionConfig::new(SessionRole::Initiator, "FIX.4.4", "CLIENT", "VENUE")
+            .with_reset_on_logon(true);
+        let mut session = FixSession::with_clock(config, ManualClock::new(start));
+
+        session.set_next_sender_seq_num(10).unwrap();
+        session.set_next_target_seq_num(20).unwrap();
+        session.on_connected();
+
+        let events = drain_events(&mut session);
+        assert!(sent_messages(&events)[0].contains("|34=1|"));
+        assert!(sent_messages(&events)[0].contains("|141=Y|"));
+
+        receive(
+            &mut session,
+            inbound(
+                "A",
+                1,
+                &[
+                    (tags::ENCRYPT_METHOD, "0"),
+                    (tags::HEART_BT_INT, "30"),
+                    (tags::RESET_SEQ_NUM_FLAG, "Y"),
+                ],
+            ),
+        );
+        assert_eq!(vec![FixSessionEvent::LoggedOn], drain_events(&mut session));
+        assert_eq!(2, session.get_next_target_seq_num());
+    }
+
+    #[test]
+    fn test_reset_on_logout() {
+        let start = UtcTimestamp::new(2024, 1, 2, 10, 0, 0, 0)
+            .unwrap()
+            .to_system_time();
+        let config = FixSessionConfig::new(SessionRole::Initiator, "FIX.4.4", "CLIENT", "VENUE")
+            .with_reset_on_logout(true);
+        let mut session = FixSession::with_clock(config, ManualClock::new(start));
+        logon(&mut session);
+
+        session.logout(None);
+        receive(&mut session, inbound("5", 2, &[]));
+        drain_events(&mut session);
+        assert_eq!(1, session.get_next_sender_seq_num());
+        assert_eq!(1, session.get_next_target_seq_num());
+
+        // Sequence numbers are kept when the connection is lost
+        logon(&mut session);
+        session.on_disconnected();
+        assert_eq!(2, session.get_next_sender_seq_num());
+        assert_eq!(2, session.get_next_target_seq_num());
+    }
+
+    #[test]
+    fn test_reset_on_disconnect() {
+        let start = UtcTimestamp::new(2024, 1, 2, 10, 0, 0, 0)
+            .unwrap()
+            .to_system_time();
+        let config = FixSessionConfig::new(SessionRole::Initiator, "FIX.4.4", "CLIENT", "VENUE")
+            .with_reset_on_disconnect(true);
+        let clock = ManualClock::new(start);
+        let mut session = FixSession::with_clock(config, clock.clone());
+        logon(&mut session);
+
+        session.on_disconnected();
+        assert_eq!(1, session.get_next_sender_seq_num());
+        assert_eq!(1, session.get_next_target_seq_num());
+
+        logon(&mut session);
+        clock.advance(Duration::from_secs(120));
+        session.on_timer();
+        session.on_timer();
+        assert_eq!(
+            Some(FixSessionEvent::Disconnect(
+                DisconnectReason::HeartbeatTimeout
+            )),
+            drain_events(&mut session).pop()
+        );
+        assert_eq!(1, session.get_next_sender_seq_num());
+        assert_eq!(1, session.get_next_target_seq_num());
+    }
+
+    #[test]
+    fn test_sequence_numbers_survive_restart() {
+        let dir = std::env::temp_dir().join(format!("rust-fix-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let prefix = dir.join("session-restart");
+
+        let start = UtcTimestamp::new(2024, 1, 2, 10, 0, 0, 0)
+            .unwrap()
+            .to_system_time();
+        let config = FixSessionConfig::new(SessionRole::Initiator, "FIX.4.4", "CLIENT", "VENUE");
+
+        {
+            let store = FileMessageStore::open(&prefix).unwrap();
+            let mut session =
+                FixSession::with_store(config.clone(), ManualClock::new(start), store);
+            session.reset().unwrap();
+
+            session.on_connected();
+            let logon = inbound(
+                "A",
+                1,
+                &[(tags::ENCRYPT_METHOD, "0"), (tags::HEART_BT_INT, "30")],
+            );
+            session.on_message(&FixMessageReader::from_bytes(&logon));
+            session.send(FixMessageWriter::new("FIX.4.4", "D")).unwrap();
+        }
+
+        let store = FileMessageStore::open(&prefix).unwrap();
+        let session = FixSession::with_store(config, ManualClock::new(start), store);
+
+        assert_eq!(3, session.get_next_sender_seq_num());
+        assert_eq!(2, session.get_next_target_seq_num());
+        assert_eq!(start, session.get_store().get_creation_time());
+        assert_eq!(2, session.get_store().get_range(1, 2).unwrap().len());
+    }
+
+    #[test]
+    fn test_resend_replaces_admin_messages_by_gap_fill() {
+        let (mut session, clock) = create_session(SessionRole::Initiator);
+        logon(&mut session);
+
+        let mut order = FixMessageWriter::new("FIX.4.4", "D");
+        order.with_value(tags::CL_ORD_ID, "ORDER1");
+        assert_eq!(2, session.send(order.clone()).unwrap());
+
+        clock.advance(Duration::from_secs(30));
+        session.on_timer();
+        assert_eq!(4, session.send(order).unwrap());
+        drain_events(&mut session);
+
+        clock.advance(Duration::from_secs(1));
+        receive(
+            &mut session,
+            inbound(
+                "2",
+                2,
+                &[(tags::BEGIN_SEQ_NO, "1"), (tags::END_SEQ_NO, "0")],
+            ),
+        );
+
+        let events = drain_events(&mut session);
+        let sent = sent_messages(&events);
+
+        assert_eq!(
+            vec![
+                "8=FIX.4.4|9=96|35=4|49=CLIENT|56=VENUE|34=1|43=Y|52=20240102-10:00:31.000|122=20240102-10:00:31.000|123=Y|36=2|10=231|",
+                "8=FIX.4.4|9=95|35=D|49=CLIENT|56=VENUE|34=2|43=Y|52=20240102-10:00:31.000|122=20240102-10:00:00.000|11=ORDER1|10=058|",
+                "8=FIX.4.4|9=96|35=4|49=CLIENT|56=VENUE|34=3|43=Y|52=20240102-10:00:31.000|122=20240102-10:00:31.000|123=Y|36=4|10=235|",
+                "8=FIX.4.4|9=95|35=D|49=CLIENT|56=VENUE|34=4|43=Y|52=20240102-10:00:31.000|122=20240102-10:00:30.000|11=ORDER1|10=063|",
+            ],
+            sent
+        );
+
+        for event in &events {
+            let FixSessionEvent::Send(message) = event else {
+                continue;
+            };
+            assert!(FixMessageReader::from_bytes(message)
+                .check_payload()
+                .is_ok());
+        }
+
+        assert_eq!(5, session.get_next_sender_seq_num());
+    }
+
+    #[test]
+    fn test_resend_of_binary_data() {
+        let (mut session, _) = create_session(SessionRole::Initiator);
+        logon(&mut session);
+
+        let mut news = FixMessageWriter::new("FIX.4.4", "B");
+        news.with_data(tags::RAW_DATA_LENGTH, tags::RAW_DATA, [0xff, 0x01, 0x80]);
+        assert_eq!(2, session.send(news).unwrap());
+        drain_events(&mut session);
+
+        receive(
+            &mut session,
+            inbound(
+                "2",
+                2,
+                &[(tags::BEGIN_SEQ_NO, "2"), (tags::END_SEQ_NO, "2")],
+            ),
+        );
+
+        let events = drain_events(&mut session);
+        let [FixSessionEvent::Send(message)] = events.as_slice() else {
+            panic!("Resend expected: {:?}", events);
+        };
+
+        let reader = FixMessageReader::from_bytes(message)
+            .check_payload()
+            .unwrap();
+        assert_eq!(Some(true), reader.get_bool(tags::POSS_DUP_FLAG).unwrap());
+        assert_eq!(
+            Some(&[0xff, 0x01, 0x80][..]),
+            reader.get_data(tags::RAW_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_resend_of_messages_which_are_not_stored() {
+        let (mut session, _) = create_session(SessionRole::Initiator);
+        logon(&mut session);
+        session.set_next_sender_seq_num(10).unwrap();
+
+        receive(
+            &mut session,
+            inbound(
+                "2",
+                2,
+                &[(tags::BEGIN_SEQ_NO, "5"), (tags::END_SEQ_NO, "7")],
+            ),
+        );
+
+        let events = drain_events(&mut session);
+        let sent = sent_messages(&events);
+        assert_eq!(1, sent.len());
+        assert!(sent[0].contains("|34=5|43=Y|"));
+        assert!(sent[0].contains("|123=Y|36=8|"));
+    }
+
+    #[test]
+    fn test_resend_request_ahead_of_gap_is_answered() {
+        let (mut session, _) = create_session(SessionRole::Initiator);
+        logon(&mut session);
+
+        receive(
+            &mut session,
+            inbound(
+                "2",
+                3,
+                &[(tags::BEGIN_SEQ_NO, "1"), (tags::END_SEQ_NO, "0")],
+            ),
+        );
+
+        // Our ResendRequest for 2 and the GapFill for our Logon
+        let events = drain_events(&mut session);
+        assert_eq!(vec!["4", "2"], sent_types(&events));
+
+        receive(
+            &mut session,
+            inbound(
+                "0",
+                2,
+                &[
+                    (tags::POSS_DUP_FLAG, "Y"),
+                    (tags::ORIG_SENDING_TIME, "20240102-10:00:00.000"),
+                ],
+            ),
+        );
+        assert_eq!(4, session.get_next_target_seq_num());
+        assert_eq!(0, session.get_queued_count());
+    }
+
+    // Daily session from 08:00 to 17:00 with the store created during yesterday's session
+    // and the clock at 07:00
+    fn create_scheduled_session(role: SessionRole) -> (FixSession<ManualClock>, ManualClock) {
+        let schedule = SessionSchedule::daily(
+            UtcTimeOnly::new(8, 0, 0, 0).unwrap(),
+            UtcTimeOnly::new(17, 0, 0, 0).unwrap(),
+        );
+        let config =
+            FixSessionConfig::new(role, "FIX.4.4", "CLIENT", "VENUE").with_schedule(schedule);
+
+        let at = |hour| {
+            UtcTimestamp::new(2024, 1, 2, hour, 0, 0, 0)
+                .unwrap()
+                .to_system_time()
+        };
+        let clock = ManualClock::new(at(9) - Duration::from_secs(24 * 3600));
+
+        let mut session = FixSession::with_clock(config, clock.clone());
+        clock.set(at(7));
+        session.set_next_sender_seq_num(5).unwrap();
+        session.set_next_target_seq_num(7).unwrap();
+
+        (session, clock)
+    }
+
+    #[test]
+    fn test_initiator_logon_outside_session_time() {
+        let (mut session, clock) = create_scheduled_session(SessionRole::Initiator);
+        assert!(!session.is_session_time());
+
+        session.on_connected();
+        assert_eq!(
+            vec![FixSessionEvent::Disconnect(
+                DisconnectReason::OutsideSessionTime
+            )],
+            drain_events(&mut session)
+        );
+        assert_eq!(5, session.get_next_sender_seq_num());
+
+        // New session starts both sequences from 1
+        clock.advance(Duration::from_secs(3600));
+        session.on_connected();
+
+        let sent = sent_messages(&drain_events(&mut session));
+        assert!(sent[0].contains("|35=A|"));
+        assert!(sent[0].contains("|34=1|"));
+        assert_eq!(1, session.get_next_target_seq_num());
+    }
+
+    #[test]
+    fn test_logout_at_end_of_session() {
+        let (mut session, clock) = create_scheduled_session(SessionRole::Initiator);
+        clock.advance(Duration::from_secs(3600));
+        session.on_connected();
+        receive(
+            &mut session,
+            inbound(
+                "A",
+                1,
+                &[(tags::ENCRYPT_METHOD, "0"), (tags::HEART_BT_INT, "0")],
+            ),
+        );
+        drain_events(&mut session);
+        assert!(session.is_logged_on());
+
+        clock.advance(Duration::from_secs(9 * 3600 - 1));
+        receive(&mut session, inbound("0", 2, &[]));
+        session.on_timer();
+        assert_eq!(vec!["0"], sent_types(&drain_events(&mut session)));
+
+        clock.advance(Duration::from_secs(1));
+        session.on_timer();
+        assert_eq!(vec!["5"], sent_types(&drain_events(&mut session)));
+        assert_eq!(FixSessionState::LogoutSent, session.get_state());
+    }
+
+    #[test]
+    fn test_acceptor_refuses_logon_outside_session_time() {
+        let (mut session, _) = create_scheduled_session(SessionRole::Acceptor);
+        session.on_connected();
+
+        receive(
+            &mut session,
+            inbound(
+                "A",
+                7,
+                &[(tags::ENCRYPT_METHOD, "0"), (tags::HEART_BT_INT, "30")],
+            ),
+        );
+        assert_eq!(
+            vec![FixSessionEvent::Disconnect(
+                DisconnectReason::OutsideSessionTime
+            )],
+            drain_events(&mut session)
+        );
+        assert_eq!(7, session.get_next_target_seq_num());
+    }
+
+    fn create_fixt_session(
+        role: SessionRole,
+        configure: impl FnOnce(FixSessionConfig) -> FixSessionConfig,
+    ) -> FixSession<ManualClock> {
+        let (_, clock) = create_session(role);
+        let config = FixSessionConfig::new(role, "FIXT.1.1", "CLIENT", "VENUE");
+        FixSession::with_clock(configure(config), clock)
+    }
+
+    fn sent_value(event: &FixSessionEvent, tag: Tag) -> Option<String> {
+        let FixSessionEvent::Send(message) = event else {
+            return None;
+        };
+
+        let reader = FixMessageReader::from_bytes(message);
+        reader
+            .get_value(tag)
+            .unwrap()
+            .map(|value| value.to_string())
+    }
+
+    #[test]
+    fn test_fixt_initiator_negotiates_default_appl_ver_id() {
+        let mut session = create_fixt_session(SessionRole::Initiator, |config| {
+            config.with_default_appl_ver_id(ApplVerID::Fix50sp2)
+        });
+        session.on_connected();
+
+        let events = drain_events(&mut session);
+        assert_eq!(
+            Some("9".to_string()),
+            sent_value(&events[0], tags::DEFAULT_APPL_VER_ID)
+        );
+
+        let logon = inbound_of_version(
+            "FIXT.1.1",
+            "A",
+            1,
+            &[
+                (tags::ENCRYPT_METHOD, "0"),
+                (tags::HEART_BT_INT, "30"),
+                (tags::DEFAULT_APPL_VER_ID, "8"),
+            ],
+        );
+        receive(&mut session, logon);
+        assert!(session.is_logged_on());
+        assert_eq!(
+            Some(&ApplVerID::Fix50sp1),
+            session.get_target_default_appl_ver_id()
+        );
+
+        // ApplVerID of the message overrides the negotiated version
+        let order = inbound_of_version(
+            "FIXT.1.1",
+            "D",
+            2,
+            &[(tags::APPL_VER_ID, "9"), (tags::CL_ORD_ID, "A")],
+        );
+        let reader = FixMessageReader::from_bytes(&order);
+        assert_eq!(Some(ApplVerID::Fix50sp2), session.get_appl_ver_id(&reader));
+
+        let order = inbound_of_version("FIXT.1.1", "D", 2, &[(tags::CL_ORD_ID, "A")]);
+        let reader = FixMessageReader::from_bytes(&order);
+        assert_eq!(Some(ApplVerID::Fix50sp1), session.get_appl_ver_id(&reader));
+    }
+
+    #[test]
+    fn test_fixt_initiator_without_default_appl_ver_id() {
+        let mut session = create_fixt_session(SessionRole::Initiator, |config| config);
+        session.on_connected();
+
+        assert_eq!(
+            vec![FixSessionEvent::Disconnect(
+                DisconnectReason::MissingDefaultApplVerId
+            )],
+            drain_events(&mut session)
+        );
+        assert_eq!(1, session.get_next_sender_seq_num());
+    }
+
+    #[test]
+    fn test_fixt_acceptor_requires_default_appl_ver_id() {
+        let mut session = create_fixt_session(SessionRole::Acceptor, |config| config);
+        session.on_connected();
+
+        let logon = inbound_of_version(
+            "FIXT.1.1",
+            "A",
+            1,
+            &[(tags::ENCRYPT_METHOD, "0"), (tags::HEART_BT_INT, "30")],
+        );
+        receive(&mut session, logon);
+
+        let events = drain_events(&mut session);
+        assert_eq!(vec!["5"], sent_types(&events));
+        assert_eq!(
+            Some(&FixSessionEvent::Disconnect(
+                DisconnectReason::InvalidLogon("DefaultApplVerID(1137) is missing".to_string())
+            )),
+            events.last()
+        );
+
+        // Acceptor without its own version confirms the one of the counterparty
+        session.on_connected();
+        let logon = inbound_of_version(
+            "FIXT.1.1",
+            "A",
+            2,
+            &[
+                (tags::ENCRYPT_METHOD, "0"),
+                (tags::HEART_BT_INT, "30"),
+                (tags::DEFAULT_APPL_VER_ID, "9"),
+            ],
+        );
+        receive(&mut session, logon);
+
+        let events = drain_events(&mut session);
+        assert_eq!(
+            Some("9".to_string()),
+            sent_value(&events[0], tags::DEFAULT_APPL_VER_ID)
+        );
+        assert_eq!(
+            Some(&ApplVerID::Fix50sp2),
+            session.get_target_default_appl_ver_id()
+        );
+    }
+}
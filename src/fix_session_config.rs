@@ -0,0 +1,266 @@
+use std::{collections::HashSet, fmt, io, time::Duration};
+
+use crate::{fixt11_messages::ApplVerID, SessionSchedule, StandardHeader, TimestampPrecision};
+
+// Transport of FIX 5.0 and later, the application version is negotiated separately
+pub(crate) const FIXT_BEGIN_STRING: &str = "FIXT.1.1";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionRole {
+    /// Connects to the counterparty and sends the first Logon
+    Initiator,
+    /// Accepts the connection and answers the counterparty Logon
+    Acceptor,
+}
+
+/// BeginString and CompIDs which identify a session, seen from our side
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FixSessionId {
+    begin_string: String,
+    sender_comp_id: String,
+    target_comp_id: String,
+}
+
+impl FixSessionId {
+    pub fn new(
+        begin_string: impl Into<String>,
+        sender_comp_id: impl Into<String>,
+        target_comp_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            begin_string: begin_string.into(),
+            sender_comp_id: sender_comp_id.into(),
+            target_comp_id: target_comp_id.into(),
+        }
+    }
+
+    pub fn get_begin_string(&self) -> &str {
+        &self.begin_string
+    }
+
+    pub fn get_sender_comp_id(&self) -> &str {
+        &self.sender_comp_id
+    }
+
+    pub fn get_target_comp_id(&self) -> &str {
+        &self.target_comp_id
+    }
+}
+
+/// Same format as QuickFIX: `FIX.4.4:CLIENT->VENUE`
+impl fmt::Display for FixSessionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}->{}",
+            self.begin_string, self.sender_comp_id, self.target_comp_id
+        )
+    }
+}
+
+/// Identity and timing parameters of a [`crate::FixSession`].
+#[derive(Debug, Clone)]
+pub struct FixSessionConfig {
+    role: SessionRole,
+    begin_string: String,
+    header: StandardHeader,
+    heart_bt_int: u32,
+    logon_timeout: Duration,
+    logout_timeout: Duration,
+    timestamp_precision: TimestampPrecision,
+    reset_on_logon: bool,
+    reset_on_logout: bool,
+    reset_on_disconnect: bool,
+    schedule: Option<SessionSchedule>,
+    default_appl_ver_id: Option<ApplVerID>,
+}
+
+impl FixSessionConfig {
+    pub const DEFAULT_HEART_BT_INT: u32 = 30;
+    pub const DEFAULT_LOGON_TIMEOUT: Duration = Duration::from_secs(10);
+    pub const DEFAULT_LOGOUT_TIMEOUT: Duration = Duration::from_secs(2);
+
+    pub fn new(
+        role: SessionRole,
+        begin_string: impl Into<String>,
+        sender_comp_id: impl Into<String>,
+        target_comp_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            role,
+            begin_string: begin_string.into(),
+            header: StandardHeader::new(sender_comp_id, target_comp_id),
+            heart_bt_int: Self::DEFAULT_HEART_BT_INT,
+            logon_timeout: Self::DEFAULT_LOGON_TIMEOUT,
+            logout_timeout: Self::DEFAULT_LOGOUT_TIMEOUT,
+            timestamp_precision: TimestampPrecision::default(),
+            reset_on_logon: false,
+            reset_on_logout: false,
+            reset_on_disconnect: false,
+            schedule: None,
+            default_appl_ver_id: None,
+        }
+    }
+
+    /// Header fields stamped on every outbound message: SubIDs, LocationIDs and the
+    /// OnBehalfOf / DeliverTo routing fields. Its CompIDs replace the ones passed to `new`.
+    pub fn with_standard_header(mut self, header: StandardHeader) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// HeartBtInt (108) in seconds sent by the initiator. Acceptors use the value of the
+    /// counterparty Logon.
+    pub fn with_heart_bt_int(mut self, seconds: u32) -> Self {
+        self.heart_bt_int = seconds;
+        self
+    }
+
+    /// How long to wait for the Logon of the counterparty
+    pub fn with_logon_timeout(mut self, timeout: Duration) -> Self {
+        self.logon_timeout = timeout;
+        self
+    }
+
+    /// How long to wait for the Logout confirmation before disconnecting
+    pub fn with_logout_timeout(mut self, timeout: Duration) -> Self {
+        self.logout_timeout = timeout;
+        self
+    }
+
+    /// Precision of SendingTime (52)
+    pub fn with_timestamp_precision(mut self, precision: TimestampPrecision) -> Self {
+        self.timestamp_precision = precision;
+        self
+    }
+
+    /// Initiator sends ResetSeqNumFlag (141=Y) in Logon and starts both sequences from 1
+    pub fn with_reset_on_logon(mut self, reset_on_logon: bool) -> Self {
+        self.reset_on_logon = reset_on_logon;
+        self
+    }
+
+    /// Both sequences start from 1 again after the Logout exchange is completed
+    pub fn with_reset_on_logout(mut self, reset_on_logout: bool) -> Self {
+        self.reset_on_logout = reset_on_logout;
+        self
+    }
+
+    /// Both sequences start from 1 again after any disconnect, including the Logout
+    pub fn with_reset_on_disconnect(mut self, reset_on_disconnect: bool) -> Self {
+        self.reset_on_disconnect = reset_on_disconnect;
+        self
+    }
+
+    /// Trading window of the session. Without it the session may be logged on at any time
+    /// and the sequence numbers are never reset by time.
+    pub fn with_schedule(mut self, schedule: SessionSchedule) -> Self {
+        self.schedule = Some(schedule);
+        self
+    }
+
+    /// DefaultApplVerID (1137) sent in Logon of FIXT.1.1 sessions. Initiators have to set
+    /// it, otherwise they disconnect with `DisconnectReason::MissingDefaultApplVerId`
+    /// instead of logging on. Acceptors without it answer with the version of the
+    /// counterparty.
+    pub fn with_default_appl_ver_id(mut self, appl_ver_id: ApplVerID) -> Self {
+        self.default_appl_ver_id = Some(appl_ver_id);
+        self
+    }
+
+    pub fn get_role(&self) -> SessionRole {
+        self.role
+    }
+
+    pub fn get_begin_string(&self) -> &str {
+        &self.begin_string
+    }
+
+    pub fn get_sender_comp_id(&self) -> &str {
+        self.header.get_sender_comp_id()
+    }
+
+    pub fn get_target_comp_id(&self) -> &str {
+        self.header.get_target_comp_id()
+    }
+
+    pub fn get_standard_header(&self) -> &StandardHeader {
+        &self.header
+    }
+
+    pub fn get_session_id(&self) -> FixSessionId {
+        FixSessionId::new(
+            self.begin_string.as_str(),
+            self.get_sender_comp_id(),
+            self.get_target_comp_id(),
+        )
+    }
+
+    pub fn get_heart_bt_int(&self) -> u32 {
+        self.heart_bt_int
+    }
+
+    pub fn get_logon_timeout(&self) -> Duration {
+        self.logon_timeout
+    }
+
+    pub fn get_logout_timeout(&self) -> Duration {
+        self.logout_timeout
+    }
+
+    pub fn get_timestamp_precision(&self) -> TimestampPrecision {
+        self.timestamp_precision
+    }
+
+    pub fn get_reset_on_logon(&self) -> bool {
+        self.reset_on_logon
+    }
+
+    pub fn get_reset_on_logout(&self) -> bool {
+        self.reset_on_logout
+    }
+
+    pub fn get_reset_on_disconnect(&self) -> bool {
+        self.reset_on_disconnect
+    }
+
+    pub fn get_schedule(&self) -> Option<&SessionSchedule> {
+        self.schedule.as_ref()
+    }
+
+    pub fn get_default_appl_ver_id(&self) -> Option<&ApplVerID> {
+        self.default_appl_ver_id.as_ref()
+    }
+
+    /// BeginString is FIXT.1.1, so the messages carry FIX 5.0 and later application versions
+    pub fn is_fixt(&self) -> bool {
+        self.begin_string == FIXT_BEGIN_STRING
+    }
+}
+
+// Every session has to be an acceptor with its own id
+pub(crate) fn check_acceptor_sessions<'c>(
+    configs: impl Iterator<Item = &'c FixSessionConfig>,
+) -> io::Result<()> {
+    let mut session_ids = HashSet::new();
+
+    for config in configs {
+        let session_id = config.get_session_id();
+
+        if config.get_role() != SessionRole::Acceptor {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Session {} is not an acceptor", session_id),
+            ));
+        }
+
+        if !session_ids.insert(session_id.clone()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Session {} is added twice", session_id),
+            ));
+        }
+    }
+
+    Ok(())
+}
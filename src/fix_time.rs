@@ -0,0 +1,615 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const NANOS_PER_SECOND: u32 = 1_000_000_000;
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Amount of fraction digits written after the seconds of time fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum TimestampPrecision {
+    Seconds,
+    #[default]
+    Millis,
+    Micros,
+    Nanos,
+}
+
+impl TimestampPrecision {
+    pub fn get_digits(&self) -> u32 {
+        match self {
+            Self::Seconds => 0,
+            Self::Millis => 3,
+            Self::Micros => 6,
+            Self::Nanos => 9,
+        }
+    }
+
+    /// Smallest precision which keeps the given amount of fraction digits
+    pub fn from_digits(digits: usize) -> Option<Self> {
+        match digits {
+            0 => Some(Self::Seconds),
+            1..=3 => Some(Self::Millis),
+            4..=6 => Some(Self::Micros),
+            7..=9 => Some(Self::Nanos),
+            _ => None,
+        }
+    }
+
+    fn truncate(&self, nanos: u32) -> u32 {
+        let divider = 10u32.pow(9 - self.get_digits());
+        nanos / divider * divider
+    }
+
+    fn write_fraction(&self, f: &mut fmt::Formatter<'_>, nanos: u32) -> fmt::Result {
+        let digits = self.get_digits();
+
+        if digits == 0 {
+            return Ok(());
+        }
+
+        write!(
+            f,
+            ".{:0width$}",
+            nanos / 10u32.pow(9 - digits),
+            width = digits as usize
+        )
+    }
+}
+
+/// Value of UTCTimestamp fields: `YYYYMMDD-HH:MM:SS[.sss|.ssssss|.sssssssss]`
+///
+/// The precision is kept from the parsed value and is used when the value is written back.
+/// Values are compared by the time they point to, regardless of the precision.
+#[derive(Debug, Clone, Copy)]
+pub struct UtcTimestamp {
+    date: LocalMktDate,
+    time: UtcTimeOnly,
+}
+
+impl UtcTimestamp {
+    pub fn new(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanos: u32,
+    ) -> Option<Self> {
+        Some(Self {
+            date: LocalMktDate::new(year, month, day)?,
+            time: UtcTimeOnly::new(hour, minute, second, nanos)?,
+        })
+    }
+
+    pub fn now() -> Self {
+        Self::from_system_time(SystemTime::now()).expect("System time is out of FIX range")
+    }
+
+    /// Converts the time to a timestamp with `Nanos` precision.
+    /// Returns `None` if the year does not fit into `0000..=9999`.
+    pub fn from_system_time(value: SystemTime) -> Option<Self> {
+        let (seconds, nanos) = match value.duration_since(UNIX_EPOCH) {
+            Ok(duration) => (duration.as_secs() as i64, duration.subsec_nanos()),
+            Err(err) => {
+                let duration = err.duration();
+                let seconds = -(duration.as_secs() as i64);
+
+                match duration.subsec_nanos() {
+                    0 => (seconds, 0),
+                    nanos => (seconds - 1, NANOS_PER_SECOND - nanos),
+                }
+            }
+        };
+
+        let date = LocalMktDate::from_days_since_epoch(seconds.div_euclid(SECONDS_PER_DAY))?;
+        let second_of_day = seconds.rem_euclid(SECONDS_PER_DAY) as u32;
+
+        Some(Self {
+            date,
+            time: UtcTimeOnly {
+                hour: (second_of_day / 3600) as u8,
+                minute: (second_of_day / 60 % 60) as u8,
+                second: (second_of_day % 60) as u8,
+                nanos,
+                precision: TimestampPrecision::Nanos,
+            },
+        })
+    }
+
+    pub fn to_system_time(&self) -> SystemTime {
+        let seconds =
+            self.date.days_since_epoch() * SECONDS_PER_DAY + self.time.seconds_of_day() as i64;
+
+        let result = if seconds >= 0 {
+            UNIX_EPOCH + Duration::from_secs(seconds as u64)
+        } else {
+            UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs())
+        };
+
+        result + Duration::from_nanos(self.time.nanos as u64)
+    }
+
+    pub fn parse(src: &str) -> Option<Self> {
+        let bytes = src.as_bytes();
+
+        if bytes.len() < 17 || !src.is_ascii() || bytes[8] != b'-' {
+            return None;
+        }
+
+        Some(Self {
+            date: LocalMktDate::parse(&src[..8])?,
+            time: UtcTimeOnly::parse(&src[9..])?,
+        })
+    }
+
+    pub fn get_date(&self) -> LocalMktDate {
+        self.date
+    }
+
+    pub fn get_time(&self) -> UtcTimeOnly {
+        self.time
+    }
+
+    pub fn get_hour(&self) -> u8 {
+        self.time.hour
+    }
+
+    pub fn get_minute(&self) -> u8 {
+        self.time.minute
+    }
+
+    pub fn get_second(&self) -> u8 {
+        self.time.second
+    }
+
+    pub fn get_nanos(&self) -> u32 {
+        self.time.nanos
+    }
+
+    pub fn get_precision(&self) -> TimestampPrecision {
+        self.time.precision
+    }
+
+    /// Changes the precision the value is written with. Extra fraction digits are truncated.
+    pub fn with_precision(&self, precision: TimestampPrecision) -> Self {
+        Self {
+            date: self.date,
+            time: self.time.with_precision(precision),
+        }
+    }
+}
+
+impl fmt::Display for UtcTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.date, self.time)
+    }
+}
+
+impl PartialEq for UtcTimestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for UtcTimestamp {}
+
+impl PartialOrd for UtcTimestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UtcTimestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.date, self.time).cmp(&(other.date, other.time))
+    }
+}
+
+impl Hash for UtcTimestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.date.hash(state);
+        self.time.hash(state);
+    }
+}
+
+/// Value of UTCTimeOnly fields: `HH:MM:SS[.sss|.ssssss|.sssssssss]`
+#[derive(Debug, Clone, Copy)]
+pub struct UtcTimeOnly {
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanos: u32,
+    precision: TimestampPrecision,
+}
+
+impl UtcTimeOnly {
+    /// Creates the value with the smallest precision which keeps `nanos`
+    pub fn new(hour: u8, minute: u8, second: u8, nanos: u32) -> Option<Self> {
+        // 60 is a leap second
+        if hour > 23 || minute > 59 || second > 60 || nanos >= NANOS_PER_SECOND {
+            return None;
+        }
+
+        let precision = [
+            TimestampPrecision::Seconds,
+            TimestampPrecision::Millis,
+            TimestampPrecision::Micros,
+        ]
+        .into_iter()
+        .find(|precision| precision.truncate(nanos) == nanos)
+        .unwrap_or(TimestampPrecision::Nanos);
+
+        Some(Self {
+            hour,
+            minute,
+            second,
+            nanos,
+            precision,
+        })
+    }
+
+    pub fn parse(src: &str) -> Option<Self> {
+        let bytes = src.as_bytes();
+
+        if bytes.len() < 8 || !src.is_ascii() || bytes[2] != b':' || bytes[5] != b':' {
+            return None;
+        }
+
+        let (nanos, precision) = match &src[8..] {
+            "" => (0, TimestampPrecision::Seconds),
+            fraction => {
+                let fraction = fraction.strip_prefix('.')?;
+                (
+                    parse_fraction(fraction)?,
+                    TimestampPrecision::from_digits(fraction.len())?,
+                )
+            }
+        };
+
+        let result = Self::new(
+            parse_number(&src[..2])? as u8,
+            parse_number(&src[3..5])? as u8,
+            parse_number(&src[6..8])? as u8,
+            nanos,
+        )?;
+
+        Some(result.with_precision(precision))
+    }
+
+    pub fn get_hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn get_minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub fn get_second(&self) -> u8 {
+        self.second
+    }
+
+    pub fn get_nanos(&self) -> u32 {
+        self.nanos
+    }
+
+    pub fn get_precision(&self) -> TimestampPrecision {
+        self.precision
+    }
+
+    /// Changes the precision the value is written with. Extra fraction digits are truncated.
+    pub fn with_precision(&self, precision: TimestampPrecision) -> Self {
+        Self {
+            nanos: precision.truncate(self.nanos),
+            precision,
+            ..*self
+        }
+    }
+
+    pub(crate) fn seconds_of_day(&self) -> u32 {
+        self.hour as u32 * 3600 + self.minute as u32 * 60 + self.second as u32
+    }
+}
+
+impl fmt::Display for UtcTimeOnly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+
+        self.precision.write_fraction(f, self.nanos)
+    }
+}
+
+impl PartialEq for UtcTimeOnly {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for UtcTimeOnly {}
+
+impl PartialOrd for UtcTimeOnly {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UtcTimeOnly {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.seconds_of_day(), self.nanos).cmp(&(other.seconds_of_day(), other.nanos))
+    }
+}
+
+impl Hash for UtcTimeOnly {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.seconds_of_day().hash(state);
+        self.nanos.hash(state);
+    }
+}
+
+/// Value of UTCDateOnly fields: `YYYYMMDD`
+pub type UtcDateOnly = LocalMktDate;
+
+/// Value of LocalMktDate and UTCDateOnly fields: `YYYYMMDD`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LocalMktDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl LocalMktDate {
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        Some(Self { year, month, day })
+    }
+
+    pub fn parse(src: &str) -> Option<Self> {
+        if src.len() != 8 || !src.is_ascii() {
+            return None;
+        }
+
+        Self::new(
+            parse_number(&src[..4])? as u16,
+            parse_number(&src[4..6])? as u8,
+            parse_number(&src[6..8])? as u8,
+        )
+    }
+
+    pub fn get_year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn get_month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn get_day(&self) -> u8 {
+        self.day
+    }
+
+    pub(crate) fn days_since_epoch(&self) -> i64 {
+        let year = self.year as i64 - if self.month <= 2 { 1 } else { 0 };
+        let month = self.month as i64;
+
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    pub(crate) fn from_days_since_epoch(days: i64) -> Option<Self> {
+        let days = days + 719_468;
+
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        };
+        let year = era * 400 + year_of_era + if month <= 2 { 1 } else { 0 };
+
+        if !(0..=9999).contains(&year) {
+            return None;
+        }
+
+        Self::new(year as u16, month as u8, day as u8)
+    }
+}
+
+impl fmt::Display for LocalMktDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}{:02}{:02}", self.year, self.month, self.day)
+    }
+}
+
+pub(crate) fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+pub(crate) fn is_leap_year(year: u16) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+fn parse_number(src: &str) -> Option<u32> {
+    if !crate::fix_data_type::is_digits(src) {
+        return None;
+    }
+
+    src.parse().ok()
+}
+
+// Fraction of a second with up to 9 digits converted to nanoseconds
+fn parse_fraction(src: &str) -> Option<u32> {
+    if src.len() > 9 {
+        return None;
+    }
+
+    let value = parse_number(src)?;
+    Some(value * 10u32.pow(9 - src.len() as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::{LocalMktDate, TimestampPrecision, UtcTimeOnly, UtcTimestamp};
+
+    #[test]
+    fn test_parse_utc_timestamp() {
+        let value = UtcTimestamp::parse("20180920-18:24:59.643").unwrap();
+
+        assert_eq!(LocalMktDate::new(2018, 9, 20).unwrap(), value.get_date());
+        assert_eq!(18, value.get_hour());
+        assert_eq!(24, value.get_minute());
+        assert_eq!(59, value.get_second());
+        assert_eq!(643_000_000, value.get_nanos());
+    }
+
+    #[test]
+    fn test_parse_utc_timestamp_without_fraction() {
+        let value = UtcTimestamp::parse("20180920-18:24:59").unwrap();
+        assert_eq!(0, value.get_nanos());
+    }
+
+    #[test]
+    fn test_invalid_utc_timestamps() {
+        assert!(UtcTimestamp::parse("20180920-18:24:59.").is_none());
+        assert!(UtcTimestamp::parse("20180920-18:24:59.1234567890").is_none());
+        assert!(UtcTimestamp::parse("20180920-18:60:59").is_none());
+        assert!(UtcTimestamp::parse("20180920 18:24:59").is_none());
+        assert!(UtcTimestamp::parse("20180920-18:24").is_none());
+    }
+
+    #[test]
+    fn test_parse_local_mkt_date() {
+        assert!(LocalMktDate::parse("20200229").is_some());
+        assert!(LocalMktDate::parse("21000229").is_none());
+        assert!(LocalMktDate::parse("20181301").is_none());
+        assert!(LocalMktDate::parse("2018091").is_none());
+    }
+
+    #[test]
+    fn test_format_keeps_parsed_precision() {
+        for src in [
+            "20180920-18:24:59",
+            "20180920-18:24:59.643",
+            "20180920-18:24:59.643001",
+            "20180920-18:24:59.643001002",
+        ] {
+            assert_eq!(src, UtcTimestamp::parse(src).unwrap().to_string());
+        }
+
+        // Precision is rounded up to the next standard one
+        let value = UtcTimestamp::parse("20180920-18:24:59.64").unwrap();
+        assert_eq!(TimestampPrecision::Millis, value.get_precision());
+        assert_eq!("20180920-18:24:59.640", value.to_string());
+    }
+
+    #[test]
+    fn test_with_precision() {
+        let value = UtcTimestamp::parse("20180920-18:24:59.643001002").unwrap();
+
+        assert_eq!(
+            "20180920-18:24:59",
+            value
+                .with_precision(TimestampPrecision::Seconds)
+                .to_string()
+        );
+        assert_eq!(
+            "20180920-18:24:59.643001",
+            value.with_precision(TimestampPrecision::Micros).to_string()
+        );
+        assert_eq!(
+            "20180920-18:24:59.643000000",
+            value
+                .with_precision(TimestampPrecision::Millis)
+                .with_precision(TimestampPrecision::Nanos)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_comparison_ignores_precision() {
+        let a = UtcTimestamp::parse("20180920-18:24:59.5").unwrap();
+        let b = UtcTimestamp::parse("20180920-18:24:59.500000").unwrap();
+        let c = UtcTimestamp::parse("20180920-18:25:00").unwrap();
+
+        assert_eq!(a, b);
+        assert!(a < c);
+    }
+
+    #[test]
+    fn test_system_time() {
+        let value = UtcTimestamp::parse("20180920-18:24:59.643").unwrap();
+        let system_time = UNIX_EPOCH + Duration::from_millis(1_537_467_899_643);
+
+        assert_eq!(system_time, value.to_system_time());
+
+        let converted = UtcTimestamp::from_system_time(system_time).unwrap();
+        assert_eq!(value, converted);
+        assert_eq!(TimestampPrecision::Nanos, converted.get_precision());
+
+        let before_epoch = UtcTimestamp::parse("19691231-23:59:59.250").unwrap();
+        assert_eq!(
+            before_epoch,
+            UtcTimestamp::from_system_time(before_epoch.to_system_time()).unwrap()
+        );
+        assert_eq!(
+            UNIX_EPOCH - Duration::from_millis(750),
+            before_epoch.to_system_time()
+        );
+    }
+
+    #[test]
+    fn test_days_since_epoch() {
+        for (date, days) in [
+            ("19700101", 0),
+            ("20000229", 11_016),
+            ("19691231", -1),
+            ("00000101", -719_528),
+            ("99991231", 2_932_896),
+        ] {
+            let date = LocalMktDate::parse(date).unwrap();
+            assert_eq!(days, date.days_since_epoch());
+            assert_eq!(Some(date), LocalMktDate::from_days_since_epoch(days));
+        }
+
+        assert!(LocalMktDate::from_days_since_epoch(2_932_897).is_none());
+    }
+
+    #[test]
+    fn test_parse_utc_time_only() {
+        let value = UtcTimeOnly::parse("18:24:59.643001").unwrap();
+
+        assert_eq!(18, value.get_hour());
+        assert_eq!(643_001_000, value.get_nanos());
+        assert_eq!(TimestampPrecision::Micros, value.get_precision());
+        assert_eq!("18:24:59.643001", value.to_string());
+
+        assert!(UtcTimeOnly::parse("18:24").is_none());
+        assert!(UtcTimeOnly::parse("24:00:00").is_none());
+        assert!(UtcTimeOnly::parse("18:24:59.").is_none());
+    }
+}
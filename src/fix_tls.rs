@@ -0,0 +1,298 @@
+use std::{io, sync::Arc};
+
+use tokio::net::TcpStream;
+use tokio_rustls::{
+    client,
+    rustls::{
+        crypto::CryptoProvider,
+        pki_types::{CertificateDer, PrivateKeyDer, ServerName},
+        server::WebPkiClientVerifier,
+        RootCertStore,
+    },
+    server, TlsAcceptor, TlsConnector,
+};
+
+/// Version of rustls the configs are built with, for [`TlsClientConfig::from_rustls`]
+/// and [`TlsServerConfig::from_rustls`]
+pub use tokio_rustls::rustls;
+
+/// Certificates of a PEM bundle
+pub fn load_certs(pem: &[u8]) -> io::Result<Vec<CertificateDer<'static>>> {
+    let certs = rustls_pemfile::certs(&mut &*pem).collect::<Result<Vec<_>, _>>()?;
+
+    if certs.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "No certificates in PEM",
+        ));
+    }
+
+    Ok(certs)
+}
+
+/// First PKCS#8, PKCS#1 or SEC1 private key of a PEM file
+pub fn load_private_key(pem: &[u8]) -> io::Result<PrivateKeyDer<'static>> {
+    rustls_pemfile::private_key(&mut &*pem)?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "No private key in PEM"))
+}
+
+/// TLS parameters of [`crate::FixInitiator`]
+#[derive(Debug, Clone)]
+pub struct TlsClientConfig {
+    config: Arc<rustls::ClientConfig>,
+    server_name: Option<String>,
+}
+
+impl TlsClientConfig {
+    /// Trusts the servers signed by the CA bundle
+    pub fn new(ca_pem: &[u8]) -> io::Result<Self> {
+        let config = client_builder(ca_pem)?.with_no_client_auth();
+        Ok(Self::from_rustls(Arc::new(config)))
+    }
+
+    /// Trusts the servers signed by the CA bundle and presents the client certificate
+    pub fn with_client_auth(ca_pem: &[u8], cert_pem: &[u8], key_pem: &[u8]) -> io::Result<Self> {
+        let config = client_builder(ca_pem)?
+            .with_client_auth_cert(load_certs(cert_pem)?, load_private_key(key_pem)?)
+            .map_err(tls_error)?;
+
+        Ok(Self::from_rustls(Arc::new(config)))
+    }
+
+    pub fn from_rustls(config: Arc<rustls::ClientConfig>) -> Self {
+        Self {
+            config,
+            server_name: None,
+        }
+    }
+
+    /// Name sent in SNI and checked against the server certificate.
+    /// By default it is the host the initiator connects to.
+    pub fn with_server_name(mut self, server_name: impl Into<String>) -> Self {
+        self.server_name = Some(server_name.into());
+        self
+    }
+
+    pub fn get_server_name(&self) -> Option<&str> {
+        self.server_name.as_deref()
+    }
+
+    pub(crate) async fn connect(
+        &self,
+        host: &str,
+        stream: TcpStream,
+    ) -> io::Result<client::TlsStream<TcpStream>> {
+        let server_name = self.server_name.as_deref().unwrap_or(host).to_string();
+        let server_name = ServerName::try_from(server_name)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+
+        TlsConnector::from(self.config.clone())
+            .connect(server_name, stream)
+            .await
+    }
+}
+
+/// TLS parameters of [`crate::FixAcceptor`]
+#[derive(Debug, Clone)]
+pub struct TlsServerConfig {
+    config: Arc<rustls::ServerConfig>,
+}
+
+impl TlsServerConfig {
+    pub fn new(cert_pem: &[u8], key_pem: &[u8]) -> io::Result<Self> {
+        let config = server_builder()?
+            .with_no_client_auth()
+            .with_single_cert(load_certs(cert_pem)?, load_private_key(key_pem)?)
+            .map_err(tls_error)?;
+
+        Ok(Self::from_rustls(Arc::new(config)))
+    }
+
+    /// Accepts only the clients which present a certificate signed by the CA bundle
+    pub fn with_client_auth(
+        cert_pem: &[u8],
+        key_pem: &[u8],
+        client_ca_pem: &[u8],
+    ) -> io::Result<Self> {
+        let verifier = WebPkiClientVerifier::builder_with_provider(
+            Arc::new(root_store(client_ca_pem)?),
+            provider(),
+        )
+        .build()
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+
+        let config = server_builder()?
+            .with_client_cert_verifier(verifier)
+            .with_single_cert(load_certs(cert_pem)?, load_private_key(key_pem)?)
+            .map_err(tls_error)?;
+
+        Ok(Self::from_rustls(Arc::new(config)))
+    }
+
+    pub fn from_rustls(config: Arc<rustls::ServerConfig>) -> Self {
+        Self { config }
+    }
+
+    pub(crate) async fn accept(
+        &self,
+        stream: TcpStream,
+    ) -> io::Result<server::TlsStream<TcpStream>> {
+        TlsAcceptor::from(self.config.clone()).accept(stream).await
+    }
+}
+
+// Explicit provider, so the application may enable other rustls providers
+fn provider() -> Arc<CryptoProvider> {
+    Arc::new(rustls::crypto::ring::default_provider())
+}
+
+fn client_builder(
+    ca_pem: &[u8],
+) -> io::Result<rustls::ConfigBuilder<rustls::ClientConfig, rustls::client::WantsClientCert>> {
+    Ok(rustls::ClientConfig::builder_with_provider(provider())
+        .with_safe_default_protocol_versions()
+        .map_err(tls_error)?
+        .with_root_certificates(root_store(ca_pem)?))
+}
+
+fn server_builder() -> io::Result<rustls::ConfigBuilder<rustls::ServerConfig, rustls::WantsVerifier>>
+{
+    rustls::ServerConfig::builder_with_provider(provider())
+        .with_safe_default_protocol_versions()
+        .map_err(tls_error)
+}
+
+fn root_store(ca_pem: &[u8]) -> io::Result<RootCertStore> {
+    let mut roots = RootCertStore::empty();
+
+    for cert in load_certs(ca_pem)? {
+        roots.add(cert).map_err(tls_error)?;
+    }
+
+    Ok(roots)
+}
+
+fn tls_error(err: rustls::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        tags, DisconnectReason, FixAcceptor, FixAcceptorHandle, FixInitiator, FixMessageWriter,
+        FixReceiver, FixSender, FixSession, FixSessionConfig, FixSessionEvent, SessionRole,
+        TlsClientConfig, TlsServerConfig,
+    };
+
+    const CA: &[u8] = include_bytes!("../test_data/tls/ca.pem");
+    const SERVER_CERT: &[u8] = include_bytes!("../test_data/tls/server.pem");
+    const SERVER_KEY: &[u8] = include_bytes!("../test_data/tls/server.key");
+    const CLIENT_CERT: &[u8] = include_bytes!("../test_data/tls/client.pem");
+    const CLIENT_KEY: &[u8] = include_bytes!("../test_data/tls/client.key");
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    async fn start_acceptor(tls: TlsServerConfig) -> FixAcceptorHandle {
+        let config = FixSessionConfig::new(SessionRole::Acceptor, "FIX.4.4", "VENUE", "CLIENT");
+
+        FixAcceptor::new()
+            .with_session(FixSession::new(config))
+            .with_tls(tls)
+            .bind("127.0.0.1:0")
+            .await
+            .unwrap()
+    }
+
+    fn start_initiator(
+        acceptor: &FixAcceptorHandle,
+        host: &str,
+        tls: TlsClientConfig,
+    ) -> (FixSender, FixReceiver) {
+        let config = FixSessionConfig::new(SessionRole::Initiator, "FIX.4.4", "CLIENT", "VENUE");
+        let port = acceptor.get_local_addr().port();
+
+        FixInitiator::new(FixSession::new(config), host, port)
+            .with_tls(tls)
+            .start()
+    }
+
+    async fn recv(receiver: &mut FixReceiver) -> FixSessionEvent {
+        tokio::time::timeout(TIMEOUT, receiver.recv())
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_session_over_tls() {
+        let mut acceptor =
+            start_acceptor(TlsServerConfig::new(SERVER_CERT, SERVER_KEY).unwrap()).await;
+
+        // Certificate is issued for localhost and 127.0.0.1
+        let (sender, mut receiver) =
+            start_initiator(&acceptor, "127.0.0.1", TlsClientConfig::new(CA).unwrap());
+        assert_eq!(FixSessionEvent::LoggedOn, recv(&mut receiver).await);
+
+        let mut order = FixMessageWriter::new("FIX.4.4", "D");
+        order.with_value(tags::CL_ORD_ID, "order-1");
+        sender.send(order).await.unwrap();
+
+        let (_, event) = tokio::time::timeout(TIMEOUT, acceptor.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(FixSessionEvent::LoggedOn, event);
+
+        let (_, event) = tokio::time::timeout(TIMEOUT, acceptor.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(event, FixSessionEvent::Application(_)));
+    }
+
+    #[tokio::test]
+    async fn test_server_name_must_match_certificate() {
+        let acceptor = start_acceptor(TlsServerConfig::new(SERVER_CERT, SERVER_KEY).unwrap()).await;
+
+        let tls = TlsClientConfig::new(CA)
+            .unwrap()
+            .with_server_name("venue.example.com");
+        let (_sender, mut receiver) = start_initiator(&acceptor, "127.0.0.1", tls);
+
+        let FixSessionEvent::Disconnect(DisconnectReason::Transport(_)) = recv(&mut receiver).await
+        else {
+            panic!("Handshake failure expected");
+        };
+
+        let tls = TlsClientConfig::new(CA)
+            .unwrap()
+            .with_server_name("localhost");
+        let (_sender, mut receiver) = start_initiator(&acceptor, "127.0.0.1", tls);
+        assert_eq!(FixSessionEvent::LoggedOn, recv(&mut receiver).await);
+    }
+
+    #[tokio::test]
+    async fn test_client_certificate_is_required() {
+        let tls = TlsServerConfig::with_client_auth(SERVER_CERT, SERVER_KEY, CA).unwrap();
+        let acceptor = start_acceptor(tls).await;
+
+        let (_sender, mut receiver) =
+            start_initiator(&acceptor, "localhost", TlsClientConfig::new(CA).unwrap());
+        let FixSessionEvent::Disconnect(DisconnectReason::Transport(_)) = recv(&mut receiver).await
+        else {
+            panic!("Connection without client certificate must fail");
+        };
+
+        let tls = TlsClientConfig::with_client_auth(CA, CLIENT_CERT, CLIENT_KEY).unwrap();
+        let (_sender, mut receiver) = start_initiator(&acceptor, "localhost", tls);
+        assert_eq!(FixSessionEvent::LoggedOn, recv(&mut receiver).await);
+    }
+
+    #[test]
+    fn test_invalid_pem() {
+        assert!(TlsClientConfig::new(b"not a certificate").is_err());
+        assert!(TlsServerConfig::new(SERVER_CERT, b"not a key").is_err());
+    }
+}
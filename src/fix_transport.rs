@@ -0,0 +1,128 @@
+use crate::{
+    tags, FixFrameDecoder, FixMessageReader, FixSession, FixSessionId, MessageStore, SessionClock,
+};
+
+/// First message of an accepted connection
+pub(crate) enum FirstFrame {
+    /// More data has to be read
+    Incomplete,
+    /// Counterparty SenderCompID is our TargetCompID
+    Session(FixSessionId),
+    /// Garbled message or a message without the session id, the connection is closed
+    Invalid,
+}
+
+/// Passes the complete frames of the decoder to the session. Garbled frames and frames with
+/// a wrong BodyLength or CheckSum are skipped, the session asks to resend them as a gap.
+pub(crate) fn process_frames<C: SessionClock, S: MessageStore>(
+    session: &mut FixSession<C, S>,
+    decoder: &mut FixFrameDecoder,
+) {
+    loop {
+        match decoder.next_frame() {
+            Ok(Some(frame)) => {
+                if let Ok(reader) = FixMessageReader::from_bytes(frame).check_payload() {
+                    session.on_message(&reader);
+                }
+            }
+            Ok(None) => break,
+            Err(_) => continue,
+        }
+    }
+}
+
+/// Finds the session the connection belongs to by the first message. The decoder is only
+/// used to find the frame, the received bytes are passed to the session as they are.
+pub(crate) fn read_first_frame(decoder: &mut FixFrameDecoder) -> FirstFrame {
+    let frame = match decoder.next_frame() {
+        Ok(Some(frame)) => frame,
+        Ok(None) => return FirstFrame::Incomplete,
+        Err(_) => return FirstFrame::Invalid,
+    };
+
+    let Ok(reader) = FixMessageReader::from_bytes(frame).check_payload() else {
+        return FirstFrame::Invalid;
+    };
+
+    let value = |tag| reader.get_value(tag).ok().flatten();
+
+    match (
+        value(tags::BEGIN_STRING),
+        value(tags::SENDER_COMP_ID),
+        value(tags::TARGET_COMP_ID),
+    ) {
+        (Some(begin_string), Some(sender_comp_id), Some(target_comp_id)) => FirstFrame::Session(
+            FixSessionId::new(begin_string, target_comp_id, sender_comp_id),
+        ),
+        _ => FirstFrame::Invalid,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tags, FixFrameDecoder, FixMessageWriter, FixSession, FixSessionConfig, FixSessionId,
+        SessionRole,
+    };
+
+    use super::{process_frames, read_first_frame, FirstFrame};
+
+    fn logon() -> Vec<u8> {
+        let mut writer = FixMessageWriter::new("FIX.4.4", "A");
+        writer.with_value(tags::SENDER_COMP_ID, "CLIENT");
+        writer.with_value(tags::TARGET_COMP_ID, "VENUE");
+        writer.with_value(tags::MSG_SEQ_NUM, 1);
+        writer.with_value(tags::ENCRYPT_METHOD, 0);
+        writer.with_value(tags::HEART_BT_INT, 30);
+        writer.compile_message()
+    }
+
+    fn corrupt_check_sum(mut message: Vec<u8>) -> Vec<u8> {
+        let index = message.len() - 2;
+        message[index] = if message[index] == b'0' { b'1' } else { b'0' };
+        message
+    }
+
+    #[test]
+    fn test_frame_with_wrong_check_sum_is_skipped() {
+        let config = FixSessionConfig::new(SessionRole::Acceptor, "FIX.4.4", "VENUE", "CLIENT");
+        let mut session = FixSession::new(config);
+        session.on_connected();
+
+        let mut decoder = FixFrameDecoder::new();
+        decoder.append(&corrupt_check_sum(logon()));
+        process_frames(&mut session, &mut decoder);
+        assert_eq!(1, session.get_next_target_seq_num());
+        assert!(!session.is_logged_on());
+
+        decoder.append(&logon());
+        process_frames(&mut session, &mut decoder);
+        assert_eq!(2, session.get_next_target_seq_num());
+        assert!(session.is_logged_on());
+    }
+
+    #[test]
+    fn test_read_first_frame() {
+        let logon = logon();
+        let mut decoder = FixFrameDecoder::new();
+
+        decoder.append(&logon[..10]);
+        assert!(matches!(
+            read_first_frame(&mut decoder),
+            FirstFrame::Incomplete
+        ));
+
+        decoder.append(&logon[10..]);
+        let FirstFrame::Session(session_id) = read_first_frame(&mut decoder) else {
+            panic!("Session id expected");
+        };
+        assert_eq!(FixSessionId::new("FIX.4.4", "VENUE", "CLIENT"), session_id);
+
+        let mut decoder = FixFrameDecoder::new();
+        decoder.append(&corrupt_check_sum(logon));
+        assert!(matches!(
+            read_first_frame(&mut decoder),
+            FirstFrame::Invalid
+        ));
+    }
+}
@@ -0,0 +1,602 @@
+use std::collections::HashSet;
+
+use crate::{
+    fix_data_type::parse_seq_num, fix_group_reader::read_group, fixt11_messages::ApplVerID, tags,
+    DataDictionary, FieldDefinition, FixDataType, FixGroupEntry, FixMessageItem, FixMessageReader,
+    FixSerializeError, FixtDictionaries, GroupLayout, LayoutItem, MessageLayout,
+    SessionRejectReason, Tag,
+};
+
+// Tags starting from this number are reserved for user defined fields
+const USER_DEFINED_TAGS_START: u32 = 5000;
+
+/// Reason why a message does not match the data dictionary, with the data required
+/// to send a Reject (3) message back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixValidationError {
+    reason: SessionRejectReason,
+    ref_tag_id: Option<Tag>,
+    ref_msg_type: Option<String>,
+    ref_seq_num: Option<u64>,
+}
+
+impl FixValidationError {
+    pub fn get_reason(&self) -> SessionRejectReason {
+        self.reason
+    }
+
+    /// Tag the problem is found at: RefTagID (371)
+    pub fn get_ref_tag_id(&self) -> Option<Tag> {
+        self.ref_tag_id
+    }
+
+    /// MsgType of the rejected message: RefMsgType (372)
+    pub fn get_ref_msg_type(&self) -> Option<&str> {
+        self.ref_msg_type.as_deref()
+    }
+
+    /// MsgSeqNum of the rejected message: RefSeqNum (45)
+    pub fn get_ref_seq_num(&self) -> Option<u64> {
+        self.ref_seq_num
+    }
+
+    pub fn get_text(&self) -> &'static str {
+        self.reason.get_text()
+    }
+}
+
+type Reject = (SessionRejectReason, Option<Tag>);
+
+// Header and trailer are defined by the transport dictionary and the body by the
+// application one. Both are the same dictionary before FIXT.1.1.
+#[derive(Clone, Copy)]
+struct Dictionaries<'d> {
+    transport: &'d DataDictionary,
+    application: &'d DataDictionary,
+}
+
+impl<'d> Dictionaries<'d> {
+    fn get_field(&self, tag: Tag) -> Option<&'d FieldDefinition> {
+        self.application
+            .get_field(tag)
+            .or_else(|| self.transport.get_field(tag))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Section {
+    Header,
+    Body,
+    Trailer,
+}
+
+/// Checks the message against the data dictionary: the tags must be defined for the message
+/// type, appear once and in the header / body / trailer order, the values must match the types
+/// and the enums of the fields, the required fields and the entries of repeating groups must
+/// be present.
+///
+/// Only the first problem is reported.
+pub fn validate(
+    reader: &FixMessageReader,
+    dictionary: &DataDictionary,
+) -> Result<(), FixValidationError> {
+    validate_message(reader, |_| {
+        Ok(Dictionaries {
+            transport: dictionary,
+            application: dictionary,
+        })
+    })
+}
+
+/// Checks the message of a FIXT.1.1 session like [`validate`]. Session messages are checked
+/// against the transport dictionary. The body of the other messages is checked against the
+/// application dictionary of their ApplVerID (1128) or, without it, of the DefaultApplVerID
+/// (1137) negotiated at Logon.
+pub fn validate_fixt(
+    reader: &FixMessageReader,
+    dictionaries: &FixtDictionaries,
+    default_appl_ver_id: Option<&ApplVerID>,
+) -> Result<(), FixValidationError> {
+    validate_message(reader, |items| {
+        let transport = dictionaries.get_transport();
+
+        let is_session_message = find_value(items, "35")
+            .is_some_and(|msg_type| transport.get_message(msg_type).is_some());
+
+        if is_session_message {
+            return Ok(Dictionaries {
+                transport,
+                application: transport,
+            });
+        }
+
+        let appl_ver_id = match find_value(items, "1128") {
+            Some(value) => ApplVerID::try_from(value).ok(),
+            None => Some(default_appl_ver_id.cloned().ok_or((
+                SessionRejectReason::RequiredTagMissing,
+                Some(tags::APPL_VER_ID),
+            ))?),
+        };
+
+        let application = appl_ver_id
+            .and_then(|appl_ver_id| dictionaries.get_application(&appl_ver_id))
+            .ok_or((
+                SessionRejectReason::InvalidApplVersion,
+                Some(tags::APPL_VER_ID),
+            ))?;
+
+        Ok(Dictionaries {
+            transport,
+            application,
+        })
+    })
+}
+
+fn validate_message<'d>(
+    reader: &FixMessageReader,
+    get_dictionaries: impl FnOnce(&[FixMessageItem]) -> Result<Dictionaries<'d>, Reject>,
+) -> Result<(), FixValidationError> {
+    let items = match reader.iter().collect::<Result<Vec<_>, _>>() {
+        Ok(items) => items,
+        Err(err) => {
+            return Err(FixValidationError {
+                reason: get_reject_reason(&err).0,
+                ref_tag_id: None,
+                ref_msg_type: None,
+                ref_seq_num: None,
+            });
+        }
+    };
+
+    let ref_msg_type = find_value(&items, "35");
+
+    let result = get_dictionaries(&items)
+        .and_then(|dictionaries| validate_items(&items, ref_msg_type, dictionaries));
+
+    match result {
+        Ok(()) => Ok(()),
+        Err((reason, ref_tag_id)) => Err(FixValidationError {
+            reason,
+            ref_tag_id,
+            ref_msg_type: ref_msg_type.map(|value| value.to_string()),
+            ref_seq_num: find_value(&items, "34").and_then(parse_seq_num),
+        }),
+    }
+}
+
+fn validate_items(
+    items: &[FixMessageItem],
+    msg_type: Option<&str>,
+    dictionaries: Dictionaries,
+) -> Result<(), Reject> {
+    let Some(msg_type) = msg_type else {
+        return Err((
+            SessionRejectReason::RequiredTagMissing,
+            Some(crate::tags::MSG_TYPE),
+        ));
+    };
+
+    let Some(message) = dictionaries.application.get_message(msg_type) else {
+        return Err((
+            SessionRejectReason::InvalidMsgType,
+            Some(crate::tags::MSG_TYPE),
+        ));
+    };
+
+    let sections = [
+        (Section::Header, dictionaries.transport.get_header()),
+        (Section::Body, message.get_layout()),
+        (Section::Trailer, dictionaries.transport.get_trailer()),
+    ];
+
+    let mut current_section = Section::Header;
+    let mut found_tags = HashSet::new();
+    let mut index = 0;
+
+    while index < items.len() {
+        let itm = &items[index];
+        let (tag, field) = get_field_definition(itm, dictionaries)?;
+
+        let Some((section, layout)) = sections.iter().find(|(_, layout)| layout.contains_tag(tag))
+        else {
+            return Err((SessionRejectReason::TagNotDefinedForMessageType, Some(tag)));
+        };
+
+        if *section < current_section {
+            return Err((
+                SessionRejectReason::TagSpecifiedOutOfRequiredOrder,
+                Some(tag),
+            ));
+        }
+
+        current_section = *section;
+
+        if !found_tags.insert(tag) {
+            return Err((SessionRejectReason::TagAppearsMoreThanOnce, Some(tag)));
+        }
+
+        check_value(field, itm)?;
+
+        if let Some(LayoutItem::Group(group)) = layout.get_item(tag) {
+            let (entries, next_index) = read_group(items, index, &group.to_fix_group_definition())
+                .map_err(|err| get_reject_reason(&err))?;
+
+            validate_group_entries(&entries, group, dictionaries)?;
+            index = next_index;
+        } else {
+            index += 1;
+        }
+    }
+
+    for (_, layout) in &sections {
+        check_required_tags(layout, &found_tags)?;
+    }
+
+    Ok(())
+}
+
+fn validate_group_entries(
+    entries: &[FixGroupEntry],
+    group: &GroupLayout,
+    dictionaries: Dictionaries,
+) -> Result<(), Reject> {
+    for entry in entries {
+        let mut found_tags = HashSet::new();
+
+        for itm in &entry.items {
+            let (tag, field) = get_field_definition(itm, dictionaries)?;
+
+            if !found_tags.insert(tag) {
+                return Err((SessionRejectReason::TagAppearsMoreThanOnce, Some(tag)));
+            }
+
+            check_value(field, itm)?;
+        }
+
+        for (count_tag, nested_entries) in &entry.groups {
+            if !found_tags.insert(*count_tag) {
+                return Err((
+                    SessionRejectReason::TagAppearsMoreThanOnce,
+                    Some(*count_tag),
+                ));
+            }
+
+            if let Some(LayoutItem::Group(nested_group)) = group.get_layout().get_item(*count_tag) {
+                validate_group_entries(nested_entries, nested_group, dictionaries)?;
+            }
+        }
+
+        check_required_tags(group.get_layout(), &found_tags)?;
+    }
+
+    Ok(())
+}
+
+fn get_field_definition<'d>(
+    itm: &FixMessageItem,
+    dictionaries: Dictionaries<'d>,
+) -> Result<(Tag, &'d FieldDefinition), Reject> {
+    let tag = itm
+        .get_tag()
+        .map_err(|_| (SessionRejectReason::InvalidTagNumber, None))?;
+
+    if itm.data.is_empty() {
+        return Err((SessionRejectReason::TagSpecifiedWithoutValue, Some(tag)));
+    }
+
+    match dictionaries.get_field(tag) {
+        Some(field) => Ok((tag, field)),
+        None if tag.get_value() >= USER_DEFINED_TAGS_START => {
+            Err((SessionRejectReason::UndefinedTag, Some(tag)))
+        }
+        None => Err((SessionRejectReason::InvalidTagNumber, Some(tag))),
+    }
+}
+
+fn check_value(field: &FieldDefinition, itm: &FixMessageItem) -> Result<(), Reject> {
+    // Only data fields may be binary, their values are not checked
+    if itm.is_binary() && field.get_data_type() == FixDataType::Data {
+        return Ok(());
+    }
+
+    let value = itm.value;
+
+    if itm.is_binary() || !field.get_data_type().is_valid(value) {
+        return Err((
+            SessionRejectReason::IncorrectDataFormat,
+            Some(field.get_tag()),
+        ));
+    }
+
+    if !field.has_values() {
+        return Ok(());
+    }
+
+    let is_allowed = |value: &str| field.get_value_description(value).is_some();
+
+    let valid = match field.get_data_type() {
+        FixDataType::MultipleValueString | FixDataType::MultipleCharValue => {
+            value.split(' ').all(is_allowed)
+        }
+        _ => is_allowed(value),
+    };
+
+    if !valid {
+        return Err((SessionRejectReason::ValueIsIncorrect, Some(field.get_tag())));
+    }
+
+    Ok(())
+}
+
+fn check_required_tags(layout: &MessageLayout, found_tags: &HashSet<Tag>) -> Result<(), Reject> {
+    match layout
+        .get_required_tags()
+        .find(|tag| !found_tags.contains(tag))
+    {
+        Some(tag) => Err((SessionRejectReason::RequiredTagMissing, Some(tag))),
+        None => Ok(()),
+    }
+}
+
+fn get_reject_reason(err: &FixSerializeError) -> Reject {
+    match err {
+        FixSerializeError::GroupCountMismatch { tag, .. } => {
+            (SessionRejectReason::IncorrectNumInGroupCount, Some(*tag))
+        }
+        FixSerializeError::GroupFieldOutOfOrder { tag } => (
+            SessionRejectReason::RepeatingGroupFieldsOutOfOrder,
+            Some(*tag),
+        ),
+        FixSerializeError::GroupFieldRepeated { tag } => {
+            (SessionRejectReason::TagAppearsMoreThanOnce, Some(*tag))
+        }
+        FixSerializeError::InvalidFieldValue { tag, .. } => {
+            (SessionRejectReason::IncorrectDataFormat, Some(*tag))
+        }
+        FixSerializeError::DataLengthMismatch { tag, .. } => {
+            (SessionRejectReason::IncorrectDataFormat, Some(*tag))
+        }
+        FixSerializeError::TagOutOfOrder { tag, .. } => (
+            SessionRejectReason::TagSpecifiedOutOfRequiredOrder,
+            Some(*tag),
+        ),
+        _ => (SessionRejectReason::InvalidTagNumber, None),
+    }
+}
+
+fn find_value<'s>(items: &[FixMessageItem<'s>], key: &str) -> Option<&'s str> {
+    items.iter().find(|itm| itm.key == key).map(|itm| itm.value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fixt11_messages::ApplVerID, tags, validate, validate_fixt, DataDictionary,
+        FixMessageReader, FixtDictionaries, SessionRejectReason, Tag,
+    };
+
+    const DICTIONARY: &str = r#"
+    <fix major="4" minor="4">
+        <header>
+            <field name="BeginString" required="Y"/>
+            <field name="BodyLength" required="Y"/>
+            <field name="MsgType" required="Y"/>
+            <field name="MsgSeqNum" required="Y"/>
+        </header>
+        <trailer>
+            <field name="CheckSum" required="Y"/>
+        </trailer>
+        <messages>
+            <message name="NewOrderSingle" msgtype="D" msgcat="app">
+                <field name="ClOrdID" required="Y"/>
+                <group name="NoPartyIDs" required="N">
+                    <field name="PartyID" required="Y"/>
+                    <field name="PartyRole" required="N"/>
+                </group>
+                <field name="Side" required="Y"/>
+                <field name="OrderQty" required="N"/>
+                <field name="ExecInst" required="N"/>
+            </message>
+        </messages>
+        <fields>
+            <field number="8" name="BeginString" type="STRING"/>
+            <field number="9" name="BodyLength" type="LENGTH"/>
+            <field number="10" name="CheckSum" type="STRING"/>
+            <field number="11" name="ClOrdID" type="STRING"/>
+            <field number="18" name="ExecInst" type="MULTIPLEVALUESTRING">
+                <value enum="1" description="NOT_HELD"/>
+                <value enum="G" description="ALL_OR_NONE"/>
+            </field>
+            <field number="34" name="MsgSeqNum" type="SEQNUM"/>
+            <field number="35" name="MsgType" type="STRING"/>
+            <field number="38" name="OrderQty" type="QTY"/>
+            <field number="44" name="Price" type="PRICE"/>
+            <field number="54" name="Side" type="CHAR">
+                <value enum="1" description="BUY"/>
+                <value enum="2" description="SELL"/>
+            </field>
+            <field number="448" name="PartyID" type="STRING"/>
+            <field number="452" name="PartyRole" type="INT"/>
+            <field number="453" name="NoPartyIDs" type="NUMINGROUP"/>
+        </fields>
+    </fix>"#;
+
+    fn check(fix_string: &str) -> Option<(SessionRejectReason, Option<Tag>)> {
+        let dictionary = DataDictionary::parse(DICTIONARY).unwrap();
+
+        match validate(&FixMessageReader::from_str(fix_string), &dictionary) {
+            Ok(()) => None,
+            Err(err) => Some((err.get_reason(), err.get_ref_tag_id())),
+        }
+    }
+
+    #[test]
+    fn test_valid_message() {
+        assert_eq!(
+            None,
+            check(
+                "8=FIX.4.4|9=10|35=D|34=2|11=A|453=2|448=B|452=1|448=C|54=1|38=10|18=G 1|10=000|"
+            )
+        );
+    }
+
+    #[test]
+    fn test_reject_reasons() {
+        for (fix_string, reason, tag) in [
+            (
+                "8=FIX.4.4|9=10|35=D|34=2|11=A|54=1|999=1|10=000|",
+                SessionRejectReason::InvalidTagNumber,
+                Some(Tag(999)),
+            ),
+            (
+                "8=FIX.4.4|9=10|35=D|34=2|11=A|54=1|5001=1|10=000|",
+                SessionRejectReason::UndefinedTag,
+                Some(Tag(5001)),
+            ),
+            (
+                "8=FIX.4.4|9=10|35=D|34=2|11=A|54=1|44=1.5|10=000|",
+                SessionRejectReason::TagNotDefinedForMessageType,
+                Some(tags::PRICE),
+            ),
+            (
+                "8=FIX.4.4|9=10|35=D|34=2|54=1|10=000|",
+                SessionRejectReason::RequiredTagMissing,
+                Some(tags::CL_ORD_ID),
+            ),
+            (
+                "8=FIX.4.4|9=10|35=D|34=2|11=A|54=1|38=1O|10=000|",
+                SessionRejectReason::IncorrectDataFormat,
+                Some(tags::ORDER_QTY),
+            ),
+            (
+                "8=FIX.4.4|9=10|35=D|34=2|11=A|54=3|10=000|",
+                SessionRejectReason::ValueIsIncorrect,
+                Some(tags::SIDE),
+            ),
+            (
+                "8=FIX.4.4|9=10|35=D|34=2|11=A|54=1|18=G X|10=000|",
+                SessionRejectReason::ValueIsIncorrect,
+                Some(tags::EXEC_INST),
+            ),
+            (
+                "8=FIX.4.4|9=10|35=D|34=2|11=A|11=B|54=1|10=000|",
+                SessionRejectReason::TagAppearsMoreThanOnce,
+                Some(tags::CL_ORD_ID),
+            ),
+            (
+                "8=FIX.4.4|9=10|35=D|34=2|11=A|453=3|448=B|448=C|54=1|10=000|",
+                SessionRejectReason::IncorrectNumInGroupCount,
+                Some(tags::NO_PARTY_IDS),
+            ),
+            (
+                "8=FIX.4.4|9=10|35=D|34=2|11=A|453=1|452=1|448=B|54=1|10=000|",
+                SessionRejectReason::RepeatingGroupFieldsOutOfOrder,
+                Some(tags::PARTY_ROLE),
+            ),
+            (
+                "8=FIX.4.4|9=10|35=D|34=2|11=A|453=1|448=B|452=1|452=2|54=1|10=000|",
+                SessionRejectReason::TagAppearsMoreThanOnce,
+                Some(tags::PARTY_ROLE),
+            ),
+            (
+                "8=FIX.4.4|9=10|35=D|11=A|34=2|54=1|10=000|",
+                SessionRejectReason::TagSpecifiedOutOfRequiredOrder,
+                Some(tags::MSG_SEQ_NUM),
+            ),
+            (
+                "8=FIX.4.4|9=10|35=D|34=2|11=|54=1|10=000|",
+                SessionRejectReason::TagSpecifiedWithoutValue,
+                Some(tags::CL_ORD_ID),
+            ),
+            (
+                "8=FIX.4.4|9=10|35=Z|34=2|10=000|",
+                SessionRejectReason::InvalidMsgType,
+                Some(tags::MSG_TYPE),
+            ),
+        ] {
+            assert_eq!(Some((reason, tag)), check(fix_string), "{}", fix_string);
+        }
+    }
+
+    #[test]
+    fn test_reject_reference() {
+        let dictionary = DataDictionary::parse(DICTIONARY).unwrap();
+        let reader = FixMessageReader::from_str("8=FIX.4.4|9=10|35=D|34=7|11=A|54=4|10=000|");
+
+        let err = validate(&reader, &dictionary).unwrap_err();
+
+        assert_eq!(5, err.get_reason().get_code());
+        assert_eq!(Some("D"), err.get_ref_msg_type());
+        assert_eq!(Some(7), err.get_ref_seq_num());
+        assert_eq!(
+            "Value is incorrect (out of range) for this tag",
+            err.get_text()
+        );
+    }
+
+    // Application dictionary of FIX 5.0 SP2 without header and trailer, as QuickFIX ships it
+    const APPLICATION: &str = r#"
+    <fix type="FIX" major="5" minor="0" servicepack="2">
+        <messages>
+            <message name="NewOrderSingle" msgtype="D" msgcat="app">
+                <field name="ClOrdID" required="Y"/>
+                <field name="Side" required="Y"/>
+            </message>
+        </messages>
+        <fields>
+            <field number="11" name="ClOrdID" type="STRING"/>
+            <field number="54" name="Side" type="CHAR">
+                <value enum="1" description="BUY"/>
+                <value enum="2" description="SELL"/>
+            </field>
+        </fields>
+    </fix>"#;
+
+    #[test]
+    fn test_fixt_dictionary_selection() {
+        let dictionaries = FixtDictionaries::new(DataDictionary::fixt11()).with_application(
+            ApplVerID::Fix50sp2,
+            DataDictionary::parse(APPLICATION).unwrap(),
+        );
+
+        let check = |fix_string: &str, default_appl_ver_id: Option<ApplVerID>| {
+            let reader = FixMessageReader::from_str(fix_string);
+
+            match validate_fixt(&reader, &dictionaries, default_appl_ver_id.as_ref()) {
+                Ok(()) => None,
+                Err(err) => Some((err.get_reason(), err.get_ref_tag_id())),
+            }
+        };
+
+        let order = "8=FIXT.1.1|9=10|35=D|49=A|56=B|34=2|52=20240101-10:00:00|11=A|54=1|10=000|";
+        assert_eq!(None, check(order, Some(ApplVerID::Fix50sp2)));
+        assert_eq!(
+            Some((
+                SessionRejectReason::RequiredTagMissing,
+                Some(tags::APPL_VER_ID)
+            )),
+            check(order, None)
+        );
+        assert_eq!(
+            Some((
+                SessionRejectReason::InvalidApplVersion,
+                Some(tags::APPL_VER_ID)
+            )),
+            check(order, Some(ApplVerID::Fix50))
+        );
+
+        // ApplVerID of the message overrides the default
+        let order = order.replace("35=D|", "35=D|1128=9|");
+        assert_eq!(None, check(&order, Some(ApplVerID::Fix50)));
+
+        let order = order.replace("54=1", "54=3");
+        assert_eq!(
+            Some((SessionRejectReason::ValueIsIncorrect, Some(tags::SIDE))),
+            check(&order, Some(ApplVerID::Fix50))
+        );
+
+        // Session messages do not depend on the application version
+        let heartbeat = "8=FIXT.1.1|9=10|35=0|49=A|56=B|34=3|52=20240101-10:00:00|10=000|";
+        assert_eq!(None, check(heartbeat, None));
+    }
+}
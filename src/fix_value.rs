@@ -0,0 +1,247 @@
+use std::io::Write;
+
+use crate::{
+    fix_data_type::{
+        parse_bool, parse_char, parse_int, parse_multiple_value_string, parse_seq_num,
+    },
+    FixDataType, FixDecimal, FixSerializeError, LocalMktDate, Tag, UtcTimeOnly, UtcTimestamp,
+};
+
+/// Value which can be written into a FIX field.
+pub trait FixValue {
+    fn write_fix_value(&self, out: &mut Vec<u8>);
+}
+
+/// Value which can be read from a FIX field.
+pub trait FromFixValue: Sized {
+    fn from_fix_value(value: &str) -> Option<Self>;
+}
+
+/// Code set of a field. Implemented by the enums generated from data dictionaries.
+pub trait FixEnum: FixValue + FromFixValue {
+    const TAG: Tag;
+    const DATA_TYPE: FixDataType;
+}
+
+/// Checks the value against the data type and converts it.
+/// Used by the code generated from data dictionaries.
+pub fn decode_fix_value<T: FromFixValue>(
+    tag: Tag,
+    value: Option<&str>,
+    data_type: FixDataType,
+) -> Result<Option<T>, FixSerializeError> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    if data_type.is_valid(value) {
+        if let Some(result) = T::from_fix_value(value) {
+            return Ok(Some(result));
+        }
+    }
+
+    Err(FixSerializeError::InvalidFieldValue {
+        tag,
+        value: value.to_string(),
+        data_type,
+    })
+}
+
+impl FixValue for str {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl FixValue for String {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl<T: FixValue + ?Sized> FixValue for &T {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        (*self).write_fix_value(out);
+    }
+}
+
+impl FixValue for FixDecimal {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        let _ = write!(out, "{}", self);
+    }
+}
+
+impl FixValue for UtcTimestamp {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        let _ = write!(out, "{}", self);
+    }
+}
+
+impl FixValue for UtcTimeOnly {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        let _ = write!(out, "{}", self);
+    }
+}
+
+impl FixValue for LocalMktDate {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        let _ = write!(out, "{}", self);
+    }
+}
+
+impl FixValue for char {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        let mut buffer = [0u8; 4];
+        out.extend_from_slice(self.encode_utf8(&mut buffer).as_bytes());
+    }
+}
+
+/// Boolean is written as `Y` or `N`
+impl FixValue for bool {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        out.push(if *self { b'Y' } else { b'N' });
+    }
+}
+
+/// Values of MultipleValueString and MultipleCharValue fields are separated by spaces
+impl<T: FixValue> FixValue for [T] {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        for (index, itm) in self.iter().enumerate() {
+            if index > 0 {
+                out.push(b' ');
+            }
+            itm.write_fix_value(out);
+        }
+    }
+}
+
+impl<T: FixValue> FixValue for Vec<T> {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        self.as_slice().write_fix_value(out);
+    }
+}
+
+macro_rules! impl_fix_value_for_int {
+    ($($tp:ty),*) => {
+        $(
+            impl FixValue for $tp {
+                fn write_fix_value(&self, out: &mut Vec<u8>) {
+                    let _ = write!(out, "{}", self);
+                }
+            }
+        )*
+    };
+}
+
+impl_fix_value_for_int!(i32, i64, u32, u64, usize);
+
+impl FromFixValue for String {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        Some(value.to_string())
+    }
+}
+
+impl FromFixValue for i64 {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        parse_int(value)
+    }
+}
+
+impl FromFixValue for u64 {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        parse_seq_num(value)
+    }
+}
+
+impl FromFixValue for char {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        parse_char(value)
+    }
+}
+
+impl FromFixValue for bool {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        parse_bool(value)
+    }
+}
+
+impl FromFixValue for FixDecimal {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        FixDecimal::parse(value)
+    }
+}
+
+impl FromFixValue for UtcTimestamp {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        UtcTimestamp::parse(value)
+    }
+}
+
+impl FromFixValue for UtcTimeOnly {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        UtcTimeOnly::parse(value)
+    }
+}
+
+impl FromFixValue for LocalMktDate {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        LocalMktDate::parse(value)
+    }
+}
+
+impl<T: FromFixValue> FromFixValue for Vec<T> {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        parse_multiple_value_string(value)?
+            .into_iter()
+            .map(T::from_fix_value)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode_fix_value, tags, FixDataType, FixDecimal, FixValue};
+
+    fn to_fix_value(value: impl FixValue) -> String {
+        let mut out = Vec::new();
+        value.write_fix_value(&mut out);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_write_values() {
+        assert_eq!("TEST", to_fix_value("TEST"));
+        assert_eq!("TEST", to_fix_value("TEST".to_string()));
+        assert_eq!("1.50", to_fix_value(FixDecimal::parse("1.50").unwrap()));
+        assert_eq!("-15", to_fix_value(-15i64));
+        assert_eq!("Y", to_fix_value(true));
+        assert_eq!("2", to_fix_value('2'));
+        assert_eq!("G 1", to_fix_value(vec!['G', '1']));
+    }
+
+    #[test]
+    fn test_decode_values() {
+        let value: Option<i64> =
+            decode_fix_value(tags::HEART_BT_INT, Some("30"), FixDataType::Int).unwrap();
+        assert_eq!(Some(30), value);
+
+        let value: Option<String> =
+            decode_fix_value(tags::TEXT, None, FixDataType::String).unwrap();
+        assert_eq!(None, value);
+
+        let result: Result<Option<FixDecimal>, _> =
+            decode_fix_value(tags::PRICE, Some("1,5"), FixDataType::Price);
+        assert!(result.unwrap_err().is_invalid_field_value());
+
+        let value: Option<Vec<char>> =
+            decode_fix_value(tags::EXEC_INST, Some("G 1"), FixDataType::MultipleCharValue).unwrap();
+        assert_eq!(Some(vec!['G', '1']), value);
+
+        let result: Result<Option<Vec<char>>, _> = decode_fix_value(
+            tags::EXEC_INST,
+            Some("G  1"),
+            FixDataType::MultipleCharValue,
+        );
+        assert!(result.unwrap_err().is_invalid_field_value());
+    }
+}
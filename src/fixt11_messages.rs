@@ -0,0 +1,1053 @@
+// Generated by rust-fix codegen from the FIXT.1.1 dictionary. Do not edit.
+
+/// EncryptMethod (98) values
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EncryptMethod {
+    /// `0`
+    NoneOther,
+    /// `1`
+    Pkcs,
+    /// `2`
+    Des,
+    /// `3`
+    PkcsDes,
+    /// `4`
+    PgpDes,
+    /// `5`
+    PgpDesMd5,
+    /// `6`
+    PemDesMd5,
+    /// Value which is not in the dictionary
+    Other(String),
+}
+
+impl EncryptMethod {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::NoneOther => "0",
+            Self::Pkcs => "1",
+            Self::Des => "2",
+            Self::PkcsDes => "3",
+            Self::PgpDes => "4",
+            Self::PgpDesMd5 => "5",
+            Self::PemDesMd5 => "6",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl TryFrom<&str> for EncryptMethod {
+    type Error = rust_fix::FixSerializeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if !rust_fix::FixDataType::Int.is_valid(value) {
+            return Err(rust_fix::FixSerializeError::InvalidFieldValue { tag: rust_fix::Tag(98), value: value.to_string(), data_type: rust_fix::FixDataType::Int });
+        }
+
+        Ok(match value {
+            "0" => Self::NoneOther,
+            "1" => Self::Pkcs,
+            "2" => Self::Des,
+            "3" => Self::PkcsDes,
+            "4" => Self::PgpDes,
+            "5" => Self::PgpDesMd5,
+            "6" => Self::PemDesMd5,
+            _ => Self::Other(value.to_string()),
+        })
+    }
+}
+
+impl rust_fix::FixValue for EncryptMethod {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.as_str().as_bytes());
+    }
+}
+
+impl rust_fix::FromFixValue for EncryptMethod {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        Self::try_from(value).ok()
+    }
+}
+
+impl rust_fix::FixEnum for EncryptMethod {
+    const TAG: rust_fix::Tag = rust_fix::Tag(98);
+    const DATA_TYPE: rust_fix::FixDataType = rust_fix::FixDataType::Int;
+}
+
+/// MessageEncoding (347) values
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MessageEncoding {
+    /// `ISO-2022-JP`
+    Iso2022Jp,
+    /// `EUC-JP`
+    EucJp,
+    /// `SHIFT_JIS`
+    ShiftJis,
+    /// `UTF-8`
+    Utf8,
+    /// Value which is not in the dictionary
+    Other(String),
+}
+
+impl MessageEncoding {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Iso2022Jp => "ISO-2022-JP",
+            Self::EucJp => "EUC-JP",
+            Self::ShiftJis => "SHIFT_JIS",
+            Self::Utf8 => "UTF-8",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl TryFrom<&str> for MessageEncoding {
+    type Error = rust_fix::FixSerializeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if !rust_fix::FixDataType::String.is_valid(value) {
+            return Err(rust_fix::FixSerializeError::InvalidFieldValue { tag: rust_fix::Tag(347), value: value.to_string(), data_type: rust_fix::FixDataType::String });
+        }
+
+        Ok(match value {
+            "ISO-2022-JP" => Self::Iso2022Jp,
+            "EUC-JP" => Self::EucJp,
+            "SHIFT_JIS" => Self::ShiftJis,
+            "UTF-8" => Self::Utf8,
+            _ => Self::Other(value.to_string()),
+        })
+    }
+}
+
+impl rust_fix::FixValue for MessageEncoding {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.as_str().as_bytes());
+    }
+}
+
+impl rust_fix::FromFixValue for MessageEncoding {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        Self::try_from(value).ok()
+    }
+}
+
+impl rust_fix::FixEnum for MessageEncoding {
+    const TAG: rust_fix::Tag = rust_fix::Tag(347);
+    const DATA_TYPE: rust_fix::FixDataType = rust_fix::FixDataType::String;
+}
+
+/// SessionRejectReason (373) values
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SessionRejectReason {
+    /// `0`
+    InvalidTagNumber,
+    /// `1`
+    RequiredTagMissing,
+    /// `2`
+    TagNotDefinedForThisMessageType,
+    /// `3`
+    UndefinedTag,
+    /// `4`
+    TagSpecifiedWithoutAValue,
+    /// `5`
+    ValueIsIncorrect,
+    /// `6`
+    IncorrectDataFormatForValue,
+    /// `7`
+    DecryptionProblem,
+    /// `8`
+    SignatureProblem,
+    /// `9`
+    CompidProblem,
+    /// `10`
+    SendingtimeAccuracyProblem,
+    /// `11`
+    InvalidMsgtype,
+    /// `12`
+    XmlValidationError,
+    /// `13`
+    TagAppearsMoreThanOnce,
+    /// `14`
+    TagSpecifiedOutOfRequiredOrder,
+    /// `15`
+    RepeatingGroupFieldsOutOfOrder,
+    /// `16`
+    IncorrectNumingroupCountForRepeatingGroup,
+    /// `17`
+    NonDataValueIncludesFieldDelimiter,
+    /// `18`
+    InvalidUnsupportedApplicationVersion,
+    /// `99`
+    OtherValue99,
+    /// Value which is not in the dictionary
+    Other(String),
+}
+
+impl SessionRejectReason {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::InvalidTagNumber => "0",
+            Self::RequiredTagMissing => "1",
+            Self::TagNotDefinedForThisMessageType => "2",
+            Self::UndefinedTag => "3",
+            Self::TagSpecifiedWithoutAValue => "4",
+            Self::ValueIsIncorrect => "5",
+            Self::IncorrectDataFormatForValue => "6",
+            Self::DecryptionProblem => "7",
+            Self::SignatureProblem => "8",
+            Self::CompidProblem => "9",
+            Self::SendingtimeAccuracyProblem => "10",
+            Self::InvalidMsgtype => "11",
+            Self::XmlValidationError => "12",
+            Self::TagAppearsMoreThanOnce => "13",
+            Self::TagSpecifiedOutOfRequiredOrder => "14",
+            Self::RepeatingGroupFieldsOutOfOrder => "15",
+            Self::IncorrectNumingroupCountForRepeatingGroup => "16",
+            Self::NonDataValueIncludesFieldDelimiter => "17",
+            Self::InvalidUnsupportedApplicationVersion => "18",
+            Self::OtherValue99 => "99",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl TryFrom<&str> for SessionRejectReason {
+    type Error = rust_fix::FixSerializeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if !rust_fix::FixDataType::Int.is_valid(value) {
+            return Err(rust_fix::FixSerializeError::InvalidFieldValue { tag: rust_fix::Tag(373), value: value.to_string(), data_type: rust_fix::FixDataType::Int });
+        }
+
+        Ok(match value {
+            "0" => Self::InvalidTagNumber,
+            "1" => Self::RequiredTagMissing,
+            "2" => Self::TagNotDefinedForThisMessageType,
+            "3" => Self::UndefinedTag,
+            "4" => Self::TagSpecifiedWithoutAValue,
+            "5" => Self::ValueIsIncorrect,
+            "6" => Self::IncorrectDataFormatForValue,
+            "7" => Self::DecryptionProblem,
+            "8" => Self::SignatureProblem,
+            "9" => Self::CompidProblem,
+            "10" => Self::SendingtimeAccuracyProblem,
+            "11" => Self::InvalidMsgtype,
+            "12" => Self::XmlValidationError,
+            "13" => Self::TagAppearsMoreThanOnce,
+            "14" => Self::TagSpecifiedOutOfRequiredOrder,
+            "15" => Self::RepeatingGroupFieldsOutOfOrder,
+            "16" => Self::IncorrectNumingroupCountForRepeatingGroup,
+            "17" => Self::NonDataValueIncludesFieldDelimiter,
+            "18" => Self::InvalidUnsupportedApplicationVersion,
+            "99" => Self::OtherValue99,
+            _ => Self::Other(value.to_string()),
+        })
+    }
+}
+
+impl rust_fix::FixValue for SessionRejectReason {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.as_str().as_bytes());
+    }
+}
+
+impl rust_fix::FromFixValue for SessionRejectReason {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        Self::try_from(value).ok()
+    }
+}
+
+impl rust_fix::FixEnum for SessionRejectReason {
+    const TAG: rust_fix::Tag = rust_fix::Tag(373);
+    const DATA_TYPE: rust_fix::FixDataType = rust_fix::FixDataType::Int;
+}
+
+/// MsgDirection (385) values
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MsgDirection {
+    /// `R`
+    Receive,
+    /// `S`
+    Send,
+    /// Value which is not in the dictionary
+    Other(String),
+}
+
+impl MsgDirection {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Receive => "R",
+            Self::Send => "S",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl TryFrom<&str> for MsgDirection {
+    type Error = rust_fix::FixSerializeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if !rust_fix::FixDataType::Char.is_valid(value) {
+            return Err(rust_fix::FixSerializeError::InvalidFieldValue { tag: rust_fix::Tag(385), value: value.to_string(), data_type: rust_fix::FixDataType::Char });
+        }
+
+        Ok(match value {
+            "R" => Self::Receive,
+            "S" => Self::Send,
+            _ => Self::Other(value.to_string()),
+        })
+    }
+}
+
+impl rust_fix::FixValue for MsgDirection {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.as_str().as_bytes());
+    }
+}
+
+impl rust_fix::FromFixValue for MsgDirection {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        Self::try_from(value).ok()
+    }
+}
+
+impl rust_fix::FixEnum for MsgDirection {
+    const TAG: rust_fix::Tag = rust_fix::Tag(385);
+    const DATA_TYPE: rust_fix::FixDataType = rust_fix::FixDataType::Char;
+}
+
+/// ApplVerID (1128) values
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ApplVerID {
+    /// `0`
+    Fix27,
+    /// `1`
+    Fix30,
+    /// `2`
+    Fix40,
+    /// `3`
+    Fix41,
+    /// `4`
+    Fix42,
+    /// `5`
+    Fix43,
+    /// `6`
+    Fix44,
+    /// `7`
+    Fix50,
+    /// `8`
+    Fix50sp1,
+    /// `9`
+    Fix50sp2,
+    /// Value which is not in the dictionary
+    Other(String),
+}
+
+impl ApplVerID {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Fix27 => "0",
+            Self::Fix30 => "1",
+            Self::Fix40 => "2",
+            Self::Fix41 => "3",
+            Self::Fix42 => "4",
+            Self::Fix43 => "5",
+            Self::Fix44 => "6",
+            Self::Fix50 => "7",
+            Self::Fix50sp1 => "8",
+            Self::Fix50sp2 => "9",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl TryFrom<&str> for ApplVerID {
+    type Error = rust_fix::FixSerializeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if !rust_fix::FixDataType::String.is_valid(value) {
+            return Err(rust_fix::FixSerializeError::InvalidFieldValue { tag: rust_fix::Tag(1128), value: value.to_string(), data_type: rust_fix::FixDataType::String });
+        }
+
+        Ok(match value {
+            "0" => Self::Fix27,
+            "1" => Self::Fix30,
+            "2" => Self::Fix40,
+            "3" => Self::Fix41,
+            "4" => Self::Fix42,
+            "5" => Self::Fix43,
+            "6" => Self::Fix44,
+            "7" => Self::Fix50,
+            "8" => Self::Fix50sp1,
+            "9" => Self::Fix50sp2,
+            _ => Self::Other(value.to_string()),
+        })
+    }
+}
+
+impl rust_fix::FixValue for ApplVerID {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.as_str().as_bytes());
+    }
+}
+
+impl rust_fix::FromFixValue for ApplVerID {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        Self::try_from(value).ok()
+    }
+}
+
+impl rust_fix::FixEnum for ApplVerID {
+    const TAG: rust_fix::Tag = rust_fix::Tag(1128);
+    const DATA_TYPE: rust_fix::FixDataType = rust_fix::FixDataType::String;
+}
+
+/// RefApplVerID (1130) values
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RefApplVerID {
+    /// `0`
+    Fix27,
+    /// `1`
+    Fix30,
+    /// `2`
+    Fix40,
+    /// `3`
+    Fix41,
+    /// `4`
+    Fix42,
+    /// `5`
+    Fix43,
+    /// `6`
+    Fix44,
+    /// `7`
+    Fix50,
+    /// `8`
+    Fix50sp1,
+    /// `9`
+    Fix50sp2,
+    /// Value which is not in the dictionary
+    Other(String),
+}
+
+impl RefApplVerID {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Fix27 => "0",
+            Self::Fix30 => "1",
+            Self::Fix40 => "2",
+            Self::Fix41 => "3",
+            Self::Fix42 => "4",
+            Self::Fix43 => "5",
+            Self::Fix44 => "6",
+            Self::Fix50 => "7",
+            Self::Fix50sp1 => "8",
+            Self::Fix50sp2 => "9",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl TryFrom<&str> for RefApplVerID {
+    type Error = rust_fix::FixSerializeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if !rust_fix::FixDataType::String.is_valid(value) {
+            return Err(rust_fix::FixSerializeError::InvalidFieldValue { tag: rust_fix::Tag(1130), value: value.to_string(), data_type: rust_fix::FixDataType::String });
+        }
+
+        Ok(match value {
+            "0" => Self::Fix27,
+            "1" => Self::Fix30,
+            "2" => Self::Fix40,
+            "3" => Self::Fix41,
+            "4" => Self::Fix42,
+            "5" => Self::Fix43,
+            "6" => Self::Fix44,
+            "7" => Self::Fix50,
+            "8" => Self::Fix50sp1,
+            "9" => Self::Fix50sp2,
+            _ => Self::Other(value.to_string()),
+        })
+    }
+}
+
+impl rust_fix::FixValue for RefApplVerID {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.as_str().as_bytes());
+    }
+}
+
+impl rust_fix::FromFixValue for RefApplVerID {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        Self::try_from(value).ok()
+    }
+}
+
+impl rust_fix::FixEnum for RefApplVerID {
+    const TAG: rust_fix::Tag = rust_fix::Tag(1130);
+    const DATA_TYPE: rust_fix::FixDataType = rust_fix::FixDataType::String;
+}
+
+/// DefaultApplVerID (1137) values
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DefaultApplVerID {
+    /// `0`
+    Fix27,
+    /// `1`
+    Fix30,
+    /// `2`
+    Fix40,
+    /// `3`
+    Fix41,
+    /// `4`
+    Fix42,
+    /// `5`
+    Fix43,
+    /// `6`
+    Fix44,
+    /// `7`
+    Fix50,
+    /// `8`
+    Fix50sp1,
+    /// `9`
+    Fix50sp2,
+    /// Value which is not in the dictionary
+    Other(String),
+}
+
+impl DefaultApplVerID {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Fix27 => "0",
+            Self::Fix30 => "1",
+            Self::Fix40 => "2",
+            Self::Fix41 => "3",
+            Self::Fix42 => "4",
+            Self::Fix43 => "5",
+            Self::Fix44 => "6",
+            Self::Fix50 => "7",
+            Self::Fix50sp1 => "8",
+            Self::Fix50sp2 => "9",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl TryFrom<&str> for DefaultApplVerID {
+    type Error = rust_fix::FixSerializeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if !rust_fix::FixDataType::String.is_valid(value) {
+            return Err(rust_fix::FixSerializeError::InvalidFieldValue { tag: rust_fix::Tag(1137), value: value.to_string(), data_type: rust_fix::FixDataType::String });
+        }
+
+        Ok(match value {
+            "0" => Self::Fix27,
+            "1" => Self::Fix30,
+            "2" => Self::Fix40,
+            "3" => Self::Fix41,
+            "4" => Self::Fix42,
+            "5" => Self::Fix43,
+            "6" => Self::Fix44,
+            "7" => Self::Fix50,
+            "8" => Self::Fix50sp1,
+            "9" => Self::Fix50sp2,
+            _ => Self::Other(value.to_string()),
+        })
+    }
+}
+
+impl rust_fix::FixValue for DefaultApplVerID {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.as_str().as_bytes());
+    }
+}
+
+impl rust_fix::FromFixValue for DefaultApplVerID {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        Self::try_from(value).ok()
+    }
+}
+
+impl rust_fix::FixEnum for DefaultApplVerID {
+    const TAG: rust_fix::Tag = rust_fix::Tag(1137);
+    const DATA_TYPE: rust_fix::FixDataType = rust_fix::FixDataType::String;
+}
+
+/// SessionStatus (1409) values
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SessionStatus {
+    /// `0`
+    SessionActive,
+    /// `1`
+    SessionPasswordChanged,
+    /// `2`
+    SessionPasswordDueToExpire,
+    /// `3`
+    NewSessionPasswordDoesNotComplyWithPolicy,
+    /// `4`
+    SessionLogoutComplete,
+    /// `5`
+    InvalidUsernameOrPassword,
+    /// `6`
+    AccountLocked,
+    /// `7`
+    LogonsAreNotAllowedAtThisTime,
+    /// `8`
+    PasswordExpired,
+    /// Value which is not in the dictionary
+    Other(String),
+}
+
+impl SessionStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::SessionActive => "0",
+            Self::SessionPasswordChanged => "1",
+            Self::SessionPasswordDueToExpire => "2",
+            Self::NewSessionPasswordDoesNotComplyWithPolicy => "3",
+            Self::SessionLogoutComplete => "4",
+            Self::InvalidUsernameOrPassword => "5",
+            Self::AccountLocked => "6",
+            Self::LogonsAreNotAllowedAtThisTime => "7",
+            Self::PasswordExpired => "8",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl TryFrom<&str> for SessionStatus {
+    type Error = rust_fix::FixSerializeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if !rust_fix::FixDataType::Int.is_valid(value) {
+            return Err(rust_fix::FixSerializeError::InvalidFieldValue { tag: rust_fix::Tag(1409), value: value.to_string(), data_type: rust_fix::FixDataType::Int });
+        }
+
+        Ok(match value {
+            "0" => Self::SessionActive,
+            "1" => Self::SessionPasswordChanged,
+            "2" => Self::SessionPasswordDueToExpire,
+            "3" => Self::NewSessionPasswordDoesNotComplyWithPolicy,
+            "4" => Self::SessionLogoutComplete,
+            "5" => Self::InvalidUsernameOrPassword,
+            "6" => Self::AccountLocked,
+            "7" => Self::LogonsAreNotAllowedAtThisTime,
+            "8" => Self::PasswordExpired,
+            _ => Self::Other(value.to_string()),
+        })
+    }
+}
+
+impl rust_fix::FixValue for SessionStatus {
+    fn write_fix_value(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.as_str().as_bytes());
+    }
+}
+
+impl rust_fix::FromFixValue for SessionStatus {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        Self::try_from(value).ok()
+    }
+}
+
+impl rust_fix::FixEnum for SessionStatus {
+    const TAG: rust_fix::Tag = rust_fix::Tag(1409);
+    const DATA_TYPE: rust_fix::FixDataType = rust_fix::FixDataType::Int;
+}
+
+/// Heartbeat (MsgType = 0)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heartbeat {
+    /// Tag 112
+    pub test_req_id: Option<String>,
+}
+
+impl Heartbeat {
+    pub const MSG_TYPE: &str = "0";
+
+    pub fn encode(&self, writer: &mut rust_fix::FixMessageWriter) {
+        if let Some(value) = &self.test_req_id {
+            writer.with_value(rust_fix::Tag(112), value);
+        }
+    }
+
+    pub fn decode(reader: &rust_fix::FixMessageReader) -> Result<Self, rust_fix::FixSerializeError> {
+        let entry = reader.read_fields(&[])?;
+        Ok(Self {
+            test_req_id: rust_fix::decode_fix_value(rust_fix::Tag(112), entry.get_value(rust_fix::Tag(112)), rust_fix::FixDataType::String)?,
+        })
+    }
+}
+
+/// TestRequest (MsgType = 1)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestRequest {
+    /// Tag 112
+    pub test_req_id: String,
+}
+
+impl TestRequest {
+    pub const MSG_TYPE: &str = "1";
+
+    pub fn encode(&self, writer: &mut rust_fix::FixMessageWriter) {
+        writer.with_value(rust_fix::Tag(112), &self.test_req_id);
+    }
+
+    pub fn decode(reader: &rust_fix::FixMessageReader) -> Result<Self, rust_fix::FixSerializeError> {
+        let entry = reader.read_fields(&[])?;
+        Ok(Self {
+            test_req_id: rust_fix::decode_fix_value(rust_fix::Tag(112), entry.get_value(rust_fix::Tag(112)), rust_fix::FixDataType::String)?.ok_or(rust_fix::FixSerializeError::RequiredTagMissing { tag: rust_fix::Tag(112) })?,
+        })
+    }
+}
+
+/// ResendRequest (MsgType = 2)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResendRequest {
+    /// Tag 7
+    pub begin_seq_no: u64,
+    /// Tag 16
+    pub end_seq_no: u64,
+}
+
+impl ResendRequest {
+    pub const MSG_TYPE: &str = "2";
+
+    pub fn encode(&self, writer: &mut rust_fix::FixMessageWriter) {
+        writer.with_value(rust_fix::Tag(7), self.begin_seq_no);
+        writer.with_value(rust_fix::Tag(16), self.end_seq_no);
+    }
+
+    pub fn decode(reader: &rust_fix::FixMessageReader) -> Result<Self, rust_fix::FixSerializeError> {
+        let entry = reader.read_fields(&[])?;
+        Ok(Self {
+            begin_seq_no: rust_fix::decode_fix_value(rust_fix::Tag(7), entry.get_value(rust_fix::Tag(7)), rust_fix::FixDataType::SeqNum)?.ok_or(rust_fix::FixSerializeError::RequiredTagMissing { tag: rust_fix::Tag(7) })?,
+            end_seq_no: rust_fix::decode_fix_value(rust_fix::Tag(16), entry.get_value(rust_fix::Tag(16)), rust_fix::FixDataType::SeqNum)?.ok_or(rust_fix::FixSerializeError::RequiredTagMissing { tag: rust_fix::Tag(16) })?,
+        })
+    }
+}
+
+/// Reject (MsgType = 3)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reject {
+    /// Tag 45
+    pub ref_seq_num: u64,
+    /// Tag 371
+    pub ref_tag_id: Option<i64>,
+    /// Tag 372
+    pub ref_msg_type: Option<String>,
+    /// Tag 1130
+    pub ref_appl_ver_id: Option<RefApplVerID>,
+    /// Tag 1406
+    pub ref_appl_ext_id: Option<i64>,
+    /// Tag 1131
+    pub ref_cstm_appl_ver_id: Option<String>,
+    /// Tag 373
+    pub session_reject_reason: Option<SessionRejectReason>,
+    /// Tag 58
+    pub text: Option<String>,
+    /// Tag 355
+    pub encoded_text: Option<Vec<u8>>,
+}
+
+impl Reject {
+    pub const MSG_TYPE: &str = "3";
+
+    pub fn encode(&self, writer: &mut rust_fix::FixMessageWriter) {
+        writer.with_value(rust_fix::Tag(45), self.ref_seq_num);
+        if let Some(value) = &self.ref_tag_id {
+            writer.with_value(rust_fix::Tag(371), value);
+        }
+        if let Some(value) = &self.ref_msg_type {
+            writer.with_value(rust_fix::Tag(372), value);
+        }
+        if let Some(value) = &self.ref_appl_ver_id {
+            writer.with_value(rust_fix::Tag(1130), value);
+        }
+        if let Some(value) = &self.ref_appl_ext_id {
+            writer.with_value(rust_fix::Tag(1406), value);
+        }
+        if let Some(value) = &self.ref_cstm_appl_ver_id {
+            writer.with_value(rust_fix::Tag(1131), value);
+        }
+        if let Some(value) = &self.session_reject_reason {
+            writer.with_value(rust_fix::Tag(373), value);
+        }
+        if let Some(value) = &self.text {
+            writer.with_value(rust_fix::Tag(58), value);
+        }
+        if let Some(value) = &self.encoded_text {
+            writer.with_data(rust_fix::Tag(354), rust_fix::Tag(355), value);
+        }
+    }
+
+    pub fn decode(reader: &rust_fix::FixMessageReader) -> Result<Self, rust_fix::FixSerializeError> {
+        let entry = reader.read_fields(&[])?;
+        Ok(Self {
+            ref_seq_num: rust_fix::decode_fix_value(rust_fix::Tag(45), entry.get_value(rust_fix::Tag(45)), rust_fix::FixDataType::SeqNum)?.ok_or(rust_fix::FixSerializeError::RequiredTagMissing { tag: rust_fix::Tag(45) })?,
+            ref_tag_id: rust_fix::decode_fix_value(rust_fix::Tag(371), entry.get_value(rust_fix::Tag(371)), rust_fix::FixDataType::Int)?,
+            ref_msg_type: rust_fix::decode_fix_value(rust_fix::Tag(372), entry.get_value(rust_fix::Tag(372)), rust_fix::FixDataType::String)?,
+            ref_appl_ver_id: rust_fix::decode_fix_value(rust_fix::Tag(1130), entry.get_value(rust_fix::Tag(1130)), rust_fix::FixDataType::String)?,
+            ref_appl_ext_id: rust_fix::decode_fix_value(rust_fix::Tag(1406), entry.get_value(rust_fix::Tag(1406)), rust_fix::FixDataType::Int)?,
+            ref_cstm_appl_ver_id: rust_fix::decode_fix_value(rust_fix::Tag(1131), entry.get_value(rust_fix::Tag(1131)), rust_fix::FixDataType::String)?,
+            session_reject_reason: rust_fix::decode_fix_value(rust_fix::Tag(373), entry.get_value(rust_fix::Tag(373)), rust_fix::FixDataType::Int)?,
+            text: rust_fix::decode_fix_value(rust_fix::Tag(58), entry.get_value(rust_fix::Tag(58)), rust_fix::FixDataType::String)?,
+            encoded_text: entry.get_data(rust_fix::Tag(355)).map(<[u8]>::to_vec),
+        })
+    }
+}
+
+/// SequenceReset (MsgType = 4)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SequenceReset {
+    /// Tag 123
+    pub gap_fill_flag: Option<bool>,
+    /// Tag 36
+    pub new_seq_no: u64,
+}
+
+impl SequenceReset {
+    pub const MSG_TYPE: &str = "4";
+
+    pub fn encode(&self, writer: &mut rust_fix::FixMessageWriter) {
+        if let Some(value) = &self.gap_fill_flag {
+            writer.with_value(rust_fix::Tag(123), value);
+        }
+        writer.with_value(rust_fix::Tag(36), self.new_seq_no);
+    }
+
+    pub fn decode(reader: &rust_fix::FixMessageReader) -> Result<Self, rust_fix::FixSerializeError> {
+        let entry = reader.read_fields(&[])?;
+        Ok(Self {
+            gap_fill_flag: rust_fix::decode_fix_value(rust_fix::Tag(123), entry.get_value(rust_fix::Tag(123)), rust_fix::FixDataType::Boolean)?,
+            new_seq_no: rust_fix::decode_fix_value(rust_fix::Tag(36), entry.get_value(rust_fix::Tag(36)), rust_fix::FixDataType::SeqNum)?.ok_or(rust_fix::FixSerializeError::RequiredTagMissing { tag: rust_fix::Tag(36) })?,
+        })
+    }
+}
+
+/// Logout (MsgType = 5)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Logout {
+    /// Tag 1409
+    pub session_status: Option<SessionStatus>,
+    /// Tag 58
+    pub text: Option<String>,
+    /// Tag 355
+    pub encoded_text: Option<Vec<u8>>,
+}
+
+impl Logout {
+    pub const MSG_TYPE: &str = "5";
+
+    pub fn encode(&self, writer: &mut rust_fix::FixMessageWriter) {
+        if let Some(value) = &self.session_status {
+            writer.with_value(rust_fix::Tag(1409), value);
+        }
+        if let Some(value) = &self.text {
+            writer.with_value(rust_fix::Tag(58), value);
+        }
+        if let Some(value) = &self.encoded_text {
+            writer.with_data(rust_fix::Tag(354), rust_fix::Tag(355), value);
+        }
+    }
+
+    pub fn decode(reader: &rust_fix::FixMessageReader) -> Result<Self, rust_fix::FixSerializeError> {
+        let entry = reader.read_fields(&[])?;
+        Ok(Self {
+            session_status: rust_fix::decode_fix_value(rust_fix::Tag(1409), entry.get_value(rust_fix::Tag(1409)), rust_fix::FixDataType::Int)?,
+            text: rust_fix::decode_fix_value(rust_fix::Tag(58), entry.get_value(rust_fix::Tag(58)), rust_fix::FixDataType::String)?,
+            encoded_text: entry.get_data(rust_fix::Tag(355)).map(<[u8]>::to_vec),
+        })
+    }
+}
+
+/// Logon (MsgType = A)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Logon {
+    /// Tag 98
+    pub encrypt_method: EncryptMethod,
+    /// Tag 108
+    pub heart_bt_int: i64,
+    /// Tag 96
+    pub raw_data: Option<Vec<u8>>,
+    /// Tag 141
+    pub reset_seq_num_flag: Option<bool>,
+    /// Tag 789
+    pub next_expected_msg_seq_num: Option<u64>,
+    /// Tag 383
+    pub max_message_size: Option<u64>,
+    /// Tag 384
+    pub no_msg_types: Vec<NoMsgTypes>,
+    /// Tag 464
+    pub test_message_indicator: Option<bool>,
+    /// Tag 553
+    pub username: Option<String>,
+    /// Tag 554
+    pub password: Option<String>,
+    /// Tag 925
+    pub new_password: Option<String>,
+    /// Tag 1400
+    pub encrypted_password_method: Option<i64>,
+    /// Tag 1402
+    pub encrypted_password: Option<Vec<u8>>,
+    /// Tag 1404
+    pub encrypted_new_password: Option<Vec<u8>>,
+    /// Tag 1409
+    pub session_status: Option<SessionStatus>,
+    /// Tag 1137
+    pub default_appl_ver_id: DefaultApplVerID,
+    /// Tag 1407
+    pub default_appl_ext_id: Option<i64>,
+    /// Tag 1408
+    pub default_cstm_appl_ver_id: Option<String>,
+    /// Tag 58
+    pub text: Option<String>,
+    /// Tag 355
+    pub encoded_text: Option<Vec<u8>>,
+}
+
+impl Logon {
+    pub const MSG_TYPE: &str = "A";
+
+    pub fn encode(&self, writer: &mut rust_fix::FixMessageWriter) {
+        writer.with_value(rust_fix::Tag(98), &self.encrypt_method);
+        writer.with_value(rust_fix::Tag(108), self.heart_bt_int);
+        if let Some(value) = &self.raw_data {
+            writer.with_data(rust_fix::Tag(95), rust_fix::Tag(96), value);
+        }
+        if let Some(value) = &self.reset_seq_num_flag {
+            writer.with_value(rust_fix::Tag(141), value);
+        }
+        if let Some(value) = &self.next_expected_msg_seq_num {
+            writer.with_value(rust_fix::Tag(789), value);
+        }
+        if let Some(value) = &self.max_message_size {
+            writer.with_value(rust_fix::Tag(383), value);
+        }
+        let mut group = rust_fix::FixGroupWriter::new(rust_fix::Tag(384));
+        for itm in &self.no_msg_types {
+            itm.encode(group.add_entry());
+        }
+        writer.with_group(&group);
+        if let Some(value) = &self.test_message_indicator {
+            writer.with_value(rust_fix::Tag(464), value);
+        }
+        if let Some(value) = &self.username {
+            writer.with_value(rust_fix::Tag(553), value);
+        }
+        if let Some(value) = &self.password {
+            writer.with_value(rust_fix::Tag(554), value);
+        }
+        if let Some(value) = &self.new_password {
+            writer.with_value(rust_fix::Tag(925), value);
+        }
+        if let Some(value) = &self.encrypted_password_method {
+            writer.with_value(rust_fix::Tag(1400), value);
+        }
+        if let Some(value) = &self.encrypted_password {
+            writer.with_data(rust_fix::Tag(1401), rust_fix::Tag(1402), value);
+        }
+        if let Some(value) = &self.encrypted_new_password {
+            writer.with_data(rust_fix::Tag(1403), rust_fix::Tag(1404), value);
+        }
+        if let Some(value) = &self.session_status {
+            writer.with_value(rust_fix::Tag(1409), value);
+        }
+        writer.with_value(rust_fix::Tag(1137), &self.default_appl_ver_id);
+        if let Some(value) = &self.default_appl_ext_id {
+            writer.with_value(rust_fix::Tag(1407), value);
+        }
+        if let Some(value) = &self.default_cstm_appl_ver_id {
+            writer.with_value(rust_fix::Tag(1408), value);
+        }
+        if let Some(value) = &self.text {
+            writer.with_value(rust_fix::Tag(58), value);
+        }
+        if let Some(value) = &self.encoded_text {
+            writer.with_data(rust_fix::Tag(354), rust_fix::Tag(355), value);
+        }
+    }
+
+    pub fn decode(reader: &rust_fix::FixMessageReader) -> Result<Self, rust_fix::FixSerializeError> {
+        let entry = reader.read_fields(&[NoMsgTypes::definition()])?;
+        Ok(Self {
+            encrypt_method: rust_fix::decode_fix_value(rust_fix::Tag(98), entry.get_value(rust_fix::Tag(98)), rust_fix::FixDataType::Int)?.ok_or(rust_fix::FixSerializeError::RequiredTagMissing { tag: rust_fix::Tag(98) })?,
+            heart_bt_int: rust_fix::decode_fix_value(rust_fix::Tag(108), entry.get_value(rust_fix::Tag(108)), rust_fix::FixDataType::Int)?.ok_or(rust_fix::FixSerializeError::RequiredTagMissing { tag: rust_fix::Tag(108) })?,
+            raw_data: entry.get_data(rust_fix::Tag(96)).map(<[u8]>::to_vec),
+            reset_seq_num_flag: rust_fix::decode_fix_value(rust_fix::Tag(141), entry.get_value(rust_fix::Tag(141)), rust_fix::FixDataType::Boolean)?,
+            next_expected_msg_seq_num: rust_fix::decode_fix_value(rust_fix::Tag(789), entry.get_value(rust_fix::Tag(789)), rust_fix::FixDataType::SeqNum)?,
+            max_message_size: rust_fix::decode_fix_value(rust_fix::Tag(383), entry.get_value(rust_fix::Tag(383)), rust_fix::FixDataType::Length)?,
+            no_msg_types: entry.get_group(rust_fix::Tag(384)).iter().map(NoMsgTypes::decode).collect::<Result<_, _>>()?,
+            test_message_indicator: rust_fix::decode_fix_value(rust_fix::Tag(464), entry.get_value(rust_fix::Tag(464)), rust_fix::FixDataType::Boolean)?,
+            username: rust_fix::decode_fix_value(rust_fix::Tag(553), entry.get_value(rust_fix::Tag(553)), rust_fix::FixDataType::String)?,
+            password: rust_fix::decode_fix_value(rust_fix::Tag(554), entry.get_value(rust_fix::Tag(554)), rust_fix::FixDataType::String)?,
+            new_password: rust_fix::decode_fix_value(rust_fix::Tag(925), entry.get_value(rust_fix::Tag(925)), rust_fix::FixDataType::String)?,
+            encrypted_password_method: rust_fix::decode_fix_value(rust_fix::Tag(1400), entry.get_value(rust_fix::Tag(1400)), rust_fix::FixDataType::Int)?,
+            encrypted_password: entry.get_data(rust_fix::Tag(1402)).map(<[u8]>::to_vec),
+            encrypted_new_password: entry.get_data(rust_fix::Tag(1404)).map(<[u8]>::to_vec),
+            session_status: rust_fix::decode_fix_value(rust_fix::Tag(1409), entry.get_value(rust_fix::Tag(1409)), rust_fix::FixDataType::Int)?,
+            default_appl_ver_id: rust_fix::decode_fix_value(rust_fix::Tag(1137), entry.get_value(rust_fix::Tag(1137)), rust_fix::FixDataType::String)?.ok_or(rust_fix::FixSerializeError::RequiredTagMissing { tag: rust_fix::Tag(1137) })?,
+            default_appl_ext_id: rust_fix::decode_fix_value(rust_fix::Tag(1407), entry.get_value(rust_fix::Tag(1407)), rust_fix::FixDataType::Int)?,
+            default_cstm_appl_ver_id: rust_fix::decode_fix_value(rust_fix::Tag(1408), entry.get_value(rust_fix::Tag(1408)), rust_fix::FixDataType::String)?,
+            text: rust_fix::decode_fix_value(rust_fix::Tag(58), entry.get_value(rust_fix::Tag(58)), rust_fix::FixDataType::String)?,
+            encoded_text: entry.get_data(rust_fix::Tag(355)).map(<[u8]>::to_vec),
+        })
+    }
+}
+
+/// Entry of NoMsgTypes (384) repeating group
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoMsgTypes {
+    /// Tag 372
+    pub ref_msg_type: Option<String>,
+    /// Tag 385
+    pub msg_direction: Option<MsgDirection>,
+    /// Tag 1130
+    pub ref_appl_ver_id: Option<RefApplVerID>,
+    /// Tag 1406
+    pub ref_appl_ext_id: Option<i64>,
+    /// Tag 1131
+    pub ref_cstm_appl_ver_id: Option<String>,
+    /// Tag 1410
+    pub default_ver_indicator: Option<bool>,
+}
+
+impl NoMsgTypes {
+    pub fn definition() -> rust_fix::FixGroupDefinition {
+        rust_fix::FixGroupDefinition::new(rust_fix::Tag(384), rust_fix::Tag(372))
+            .with_tags(&[rust_fix::Tag(372), rust_fix::Tag(385), rust_fix::Tag(1130), rust_fix::Tag(1406), rust_fix::Tag(1131), rust_fix::Tag(1410)])
+    }
+
+    pub fn encode(&self, entry: &mut rust_fix::FixGroupEntryWriter) {
+        if let Some(value) = &self.ref_msg_type {
+            entry.with_value(rust_fix::Tag(372), value);
+        }
+        if let Some(value) = &self.msg_direction {
+            entry.with_value(rust_fix::Tag(385), value);
+        }
+        if let Some(value) = &self.ref_appl_ver_id {
+            entry.with_value(rust_fix::Tag(1130), value);
+        }
+        if let Some(value) = &self.ref_appl_ext_id {
+            entry.with_value(rust_fix::Tag(1406), value);
+        }
+        if let Some(value) = &self.ref_cstm_appl_ver_id {
+            entry.with_value(rust_fix::Tag(1131), value);
+        }
+        if let Some(value) = &self.default_ver_indicator {
+            entry.with_value(rust_fix::Tag(1410), value);
+        }
+    }
+
+    pub fn decode(entry: &rust_fix::FixGroupEntry) -> Result<Self, rust_fix::FixSerializeError> {
+        Ok(Self {
+            ref_msg_type: rust_fix::decode_fix_value(rust_fix::Tag(372), entry.get_value(rust_fix::Tag(372)), rust_fix::FixDataType::String)?,
+            msg_direction: rust_fix::decode_fix_value(rust_fix::Tag(385), entry.get_value(rust_fix::Tag(385)), rust_fix::FixDataType::Char)?,
+            ref_appl_ver_id: rust_fix::decode_fix_value(rust_fix::Tag(1130), entry.get_value(rust_fix::Tag(1130)), rust_fix::FixDataType::String)?,
+            ref_appl_ext_id: rust_fix::decode_fix_value(rust_fix::Tag(1406), entry.get_value(rust_fix::Tag(1406)), rust_fix::FixDataType::Int)?,
+            ref_cstm_appl_ver_id: rust_fix::decode_fix_value(rust_fix::Tag(1131), entry.get_value(rust_fix::Tag(1131)), rust_fix::FixDataType::String)?,
+            default_ver_indicator: rust_fix::decode_fix_value(rust_fix::Tag(1410), entry.get_value(rust_fix::Tag(1410)), rust_fix::FixDataType::Boolean)?,
+        })
+    }
+}
@@ -1,5 +1,9 @@
+extern crate self as rust_fix;
+
+pub mod codegen;
 mod errors;
 mod fix_message_builder;
+pub mod tags;
 pub mod utils;
 
 pub use errors::*;
@@ -15,3 +19,69 @@ mod fix_message_reader;
 pub use fix_message_reader::*;
 mod fix_message_item;
 pub use fix_message_item::*;
+mod tag;
+pub use tag::*;
+mod fix_data_type;
+pub use fix_data_type::*;
+mod fix_time;
+pub use fix_time::*;
+mod fix_frame_decoder;
+pub use fix_frame_decoder::*;
+mod fix_decimal;
+pub use fix_decimal::*;
+mod fix_value;
+pub use fix_value::*;
+mod fix_group_writer;
+pub use fix_group_writer::*;
+mod fix_group_reader;
+pub use fix_group_reader::*;
+mod standard_header;
+pub use standard_header::*;
+mod data_dictionary;
+pub use data_dictionary::*;
+mod session_reject_reason;
+pub use session_reject_reason::*;
+mod fix_validator;
+pub use fix_validator::*;
+mod message_store;
+pub use message_store::*;
+mod file_message_store;
+pub use file_message_store::*;
+mod session_clock;
+pub use session_clock::*;
+mod time_zone;
+pub use time_zone::*;
+mod session_schedule;
+pub use session_schedule::*;
+mod fix_session_config;
+pub use fix_session_config::*;
+mod session_settings;
+pub use session_settings::*;
+mod fix_session;
+pub use fix_session::*;
+mod blocking_connection;
+mod fix_transport;
+pub use blocking_connection::*;
+mod blocking_initiator;
+pub use blocking_initiator::*;
+mod blocking_acceptor;
+pub use blocking_acceptor::*;
+#[cfg(feature = "tokio")]
+mod fix_connection;
+#[cfg(feature = "tokio")]
+pub use fix_connection::*;
+#[cfg(feature = "tokio")]
+mod fix_initiator;
+#[cfg(feature = "tokio")]
+pub use fix_initiator::*;
+#[cfg(feature = "tokio")]
+mod fix_acceptor;
+#[cfg(feature = "tokio")]
+pub use fix_acceptor::*;
+#[cfg(feature = "tls")]
+mod fix_tls;
+#[cfg(feature = "tls")]
+pub use fix_tls::*;
+mod xml_element;
+#[rustfmt::skip]
+pub mod fixt11_messages;
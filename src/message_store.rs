@@ -0,0 +1,148 @@
+use std::{collections::BTreeMap, io, time::SystemTime};
+
+/// Storage of the session state: outbound messages, so they can be resent on
+/// ResendRequest (2), both sequence numbers and the time the session was created.
+///
+/// Messages are kept as compiled by `FixMessageWriter::compile_message` and keyed by their
+/// MsgSeqNum (34).
+pub trait MessageStore {
+    fn set(&mut self, seq_num: u64, message: &[u8]) -> io::Result<()>;
+
+    /// Messages with `begin <= seq_num <= end` ordered by the sequence number.
+    /// Sequence numbers which are not stored are skipped.
+    fn get_range(&self, begin: u64, end: u64) -> io::Result<Vec<(u64, Vec<u8>)>>;
+
+    fn get_next_sender_seq_num(&self) -> u64;
+
+    fn get_next_target_seq_num(&self) -> u64;
+
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> io::Result<()>;
+
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> io::Result<()>;
+
+    /// Time of the creation or of the last reset of the store
+    fn get_creation_time(&self) -> SystemTime;
+
+    /// Removes all the messages, starts both sequences from 1 and sets the creation time
+    fn reset(&mut self, creation_time: SystemTime) -> io::Result<()>;
+}
+
+#[derive(Debug, Clone)]
+pub struct MemoryMessageStore {
+    messages: BTreeMap<u64, Vec<u8>>,
+    next_sender_seq_num: u64,
+    next_target_seq_num: u64,
+    creation_time: SystemTime,
+}
+
+impl Default for MemoryMessageStore {
+    fn default() -> Self {
+        Self::with_creation_time(SystemTime::now())
+    }
+}
+
+impl MemoryMessageStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Empty store with the given creation time, usually the time of the session clock
+    pub fn with_creation_time(creation_time: SystemTime) -> Self {
+        Self {
+            messages: BTreeMap::new(),
+            next_sender_seq_num: 1,
+            next_target_seq_num: 1,
+            creation_time,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+}
+
+impl MessageStore for MemoryMessageStore {
+    fn set(&mut self, seq_num: u64, message: &[u8]) -> io::Result<()> {
+        self.messages.insert(seq_num, message.to_vec());
+        Ok(())
+    }
+
+    fn get_range(&self, begin: u64, end: u64) -> io::Result<Vec<(u64, Vec<u8>)>> {
+        if begin > end {
+            return Ok(Vec::new());
+        }
+
+        Ok(self
+            .messages
+            .range(begin..=end)
+            .map(|(seq_num, message)| (*seq_num, message.clone()))
+            .collect())
+    }
+
+    fn get_next_sender_seq_num(&self) -> u64 {
+        self.next_sender_seq_num
+    }
+
+    fn get_next_target_seq_num(&self) -> u64 {
+        self.next_target_seq_num
+    }
+
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> io::Result<()> {
+        self.next_sender_seq_num = seq_num;
+        Ok(())
+    }
+
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> io::Result<()> {
+        self.next_target_seq_num = seq_num;
+        Ok(())
+    }
+
+    fn get_creation_time(&self) -> SystemTime {
+        self.creation_time
+    }
+
+    fn reset(&mut self, creation_time: SystemTime) -> io::Result<()> {
+        self.messages.clear();
+        self.next_sender_seq_num = 1;
+        self.next_target_seq_num = 1;
+        self.creation_time = creation_time;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use crate::{MemoryMessageStore, MessageStore};
+
+    #[test]
+    fn test_memory_store() {
+        let creation_time = SystemTime::UNIX_EPOCH + Duration::from_secs(3600);
+        let mut store = MemoryMessageStore::with_creation_time(creation_time);
+        assert_eq!(creation_time, store.get_creation_time());
+
+        store.set(1, b"first").unwrap();
+        store.set(2, b"second").unwrap();
+        store.set(4, b"fourth").unwrap();
+
+        assert_eq!(
+            vec![(2, b"second".to_vec()), (4, b"fourth".to_vec())],
+            store.get_range(2, 10).unwrap()
+        );
+        assert!(store.get_range(5, 1).unwrap().is_empty());
+
+        store.set_next_sender_seq_num(5).unwrap();
+        store.set_next_target_seq_num(7).unwrap();
+
+        store.reset(SystemTime::UNIX_EPOCH).unwrap();
+        assert!(store.is_empty());
+        assert_eq!(1, store.get_next_sender_seq_num());
+        assert_eq!(1, store.get_next_target_seq_num());
+        assert_eq!(SystemTime::UNIX_EPOCH, store.get_creation_time());
+    }
+}
@@ -0,0 +1,69 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+/// Source of the current time for the session layer.
+///
+/// Sessions use it for SendingTime (52) and for heartbeat and logon/logout timeouts, so
+/// tests can drive them with [`ManualClock`] instead of waiting.
+pub trait SessionClock {
+    fn now(&self) -> SystemTime;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl SessionClock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Clock which only moves when it is told to. Clones share the same time.
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    now: Arc<Mutex<SystemTime>>,
+}
+
+impl ManualClock {
+    pub fn new(now: SystemTime) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    pub fn set(&self, now: SystemTime) {
+        *self.now.lock().unwrap() = now;
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+impl SessionClock for ManualClock {
+    fn now(&self) -> SystemTime {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use crate::{ManualClock, SessionClock};
+
+    #[test]
+    fn test_manual_clock_is_shared_between_clones() {
+        let clock = ManualClock::new(SystemTime::UNIX_EPOCH);
+        let clone = clock.clone();
+
+        clock.advance(Duration::from_secs(30));
+
+        assert_eq!(
+            SystemTime::UNIX_EPOCH + Duration::from_secs(30),
+            clone.now()
+        );
+    }
+}
@@ -0,0 +1,84 @@
+/// Values of SessionRejectReason (373) field of the Reject (3) message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SessionRejectReason {
+    InvalidTagNumber = 0,
+    RequiredTagMissing = 1,
+    TagNotDefinedForMessageType = 2,
+    UndefinedTag = 3,
+    TagSpecifiedWithoutValue = 4,
+    ValueIsIncorrect = 5,
+    IncorrectDataFormat = 6,
+    DecryptionProblem = 7,
+    SignatureProblem = 8,
+    CompIdProblem = 9,
+    SendingTimeAccuracyProblem = 10,
+    InvalidMsgType = 11,
+    XmlValidationError = 12,
+    TagAppearsMoreThanOnce = 13,
+    TagSpecifiedOutOfRequiredOrder = 14,
+    RepeatingGroupFieldsOutOfOrder = 15,
+    IncorrectNumInGroupCount = 16,
+    NonDataValueIncludesFieldDelimiter = 17,
+    InvalidApplVersion = 18,
+    Other = 99,
+}
+
+impl SessionRejectReason {
+    pub fn get_code(&self) -> u32 {
+        *self as u32
+    }
+
+    pub fn from_code(code: u32) -> Option<Self> {
+        let result = match code {
+            0 => Self::InvalidTagNumber,
+            1 => Self::RequiredTagMissing,
+            2 => Self::TagNotDefinedForMessageType,
+            3 => Self::UndefinedTag,
+            4 => Self::TagSpecifiedWithoutValue,
+            5 => Self::ValueIsIncorrect,
+            6 => Self::IncorrectDataFormat,
+            7 => Self::DecryptionProblem,
+            8 => Self::SignatureProblem,
+            9 => Self::CompIdProblem,
+            10 => Self::SendingTimeAccuracyProblem,
+            11 => Self::InvalidMsgType,
+            12 => Self::XmlValidationError,
+            13 => Self::TagAppearsMoreThanOnce,
+            14 => Self::TagSpecifiedOutOfRequiredOrder,
+            15 => Self::RepeatingGroupFieldsOutOfOrder,
+            16 => Self::IncorrectNumInGroupCount,
+            17 => Self::NonDataValueIncludesFieldDelimiter,
+            18 => Self::InvalidApplVersion,
+            99 => Self::Other,
+            _ => return None,
+        };
+
+        Some(result)
+    }
+
+    /// Text for the Text (58) field of the Reject message
+    pub fn get_text(&self) -> &'static str {
+        match self {
+            Self::InvalidTagNumber => "Invalid tag number",
+            Self::RequiredTagMissing => "Required tag missing",
+            Self::TagNotDefinedForMessageType => "Tag not defined for this message type",
+            Self::UndefinedTag => "Undefined Tag",
+            Self::TagSpecifiedWithoutValue => "Tag specified without a value",
+            Self::ValueIsIncorrect => "Value is incorrect (out of range) for this tag",
+            Self::IncorrectDataFormat => "Incorrect data format for value",
+            Self::DecryptionProblem => "Decryption problem",
+            Self::SignatureProblem => "Signature problem",
+            Self::CompIdProblem => "CompID problem",
+            Self::SendingTimeAccuracyProblem => "SendingTime accuracy problem",
+            Self::InvalidMsgType => "Invalid MsgType",
+            Self::XmlValidationError => "XML Validation error",
+            Self::TagAppearsMoreThanOnce => "Tag appears more than once",
+            Self::TagSpecifiedOutOfRequiredOrder => "Tag specified out of required order",
+            Self::RepeatingGroupFieldsOutOfOrder => "Repeating group fields out of order",
+            Self::IncorrectNumInGroupCount => "Incorrect NumInGroup count for repeating group",
+            Self::NonDataValueIncludesFieldDelimiter => "Non-data value includes field delimiter",
+            Self::InvalidApplVersion => "Invalid/Unsupported Application Version",
+            Self::Other => "Other",
+        }
+    }
+}
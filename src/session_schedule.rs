@@ -0,0 +1,263 @@
+use std::time::SystemTime;
+
+use crate::{
+    time_zone::{self, TimeZone},
+    UtcTimeOnly,
+};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+const SECONDS_PER_WEEK: i64 = 7 * SECONDS_PER_DAY;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+}
+
+impl Weekday {
+    const ALL: [Self; 7] = [
+        Self::Sunday,
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+    ];
+
+    /// Full or three-letter English name in any case: `Monday`, `mon`
+    pub fn parse(src: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|day| {
+            let name = day.get_name();
+            src.eq_ignore_ascii_case(name) || src.eq_ignore_ascii_case(&name[..3])
+        })
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Sunday => "Sunday",
+            Self::Monday => "Monday",
+            Self::Tuesday => "Tuesday",
+            Self::Wednesday => "Wednesday",
+            Self::Thursday => "Thursday",
+            Self::Friday => "Friday",
+            Self::Saturday => "Saturday",
+        }
+    }
+
+    // Days from Thursday, the weekday of 1970-01-01
+    fn get_days_since_epoch_weekday(&self) -> i64 {
+        (*self as i64 + 3) % 7
+    }
+}
+
+/// Daily or weekly window in which the session may be logged on.
+///
+/// Start and end times are local times of the schedule time zone. A daily session whose
+/// end time is before the start time spans midnight; equal times make a 24-hour session
+/// which starts over at that time. Weekly sessions run once a week from the start day and
+/// time to the end day and time.
+///
+/// [`crate::FixSession`] with a schedule refuses logons outside the window, logs out at its
+/// end and resets the sequence numbers and the message store when a new session starts.
+#[derive(Debug, Clone)]
+pub struct SessionSchedule {
+    start_time: UtcTimeOnly,
+    end_time: UtcTimeOnly,
+    days: Option<(Weekday, Weekday)>,
+    time_zone: TimeZone,
+}
+
+impl SessionSchedule {
+    pub fn daily(start_time: UtcTimeOnly, end_time: UtcTimeOnly) -> Self {
+        Self {
+            start_time,
+            end_time,
+            days: None,
+            time_zone: TimeZone::utc(),
+        }
+    }
+
+    pub fn weekly(
+        start_day: Weekday,
+        start_time: UtcTimeOnly,
+        end_day: Weekday,
+        end_time: UtcTimeOnly,
+    ) -> Self {
+        Self {
+            days: Some((start_day, end_day)),
+            ..Self::daily(start_time, end_time)
+        }
+    }
+
+    /// Zone of the start and end times, UTC by default
+    pub fn with_time_zone(mut self, time_zone: TimeZone) -> Self {
+        self.time_zone = time_zone;
+        self
+    }
+
+    pub fn get_start_time(&self) -> UtcTimeOnly {
+        self.start_time
+    }
+
+    pub fn get_end_time(&self) -> UtcTimeOnly {
+        self.end_time
+    }
+
+    /// Start and end days of weekly sessions
+    pub fn get_days(&self) -> Option<(Weekday, Weekday)> {
+        self.days
+    }
+
+    pub fn get_time_zone(&self) -> &TimeZone {
+        &self.time_zone
+    }
+
+    pub fn is_session_time(&self, time: SystemTime) -> bool {
+        self.get_session_start(time).is_some()
+    }
+
+    /// Start of the session the time belongs to. Returns `None` outside of the sessions.
+    pub fn get_session_start(&self, time: SystemTime) -> Option<SystemTime> {
+        let (period, start, duration) = self.get_period();
+
+        let local = self.time_zone.to_local(time_zone::unix_seconds(time));
+        let session_start = local - (local - start).rem_euclid(period);
+
+        if local - session_start >= duration {
+            return None;
+        }
+
+        Some(time_zone::from_unix_seconds(
+            self.time_zone.to_utc(session_start),
+        ))
+    }
+
+    /// Both times are within the same session
+    pub fn is_same_session(&self, a: SystemTime, b: SystemTime) -> bool {
+        match (self.get_session_start(a), self.get_session_start(b)) {
+            (Some(a), Some(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    // Length of the cycle, offset of the first session start from the epoch and length of
+    // the session in local seconds
+    fn get_period(&self) -> (i64, i64, i64) {
+        let start_time = self.start_time.seconds_of_day() as i64;
+        let end_time = self.end_time.seconds_of_day() as i64;
+
+        let (period, start, end) = match self.days {
+            None => (SECONDS_PER_DAY, start_time, end_time),
+            Some((start_day, end_day)) => (
+                SECONDS_PER_WEEK,
+                start_day.get_days_since_epoch_weekday() * SECONDS_PER_DAY + start_time,
+                end_day.get_days_since_epoch_weekday() * SECONDS_PER_DAY + end_time,
+            ),
+        };
+
+        let duration = match (end - start).rem_euclid(period) {
+            0 => period,
+            duration => duration,
+        };
+
+        (period, start, duration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use crate::{SessionSchedule, TimeZone, UtcTimeOnly, UtcTimestamp, Weekday};
+
+    const NEW_YORK: &[u8] = include_bytes!("../test_data/zoneinfo/America/New_York");
+
+    fn at(src: &str) -> SystemTime {
+        UtcTimestamp::parse(src).unwrap().to_system_time()
+    }
+
+    fn time(hour: u8, minute: u8) -> UtcTimeOnly {
+        UtcTimeOnly::new(hour, minute, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_daily_session() {
+        let schedule = SessionSchedule::daily(time(8, 0), time(17, 30));
+
+        assert!(!schedule.is_session_time(at("20240701-07:59:59")));
+        assert_eq!(
+            Some(at("20240701-08:00:00")),
+            schedule.get_session_start(at("20240701-08:00:00"))
+        );
+        assert_eq!(
+            Some(at("20240701-08:00:00")),
+            schedule.get_session_start(at("20240701-17:29:59"))
+        );
+        assert!(!schedule.is_session_time(at("20240701-17:30:00")));
+
+        assert!(schedule.is_same_session(at("20240701-09:00:00"), at("20240701-16:00:00")));
+        assert!(!schedule.is_same_session(at("20240701-09:00:00"), at("20240702-09:00:00")));
+    }
+
+    #[test]
+    fn test_daily_session_over_midnight() {
+        let schedule = SessionSchedule::daily(time(22, 0), time(6, 0));
+
+        assert_eq!(
+            Some(at("20240701-22:00:00")),
+            schedule.get_session_start(at("20240702-05:00:00"))
+        );
+        assert!(!schedule.is_session_time(at("20240702-12:00:00")));
+
+        // Equal times make a 24-hour session
+        let schedule = SessionSchedule::daily(time(17, 0), time(17, 0));
+        assert!(schedule.is_session_time(at("20240702-16:59:59")));
+        assert!(!schedule.is_same_session(at("20240702-16:59:59"), at("20240702-17:00:00")));
+    }
+
+    #[test]
+    fn test_weekly_session() {
+        // 2024-07-01 is Monday
+        let schedule =
+            SessionSchedule::weekly(Weekday::Sunday, time(17, 0), Weekday::Friday, time(17, 0));
+
+        assert!(!schedule.is_session_time(at("20240706-12:00:00")));
+        assert!(!schedule.is_session_time(at("20240707-16:59:59")));
+        assert_eq!(
+            Some(at("20240707-17:00:00")),
+            schedule.get_session_start(at("20240710-12:00:00"))
+        );
+        assert!(schedule.is_session_time(at("20240712-16:59:59")));
+        assert!(!schedule.is_session_time(at("20240712-17:00:00")));
+    }
+
+    #[test]
+    fn test_session_in_time_zone() {
+        let new_york = TimeZone::from_tzif("America/New_York", NEW_YORK).unwrap();
+        let schedule = SessionSchedule::daily(time(9, 30), time(16, 0)).with_time_zone(new_york);
+
+        // EDT in summer, EST in winter
+        assert_eq!(
+            Some(at("20240701-13:30:00")),
+            schedule.get_session_start(at("20240701-15:00:00"))
+        );
+        assert!(!schedule.is_session_time(at("20240701-20:00:00")));
+        assert_eq!(
+            Some(at("20240115-14:30:00")),
+            schedule.get_session_start(at("20240115-20:59:59"))
+        );
+    }
+
+    #[test]
+    fn test_parse_weekday() {
+        assert_eq!(Some(Weekday::Monday), Weekday::parse("Monday"));
+        assert_eq!(Some(Weekday::Sunday), Weekday::parse("sun"));
+        assert_eq!(None, Weekday::parse("Mo"));
+    }
+}
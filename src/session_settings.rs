@@ -0,0 +1,714 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    fix_session_config::FIXT_BEGIN_STRING, fixt11_messages::ApplVerID, FixSessionConfig,
+    FixSessionId, SessionRole, SessionSchedule, SessionSettingsError, StandardHeader, TimeZone,
+    UtcTimeOnly, Weekday,
+};
+
+const DEFAULT_SECTION: &str = "DEFAULT";
+const SESSION_SECTION: &str = "SESSION";
+
+/// Sessions of a QuickFIX-style configuration file.
+///
+/// The file consists of one `[DEFAULT]` section and `[SESSION]` sections with
+/// `Key=Value` lines. Every session inherits the keys of `[DEFAULT]` and may override
+/// them. Lines starting with `#` or `;` are comments.
+///
+/// ```ini
+/// [DEFAULT]
+/// ConnectionType=initiator
+/// HeartBtInt=30
+/// FileStorePath=store
+///
+/// [SESSION]
+/// BeginString=FIX.4.4
+/// SenderCompID=CLIENT
+/// TargetCompID=VENUE
+/// SocketConnectHost=127.0.0.1
+/// SocketConnectPort=9876
+/// ```
+#[derive(Debug, Clone)]
+pub struct SessionSettings {
+    defaults: BTreeMap<String, String>,
+    sessions: Vec<SessionSettingsEntry>,
+}
+
+impl SessionSettings {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SessionSettingsError> {
+        let src = std::fs::read_to_string(path)?;
+        Self::parse(&src)
+    }
+
+    pub fn parse(src: &str) -> Result<Self, SessionSettingsError> {
+        let mut defaults = BTreeMap::new();
+        let mut sections: Vec<BTreeMap<String, String>> = Vec::new();
+        let mut in_defaults = None;
+
+        for (index, line) in src.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+
+            if let Some(name) = line
+                .strip_prefix('[')
+                .and_then(|line| line.strip_suffix(']'))
+            {
+                let name = name.trim();
+
+                if name.eq_ignore_ascii_case(DEFAULT_SECTION) {
+                    in_defaults = Some(true);
+                } else if name.eq_ignore_ascii_case(SESSION_SECTION) {
+                    in_defaults = Some(false);
+                    sections.push(BTreeMap::new());
+                } else {
+                    return Err(SessionSettingsError::UnknownSection {
+                        line: index + 1,
+                        name: name.to_string(),
+                    });
+                }
+
+                continue;
+            }
+
+            let (Some(in_defaults), Some((key, value))) = (in_defaults, line.split_once('='))
+            else {
+                return Err(SessionSettingsError::InvalidLine { line: index + 1 });
+            };
+
+            let key = key.trim();
+            if key.is_empty() {
+                return Err(SessionSettingsError::InvalidLine { line: index + 1 });
+            }
+
+            let section = if in_defaults {
+                &mut defaults
+            } else {
+                sections.last_mut().unwrap()
+            };
+
+            section.insert(key.to_string(), value.trim().to_string());
+        }
+
+        let mut sessions: Vec<SessionSettingsEntry> = Vec::with_capacity(sections.len());
+
+        for (index, section) in sections.into_iter().enumerate() {
+            let mut values = defaults.clone();
+            values.extend(section);
+
+            let entry = SessionSettingsEntry::new(format!("SESSION {}", index + 1), values)?;
+
+            if let Some(other) = sessions
+                .iter()
+                .find(|other| other.session_id == entry.session_id)
+            {
+                return Err(SessionSettingsError::DuplicateSession {
+                    section: entry.section,
+                    other_section: other.section.clone(),
+                });
+            }
+
+            sessions.push(entry);
+        }
+
+        Ok(Self { defaults, sessions })
+    }
+
+    /// Value of the `[DEFAULT]` section
+    pub fn get_default(&self, key: &str) -> Option<&str> {
+        self.defaults.get(key).map(|value| value.as_str())
+    }
+
+    /// Sessions in the order of the file
+    pub fn get_sessions(&self) -> &[SessionSettingsEntry] {
+        &self.sessions
+    }
+
+    pub fn get_session(&self, session_id: &FixSessionId) -> Option<&SessionSettingsEntry> {
+        self.sessions
+            .iter()
+            .find(|entry| &entry.session_id == session_id)
+    }
+}
+
+/// Settings of one `[SESSION]` section merged with `[DEFAULT]`.
+///
+/// Known keys are validated while loading. All the keys, including the ones this crate
+/// does not use, are available through [`Self::get_value`].
+#[derive(Debug, Clone)]
+pub struct SessionSettingsEntry {
+    section: String,
+    values: BTreeMap<String, String>,
+    role: SessionRole,
+    session_id: FixSessionId,
+    heart_bt_int: u32,
+    socket_connect_port: Option<u16>,
+    socket_accept_port: Option<u16>,
+    schedule: Option<SessionSchedule>,
+    reset_on_logon: bool,
+    reset_on_logout: bool,
+    reset_on_disconnect: bool,
+    logon_timeout: Duration,
+    logout_timeout: Duration,
+    default_appl_ver_id: Option<ApplVerID>,
+}
+
+impl SessionSettingsEntry {
+    fn new(
+        section: String,
+        values: BTreeMap<String, String>,
+    ) -> Result<Self, SessionSettingsError> {
+        let src = Section {
+            name: &section,
+            values: &values,
+        };
+
+        let role = match src.get_required("ConnectionType")? {
+            "initiator" => SessionRole::Initiator,
+            "acceptor" => SessionRole::Acceptor,
+            _ => return Err(src.invalid_value("ConnectionType")),
+        };
+
+        let session_id = FixSessionId::new(
+            src.get_required("BeginString")?,
+            src.get_required("SenderCompID")?,
+            src.get_required("TargetCompID")?,
+        );
+
+        let socket_connect_port = src.parse_value("SocketConnectPort", parse_port)?;
+        let socket_accept_port = src.parse_value("SocketAcceptPort", parse_port)?;
+
+        match role {
+            SessionRole::Initiator => {
+                src.get_required("SocketConnectHost")?;
+                if socket_connect_port.is_none() {
+                    return Err(src.missing_key("SocketConnectPort"));
+                }
+            }
+            SessionRole::Acceptor => {
+                if socket_accept_port.is_none() {
+                    return Err(src.missing_key("SocketAcceptPort"));
+                }
+            }
+        }
+
+        let default_appl_ver_id = src.parse_value("DefaultApplVerID", parse_appl_ver_id)?;
+
+        if role == SessionRole::Initiator
+            && session_id.get_begin_string() == FIXT_BEGIN_STRING
+            && default_appl_ver_id.is_none()
+        {
+            return Err(src.missing_key("DefaultApplVerID"));
+        }
+
+        let start_time = src.parse_value("StartTime", UtcTimeOnly::parse)?;
+        let end_time = src.parse_value("EndTime", UtcTimeOnly::parse)?;
+
+        let start_day = src.parse_value("StartDay", Weekday::parse)?;
+        let end_day = src.parse_value("EndDay", Weekday::parse)?;
+
+        let schedule = match (start_time, end_time, start_day, end_day) {
+            (None, None, None, None) => None,
+            (Some(start_time), Some(end_time), None, None) => {
+                Some(SessionSchedule::daily(start_time, end_time))
+            }
+            (Some(start_time), Some(end_time), Some(start_day), Some(end_day)) => Some(
+                SessionSchedule::weekly(start_day, start_time, end_day, end_time),
+            ),
+            (None, _, _, _) => return Err(src.missing_key("StartTime")),
+            (_, None, _, _) => return Err(src.missing_key("EndTime")),
+            (_, _, None, _) => return Err(src.missing_key("StartDay")),
+            (_, _, _, None) => return Err(src.missing_key("EndDay")),
+        };
+
+        let time_zone = src.parse_value("TimeZone", |name| TimeZone::load(name).ok())?;
+        let schedule = match (schedule, time_zone) {
+            (Some(schedule), Some(time_zone)) => Some(schedule.with_time_zone(time_zone)),
+            (schedule, _) => schedule,
+        };
+
+        Ok(Self {
+            role,
+            session_id,
+            heart_bt_int: src
+                .parse_value("HeartBtInt", |value| value.parse().ok())?
+                .unwrap_or(FixSessionConfig::DEFAULT_HEART_BT_INT),
+            socket_connect_port,
+            socket_accept_port,
+            schedule,
+            reset_on_logon: src.parse_flag("ResetOnLogon")?,
+            reset_on_logout: src.parse_flag("ResetOnLogout")?,
+            reset_on_disconnect: src.parse_flag("ResetOnDisconnect")?,
+            logon_timeout: src
+                .parse_value("LogonTimeout", parse_seconds)?
+                .unwrap_or(FixSessionConfig::DEFAULT_LOGON_TIMEOUT),
+            logout_timeout: src
+                .parse_value("LogoutTimeout", parse_seconds)?
+                .unwrap_or(FixSessionConfig::DEFAULT_LOGOUT_TIMEOUT),
+            default_appl_ver_id,
+            section,
+            values,
+        })
+    }
+
+    /// `SESSION 1`, `SESSION 2`, ... in the order of the file
+    pub fn get_section(&self) -> &str {
+        &self.section
+    }
+
+    /// Any value of the section or of `[DEFAULT]`
+    pub fn get_value(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(|value| value.as_str())
+    }
+
+    /// `ConnectionType`
+    pub fn get_role(&self) -> SessionRole {
+        self.role
+    }
+
+    /// `BeginString`, `SenderCompID` and `TargetCompID`
+    pub fn get_session_id(&self) -> &FixSessionId {
+        &self.session_id
+    }
+
+    pub fn get_sender_sub_id(&self) -> Option<&str> {
+        self.get_value("SenderSubID")
+    }
+
+    pub fn get_sender_location_id(&self) -> Option<&str> {
+        self.get_value("SenderLocationID")
+    }
+
+    pub fn get_target_sub_id(&self) -> Option<&str> {
+        self.get_value("TargetSubID")
+    }
+
+    pub fn get_target_location_id(&self) -> Option<&str> {
+        self.get_value("TargetLocationID")
+    }
+
+    pub fn get_heart_bt_int(&self) -> u32 {
+        self.heart_bt_int
+    }
+
+    /// `SocketConnectHost` of an initiator
+    pub fn get_socket_connect_host(&self) -> Option<&str> {
+        self.get_value("SocketConnectHost")
+    }
+
+    /// `SocketConnectPort` of an initiator
+    pub fn get_socket_connect_port(&self) -> Option<u16> {
+        self.socket_connect_port
+    }
+
+    /// `SocketAcceptPort` of an acceptor
+    pub fn get_socket_accept_port(&self) -> Option<u16> {
+        self.socket_accept_port
+    }
+
+    /// Directory of [`crate::FileMessageStore`] files
+    pub fn get_file_store_path(&self) -> Option<PathBuf> {
+        self.get_value("FileStorePath").map(PathBuf::from)
+    }
+
+    /// `StartTime` and `EndTime` are either both set or both missing
+    pub fn get_start_time(&self) -> Option<UtcTimeOnly> {
+        self.schedule
+            .as_ref()
+            .map(|schedule| schedule.get_start_time())
+    }
+
+    pub fn get_end_time(&self) -> Option<UtcTimeOnly> {
+        self.schedule
+            .as_ref()
+            .map(|schedule| schedule.get_end_time())
+    }
+
+    /// `StartTime` and `EndTime`, with `StartDay` and `EndDay` for weekly sessions, in
+    /// `TimeZone` (UTC by default)
+    pub fn get_schedule(&self) -> Option<&SessionSchedule> {
+        self.schedule.as_ref()
+    }
+
+    pub fn get_reset_on_logon(&self) -> bool {
+        self.reset_on_logon
+    }
+
+    pub fn get_reset_on_logout(&self) -> bool {
+        self.reset_on_logout
+    }
+
+    pub fn get_reset_on_disconnect(&self) -> bool {
+        self.reset_on_disconnect
+    }
+
+    pub fn get_logon_timeout(&self) -> Duration {
+        self.logon_timeout
+    }
+
+    pub fn get_logout_timeout(&self) -> Duration {
+        self.logout_timeout
+    }
+
+    /// `DefaultApplVerID` of FIXT.1.1 sessions, required for initiators
+    pub fn get_default_appl_ver_id(&self) -> Option<&ApplVerID> {
+        self.default_appl_ver_id.as_ref()
+    }
+
+    /// `DataDictionary` of FIX.4.x sessions
+    pub fn get_data_dictionary(&self) -> Option<PathBuf> {
+        self.get_value("DataDictionary").map(PathBuf::from)
+    }
+
+    /// `TransportDataDictionary` of FIXT.1.1 sessions
+    pub fn get_transport_data_dictionary(&self) -> Option<PathBuf> {
+        self.get_value("TransportDataDictionary").map(PathBuf::from)
+    }
+
+    /// `AppDataDictionary` of FIXT.1.1 sessions
+    pub fn get_app_data_dictionary(&self) -> Option<PathBuf> {
+        self.get_value("AppDataDictionary").map(PathBuf::from)
+    }
+
+    /// CompIDs, SubIDs and LocationIDs of the section
+    pub fn to_standard_header(&self) -> StandardHeader {
+        let mut header = StandardHeader::new(
+            self.session_id.get_sender_comp_id(),
+            self.session_id.get_target_comp_id(),
+        );
+
+        if let Some(value) = self.get_sender_sub_id() {
+            header = header.with_sender_sub_id(value);
+        }
+        if let Some(value) = self.get_sender_location_id() {
+            header = header.with_sender_location_id(value);
+        }
+        if let Some(value) = self.get_target_sub_id() {
+            header = header.with_target_sub_id(value);
+        }
+        if let Some(value) = self.get_target_location_id() {
+            header = header.with_target_location_id(value);
+        }
+
+        header
+    }
+
+    /// Session parameters of the section. Host, port, store and dictionary paths are used
+    /// to create the transport, store and validator.
+    pub fn to_session_config(&self) -> FixSessionConfig {
+        let config = FixSessionConfig::new(
+            self.role,
+            self.session_id.get_begin_string(),
+            self.session_id.get_sender_comp_id(),
+            self.session_id.get_target_comp_id(),
+        )
+        .with_standard_header(self.to_standard_header())
+        .with_heart_bt_int(self.heart_bt_int)
+        .with_logon_timeout(self.logon_timeout)
+        .with_logout_timeout(self.logout_timeout)
+        .with_reset_on_logon(self.reset_on_logon)
+        .with_reset_on_logout(self.reset_on_logout)
+        .with_reset_on_disconnect(self.reset_on_disconnect);
+
+        let config = match &self.schedule {
+            Some(schedule) => config.with_schedule(schedule.clone()),
+            None => config,
+        };
+
+        match &self.default_appl_ver_id {
+            Some(appl_ver_id) => config.with_default_appl_ver_id(appl_ver_id.clone()),
+            None => config,
+        }
+    }
+}
+
+// Section being validated, so the errors name it
+struct Section<'s> {
+    name: &'s str,
+    values: &'s BTreeMap<String, String>,
+}
+
+impl Section<'_> {
+    fn get_value(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(|value| value.as_str())
+    }
+
+    fn get_required(&self, key: &'static str) -> Result<&str, SessionSettingsError> {
+        match self.get_value(key) {
+            Some(value) if !value.is_empty() => Ok(value),
+            _ => Err(self.missing_key(key)),
+        }
+    }
+
+    fn parse_value<T>(
+        &self,
+        key: &'static str,
+        parse: impl Fn(&str) -> Option<T>,
+    ) -> Result<Option<T>, SessionSettingsError> {
+        match self.get_value(key) {
+            Some(value) => parse(value)
+                .map(Some)
+                .ok_or_else(|| self.invalid_value(key)),
+            None => Ok(None),
+        }
+    }
+
+    fn parse_flag(&self, key: &'static str) -> Result<bool, SessionSettingsError> {
+        let flag = self.parse_value(key, |value| match value {
+            "Y" => Some(true),
+            "N" => Some(false),
+            _ => None,
+        })?;
+
+        Ok(flag.unwrap_or(false))
+    }
+
+    fn missing_key(&self, key: &'static str) -> SessionSettingsError {
+        SessionSettingsError::MissingKey {
+            section: self.name.to_string(),
+            key,
+        }
+    }
+
+    fn invalid_value(&self, key: &'static str) -> SessionSettingsError {
+        SessionSettingsError::InvalidValue {
+            section: self.name.to_string(),
+            key,
+            value: self.get_value(key).unwrap_or_default().to_string(),
+        }
+    }
+}
+
+fn parse_port(value: &str) -> Option<u16> {
+    value.parse().ok().filter(|port| *port > 0)
+}
+
+fn parse_seconds(value: &str) -> Option<Duration> {
+    value.parse().ok().map(Duration::from_secs)
+}
+
+// Same as QuickFIX: BeginString of the version or the ApplVerID (1128) value
+fn parse_appl_ver_id(value: &str) -> Option<ApplVerID> {
+    let appl_ver_id = match value {
+        "FIX.2.7" => ApplVerID::Fix27,
+        "FIX.3.0" => ApplVerID::Fix30,
+        "FIX.4.0" => ApplVerID::Fix40,
+        "FIX.4.1" => ApplVerID::Fix41,
+        "FIX.4.2" => ApplVerID::Fix42,
+        "FIX.4.3" => ApplVerID::Fix43,
+        "FIX.4.4" => ApplVerID::Fix44,
+        "FIX.5.0" => ApplVerID::Fix50,
+        "FIX.5.0SP1" => ApplVerID::Fix50sp1,
+        "FIX.5.0SP2" => ApplVerID::Fix50sp2,
+        _ => ApplVerID::try_from(value).ok()?,
+    };
+
+    match appl_ver_id {
+        ApplVerID::Other(_) => None,
+        appl_ver_id => Some(appl_ver_id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        fixt11_messages::ApplVerID, FixSessionId, SessionRole, SessionSettings,
+        SessionSettingsError, UtcTimeOnly, Weekday,
+    };
+
+    const SETTINGS: &str = "
+# Shared by all the sessions
+[DEFAULT]
+ConnectionType=initiator
+HeartBtInt=30
+FileStorePath=store
+StartTime=08:00:00
+EndTime=17:30:00
+ResetOnLogon=Y
+
+[SESSION]
+BeginString=FIX.4.4
+SenderCompID=CLIENT
+SenderSubID=DESK1
+TargetCompID=VENUE
+SocketConnectHost=127.0.0.1
+SocketConnectPort=9876
+DataDictionary=spec/FIX44.xml
+ResetOnLogout=Y
+
+[SESSION]
+ConnectionType=acceptor
+BeginString=FIXT.1.1
+SenderCompID=VENUE
+TargetCompID=CLIENT
+SocketAcceptPort=9877
+HeartBtInt=60
+DefaultApplVerID=FIX.5.0SP2
+ResetOnLogon=N
+LogonTimeout=5
+TransportDataDictionary=spec/FIXT11.xml
+AppDataDictionary=spec/FIX50SP2.xml
+";
+
+    #[test]
+    fn test_sessions_inherit_defaults() {
+        let settings = SessionSettings::parse(SETTINGS).unwrap();
+        assert_eq!(Some("store"), settings.get_default("FileStorePath"));
+        assert_eq!(2, settings.get_sessions().len());
+
+        let client = settings
+            .get_session(&FixSessionId::new("FIX.4.4", "CLIENT", "VENUE"))
+            .unwrap();
+        assert_eq!("SESSION 1", client.get_section());
+        assert_eq!(SessionRole::Initiator, client.get_role());
+        assert_eq!(Some("DESK1"), client.get_sender_sub_id());
+        assert_eq!(None, client.get_target_sub_id());
+        assert_eq!(Some("127.0.0.1"), client.get_socket_connect_host());
+        assert_eq!(Some(9876), client.get_socket_connect_port());
+        assert_eq!(Some("store".into()), client.get_file_store_path());
+        assert_eq!(UtcTimeOnly::new(8, 0, 0, 0), client.get_start_time());
+        assert_eq!(UtcTimeOnly::new(17, 30, 0, 0), client.get_end_time());
+        assert!(client.get_reset_on_logon());
+        assert_eq!(Some("spec/FIX44.xml".into()), client.get_data_dictionary());
+
+        let config = client.to_session_config();
+        assert_eq!("CLIENT", config.get_sender_comp_id());
+        assert_eq!(
+            Some("DESK1"),
+            config.get_standard_header().get_sender_sub_id()
+        );
+        assert_eq!(30, config.get_heart_bt_int());
+        assert!(config.get_reset_on_logon());
+        assert!(config.get_reset_on_logout());
+        assert!(!config.get_reset_on_disconnect());
+
+        let schedule = config.get_schedule().unwrap();
+        assert_eq!(None, schedule.get_days());
+        assert_eq!("UTC", schedule.get_time_zone().get_name());
+
+        let venue = &settings.get_sessions()[1];
+        assert_eq!(SessionRole::Acceptor, venue.get_role());
+        assert_eq!(Some(9877), venue.get_socket_accept_port());
+        assert_eq!(60, venue.get_heart_bt_int());
+        assert!(!venue.get_reset_on_logon());
+        assert_eq!(Duration::from_secs(5), venue.get_logon_timeout());
+        assert_eq!(
+            Some(&ApplVerID::Fix50sp2),
+            venue.to_session_config().get_default_appl_ver_id()
+        );
+        assert_eq!(
+            Some("spec/FIX50SP2.xml".into()),
+            venue.get_app_data_dictionary()
+        );
+        assert_eq!(Some("store"), venue.get_value("FileStorePath"));
+    }
+
+    #[test]
+    fn test_validation_errors_name_section_and_key() {
+        let err =
+            SessionSettings::parse(&SETTINGS.replace("SocketAcceptPort=9877", "")).unwrap_err();
+        assert_eq!(Some("SESSION 2"), err.get_section());
+        assert_eq!(Some("SocketAcceptPort"), err.get_key());
+
+        let err = SessionSettings::parse(&SETTINGS.replace("HeartBtInt=60", "HeartBtInt=sixty"))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            SessionSettingsError::InvalidValue { section, key: "HeartBtInt", value }
+                if section == "SESSION 2" && value == "sixty"
+        ));
+
+        let err = SessionSettings::parse(&SETTINGS.replace("EndTime=17:30:00", "")).unwrap_err();
+        assert_eq!(Some("EndTime"), err.get_key());
+
+        let err = SessionSettings::parse(&SETTINGS.replace("ResetOnLogon=N", "StartDay=Sunday"))
+            .unwrap_err();
+        assert_eq!(Some("EndDay"), err.get_key());
+
+        let err = SessionSettings::parse(&SETTINGS.replace("ResetOnLogon=N", "TimeZone=Mars/Base"))
+            .unwrap_err();
+        assert_eq!(Some("TimeZone"), err.get_key());
+
+        let err = SessionSettings::parse(&SETTINGS.replace("ResetOnLogon=Y", "ResetOnLogon=yes"))
+            .unwrap_err();
+        assert_eq!(Some("ResetOnLogon"), err.get_key());
+
+        let err = SessionSettings::parse(
+            &SETTINGS.replace("DefaultApplVerID=FIX.5.0SP2", "DefaultApplVerID=FIX.9"),
+        )
+        .unwrap_err();
+        assert_eq!(Some("DefaultApplVerID"), err.get_key());
+
+        // FIXT.1.1 initiators have to send DefaultApplVerID in Logon
+        let err = SessionSettings::parse(
+            &SETTINGS
+                .replace(
+                    "ConnectionType=acceptor",
+                    "SocketConnectHost=h\nSocketConnectPort=1",
+                )
+                .replace("DefaultApplVerID=FIX.5.0SP2", ""),
+        )
+        .unwrap_err();
+        assert_eq!(Some("DefaultApplVerID"), err.get_key());
+
+        let err = SessionSettings::parse(
+            &SETTINGS
+                .replace("FIXT.1.1", "FIX.4.4")
+                .replace("SenderCompID=VENUE", "SenderCompID=CLIENT")
+                .replace("TargetCompID=CLIENT", "TargetCompID=VENUE"),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            SessionSettingsError::DuplicateSession { section, other_section }
+                if section == "SESSION 2" && other_section == "SESSION 1"
+        ));
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        assert!(matches!(
+            SessionSettings::parse("[DEFAULT]\nHeartBtInt"),
+            Err(SessionSettingsError::InvalidLine { line: 2 })
+        ));
+        assert!(matches!(
+            SessionSettings::parse("HeartBtInt=30"),
+            Err(SessionSettingsError::InvalidLine { line: 1 })
+        ));
+        assert!(matches!(
+            SessionSettings::parse("[DEFAULT]\n\n[SESSIONS]"),
+            Err(SessionSettingsError::UnknownSection { line: 3, .. })
+        ));
+        assert!(matches!(
+            SessionSettings::load("/not/existing/settings.cfg"),
+            Err(SessionSettingsError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_weekly_schedule() {
+        let settings = SessionSettings::parse(&SETTINGS.replace(
+            "ResetOnLogon=N",
+            "StartDay=sun\nEndDay=Friday\nTimeZone=UTC",
+        ))
+        .unwrap();
+
+        let schedule = settings.get_sessions()[1].get_schedule().unwrap();
+        assert_eq!(
+            Some((Weekday::Sunday, Weekday::Friday)),
+            schedule.get_days()
+        );
+        assert_eq!(
+            UtcTimeOnly::new(8, 0, 0, 0),
+            Some(schedule.get_start_time())
+        );
+    }
+}
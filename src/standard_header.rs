@@ -0,0 +1,301 @@
+use crate::{tags, FixMessageBodyBuilder, FixMessageWriter, Tag, UtcTimestamp};
+
+/// Routing fields of the StandardHeader which are the same for every outbound message of a
+/// session. `stamp` inserts them with MsgSeqNum (34) and SendingTime (52) right after
+/// MsgType (35) in the order of the spec:
+///
+/// `49 56 115 128 34 50 142 57 143 116 144 129 145 43 52 122`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StandardHeader {
+    sender_comp_id: String,
+    target_comp_id: String,
+    on_behalf_of_comp_id: Option<String>,
+    deliver_to_comp_id: Option<String>,
+    sender_sub_id: Option<String>,
+    sender_location_id: Option<String>,
+    target_sub_id: Option<String>,
+    target_location_id: Option<String>,
+    on_behalf_of_sub_id: Option<String>,
+    on_behalf_of_location_id: Option<String>,
+    deliver_to_sub_id: Option<String>,
+    deliver_to_location_id: Option<String>,
+}
+
+impl StandardHeader {
+    pub fn new(sender_comp_id: impl Into<String>, target_comp_id: impl Into<String>) -> Self {
+        Self {
+            sender_comp_id: sender_comp_id.into(),
+            target_comp_id: target_comp_id.into(),
+            on_behalf_of_comp_id: None,
+            deliver_to_comp_id: None,
+            sender_sub_id: None,
+            sender_location_id: None,
+            target_sub_id: None,
+            target_location_id: None,
+            on_behalf_of_sub_id: None,
+            on_behalf_of_location_id: None,
+            deliver_to_sub_id: None,
+            deliver_to_location_id: None,
+        }
+    }
+
+    /// OnBehalfOfCompID (115) of the firm the messages are sent for through a third party
+    pub fn with_on_behalf_of_comp_id(mut self, value: impl Into<String>) -> Self {
+        self.on_behalf_of_comp_id = Some(value.into());
+        self
+    }
+
+    /// DeliverToCompID (128) of the firm the third party delivers the messages to
+    pub fn with_deliver_to_comp_id(mut self, value: impl Into<String>) -> Self {
+        self.deliver_to_comp_id = Some(value.into());
+        self
+    }
+
+    pub fn with_sender_sub_id(mut self, value: impl Into<String>) -> Self {
+        self.sender_sub_id = Some(value.into());
+        self
+    }
+
+    pub fn with_sender_location_id(mut self, value: impl Into<String>) -> Self {
+        self.sender_location_id = Some(value.into());
+        self
+    }
+
+    pub fn with_target_sub_id(mut self, value: impl Into<String>) -> Self {
+        self.target_sub_id = Some(value.into());
+        self
+    }
+
+    pub fn with_target_location_id(mut self, value: impl Into<String>) -> Self {
+        self.target_location_id = Some(value.into());
+        self
+    }
+
+    pub fn with_on_behalf_of_sub_id(mut self, value: impl Into<String>) -> Self {
+        self.on_behalf_of_sub_id = Some(value.into());
+        self
+    }
+
+    pub fn with_on_behalf_of_location_id(mut self, value: impl Into<String>) -> Self {
+        self.on_behalf_of_location_id = Some(value.into());
+        self
+    }
+
+    pub fn with_deliver_to_sub_id(mut self, value: impl Into<String>) -> Self {
+        self.deliver_to_sub_id = Some(value.into());
+        self
+    }
+
+    pub fn with_deliver_to_location_id(mut self, value: impl Into<String>) -> Self {
+        self.deliver_to_location_id = Some(value.into());
+        self
+    }
+
+    pub fn get_sender_comp_id(&self) -> &str {
+        &self.sender_comp_id
+    }
+
+    pub fn get_target_comp_id(&self) -> &str {
+        &self.target_comp_id
+    }
+
+    pub fn get_on_behalf_of_comp_id(&self) -> Option<&str> {
+        self.on_behalf_of_comp_id.as_deref()
+    }
+
+    pub fn get_deliver_to_comp_id(&self) -> Option<&str> {
+        self.deliver_to_comp_id.as_deref()
+    }
+
+    pub fn get_sender_sub_id(&self) -> Option<&str> {
+        self.sender_sub_id.as_deref()
+    }
+
+    pub fn get_sender_location_id(&self) -> Option<&str> {
+        self.sender_location_id.as_deref()
+    }
+
+    pub fn get_target_sub_id(&self) -> Option<&str> {
+        self.target_sub_id.as_deref()
+    }
+
+    pub fn get_target_location_id(&self) -> Option<&str> {
+        self.target_location_id.as_deref()
+    }
+
+    pub fn get_on_behalf_of_sub_id(&self) -> Option<&str> {
+        self.on_behalf_of_sub_id.as_deref()
+    }
+
+    pub fn get_on_behalf_of_location_id(&self) -> Option<&str> {
+        self.on_behalf_of_location_id.as_deref()
+    }
+
+    pub fn get_deliver_to_sub_id(&self) -> Option<&str> {
+        self.deliver_to_sub_id.as_deref()
+    }
+
+    pub fn get_deliver_to_location_id(&self) -> Option<&str> {
+        self.deliver_to_location_id.as_deref()
+    }
+
+    /// Inserts the header fields right after MsgType (35) and ApplVerID (1128) of the message
+    pub fn stamp(&self, writer: &mut FixMessageWriter, seq_num: u64, sending_time: UtcTimestamp) {
+        self.stamp_fields(writer, seq_num, Some(sending_time), false);
+    }
+
+    // PossDup messages carry PossDupFlag (43) and OrigSendingTime (122) equal to SendingTime
+    pub(crate) fn stamp_fields(
+        &self,
+        writer: &mut FixMessageWriter,
+        seq_num: u64,
+        sending_time: Option<UtcTimestamp>,
+        poss_dup: bool,
+    ) {
+        let mut header = FixMessageBodyBuilder::new();
+        header.append(tags::SENDER_COMP_ID, self.sender_comp_id.as_str());
+        header.append(tags::TARGET_COMP_ID, self.target_comp_id.as_str());
+        append_optional(
+            &mut header,
+            tags::ON_BEHALF_OF_COMP_ID,
+            &self.on_behalf_of_comp_id,
+        );
+        append_optional(
+            &mut header,
+            tags::DELIVER_TO_COMP_ID,
+            &self.deliver_to_comp_id,
+        );
+        header.append(tags::MSG_SEQ_NUM, seq_num);
+        append_optional(&mut header, tags::SENDER_SUB_ID, &self.sender_sub_id);
+        append_optional(
+            &mut header,
+            tags::SENDER_LOCATION_ID,
+            &self.sender_location_id,
+        );
+        append_optional(&mut header, tags::TARGET_SUB_ID, &self.target_sub_id);
+        append_optional(
+            &mut header,
+            tags::TARGET_LOCATION_ID,
+            &self.target_location_id,
+        );
+        append_optional(
+            &mut header,
+            tags::ON_BEHALF_OF_SUB_ID,
+            &self.on_behalf_of_sub_id,
+        );
+        append_optional(
+            &mut header,
+            tags::ON_BEHALF_OF_LOCATION_ID,
+            &self.on_behalf_of_location_id,
+        );
+        append_optional(
+            &mut header,
+            tags::DELIVER_TO_SUB_ID,
+            &self.deliver_to_sub_id,
+        );
+        append_optional(
+            &mut header,
+            tags::DELIVER_TO_LOCATION_ID,
+            &self.deliver_to_location_id,
+        );
+
+        if poss_dup {
+            header.append(tags::POSS_DUP_FLAG, true);
+        }
+
+        if let Some(sending_time) = sending_time {
+            header.append(tags::SENDING_TIME, sending_time);
+
+            if poss_dup {
+                header.append(tags::ORIG_SENDING_TIME, sending_time);
+            }
+        }
+
+        writer.insert_header(&header);
+    }
+}
+
+/// Fields written after the body, right before CheckSum (10)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StandardTrailer {
+    signature: Option<Vec<u8>>,
+}
+
+impl StandardTrailer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Signature (89). SignatureLength (93) is written before it automatically.
+    pub fn with_signature(mut self, signature: impl Into<Vec<u8>>) -> Self {
+        self.signature = Some(signature.into());
+        self
+    }
+
+    pub fn get_signature(&self) -> Option<&[u8]> {
+        self.signature.as_deref()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.signature.is_none()
+    }
+
+    pub(crate) fn write_to(&self, body: &mut FixMessageBodyBuilder) {
+        if let Some(signature) = &self.signature {
+            body.append(tags::SIGNATURE_LENGTH, signature.len());
+            body.append_data(tags::SIGNATURE, signature);
+        }
+    }
+}
+
+fn append_optional(header: &mut FixMessageBodyBuilder, tag: Tag, value: &Option<String>) {
+    if let Some(value) = value {
+        header.append(tag, value.as_str());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fixt11_messages::ApplVerID, tags, FixMessageReader, FixMessageWriter, StandardHeader,
+        StandardTrailer, UtcTimestamp,
+    };
+
+    #[test]
+    fn test_header_fields_are_in_spec_order() {
+        let header = StandardHeader::new("CLIENT", "VENUE")
+            .with_target_sub_id("DESK")
+            .with_sender_sub_id("TRADER")
+            .with_deliver_to_comp_id("BROKER")
+            .with_on_behalf_of_comp_id("FUND");
+
+        let mut writer = FixMessageWriter::new("FIXT.1.1", "D");
+        writer.with_value(tags::CL_ORD_ID, "A");
+        writer.with_appl_ver_id(&ApplVerID::Fix50sp2);
+
+        let sending_time = UtcTimestamp::new(2024, 1, 2, 10, 0, 0, 0).unwrap();
+        header.stamp(&mut writer, 7, sending_time);
+
+        assert_eq!(
+            "8=FIXT.1.1|9=100|35=D|1128=9|49=CLIENT|56=VENUE|115=FUND|128=BROKER|34=7|50=TRADER|57=DESK|52=20240102-10:00:00|11=A|10=191|",
+            writer.to_string()
+        );
+    }
+
+    #[test]
+    fn test_signature_trailer() {
+        let mut writer = FixMessageWriter::new("FIX.4.4", "0");
+        writer.set_trailer(StandardTrailer::new().with_signature("SIGNED"));
+        writer.with_value(tags::TEST_REQ_ID, "T");
+
+        let message = writer.compile_message();
+        FixMessageReader::from_bytes(&message)
+            .check_payload()
+            .unwrap();
+
+        assert_eq!(
+            "8=FIX.4.4|9=26|35=0|112=T|93=6|89=SIGNED|10=069|",
+            writer.to_string()
+        );
+    }
+}
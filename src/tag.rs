@@ -0,0 +1,117 @@
+use std::{fmt, io::Write, str::FromStr};
+
+use crate::FixSerializeError;
+
+/// Number of a FIX field. Standard tags are declared in [`crate::tags`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Tag(pub u32);
+
+impl Tag {
+    pub const fn new(value: u32) -> Self {
+        Self(value)
+    }
+
+    pub fn get_value(&self) -> u32 {
+        self.0
+    }
+
+    pub fn get_name(&self) -> Option<&'static str> {
+        crate::tags::get_tag_name(*self)
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u32> for Tag {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl FromStr for Tag {
+    type Err = FixSerializeError;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        if src.is_empty() {
+            return Err(FixSerializeError::EmptyTag { offset: 0 });
+        }
+
+        if !src.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(FixSerializeError::NonNumericTag { offset: 0 });
+        }
+
+        match src.parse() {
+            Ok(value) => Ok(Self(value)),
+            Err(_) => Err(FixSerializeError::NonNumericTag { offset: 0 }),
+        }
+    }
+}
+
+/// Anything which can be used as a tag by the writer, builder and reader APIs.
+///
+/// Only [`Tag`] implements it, so a misspelled constant from [`crate::tags`] does not compile.
+/// Numbers which are not declared there, e.g. user defined tags, are wrapped explicitly:
+/// `Tag(5001)` or `"5001".parse::<Tag>()`.
+///
+/// ```compile_fail
+/// let mut writer = rust_fix::FixMessageWriter::new("FIX.4.4", "D");
+/// writer.with_value("94", "ORDER1");
+/// ```
+pub trait AsFixTag {
+    fn write_fix_tag(&self, out: &mut Vec<u8>);
+    fn is_same_tag(&self, key: &str) -> bool;
+}
+
+impl AsFixTag for Tag {
+    fn write_fix_tag(&self, out: &mut Vec<u8>) {
+        let _ = write!(out, "{}", self.0);
+    }
+
+    fn is_same_tag(&self, key: &str) -> bool {
+        match key.parse::<Tag>() {
+            Ok(tag) => tag == *self,
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tags, AsFixTag, Tag};
+
+    #[test]
+    fn test_parse_tag() {
+        assert_eq!(tags::MSG_SEQ_NUM, "34".parse::<Tag>().unwrap());
+        assert!("".parse::<Tag>().unwrap_err().is_empty_tag());
+        assert!("3a".parse::<Tag>().unwrap_err().is_non_numeric_tag());
+        assert!("+34".parse::<Tag>().unwrap_err().is_non_numeric_tag());
+        assert!("99999999999"
+            .parse::<Tag>()
+            .unwrap_err()
+            .is_non_numeric_tag());
+    }
+
+    #[test]
+    fn test_write_tag() {
+        let mut out = Vec::new();
+        tags::SENDER_COMP_ID.write_fix_tag(&mut out);
+
+        assert_eq!(b"49", out.as_slice());
+    }
+
+    #[test]
+    fn test_is_same_tag() {
+        assert!(tags::SENDER_COMP_ID.is_same_tag("49"));
+        assert!(!tags::SENDER_COMP_ID.is_same_tag("94"));
+    }
+
+    #[test]
+    fn test_tag_name() {
+        assert_eq!(Some("SendingTime"), tags::SENDING_TIME.get_name());
+        assert_eq!("52", tags::SENDING_TIME.to_string());
+    }
+}
@@ -0,0 +1,1669 @@
+//! Standard field tags defined by FIX.4.0 - FIX.5.0SP2, from Account (1) to
+//! RiskEncodedSecurityDesc (1621).
+//!
+//! Tags which were renamed between versions are declared once using the latest name.
+//! Numbers the specification never assigned (101, 261, 995, 1004, 1010, 1076 - 1078, 1233,
+//! 1238) are not declared.
+use crate::Tag;
+
+macro_rules! fix_tags {
+    ($($name:ident = $value:literal, $fix_name:literal;)*) => {
+        $(
+            #[doc = concat!($fix_name, " (", stringify!($value), ")")]
+            pub const $name: Tag = Tag($value);
+        )*
+
+        /// Name of the standard tag as it is written in the FIX specification
+        pub fn get_tag_name(tag: Tag) -> Option<&'static str> {
+            match tag.0 {
+                $($value => Some($fix_name),)*
+                _ => None,
+            }
+        }
+
+        pub fn get_tag_by_name(name: &str) -> Option<Tag> {
+            match name {
+                $($fix_name => Some($name),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+fix_tags! {
+    ACCOUNT = 1, "Account";
+    ADV_ID = 2, "AdvId";
+    ADV_REF_ID = 3, "AdvRefID";
+    ADV_SIDE = 4, "AdvSide";
+    ADV_TRANS_TYPE = 5, "AdvTransType";
+    AVG_PX = 6, "AvgPx";
+    BEGIN_SEQ_NO = 7, "BeginSeqNo";
+    BEGIN_STRING = 8, "BeginString";
+    BODY_LENGTH = 9, "BodyLength";
+    CHECK_SUM = 10, "CheckSum";
+    CL_ORD_ID = 11, "ClOrdID";
+    COMMISSION = 12, "Commission";
+    COMM_TYPE = 13, "CommType";
+    CUM_QTY = 14, "CumQty";
+    CURRENCY = 15, "Currency";
+    END_SEQ_NO = 16, "EndSeqNo";
+    EXEC_ID = 17, "ExecID";
+    EXEC_INST = 18, "ExecInst";
+    EXEC_REF_ID = 19, "ExecRefID";
+    EXEC_TRANS_TYPE = 20, "ExecTransType";
+    HANDL_INST = 21, "HandlInst";
+    SECURITY_ID_SOURCE = 22, "SecurityIDSource";
+    IOI_ID = 23, "IOIID";
+    IOI_OTH_SVC = 24, "IOIOthSvc";
+    IOI_QLTY_IND = 25, "IOIQltyInd";
+    IOI_REF_ID = 26, "IOIRefID";
+    IOI_QTY = 27, "IOIQty";
+    IOI_TRANS_TYPE = 28, "IOITransType";
+    LAST_CAPACITY = 29, "LastCapacity";
+    LAST_MKT = 30, "LastMkt";
+    LAST_PX = 31, "LastPx";
+    LAST_QTY = 32, "LastQty";
+    NO_LINES_OF_TEXT = 33, "NoLinesOfText";
+    MSG_SEQ_NUM = 34, "MsgSeqNum";
+    MSG_TYPE = 35, "MsgType";
+    NEW_SEQ_NO = 36, "NewSeqNo";
+    ORDER_ID = 37, "OrderID";
+    ORDER_QTY = 38, "OrderQty";
+    ORD_STATUS = 39, "OrdStatus";
+    ORD_TYPE = 40, "OrdType";
+    ORIG_CL_ORD_ID = 41, "OrigClOrdID";
+    ORIG_TIME = 42, "OrigTime";
+    POSS_DUP_FLAG = 43, "PossDupFlag";
+    PRICE = 44, "Price";
+    REF_SEQ_NUM = 45, "RefSeqNum";
+    RELATD_SYM = 46, "RelatdSym";
+    RULE80_A = 47, "Rule80A";
+    SECURITY_ID = 48, "SecurityID";
+    SENDER_COMP_ID = 49, "SenderCompID";
+    SENDER_SUB_ID = 50, "SenderSubID";
+    SENDING_DATE = 51, "SendingDate";
+    SENDING_TIME = 52, "SendingTime";
+    QUANTITY = 53, "Quantity";
+    SIDE = 54, "Side";
+    SYMBOL = 55, "Symbol";
+    TARGET_COMP_ID = 56, "TargetCompID";
+    TARGET_SUB_ID = 57, "TargetSubID";
+    TEXT = 58, "Text";
+    TIME_IN_FORCE = 59, "TimeInForce";
+    TRANSACT_TIME = 60, "TransactTime";
+    URGENCY = 61, "Urgency";
+    VALID_UNTIL_TIME = 62, "ValidUntilTime";
+    SETTL_TYPE = 63, "SettlType";
+    SETTL_DATE = 64, "SettlDate";
+    SYMBOL_SFX = 65, "SymbolSfx";
+    LIST_ID = 66, "ListID";
+    LIST_SEQ_NO = 67, "ListSeqNo";
+    TOT_NO_ORDERS = 68, "TotNoOrders";
+    LIST_EXEC_INST = 69, "ListExecInst";
+    ALLOC_ID = 70, "AllocID";
+    ALLOC_TRANS_TYPE = 71, "AllocTransType";
+    REF_ALLOC_ID = 72, "RefAllocID";
+    NO_ORDERS = 73, "NoOrders";
+    AVG_PX_PRECISION = 74, "AvgPxPrecision";
+    TRADE_DATE = 75, "TradeDate";
+    EXEC_BROKER = 76, "ExecBroker";
+    POSITION_EFFECT = 77, "PositionEffect";
+    NO_ALLOCS = 78, "NoAllocs";
+    ALLOC_ACCOUNT = 79, "AllocAccount";
+    ALLOC_QTY = 80, "AllocQty";
+    PROCESS_CODE = 81, "ProcessCode";
+    NO_RPTS = 82, "NoRpts";
+    RPT_SEQ = 83, "RptSeq";
+    CXL_QTY = 84, "CxlQty";
+    NO_DLVY_INST = 85, "NoDlvyInst";
+    DLVY_INST = 86, "DlvyInst";
+    ALLOC_STATUS = 87, "AllocStatus";
+    ALLOC_REJ_CODE = 88, "AllocRejCode";
+    SIGNATURE = 89, "Signature";
+    SECURE_DATA_LEN = 90, "SecureDataLen";
+    SECURE_DATA = 91, "SecureData";
+    BROKER_OF_CREDIT = 92, "BrokerOfCredit";
+    SIGNATURE_LENGTH = 93, "SignatureLength";
+    EMAIL_TYPE = 94, "EmailType";
+    RAW_DATA_LENGTH = 95, "RawDataLength";
+    RAW_DATA = 96, "RawData";
+    POSS_RESEND = 97, "PossResend";
+    ENCRYPT_METHOD = 98, "EncryptMethod";
+    STOP_PX = 99, "StopPx";
+    EX_DESTINATION = 100, "ExDestination";
+    CXL_REJ_REASON = 102, "CxlRejReason";
+    ORD_REJ_REASON = 103, "OrdRejReason";
+    IOI_QUALIFIER = 104, "IOIQualifier";
+    WAVE_NO = 105, "WaveNo";
+    ISSUER = 106, "Issuer";
+    SECURITY_DESC = 107, "SecurityDesc";
+    HEART_BT_INT = 108, "HeartBtInt";
+    CLIENT_ID = 109, "ClientID";
+    MIN_QTY = 110, "MinQty";
+    MAX_FLOOR = 111, "MaxFloor";
+    TEST_REQ_ID = 112, "TestReqID";
+    REPORT_TO_EXCH = 113, "ReportToExch";
+    LOCATE_REQD = 114, "LocateReqd";
+    ON_BEHALF_OF_COMP_ID = 115, "OnBehalfOfCompID";
+    ON_BEHALF_OF_SUB_ID = 116, "OnBehalfOfSubID";
+    QUOTE_ID = 117, "QuoteID";
+    NET_MONEY = 118, "NetMoney";
+    SETTL_CURR_AMT = 119, "SettlCurrAmt";
+    SETTL_CURRENCY = 120, "SettlCurrency";
+    FOREX_REQ = 121, "ForexReq";
+    ORIG_SENDING_TIME = 122, "OrigSendingTime";
+    GAP_FILL_FLAG = 123, "GapFillFlag";
+    NO_EXECS = 124, "NoExecs";
+    CXL_TYPE = 125, "CxlType";
+    EXPIRE_TIME = 126, "ExpireTime";
+    DK_REASON = 127, "DKReason";
+    DELIVER_TO_COMP_ID = 128, "DeliverToCompID";
+    DELIVER_TO_SUB_ID = 129, "DeliverToSubID";
+    IOI_NATURAL_FLAG = 130, "IOINaturalFlag";
+    QUOTE_REQ_ID = 131, "QuoteReqID";
+    BID_PX = 132, "BidPx";
+    OFFER_PX = 133, "OfferPx";
+    BID_SIZE = 134, "BidSize";
+    OFFER_SIZE = 135, "OfferSize";
+    NO_MISC_FEES = 136, "NoMiscFees";
+    MISC_FEE_AMT = 137, "MiscFeeAmt";
+    MISC_FEE_CURR = 138, "MiscFeeCurr";
+    MISC_FEE_TYPE = 139, "MiscFeeType";
+    PREV_CLOSE_PX = 140, "PrevClosePx";
+    RESET_SEQ_NUM_FLAG = 141, "ResetSeqNumFlag";
+    SENDER_LOCATION_ID = 142, "SenderLocationID";
+    TARGET_LOCATION_ID = 143, "TargetLocationID";
+    ON_BEHALF_OF_LOCATION_ID = 144, "OnBehalfOfLocationID";
+    DELIVER_TO_LOCATION_ID = 145, "DeliverToLocationID";
+    NO_RELATED_SYM = 146, "NoRelatedSym";
+    SUBJECT = 147, "Subject";
+    HEADLINE = 148, "Headline";
+    URL_LINK = 149, "URLLink";
+    EXEC_TYPE = 150, "ExecType";
+    LEAVES_QTY = 151, "LeavesQty";
+    CASH_ORDER_QTY = 152, "CashOrderQty";
+    ALLOC_AVG_PX = 153, "AllocAvgPx";
+    ALLOC_NET_MONEY = 154, "AllocNetMoney";
+    SETTL_CURR_FX_RATE = 155, "SettlCurrFxRate";
+    SETTL_CURR_FX_RATE_CALC = 156, "SettlCurrFxRateCalc";
+    NUM_DAYS_INTEREST = 157, "NumDaysInterest";
+    ACCRUED_INTEREST_RATE = 158, "AccruedInterestRate";
+    ACCRUED_INTEREST_AMT = 159, "AccruedInterestAmt";
+    SETTL_INST_MODE = 160, "SettlInstMode";
+    ALLOC_TEXT = 161, "AllocText";
+    SETTL_INST_ID = 162, "SettlInstID";
+    SETTL_INST_TRANS_TYPE = 163, "SettlInstTransType";
+    EMAIL_THREAD_ID = 164, "EmailThreadID";
+    SETTL_INST_SOURCE = 165, "SettlInstSource";
+    SETTL_LOCATION = 166, "SettlLocation";
+    SECURITY_TYPE = 167, "SecurityType";
+    EFFECTIVE_TIME = 168, "EffectiveTime";
+    STAND_INST_DB_TYPE = 169, "StandInstDbType";
+    STAND_INST_DB_NAME = 170, "StandInstDbName";
+    STAND_INST_DB_ID = 171, "StandInstDbID";
+    SETTL_DELIVERY_TYPE = 172, "SettlDeliveryType";
+    SETTL_DEPOSITORY_CODE = 173, "SettlDepositoryCode";
+    SETTL_BRKR_CODE = 174, "SettlBrkrCode";
+    SETTL_INST_CODE = 175, "SettlInstCode";
+    SECURITY_SETTL_AGENT_NAME = 176, "SecuritySettlAgentName";
+    SECURITY_SETTL_AGENT_CODE = 177, "SecuritySettlAgentCode";
+    SECURITY_SETTL_AGENT_ACCT_NUM = 178, "SecuritySettlAgentAcctNum";
+    SECURITY_SETTL_AGENT_ACCT_NAME = 179, "SecuritySettlAgentAcctName";
+    SECURITY_SETTL_AGENT_CONTACT_NAME = 180, "SecuritySettlAgentContactName";
+    SECURITY_SETTL_AGENT_CONTACT_PHONE = 181, "SecuritySettlAgentContactPhone";
+    CASH_SETTL_AGENT_NAME = 182, "CashSettlAgentName";
+    CASH_SETTL_AGENT_CODE = 183, "CashSettlAgentCode";
+    CASH_SETTL_AGENT_ACCT_NUM = 184, "CashSettlAgentAcctNum";
+    CASH_SETTL_AGENT_ACCT_NAME = 185, "CashSettlAgentAcctName";
+    CASH_SETTL_AGENT_CONTACT_NAME = 186, "CashSettlAgentContactName";
+    CASH_SETTL_AGENT_CONTACT_PHONE = 187, "CashSettlAgentContactPhone";
+    BID_SPOT_RATE = 188, "BidSpotRate";
+    BID_FORWARD_POINTS = 189, "BidForwardPoints";
+    OFFER_SPOT_RATE = 190, "OfferSpotRate";
+    OFFER_FORWARD_POINTS = 191, "OfferForwardPoints";
+    ORDER_QTY2 = 192, "OrderQty2";
+    SETTL_DATE2 = 193, "SettlDate2";
+    LAST_SPOT_RATE = 194, "LastSpotRate";
+    LAST_FORWARD_POINTS = 195, "LastForwardPoints";
+    ALLOC_LINK_ID = 196, "AllocLinkID";
+    ALLOC_LINK_TYPE = 197, "AllocLinkType";
+    SECONDARY_ORDER_ID = 198, "SecondaryOrderID";
+    NO_IOI_QUALIFIERS = 199, "NoIOIQualifiers";
+    MATURITY_MONTH_YEAR = 200, "MaturityMonthYear";
+    PUT_OR_CALL = 201, "PutOrCall";
+    STRIKE_PRICE = 202, "StrikePrice";
+    COVERED_OR_UNCOVERED = 203, "CoveredOrUncovered";
+    CUSTOMER_OR_FIRM = 204, "CustomerOrFirm";
+    MATURITY_DAY = 205, "MaturityDay";
+    OPT_ATTRIBUTE = 206, "OptAttribute";
+    SECURITY_EXCHANGE = 207, "SecurityExchange";
+    NOTIFY_BROKER_OF_CREDIT = 208, "NotifyBrokerOfCredit";
+    ALLOC_HANDL_INST = 209, "AllocHandlInst";
+    MAX_SHOW = 210, "MaxShow";
+    PEG_OFFSET_VALUE = 211, "PegOffsetValue";
+    XML_DATA_LEN = 212, "XmlDataLen";
+    XML_DATA = 213, "XmlData";
+    SETTL_INST_REF_ID = 214, "SettlInstRefID";
+    NO_ROUTING_IDS = 215, "NoRoutingIDs";
+    ROUTING_TYPE = 216, "RoutingType";
+    ROUTING_ID = 217, "RoutingID";
+    SPREAD = 218, "Spread";
+    BENCHMARK = 219, "Benchmark";
+    BENCHMARK_CURVE_CURRENCY = 220, "BenchmarkCurveCurrency";
+    BENCHMARK_CURVE_NAME = 221, "BenchmarkCurveName";
+    BENCHMARK_CURVE_POINT = 222, "BenchmarkCurvePoint";
+    COUPON_RATE = 223, "CouponRate";
+    COUPON_PAYMENT_DATE = 224, "CouponPaymentDate";
+    ISSUE_DATE = 225, "IssueDate";
+    REPURCHASE_TERM = 226, "RepurchaseTerm";
+    REPURCHASE_RATE = 227, "RepurchaseRate";
+    FACTOR = 228, "Factor";
+    TRADE_ORIGINATION_DATE = 229, "TradeOriginationDate";
+    EX_DATE = 230, "ExDate";
+    CONTRACT_MULTIPLIER = 231, "ContractMultiplier";
+    NO_STIPULATIONS = 232, "NoStipulations";
+    STIPULATION_TYPE = 233, "StipulationType";
+    STIPULATION_VALUE = 234, "StipulationValue";
+    YIELD_TYPE = 235, "YieldType";
+    YIELD = 236, "Yield";
+    TOTAL_TAKEDOWN = 237, "TotalTakedown";
+    CONCESSION = 238, "Concession";
+    REPO_COLLATERAL_SECURITY_TYPE = 239, "RepoCollateralSecurityType";
+    REDEMPTION_DATE = 240, "RedemptionDate";
+    UNDERLYING_COUPON_PAYMENT_DATE = 241, "UnderlyingCouponPaymentDate";
+    UNDERLYING_ISSUE_DATE = 242, "UnderlyingIssueDate";
+    UNDERLYING_REPO_COLLATERAL_SECURITY_TYPE = 243, "UnderlyingRepoCollateralSecurityType";
+    UNDERLYING_REPURCHASE_TERM = 244, "UnderlyingRepurchaseTerm";
+    UNDERLYING_REPURCHASE_RATE = 245, "UnderlyingRepurchaseRate";
+    UNDERLYING_FACTOR = 246, "UnderlyingFactor";
+    UNDERLYING_REDEMPTION_DATE = 247, "UnderlyingRedemptionDate";
+    LEG_COUPON_PAYMENT_DATE = 248, "LegCouponPaymentDate";
+    LEG_ISSUE_DATE = 249, "LegIssueDate";
+    LEG_REPO_COLLATERAL_SECURITY_TYPE = 250, "LegRepoCollateralSecurityType";
+    LEG_REPURCHASE_TERM = 251, "LegRepurchaseTerm";
+    LEG_REPURCHASE_RATE = 252, "LegRepurchaseRate";
+    LEG_FACTOR = 253, "LegFactor";
+    LEG_REDEMPTION_DATE = 254, "LegRedemptionDate";
+    CREDIT_RATING = 255, "CreditRating";
+    UNDERLYING_CREDIT_RATING = 256, "UnderlyingCreditRating";
+    LEG_CREDIT_RATING = 257, "LegCreditRating";
+    TRADED_FLAT_SWITCH = 258, "TradedFlatSwitch";
+    BASIS_FEATURE_DATE = 259, "BasisFeatureDate";
+    BASIS_FEATURE_PRICE = 260, "BasisFeaturePrice";
+    MD_REQ_ID = 262, "MDReqID";
+    SUBSCRIPTION_REQUEST_TYPE = 263, "SubscriptionRequestType";
+    MARKET_DEPTH = 264, "MarketDepth";
+    MD_UPDATE_TYPE = 265, "MDUpdateType";
+    AGGREGATED_BOOK = 266, "AggregatedBook";
+    NO_MD_ENTRY_TYPES = 267, "NoMDEntryTypes";
+    NO_MD_ENTRIES = 268, "NoMDEntries";
+    MD_ENTRY_TYPE = 269, "MDEntryType";
+    MD_ENTRY_PX = 270, "MDEntryPx";
+    MD_ENTRY_SIZE = 271, "MDEntrySize";
+    MD_ENTRY_DATE = 272, "MDEntryDate";
+    MD_ENTRY_TIME = 273, "MDEntryTime";
+    TICK_DIRECTION = 274, "TickDirection";
+    MD_MKT = 275, "MDMkt";
+    QUOTE_CONDITION = 276, "QuoteCondition";
+    TRADE_CONDITION = 277, "TradeCondition";
+    MD_ENTRY_ID = 278, "MDEntryID";
+    MD_UPDATE_ACTION = 279, "MDUpdateAction";
+    MD_ENTRY_REF_ID = 280, "MDEntryRefID";
+    MD_REQ_REJ_REASON = 281, "MDReqRejReason";
+    MD_ENTRY_ORIGINATOR = 282, "MDEntryOriginator";
+    LOCATION_ID = 283, "LocationID";
+    DESK_ID = 284, "DeskID";
+    DELETE_REASON = 285, "DeleteReason";
+    OPEN_CLOSE_SETTL_FLAG = 286, "OpenCloseSettlFlag";
+    SELLER_DAYS = 287, "SellerDays";
+    MD_ENTRY_BUYER = 288, "MDEntryBuyer";
+    MD_ENTRY_SELLER = 289, "MDEntrySeller";
+    MD_ENTRY_POSITION_NO = 290, "MDEntryPositionNo";
+    FINANCIAL_STATUS = 291, "FinancialStatus";
+    CORPORATE_ACTION = 292, "CorporateAction";
+    DEF_BID_SIZE = 293, "DefBidSize";
+    DEF_OFFER_SIZE = 294, "DefOfferSize";
+    NO_QUOTE_ENTRIES = 295, "NoQuoteEntries";
+    NO_QUOTE_SETS = 296, "NoQuoteSets";
+    QUOTE_STATUS = 297, "QuoteStatus";
+    QUOTE_CANCEL_TYPE = 298, "QuoteCancelType";
+    QUOTE_ENTRY_ID = 299, "QuoteEntryID";
+    QUOTE_REJECT_REASON = 300, "QuoteRejectReason";
+    QUOTE_RESPONSE_LEVEL = 301, "QuoteResponseLevel";
+    QUOTE_SET_ID = 302, "QuoteSetID";
+    QUOTE_REQUEST_TYPE = 303, "QuoteRequestType";
+    TOT_NO_QUOTE_ENTRIES = 304, "TotNoQuoteEntries";
+    UNDERLYING_SECURITY_ID_SOURCE = 305, "UnderlyingSecurityIDSource";
+    UNDERLYING_ISSUER = 306, "UnderlyingIssuer";
+    UNDERLYING_SECURITY_DESC = 307, "UnderlyingSecurityDesc";
+    UNDERLYING_SECURITY_EXCHANGE = 308, "UnderlyingSecurityExchange";
+    UNDERLYING_SECURITY_ID = 309, "UnderlyingSecurityID";
+    UNDERLYING_SECURITY_TYPE = 310, "UnderlyingSecurityType";
+    UNDERLYING_SYMBOL = 311, "UnderlyingSymbol";
+    UNDERLYING_SYMBOL_SFX = 312, "UnderlyingSymbolSfx";
+    UNDERLYING_MATURITY_MONTH_YEAR = 313, "UnderlyingMaturityMonthYear";
+    UNDERLYING_MATURITY_DAY = 314, "UnderlyingMaturityDay";
+    UNDERLYING_PUT_OR_CALL = 315, "UnderlyingPutOrCall";
+    UNDERLYING_STRIKE_PRICE = 316, "UnderlyingStrikePrice";
+    UNDERLYING_OPT_ATTRIBUTE = 317, "UnderlyingOptAttribute";
+    UNDERLYING_CURRENCY = 318, "UnderlyingCurrency";
+    RATIO_QTY = 319, "RatioQty";
+    SECURITY_REQ_ID = 320, "SecurityReqID";
+    SECURITY_REQUEST_TYPE = 321, "SecurityRequestType";
+    SECURITY_RESPONSE_ID = 322, "SecurityResponseID";
+    SECURITY_RESPONSE_TYPE = 323, "SecurityResponseType";
+    SECURITY_STATUS_REQ_ID = 324, "SecurityStatusReqID";
+    UNSOLICITED_INDICATOR = 325, "UnsolicitedIndicator";
+    SECURITY_TRADING_STATUS = 326, "SecurityTradingStatus";
+    HALT_REASON = 327, "HaltReason";
+    IN_VIEW_OF_COMMON = 328, "InViewOfCommon";
+    DUE_TO_RELATED = 329, "DueToRelated";
+    BUY_VOLUME = 330, "BuyVolume";
+    SELL_VOLUME = 331, "SellVolume";
+    HIGH_PX = 332, "HighPx";
+    LOW_PX = 333, "LowPx";
+    ADJUSTMENT = 334, "Adjustment";
+    TRAD_SES_REQ_ID = 335, "TradSesReqID";
+    TRADING_SESSION_ID = 336, "TradingSessionID";
+    CONTRA_TRADER = 337, "ContraTrader";
+    TRAD_SES_METHOD = 338, "TradSesMethod";
+    TRAD_SES_MODE = 339, "TradSesMode";
+    TRAD_SES_STATUS = 340, "TradSesStatus";
+    TRAD_SES_START_TIME = 341, "TradSesStartTime";
+    TRAD_SES_OPEN_TIME = 342, "TradSesOpenTime";
+    TRAD_SES_PRE_CLOSE_TIME = 343, "TradSesPreCloseTime";
+    TRAD_SES_CLOSE_TIME = 344, "TradSesCloseTime";
+    TRAD_SES_END_TIME = 345, "TradSesEndTime";
+    NUMBER_OF_ORDERS = 346, "NumberOfOrders";
+    MESSAGE_ENCODING = 347, "MessageEncoding";
+    ENCODED_ISSUER_LEN = 348, "EncodedIssuerLen";
+    ENCODED_ISSUER = 349, "EncodedIssuer";
+    ENCODED_SECURITY_DESC_LEN = 350, "EncodedSecurityDescLen";
+    ENCODED_SECURITY_DESC = 351, "EncodedSecurityDesc";
+    ENCODED_LIST_EXEC_INST_LEN = 352, "EncodedListExecInstLen";
+    ENCODED_LIST_EXEC_INST = 353, "EncodedListExecInst";
+    ENCODED_TEXT_LEN = 354, "EncodedTextLen";
+    ENCODED_TEXT = 355, "EncodedText";
+    ENCODED_SUBJECT_LEN = 356, "EncodedSubjectLen";
+    ENCODED_SUBJECT = 357, "EncodedSubject";
+    ENCODED_HEADLINE_LEN = 358, "EncodedHeadlineLen";
+    ENCODED_HEADLINE = 359, "EncodedHeadline";
+    ENCODED_ALLOC_TEXT_LEN = 360, "EncodedAllocTextLen";
+    ENCODED_ALLOC_TEXT = 361, "EncodedAllocText";
+    ENCODED_UNDERLYING_ISSUER_LEN = 362, "EncodedUnderlyingIssuerLen";
+    ENCODED_UNDERLYING_ISSUER = 363, "EncodedUnderlyingIssuer";
+    ENCODED_UNDERLYING_SECURITY_DESC_LEN = 364, "EncodedUnderlyingSecurityDescLen";
+    ENCODED_UNDERLYING_SECURITY_DESC = 365, "EncodedUnderlyingSecurityDesc";
+    ALLOC_PRICE = 366, "AllocPrice";
+    QUOTE_SET_VALID_UNTIL_TIME = 367, "QuoteSetValidUntilTime";
+    QUOTE_ENTRY_REJECT_REASON = 368, "QuoteEntryRejectReason";
+    LAST_MSG_SEQ_NUM_PROCESSED = 369, "LastMsgSeqNumProcessed";
+    ON_BEHALF_OF_SENDING_TIME = 370, "OnBehalfOfSendingTime";
+    REF_TAG_ID = 371, "RefTagID";
+    REF_MSG_TYPE = 372, "RefMsgType";
+    SESSION_REJECT_REASON = 373, "SessionRejectReason";
+    BID_REQUEST_TRANS_TYPE = 374, "BidRequestTransType";
+    CONTRA_BROKER = 375, "ContraBroker";
+    COMPLIANCE_ID = 376, "ComplianceID";
+    SOLICITED_FLAG = 377, "SolicitedFlag";
+    EXEC_RESTATEMENT_REASON = 378, "ExecRestatementReason";
+    BUSINESS_REJECT_REF_ID = 379, "BusinessRejectRefID";
+    BUSINESS_REJECT_REASON = 380, "BusinessRejectReason";
+    GROSS_TRADE_AMT = 381, "GrossTradeAmt";
+    NO_CONTRA_BROKERS = 382, "NoContraBrokers";
+    MAX_MESSAGE_SIZE = 383, "MaxMessageSize";
+    NO_MSG_TYPES = 384, "NoMsgTypes";
+    MSG_DIRECTION = 385, "MsgDirection";
+    NO_TRADING_SESSIONS = 386, "NoTradingSessions";
+    TOTAL_VOLUME_TRADED = 387, "TotalVolumeTraded";
+    DISCRETION_INST = 388, "DiscretionInst";
+    DISCRETION_OFFSET_VALUE = 389, "DiscretionOffsetValue";
+    BID_ID = 390, "BidID";
+    CLIENT_BID_ID = 391, "ClientBidID";
+    LIST_NAME = 392, "ListName";
+    TOT_NO_RELATED_SYM = 393, "TotNoRelatedSym";
+    BID_TYPE = 394, "BidType";
+    NUM_TICKETS = 395, "NumTickets";
+    SIDE_VALUE1 = 396, "SideValue1";
+    SIDE_VALUE2 = 397, "SideValue2";
+    NO_BID_DESCRIPTORS = 398, "NoBidDescriptors";
+    BID_DESCRIPTOR_TYPE = 399, "BidDescriptorType";
+    BID_DESCRIPTOR = 400, "BidDescriptor";
+    SIDE_VALUE_IND = 401, "SideValueInd";
+    LIQUIDITY_PCT_LOW = 402, "LiquidityPctLow";
+    LIQUIDITY_PCT_HIGH = 403, "LiquidityPctHigh";
+    LIQUIDITY_VALUE = 404, "LiquidityValue";
+    EFP_TRACKING_ERROR = 405, "EFPTrackingError";
+    FAIR_VALUE = 406, "FairValue";
+    OUTSIDE_INDEX_PCT = 407, "OutsideIndexPct";
+    VALUE_OF_FUTURES = 408, "ValueOfFutures";
+    LIQUIDITY_IND_TYPE = 409, "LiquidityIndType";
+    WT_AVERAGE_LIQUIDITY = 410, "WtAverageLiquidity";
+    EXCHANGE_FOR_PHYSICAL = 411, "ExchangeForPhysical";
+    OUT_MAIN_CNTRY_U_INDEX = 412, "OutMainCntryUIndex";
+    CROSS_PERCENT = 413, "CrossPercent";
+    PROG_RPT_REQS = 414, "ProgRptReqs";
+    PROG_PERIOD_INTERVAL = 415, "ProgPeriodInterval";
+    INC_TAX_IND = 416, "IncTaxInd";
+    NUM_BIDDERS = 417, "NumBidders";
+    BID_TRADE_TYPE = 418, "BidTradeType";
+    BASIS_PX_TYPE = 419, "BasisPxType";
+    NO_BID_COMPONENTS = 420, "NoBidComponents";
+    COUNTRY = 421, "Country";
+    TOT_NO_STRIKES = 422, "TotNoStrikes";
+    PRICE_TYPE = 423, "PriceType";
+    DAY_ORDER_QTY = 424, "DayOrderQty";
+    DAY_CUM_QTY = 425, "DayCumQty";
+    DAY_AVG_PX = 426, "DayAvgPx";
+    GT_BOOKING_INST = 427, "GTBookingInst";
+    NO_STRIKES = 428, "NoStrikes";
+    LIST_STATUS_TYPE = 429, "ListStatusType";
+    NET_GROSS_IND = 430, "NetGrossInd";
+    LIST_ORDER_STATUS = 431, "ListOrderStatus";
+    EXPIRE_DATE = 432, "ExpireDate";
+    LIST_EXEC_INST_TYPE = 433, "ListExecInstType";
+    CXL_REJ_RESPONSE_TO = 434, "CxlRejResponseTo";
+    UNDERLYING_COUPON_RATE = 435, "UnderlyingCouponRate";
+    UNDERLYING_CONTRACT_MULTIPLIER = 436, "UnderlyingContractMultiplier";
+    CONTRA_TRADE_QTY = 437, "ContraTradeQty";
+    CONTRA_TRADE_TIME = 438, "ContraTradeTime";
+    CLEARING_FIRM = 439, "ClearingFirm";
+    CLEARING_ACCOUNT = 440, "ClearingAccount";
+    LIQUIDITY_NUM_SECURITIES = 441, "LiquidityNumSecurities";
+    MULTI_LEG_REPORTING_TYPE = 442, "MultiLegReportingType";
+    STRIKE_TIME = 443, "StrikeTime";
+    LIST_STATUS_TEXT = 444, "ListStatusText";
+    ENCODED_LIST_STATUS_TEXT_LEN = 445, "EncodedListStatusTextLen";
+    ENCODED_LIST_STATUS_TEXT = 446, "EncodedListStatusText";
+    PARTY_ID_SOURCE = 447, "PartyIDSource";
+    PARTY_ID = 448, "PartyID";
+    TOTAL_VOLUME_TRADED_DATE = 449, "TotalVolumeTradedDate";
+    TOTAL_VOLUME_TRADED_TIME = 450, "TotalVolumeTradedTime";
+    NET_CHG_PREV_DAY = 451, "NetChgPrevDay";
+    PARTY_ROLE = 452, "PartyRole";
+    NO_PARTY_IDS = 453, "NoPartyIDs";
+    NO_SECURITY_ALT_ID = 454, "NoSecurityAltID";
+    SECURITY_ALT_ID = 455, "SecurityAltID";
+    SECURITY_ALT_ID_SOURCE = 456, "SecurityAltIDSource";
+    NO_UNDERLYING_SECURITY_ALT_ID = 457, "NoUnderlyingSecurityAltID";
+    UNDERLYING_SECURITY_ALT_ID = 458, "UnderlyingSecurityAltID";
+    UNDERLYING_SECURITY_ALT_ID_SOURCE = 459, "UnderlyingSecurityAltIDSource";
+    PRODUCT = 460, "Product";
+    CFI_CODE = 461, "CFICode";
+    UNDERLYING_PRODUCT = 462, "UnderlyingProduct";
+    UNDERLYING_CFI_CODE = 463, "UnderlyingCFICode";
+    TEST_MESSAGE_INDICATOR = 464, "TestMessageIndicator";
+    QUANTITY_TYPE = 465, "QuantityType";
+    BOOKING_REF_ID = 466, "BookingRefID";
+    INDIVIDUAL_ALLOC_ID = 467, "IndividualAllocID";
+    ROUNDING_DIRECTION = 468, "RoundingDirection";
+    ROUNDING_MODULUS = 469, "RoundingModulus";
+    COUNTRY_OF_ISSUE = 470, "CountryOfIssue";
+    STATE_OR_PROVINCE_OF_ISSUE = 471, "StateOrProvinceOfIssue";
+    LOCALE_OF_ISSUE = 472, "LocaleOfIssue";
+    NO_REGIST_DTLS = 473, "NoRegistDtls";
+    MAILING_DTLS = 474, "MailingDtls";
+    INVESTOR_COUNTRY_OF_RESIDENCE = 475, "InvestorCountryOfResidence";
+    PAYMENT_REF = 476, "PaymentRef";
+    DISTRIB_PAYMENT_METHOD = 477, "DistribPaymentMethod";
+    CASH_DISTRIB_CURR = 478, "CashDistribCurr";
+    COMM_CURRENCY = 479, "CommCurrency";
+    CANCELLATION_RIGHTS = 480, "CancellationRights";
+    MONEY_LAUNDERING_STATUS = 481, "MoneyLaunderingStatus";
+    MAILING_INST = 482, "MailingInst";
+    TRANS_BKD_TIME = 483, "TransBkdTime";
+    EXEC_PRICE_TYPE = 484, "ExecPriceType";
+    EXEC_PRICE_ADJUSTMENT = 485, "ExecPriceAdjustment";
+    DATE_OF_BIRTH = 486, "DateOfBirth";
+    TRADE_REPORT_TRANS_TYPE = 487, "TradeReportTransType";
+    CARD_HOLDER_NAME = 488, "CardHolderName";
+    CARD_NUMBER = 489, "CardNumber";
+    CARD_EXP_DATE = 490, "CardExpDate";
+    CARD_ISS_NUM = 491, "CardIssNum";
+    PAYMENT_METHOD = 492, "PaymentMethod";
+    REGIST_ACCT_TYPE = 493, "RegistAcctType";
+    DESIGNATION = 494, "Designation";
+    TAX_ADVANTAGE_TYPE = 495, "TaxAdvantageType";
+    REGIST_REJ_REASON_TEXT = 496, "RegistRejReasonText";
+    FUND_RENEW_WAIV = 497, "FundRenewWaiv";
+    CASH_DISTRIB_AGENT_NAME = 498, "CashDistribAgentName";
+    CASH_DISTRIB_AGENT_CODE = 499, "CashDistribAgentCode";
+    CASH_DISTRIB_AGENT_ACCT_NUMBER = 500, "CashDistribAgentAcctNumber";
+    CASH_DISTRIB_PAY_REF = 501, "CashDistribPayRef";
+    CASH_DISTRIB_AGENT_ACCT_NAME = 502, "CashDistribAgentAcctName";
+    CARD_START_DATE = 503, "CardStartDate";
+    PAYMENT_DATE = 504, "PaymentDate";
+    PAYMENT_REMITTER_ID = 505, "PaymentRemitterID";
+    REGIST_STATUS = 506, "RegistStatus";
+    REGIST_REJ_REASON_CODE = 507, "RegistRejReasonCode";
+    REGIST_REF_ID = 508, "RegistRefID";
+    REGIST_DTLS = 509, "RegistDtls";
+    NO_DISTRIB_INSTS = 510, "NoDistribInsts";
+    REGIST_EMAIL = 511, "RegistEmail";
+    DISTRIB_PERCENTAGE = 512, "DistribPercentage";
+    REGIST_ID = 513, "RegistID";
+    REGIST_TRANS_TYPE = 514, "RegistTransType";
+    EXEC_VALUATION_POINT = 515, "ExecValuationPoint";
+    ORDER_PERCENT = 516, "OrderPercent";
+    OWNERSHIP_TYPE = 517, "OwnershipType";
+    NO_CONT_AMTS = 518, "NoContAmts";
+    CONT_AMT_TYPE = 519, "ContAmtType";
+    CONT_AMT_VALUE = 520, "ContAmtValue";
+    CONT_AMT_CURR = 521, "ContAmtCurr";
+    OWNER_TYPE = 522, "OwnerType";
+    PARTY_SUB_ID = 523, "PartySubID";
+    NESTED_PARTY_ID = 524, "NestedPartyID";
+    NESTED_PARTY_ID_SOURCE = 525, "NestedPartyIDSource";
+    SECONDARY_CL_ORD_ID = 526, "SecondaryClOrdID";
+    SECONDARY_EXEC_ID = 527, "SecondaryExecID";
+    ORDER_CAPACITY = 528, "OrderCapacity";
+    ORDER_RESTRICTIONS = 529, "OrderRestrictions";
+    MASS_CANCEL_REQUEST_TYPE = 530, "MassCancelRequestType";
+    MASS_CANCEL_RESPONSE = 531, "MassCancelResponse";
+    MASS_CANCEL_REJECT_REASON = 532, "MassCancelRejectReason";
+    TOTAL_AFFECTED_ORDERS = 533, "TotalAffectedOrders";
+    NO_AFFECTED_ORDERS = 534, "NoAffectedOrders";
+    AFFECTED_ORDER_ID = 535, "AffectedOrderID";
+    AFFECTED_SECONDARY_ORDER_ID = 536, "AffectedSecondaryOrderID";
+    QUOTE_TYPE = 537, "QuoteType";
+    NESTED_PARTY_ROLE = 538, "NestedPartyRole";
+    NO_NESTED_PARTY_IDS = 539, "NoNestedPartyIDs";
+    TOTAL_ACCRUED_INTEREST_AMT = 540, "TotalAccruedInterestAmt";
+    MATURITY_DATE = 541, "MaturityDate";
+    UNDERLYING_MATURITY_DATE = 542, "UnderlyingMaturityDate";
+    INSTR_REGISTRY = 543, "InstrRegistry";
+    CASH_MARGIN = 544, "CashMargin";
+    NESTED_PARTY_SUB_ID = 545, "NestedPartySubID";
+    SCOPE = 546, "Scope";
+    MD_IMPLICIT_DELETE = 547, "MDImplicitDelete";
+    CROSS_ID = 548, "CrossID";
+    CROSS_TYPE = 549, "CrossType";
+    CROSS_PRIORITIZATION = 550, "CrossPrioritization";
+    ORIG_CROSS_ID = 551, "OrigCrossID";
+    NO_SIDES = 552, "NoSides";
+    USERNAME = 553, "Username";
+    PASSWORD = 554, "Password";
+    NO_LEGS = 555, "NoLegs";
+    LEG_CURRENCY = 556, "LegCurrency";
+    TOT_NO_SECURITY_TYPES = 557, "TotNoSecurityTypes";
+    NO_SECURITY_TYPES = 558, "NoSecurityTypes";
+    SECURITY_LIST_REQUEST_TYPE = 559, "SecurityListRequestType";
+    SECURITY_REQUEST_RESULT = 560, "SecurityRequestResult";
+    ROUND_LOT = 561, "RoundLot";
+    MIN_TRADE_VOL = 562, "MinTradeVol";
+    MULTI_LEG_RPT_TYPE_REQ = 563, "MultiLegRptTypeReq";
+    LEG_POSITION_EFFECT = 564, "LegPositionEffect";
+    LEG_COVERED_OR_UNCOVERED = 565, "LegCoveredOrUncovered";
+    LEG_PRICE = 566, "LegPrice";
+    TRAD_SES_STATUS_REJ_REASON = 567, "TradSesStatusRejReason";
+    TRADE_REQUEST_ID = 568, "TradeRequestID";
+    TRADE_REQUEST_TYPE = 569, "TradeRequestType";
+    PREVIOUSLY_REPORTED = 570, "PreviouslyReported";
+    TRADE_REPORT_ID = 571, "TradeReportID";
+    TRADE_REPORT_REF_ID = 572, "TradeReportRefID";
+    MATCH_STATUS = 573, "MatchStatus";
+    MATCH_TYPE = 574, "MatchType";
+    ODD_LOT = 575, "OddLot";
+    NO_CLEARING_INSTRUCTIONS = 576, "NoClearingInstructions";
+    CLEARING_INSTRUCTION = 577, "ClearingInstruction";
+    TRADE_INPUT_SOURCE = 578, "TradeInputSource";
+    TRADE_INPUT_DEVICE = 579, "TradeInputDevice";
+    NO_DATES = 580, "NoDates";
+    ACCOUNT_TYPE = 581, "AccountType";
+    CUST_ORDER_CAPACITY = 582, "CustOrderCapacity";
+    CL_ORD_LINK_ID = 583, "ClOrdLinkID";
+    MASS_STATUS_REQ_ID = 584, "MassStatusReqID";
+    MASS_STATUS_REQ_TYPE = 585, "MassStatusReqType";
+    ORIG_ORD_MOD_TIME = 586, "OrigOrdModTime";
+    LEG_SETTL_TYPE = 587, "LegSettlType";
+    LEG_SETTL_DATE = 588, "LegSettlDate";
+    DAY_BOOKING_INST = 589, "DayBookingInst";
+    BOOKING_UNIT = 590, "BookingUnit";
+    PREALLOC_METHOD = 591, "PreallocMethod";
+    UNDERLYING_COUNTRY_OF_ISSUE = 592, "UnderlyingCountryOfIssue";
+    UNDERLYING_STATE_OR_PROVINCE_OF_ISSUE = 593, "UnderlyingStateOrProvinceOfIssue";
+    UNDERLYING_LOCALE_OF_ISSUE = 594, "UnderlyingLocaleOfIssue";
+    UNDERLYING_INSTR_REGISTRY = 595, "UnderlyingInstrRegistry";
+    LEG_COUNTRY_OF_ISSUE = 596, "LegCountryOfIssue";
+    LEG_STATE_OR_PROVINCE_OF_ISSUE = 597, "LegStateOrProvinceOfIssue";
+    LEG_LOCALE_OF_ISSUE = 598, "LegLocaleOfIssue";
+    LEG_INSTR_REGISTRY = 599, "LegInstrRegistry";
+    LEG_SYMBOL = 600, "LegSymbol";
+    LEG_SYMBOL_SFX = 601, "LegSymbolSfx";
+    LEG_SECURITY_ID = 602, "LegSecurityID";
+    LEG_SECURITY_ID_SOURCE = 603, "LegSecurityIDSource";
+    NO_LEG_SECURITY_ALT_ID = 604, "NoLegSecurityAltID";
+    LEG_SECURITY_ALT_ID = 605, "LegSecurityAltID";
+    LEG_SECURITY_ALT_ID_SOURCE = 606, "LegSecurityAltIDSource";
+    LEG_PRODUCT = 607, "LegProduct";
+    LEG_CFI_CODE = 608, "LegCFICode";
+    LEG_SECURITY_TYPE = 609, "LegSecurityType";
+    LEG_MATURITY_MONTH_YEAR = 610, "LegMaturityMonthYear";
+    LEG_MATURITY_DATE = 611, "LegMaturityDate";
+    LEG_STRIKE_PRICE = 612, "LegStrikePrice";
+    LEG_OPT_ATTRIBUTE = 613, "LegOptAttribute";
+    LEG_CONTRACT_MULTIPLIER = 614, "LegContractMultiplier";
+    LEG_COUPON_RATE = 615, "LegCouponRate";
+    LEG_SECURITY_EXCHANGE = 616, "LegSecurityExchange";
+    LEG_ISSUER = 617, "LegIssuer";
+    ENCODED_LEG_ISSUER_LEN = 618, "EncodedLegIssuerLen";
+    ENCODED_LEG_ISSUER = 619, "EncodedLegIssuer";
+    LEG_SECURITY_DESC = 620, "LegSecurityDesc";
+    ENCODED_LEG_SECURITY_DESC_LEN = 621, "EncodedLegSecurityDescLen";
+    ENCODED_LEG_SECURITY_DESC = 622, "EncodedLegSecurityDesc";
+    LEG_RATIO_QTY = 623, "LegRatioQty";
+    LEG_SIDE = 624, "LegSide";
+    TRADING_SESSION_SUB_ID = 625, "TradingSessionSubID";
+    ALLOC_TYPE = 626, "AllocType";
+    NO_HOPS = 627, "NoHops";
+    HOP_COMP_ID = 628, "HopCompID";
+    HOP_SENDING_TIME = 629, "HopSendingTime";
+    HOP_REF_ID = 630, "HopRefID";
+    MID_PX = 631, "MidPx";
+    BID_YIELD = 632, "BidYield";
+    MID_YIELD = 633, "MidYield";
+    OFFER_YIELD = 634, "OfferYield";
+    CLEARING_FEE_INDICATOR = 635, "ClearingFeeIndicator";
+    WORKING_INDICATOR = 636, "WorkingIndicator";
+    LEG_LAST_PX = 637, "LegLastPx";
+    PRIORITY_INDICATOR = 638, "PriorityIndicator";
+    PRICE_IMPROVEMENT = 639, "PriceImprovement";
+    PRICE2 = 640, "Price2";
+    LAST_FORWARD_POINTS2 = 641, "LastForwardPoints2";
+    BID_FORWARD_POINTS2 = 642, "BidForwardPoints2";
+    OFFER_FORWARD_POINTS2 = 643, "OfferForwardPoints2";
+    RFQ_REQ_ID = 644, "RFQReqID";
+    MKT_BID_PX = 645, "MktBidPx";
+    MKT_OFFER_PX = 646, "MktOfferPx";
+    MIN_BID_SIZE = 647, "MinBidSize";
+    MIN_OFFER_SIZE = 648, "MinOfferSize";
+    QUOTE_STATUS_REQ_ID = 649, "QuoteStatusReqID";
+    LEGAL_CONFIRM = 650, "LegalConfirm";
+    UNDERLYING_LAST_PX = 651, "UnderlyingLastPx";
+    UNDERLYING_LAST_QTY = 652, "UnderlyingLastQty";
+    SEC_DEF_STATUS = 653, "SecDefStatus";
+    LEG_REF_ID = 654, "LegRefID";
+    CONTRA_LEG_REF_ID = 655, "ContraLegRefID";
+    SETTL_CURR_BID_FX_RATE = 656, "SettlCurrBidFxRate";
+    SETTL_CURR_OFFER_FX_RATE = 657, "SettlCurrOfferFxRate";
+    QUOTE_REQUEST_REJECT_REASON = 658, "QuoteRequestRejectReason";
+    SIDE_COMPLIANCE_ID = 659, "SideComplianceID";
+    ACCT_ID_SOURCE = 660, "AcctIDSource";
+    ALLOC_ACCT_ID_SOURCE = 661, "AllocAcctIDSource";
+    BENCHMARK_PRICE = 662, "BenchmarkPrice";
+    BENCHMARK_PRICE_TYPE = 663, "BenchmarkPriceType";
+    CONFIRM_ID = 664, "ConfirmID";
+    CONFIRM_STATUS = 665, "ConfirmStatus";
+    CONFIRM_TRANS_TYPE = 666, "ConfirmTransType";
+    CONTRACT_SETTL_MONTH = 667, "ContractSettlMonth";
+    DELIVERY_FORM = 668, "DeliveryForm";
+    LAST_PAR_PX = 669, "LastParPx";
+    NO_LEG_ALLOCS = 670, "NoLegAllocs";
+    LEG_ALLOC_ACCOUNT = 671, "LegAllocAccount";
+    LEG_INDIVIDUAL_ALLOC_ID = 672, "LegIndividualAllocID";
+    LEG_ALLOC_QTY = 673, "LegAllocQty";
+    LEG_ALLOC_ACCT_ID_SOURCE = 674, "LegAllocAcctIDSource";
+    LEG_SETTL_CURRENCY = 675, "LegSettlCurrency";
+    LEG_BENCHMARK_CURVE_CURRENCY = 676, "LegBenchmarkCurveCurrency";
+    LEG_BENCHMARK_CURVE_NAME = 677, "LegBenchmarkCurveName";
+    LEG_BENCHMARK_CURVE_POINT = 678, "LegBenchmarkCurvePoint";
+    LEG_BENCHMARK_PRICE = 679, "LegBenchmarkPrice";
+    LEG_BENCHMARK_PRICE_TYPE = 680, "LegBenchmarkPriceType";
+    LEG_BID_PX = 681, "LegBidPx";
+    LEG_IOI_QTY = 682, "LegIOIQty";
+    NO_LEG_STIPULATIONS = 683, "NoLegStipulations";
+    LEG_OFFER_PX = 684, "LegOfferPx";
+    LEG_ORDER_QTY = 685, "LegOrderQty";
+    LEG_PRICE_TYPE = 686, "LegPriceType";
+    LEG_QTY = 687, "LegQty";
+    LEG_STIPULATION_TYPE = 688, "LegStipulationType";
+    LEG_STIPULATION_VALUE = 689, "LegStipulationValue";
+    LEG_SWAP_TYPE = 690, "LegSwapType";
+    POOL = 691, "Pool";
+    QUOTE_PRICE_TYPE = 692, "QuotePriceType";
+    QUOTE_RESP_ID = 693, "QuoteRespID";
+    QUOTE_RESP_TYPE = 694, "QuoteRespType";
+    QUOTE_QUALIFIER = 695, "QuoteQualifier";
+    YIELD_REDEMPTION_DATE = 696, "YieldRedemptionDate";
+    YIELD_REDEMPTION_PRICE = 697, "YieldRedemptionPrice";
+    YIELD_REDEMPTION_PRICE_TYPE = 698, "YieldRedemptionPriceType";
+    BENCHMARK_SECURITY_ID = 699, "BenchmarkSecurityID";
+    REVERSAL_INDICATOR = 700, "ReversalIndicator";
+    YIELD_CALC_DATE = 701, "YieldCalcDate";
+    NO_POSITIONS = 702, "NoPositions";
+    POS_TYPE = 703, "PosType";
+    LONG_QTY = 704, "LongQty";
+    SHORT_QTY = 705, "ShortQty";
+    POS_QTY_STATUS = 706, "PosQtyStatus";
+    POS_AMT_TYPE = 707, "PosAmtType";
+    POS_AMT = 708, "PosAmt";
+    POS_TRANS_TYPE = 709, "PosTransType";
+    POS_REQ_ID = 710, "PosReqID";
+    NO_UNDERLYINGS = 711, "NoUnderlyings";
+    POS_MAINT_ACTION = 712, "PosMaintAction";
+    ORIG_POS_REQ_REF_ID = 713, "OrigPosReqRefID";
+    POS_MAINT_RPT_REF_ID = 714, "PosMaintRptRefID";
+    CLEARING_BUSINESS_DATE = 715, "ClearingBusinessDate";
+    SETTL_SESS_ID = 716, "SettlSessID";
+    SETTL_SESS_SUB_ID = 717, "SettlSessSubID";
+    ADJUSTMENT_TYPE = 718, "AdjustmentType";
+    CONTRARY_INSTRUCTION_INDICATOR = 719, "ContraryInstructionIndicator";
+    PRIOR_SPREAD_INDICATOR = 720, "PriorSpreadIndicator";
+    POS_MAINT_RPT_ID = 721, "PosMaintRptID";
+    POS_MAINT_STATUS = 722, "PosMaintStatus";
+    POS_MAINT_RESULT = 723, "PosMaintResult";
+    POS_REQ_TYPE = 724, "PosReqType";
+    RESPONSE_TRANSPORT_TYPE = 725, "ResponseTransportType";
+    RESPONSE_DESTINATION = 726, "ResponseDestination";
+    TOTAL_NUM_POS_REPORTS = 727, "TotalNumPosReports";
+    POS_REQ_RESULT = 728, "PosReqResult";
+    POS_REQ_STATUS = 729, "PosReqStatus";
+    SETTL_PRICE = 730, "SettlPrice";
+    SETTL_PRICE_TYPE = 731, "SettlPriceType";
+    UNDERLYING_SETTL_PRICE = 732, "UnderlyingSettlPrice";
+    UNDERLYING_SETTL_PRICE_TYPE = 733, "UnderlyingSettlPriceType";
+    PRIOR_SETTL_PRICE = 734, "PriorSettlPrice";
+    NO_QUOTE_QUALIFIERS = 735, "NoQuoteQualifiers";
+    ALLOC_SETTL_CURRENCY = 736, "AllocSettlCurrency";
+    ALLOC_SETTL_CURR_AMT = 737, "AllocSettlCurrAmt";
+    INTEREST_AT_MATURITY = 738, "InterestAtMaturity";
+    LEG_DATED_DATE = 739, "LegDatedDate";
+    LEG_POOL = 740, "LegPool";
+    ALLOC_INTEREST_AT_MATURITY = 741, "AllocInterestAtMaturity";
+    ALLOC_ACCRUED_INTEREST_AMT = 742, "AllocAccruedInterestAmt";
+    DELIVERY_DATE = 743, "DeliveryDate";
+    ASSIGNMENT_METHOD = 744, "AssignmentMethod";
+    ASSIGNMENT_UNIT = 745, "AssignmentUnit";
+    OPEN_INTEREST = 746, "OpenInterest";
+    EXERCISE_METHOD = 747, "ExerciseMethod";
+    TOT_NUM_TRADE_REPORTS = 748, "TotNumTradeReports";
+    TRADE_REQUEST_RESULT = 749, "TradeRequestResult";
+    TRADE_REQUEST_STATUS = 750, "TradeRequestStatus";
+    TRADE_REPORT_REJECT_REASON = 751, "TradeReportRejectReason";
+    SIDE_MULTI_LEG_REPORTING_TYPE = 752, "SideMultiLegReportingType";
+    NO_POS_AMT = 753, "NoPosAmt";
+    AUTO_ACCEPT_INDICATOR = 754, "AutoAcceptIndicator";
+    ALLOC_REPORT_ID = 755, "AllocReportID";
+    NO_NESTED2_PARTY_IDS = 756, "NoNested2PartyIDs";
+    NESTED2_PARTY_ID = 757, "Nested2PartyID";
+    NESTED2_PARTY_ID_SOURCE = 758, "Nested2PartyIDSource";
+    NESTED2_PARTY_ROLE = 759, "Nested2PartyRole";
+    NESTED2_PARTY_SUB_ID = 760, "Nested2PartySubID";
+    BENCHMARK_SECURITY_ID_SOURCE = 761, "BenchmarkSecurityIDSource";
+    SECURITY_SUB_TYPE = 762, "SecuritySubType";
+    UNDERLYING_SECURITY_SUB_TYPE = 763, "UnderlyingSecuritySubType";
+    LEG_SECURITY_SUB_TYPE = 764, "LegSecuritySubType";
+    ALLOWABLE_ONE_SIDEDNESS_PCT = 765, "AllowableOneSidednessPct";
+    ALLOWABLE_ONE_SIDEDNESS_VALUE = 766, "AllowableOneSidednessValue";
+    ALLOWABLE_ONE_SIDEDNESS_CURR = 767, "AllowableOneSidednessCurr";
+    NO_TRD_REG_TIMESTAMPS = 768, "NoTrdRegTimestamps";
+    TRD_REG_TIMESTAMP = 769, "TrdRegTimestamp";
+    TRD_REG_TIMESTAMP_TYPE = 770, "TrdRegTimestampType";
+    TRD_REG_TIMESTAMP_ORIGIN = 771, "TrdRegTimestampOrigin";
+    CONFIRM_REF_ID = 772, "ConfirmRefID";
+    CONFIRM_TYPE = 773, "ConfirmType";
+    CONFIRM_REJ_REASON = 774, "ConfirmRejReason";
+    BOOKING_TYPE = 775, "BookingType";
+    INDIVIDUAL_ALLOC_REJ_CODE = 776, "IndividualAllocRejCode";
+    SETTL_INST_MSG_ID = 777, "SettlInstMsgID";
+    NO_SETTL_INST = 778, "NoSettlInst";
+    LAST_UPDATE_TIME = 779, "LastUpdateTime";
+    ALLOC_SETTL_INST_TYPE = 780, "AllocSettlInstType";
+    NO_SETTL_PARTY_IDS = 781, "NoSettlPartyIDs";
+    SETTL_PARTY_ID = 782, "SettlPartyID";
+    SETTL_PARTY_ID_SOURCE = 783, "SettlPartyIDSource";
+    SETTL_PARTY_ROLE = 784, "SettlPartyRole";
+    SETTL_PARTY_SUB_ID = 785, "SettlPartySubID";
+    SETTL_PARTY_SUB_ID_TYPE = 786, "SettlPartySubIDType";
+    DLVY_INST_TYPE = 787, "DlvyInstType";
+    TERMINATION_TYPE = 788, "TerminationType";
+    NEXT_EXPECTED_MSG_SEQ_NUM = 789, "NextExpectedMsgSeqNum";
+    ORD_STATUS_REQ_ID = 790, "OrdStatusReqID";
+    SETTL_INST_REQ_ID = 791, "SettlInstReqID";
+    SETTL_INST_REQ_REJ_CODE = 792, "SettlInstReqRejCode";
+    SECONDARY_ALLOC_ID = 793, "SecondaryAllocID";
+    ALLOC_REPORT_TYPE = 794, "AllocReportType";
+    ALLOC_REPORT_REF_ID = 795, "AllocReportRefID";
+    ALLOC_CANC_REPLACE_REASON = 796, "AllocCancReplaceReason";
+    COPY_MSG_INDICATOR = 797, "CopyMsgIndicator";
+    ALLOC_ACCOUNT_TYPE = 798, "AllocAccountType";
+    ORDER_AVG_PX = 799, "OrderAvgPx";
+    ORDER_BOOKING_QTY = 800, "OrderBookingQty";
+    NO_SETTL_PARTY_SUB_IDS = 801, "NoSettlPartySubIDs";
+    NO_PARTY_SUB_IDS = 802, "NoPartySubIDs";
+    PARTY_SUB_ID_TYPE = 803, "PartySubIDType";
+    NO_NESTED_PARTY_SUB_IDS = 804, "NoNestedPartySubIDs";
+    NESTED_PARTY_SUB_ID_TYPE = 805, "NestedPartySubIDType";
+    NO_NESTED2_PARTY_SUB_IDS = 806, "NoNested2PartySubIDs";
+    NESTED2_PARTY_SUB_ID_TYPE = 807, "Nested2PartySubIDType";
+    ALLOC_INTERMED_REQ_TYPE = 808, "AllocIntermedReqType";
+    NO_USERNAMES = 809, "NoUsernames";
+    UNDERLYING_PX = 810, "UnderlyingPx";
+    PRICE_DELTA = 811, "PriceDelta";
+    APPL_QUEUE_MAX = 812, "ApplQueueMax";
+    APPL_QUEUE_DEPTH = 813, "ApplQueueDepth";
+    APPL_QUEUE_RESOLUTION = 814, "ApplQueueResolution";
+    APPL_QUEUE_ACTION = 815, "ApplQueueAction";
+    NO_ALT_MD_SOURCE = 816, "NoAltMDSource";
+    ALT_MD_SOURCE_ID = 817, "AltMDSourceID";
+    SECONDARY_TRADE_REPORT_ID = 818, "SecondaryTradeReportID";
+    AVG_PX_INDICATOR = 819, "AvgPxIndicator";
+    TRADE_LINK_ID = 820, "TradeLinkID";
+    ORDER_INPUT_DEVICE = 821, "OrderInputDevice";
+    UNDERLYING_TRADING_SESSION_ID = 822, "UnderlyingTradingSessionID";
+    UNDERLYING_TRADING_SESSION_SUB_ID = 823, "UnderlyingTradingSessionSubID";
+    TRADE_LEG_REF_ID = 824, "TradeLegRefID";
+    EXCHANGE_RULE = 825, "ExchangeRule";
+    TRADE_ALLOC_INDICATOR = 826, "TradeAllocIndicator";
+    EXPIRATION_CYCLE = 827, "ExpirationCycle";
+    TRD_TYPE = 828, "TrdType";
+    TRD_SUB_TYPE = 829, "TrdSubType";
+    TRANSFER_REASON = 830, "TransferReason";
+    ASGN_REQ_ID = 831, "AsgnReqID";
+    TOT_NUM_ASSIGNMENT_REPORTS = 832, "TotNumAssignmentReports";
+    ASGN_RPT_ID = 833, "AsgnRptID";
+    THRESHOLD_AMOUNT = 834, "ThresholdAmount";
+    PEG_MOVE_TYPE = 835, "PegMoveType";
+    PEG_OFFSET_TYPE = 836, "PegOffsetType";
+    PEG_LIMIT_TYPE = 837, "PegLimitType";
+    PEG_ROUND_DIRECTION = 838, "PegRoundDirection";
+    PEGGED_PRICE = 839, "PeggedPrice";
+    PEG_SCOPE = 840, "PegScope";
+    DISCRETION_MOVE_TYPE = 841, "DiscretionMoveType";
+    DISCRETION_OFFSET_TYPE = 842, "DiscretionOffsetType";
+    DISCRETION_LIMIT_TYPE = 843, "DiscretionLimitType";
+    DISCRETION_ROUND_DIRECTION = 844, "DiscretionRoundDirection";
+    DISCRETION_PRICE = 845, "DiscretionPrice";
+    DISCRETION_SCOPE = 846, "DiscretionScope";
+    TARGET_STRATEGY = 847, "TargetStrategy";
+    TARGET_STRATEGY_PARAMETERS = 848, "TargetStrategyParameters";
+    PARTICIPATION_RATE = 849, "ParticipationRate";
+    TARGET_STRATEGY_PERFORMANCE = 850, "TargetStrategyPerformance";
+    LAST_LIQUIDITY_IND = 851, "LastLiquidityInd";
+    PUBLISH_TRD_INDICATOR = 852, "PublishTrdIndicator";
+    SHORT_SALE_REASON = 853, "ShortSaleReason";
+    QTY_TYPE = 854, "QtyType";
+    SECONDARY_TRD_TYPE = 855, "SecondaryTrdType";
+    TRADE_REPORT_TYPE = 856, "TradeReportType";
+    ALLOC_NO_ORDERS_TYPE = 857, "AllocNoOrdersType";
+    SHARED_COMMISSION = 858, "SharedCommission";
+    CONFIRM_REQ_ID = 859, "ConfirmReqID";
+    AVG_PAR_PX = 860, "AvgParPx";
+    REPORTED_PX = 861, "ReportedPx";
+    NO_CAPACITIES = 862, "NoCapacities";
+    ORDER_CAPACITY_QTY = 863, "OrderCapacityQty";
+    NO_EVENTS = 864, "NoEvents";
+    EVENT_TYPE = 865, "EventType";
+    EVENT_DATE = 866, "EventDate";
+    EVENT_PX = 867, "EventPx";
+    EVENT_TEXT = 868, "EventText";
+    PCT_AT_RISK = 869, "PctAtRisk";
+    NO_INSTR_ATTRIB = 870, "NoInstrAttrib";
+    INSTR_ATTRIB_TYPE = 871, "InstrAttribType";
+    INSTR_ATTRIB_VALUE = 872, "InstrAttribValue";
+    DATED_DATE = 873, "DatedDate";
+    INTEREST_ACCRUAL_DATE = 874, "InterestAccrualDate";
+    CP_PROGRAM = 875, "CPProgram";
+    CP_REG_TYPE = 876, "CPRegType";
+    UNDERLYING_CP_PROGRAM = 877, "UnderlyingCPProgram";
+    UNDERLYING_CP_REG_TYPE = 878, "UnderlyingCPRegType";
+    UNDERLYING_QTY = 879, "UnderlyingQty";
+    TRD_MATCH_ID = 880, "TrdMatchID";
+    SECONDARY_TRADE_REPORT_REF_ID = 881, "SecondaryTradeReportRefID";
+    UNDERLYING_DIRTY_PRICE = 882, "UnderlyingDirtyPrice";
+    UNDERLYING_END_PRICE = 883, "UnderlyingEndPrice";
+    UNDERLYING_START_VALUE = 884, "UnderlyingStartValue";
+    UNDERLYING_CURRENT_VALUE = 885, "UnderlyingCurrentValue";
+    UNDERLYING_END_VALUE = 886, "UnderlyingEndValue";
+    NO_UNDERLYING_STIPS = 887, "NoUnderlyingStips";
+    UNDERLYING_STIP_TYPE = 888, "UnderlyingStipType";
+    UNDERLYING_STIP_VALUE = 889, "UnderlyingStipValue";
+    MATURITY_NET_MONEY = 890, "MaturityNetMoney";
+    MISC_FEE_BASIS = 891, "MiscFeeBasis";
+    TOT_NO_ALLOCS = 892, "TotNoAllocs";
+    LAST_FRAGMENT = 893, "LastFragment";
+    COLL_REQ_ID = 894, "CollReqID";
+    COLL_ASGN_REASON = 895, "CollAsgnReason";
+    COLL_INQUIRY_QUALIFIER = 896, "CollInquiryQualifier";
+    NO_TRADES = 897, "NoTrades";
+    MARGIN_RATIO = 898, "MarginRatio";
+    MARGIN_EXCESS = 899, "MarginExcess";
+    TOTAL_NET_VALUE = 900, "TotalNetValue";
+    CASH_OUTSTANDING = 901, "CashOutstanding";
+    COLL_ASGN_ID = 902, "CollAsgnID";
+    COLL_ASGN_TRANS_TYPE = 903, "CollAsgnTransType";
+    COLL_RESP_ID = 904, "CollRespID";
+    COLL_ASGN_RESP_TYPE = 905, "CollAsgnRespType";
+    COLL_ASGN_REJECT_REASON = 906, "CollAsgnRejectReason";
+    COLL_ASGN_REF_ID = 907, "CollAsgnRefID";
+    COLL_RPT_ID = 908, "CollRptID";
+    COLL_INQUIRY_ID = 909, "CollInquiryID";
+    COLL_STATUS = 910, "CollStatus";
+    TOT_NUM_REPORTS = 911, "TotNumReports";
+    LAST_RPT_REQUESTED = 912, "LastRptRequested";
+    AGREEMENT_DESC = 913, "AgreementDesc";
+    AGREEMENT_ID = 914, "AgreementID";
+    AGREEMENT_DATE = 915, "AgreementDate";
+    START_DATE = 916, "StartDate";
+    END_DATE = 917, "EndDate";
+    AGREEMENT_CURRENCY = 918, "AgreementCurrency";
+    DELIVERY_TYPE = 919, "DeliveryType";
+    END_ACCRUED_INTEREST_AMT = 920, "EndAccruedInterestAmt";
+    START_CASH = 921, "StartCash";
+    END_CASH = 922, "EndCash";
+    USER_REQUEST_ID = 923, "UserRequestID";
+    USER_REQUEST_TYPE = 924, "UserRequestType";
+    NEW_PASSWORD = 925, "NewPassword";
+    USER_STATUS = 926, "UserStatus";
+    USER_STATUS_TEXT = 927, "UserStatusText";
+    STATUS_VALUE = 928, "StatusValue";
+    STATUS_TEXT = 929, "StatusText";
+    REF_COMP_ID = 930, "RefCompID";
+    REF_SUB_ID = 931, "RefSubID";
+    NETWORK_RESPONSE_ID = 932, "NetworkResponseID";
+    NETWORK_REQUEST_ID = 933, "NetworkRequestID";
+    LAST_NETWORK_RESPONSE_ID = 934, "LastNetworkResponseID";
+    NETWORK_REQUEST_TYPE = 935, "NetworkRequestType";
+    NO_COMP_IDS = 936, "NoCompIDs";
+    NETWORK_STATUS_RESPONSE_TYPE = 937, "NetworkStatusResponseType";
+    NO_COLL_INQUIRY_QUALIFIER = 938, "NoCollInquiryQualifier";
+    TRD_RPT_STATUS = 939, "TrdRptStatus";
+    AFFIRM_STATUS = 940, "AffirmStatus";
+    UNDERLYING_STRIKE_CURRENCY = 941, "UnderlyingStrikeCurrency";
+    LEG_STRIKE_CURRENCY = 942, "LegStrikeCurrency";
+    TIME_BRACKET = 943, "TimeBracket";
+    COLL_ACTION = 944, "CollAction";
+    COLL_INQUIRY_STATUS = 945, "CollInquiryStatus";
+    COLL_INQUIRY_RESULT = 946, "CollInquiryResult";
+    STRIKE_CURRENCY = 947, "StrikeCurrency";
+    NO_NESTED3_PARTY_IDS = 948, "NoNested3PartyIDs";
+    NESTED3_PARTY_ID = 949, "Nested3PartyID";
+    NESTED3_PARTY_ID_SOURCE = 950, "Nested3PartyIDSource";
+    NESTED3_PARTY_ROLE = 951, "Nested3PartyRole";
+    NO_NESTED3_PARTY_SUB_IDS = 952, "NoNested3PartySubIDs";
+    NESTED3_PARTY_SUB_ID = 953, "Nested3PartySubID";
+    NESTED3_PARTY_SUB_ID_TYPE = 954, "Nested3PartySubIDType";
+    LEG_CONTRACT_SETTL_MONTH = 955, "LegContractSettlMonth";
+    LEG_INTEREST_ACCRUAL_DATE = 956, "LegInterestAccrualDate";
+    NO_STRATEGY_PARAMETERS = 957, "NoStrategyParameters";
+    STRATEGY_PARAMETER_NAME = 958, "StrategyParameterName";
+    STRATEGY_PARAMETER_TYPE = 959, "StrategyParameterType";
+    STRATEGY_PARAMETER_VALUE = 960, "StrategyParameterValue";
+    HOST_CROSS_ID = 961, "HostCrossID";
+    SIDE_TIME_IN_FORCE = 962, "SideTimeInForce";
+    MD_REPORT_ID = 963, "MDReportID";
+    SECURITY_REPORT_ID = 964, "SecurityReportID";
+    SECURITY_STATUS = 965, "SecurityStatus";
+    SETTLE_ON_OPEN_FLAG = 966, "SettleOnOpenFlag";
+    STRIKE_MULTIPLIER = 967, "StrikeMultiplier";
+    STRIKE_VALUE = 968, "StrikeValue";
+    MIN_PRICE_INCREMENT = 969, "MinPriceIncrement";
+    POSITION_LIMIT = 970, "PositionLimit";
+    NT_POSITION_LIMIT = 971, "NTPositionLimit";
+    UNDERLYING_ALLOCATION_PERCENT = 972, "UnderlyingAllocationPercent";
+    UNDERLYING_CASH_AMOUNT = 973, "UnderlyingCashAmount";
+    UNDERLYING_CASH_TYPE = 974, "UnderlyingCashType";
+    UNDERLYING_SETTLEMENT_TYPE = 975, "UnderlyingSettlementType";
+    QUANTITY_DATE = 976, "QuantityDate";
+    CONT_INT_RPT_ID = 977, "ContIntRptID";
+    LATE_INDICATOR = 978, "LateIndicator";
+    INPUT_SOURCE = 979, "InputSource";
+    SECURITY_UPDATE_ACTION = 980, "SecurityUpdateAction";
+    NO_EXPIRATION = 981, "NoExpiration";
+    EXPIRATION_QTY_TYPE = 982, "ExpirationQtyType";
+    EXP_QTY = 983, "ExpQty";
+    NO_UNDERLYING_AMOUNTS = 984, "NoUnderlyingAmounts";
+    UNDERLYING_PAY_AMOUNT = 985, "UnderlyingPayAmount";
+    UNDERLYING_COLLECT_AMOUNT = 986, "UnderlyingCollectAmount";
+    UNDERLYING_SETTLEMENT_DATE = 987, "UnderlyingSettlementDate";
+    UNDERLYING_SETTLEMENT_STATUS = 988, "UnderlyingSettlementStatus";
+    SECONDARY_INDIVIDUAL_ALLOC_ID = 989, "SecondaryIndividualAllocID";
+    LEG_REPORT_ID = 990, "LegReportID";
+    RND_PX = 991, "RndPx";
+    INDIVIDUAL_ALLOC_TYPE = 992, "IndividualAllocType";
+    ALLOC_CUSTOMER_CAPACITY = 993, "AllocCustomerCapacity";
+    TIER_CODE = 994, "TierCode";
+    UNIT_OF_MEASURE = 996, "UnitOfMeasure";
+    TIME_UNIT = 997, "TimeUnit";
+    UNDERLYING_UNIT_OF_MEASURE = 998, "UnderlyingUnitOfMeasure";
+    LEG_UNIT_OF_MEASURE = 999, "LegUnitOfMeasure";
+    UNDERLYING_TIME_UNIT = 1000, "UnderlyingTimeUnit";
+    LEG_TIME_UNIT = 1001, "LegTimeUnit";
+    ALLOC_METHOD = 1002, "AllocMethod";
+    TRADE_ID = 1003, "TradeID";
+    SIDE_TRADE_REPORT_ID = 1005, "SideTradeReportID";
+    SIDE_FILL_STATION_CD = 1006, "SideFillStationCd";
+    SIDE_REASON_CD = 1007, "SideReasonCd";
+    SIDE_TRD_SUB_TYP = 1008, "SideTrdSubTyp";
+    SIDE_LAST_QTY = 1009, "SideLastQty";
+    MESSAGE_EVENT_SOURCE = 1011, "MessageEventSource";
+    SIDE_TRD_REG_TIMESTAMP = 1012, "SideTrdRegTimestamp";
+    SIDE_TRD_REG_TIMESTAMP_TYPE = 1013, "SideTrdRegTimestampType";
+    SIDE_TRD_REG_TIMESTAMP_SRC = 1014, "SideTrdRegTimestampSrc";
+    AS_OF_INDICATOR = 1015, "AsOfIndicator";
+    NO_SIDE_TRD_REG_TS = 1016, "NoSideTrdRegTS";
+    LEG_OPTION_RATIO = 1017, "LegOptionRatio";
+    NO_INSTRUMENT_PARTIES = 1018, "NoInstrumentParties";
+    INSTRUMENT_PARTY_ID = 1019, "InstrumentPartyID";
+    TRADE_VOLUME = 1020, "TradeVolume";
+    MD_BOOK_TYPE = 1021, "MDBookType";
+    MD_FEED_TYPE = 1022, "MDFeedType";
+    MD_PRICE_LEVEL = 1023, "MDPriceLevel";
+    MD_ORIGIN_TYPE = 1024, "MDOriginType";
+    FIRST_PX = 1025, "FirstPx";
+    MD_ENTRY_SPOT_RATE = 1026, "MDEntrySpotRate";
+    MD_ENTRY_FORWARD_POINTS = 1027, "MDEntryForwardPoints";
+    MANUAL_ORDER_INDICATOR = 1028, "ManualOrderIndicator";
+    CUST_DIRECTED_ORDER = 1029, "CustDirectedOrder";
+    RECEIVED_DEPT_ID = 1030, "ReceivedDeptID";
+    CUST_ORDER_HANDLING_INST = 1031, "CustOrderHandlingInst";
+    ORDER_HANDLING_INST_SOURCE = 1032, "OrderHandlingInstSource";
+    DESK_TYPE = 1033, "DeskType";
+    DESK_TYPE_SOURCE = 1034, "DeskTypeSource";
+    DESK_ORDER_HANDLING_INST = 1035, "DeskOrderHandlingInst";
+    EXEC_ACK_STATUS = 1036, "ExecAckStatus";
+    UNDERLYING_DELIVERY_AMOUNT = 1037, "UnderlyingDeliveryAmount";
+    UNDERLYING_CAP_VALUE = 1038, "UnderlyingCapValue";
+    UNDERLYING_SETTL_METHOD = 1039, "UnderlyingSettlMethod";
+    SECONDARY_TRADE_ID = 1040, "SecondaryTradeID";
+    FIRM_TRADE_ID = 1041, "FirmTradeID";
+    SECONDARY_FIRM_TRADE_ID = 1042, "SecondaryFirmTradeID";
+    COLL_APPL_TYPE = 1043, "CollApplType";
+    UNDERLYING_ADJUSTED_QUANTITY = 1044, "UnderlyingAdjustedQuantity";
+    UNDERLYING_FX_RATE = 1045, "UnderlyingFXRate";
+    UNDERLYING_FX_RATE_CALC = 1046, "UnderlyingFXRateCalc";
+    ALLOC_POSITION_EFFECT = 1047, "AllocPositionEffect";
+    DEALING_CAPACITY = 1048, "DealingCapacity";
+    INSTRMT_ASSIGNMENT_METHOD = 1049, "InstrmtAssignmentMethod";
+    INSTRUMENT_PARTY_ID_SOURCE = 1050, "InstrumentPartyIDSource";
+    INSTRUMENT_PARTY_ROLE = 1051, "InstrumentPartyRole";
+    NO_INSTRUMENT_PARTY_SUB_IDS = 1052, "NoInstrumentPartySubIDs";
+    INSTRUMENT_PARTY_SUB_ID = 1053, "InstrumentPartySubID";
+    INSTRUMENT_PARTY_SUB_ID_TYPE = 1054, "InstrumentPartySubIDType";
+    POSITION_CURRENCY = 1055, "PositionCurrency";
+    CALCULATED_CCY_LAST_QTY = 1056, "CalculatedCcyLastQty";
+    AGGRESSOR_INDICATOR = 1057, "AggressorIndicator";
+    NO_UNDLY_INSTRUMENT_PARTIES = 1058, "NoUndlyInstrumentParties";
+    UNDERLYING_INSTRUMENT_PARTY_ID = 1059, "UnderlyingInstrumentPartyID";
+    UNDERLYING_INSTRUMENT_PARTY_ID_SOURCE = 1060, "UnderlyingInstrumentPartyIDSource";
+    UNDERLYING_INSTRUMENT_PARTY_ROLE = 1061, "UnderlyingInstrumentPartyRole";
+    NO_UNDLY_INSTRUMENT_PARTY_SUB_IDS = 1062, "NoUndlyInstrumentPartySubIDs";
+    UNDERLYING_INSTRUMENT_PARTY_SUB_ID = 1063, "UnderlyingInstrumentPartySubID";
+    UNDERLYING_INSTRUMENT_PARTY_SUB_ID_TYPE = 1064, "UnderlyingInstrumentPartySubIDType";
+    BID_SWAP_POINTS = 1065, "BidSwapPoints";
+    OFFER_SWAP_POINTS = 1066, "OfferSwapPoints";
+    LEG_BID_FORWARD_POINTS = 1067, "LegBidForwardPoints";
+    LEG_OFFER_FORWARD_POINTS = 1068, "LegOfferForwardPoints";
+    SWAP_POINTS = 1069, "SwapPoints";
+    MD_QUOTE_TYPE = 1070, "MDQuoteType";
+    LAST_SWAP_POINTS = 1071, "LastSwapPoints";
+    SIDE_GROSS_TRADE_AMT = 1072, "SideGrossTradeAmt";
+    LEG_LAST_FORWARD_POINTS = 1073, "LegLastForwardPoints";
+    LEG_CALCULATED_CCY_LAST_QTY = 1074, "LegCalculatedCcyLastQty";
+    LEG_GROSS_TRADE_AMT = 1075, "LegGrossTradeAmt";
+    MATURITY_TIME = 1079, "MaturityTime";
+    REF_ORDER_ID = 1080, "RefOrderID";
+    REF_ORDER_ID_SOURCE = 1081, "RefOrderIDSource";
+    SECONDARY_DISPLAY_QTY = 1082, "SecondaryDisplayQty";
+    DISPLAY_WHEN = 1083, "DisplayWhen";
+    DISPLAY_METHOD = 1084, "DisplayMethod";
+    DISPLAY_LOW_QTY = 1085, "DisplayLowQty";
+    DISPLAY_HIGH_QTY = 1086, "DisplayHighQty";
+    DISPLAY_MIN_INCR = 1087, "DisplayMinIncr";
+    REFRESH_QTY = 1088, "RefreshQty";
+    MATCH_INCREMENT = 1089, "MatchIncrement";
+    MAX_PRICE_LEVELS = 1090, "MaxPriceLevels";
+    PRE_TRADE_ANONYMITY = 1091, "PreTradeAnonymity";
+    PRICE_PROTECTION_SCOPE = 1092, "PriceProtectionScope";
+    LOT_TYPE = 1093, "LotType";
+    PEG_PRICE_TYPE = 1094, "PegPriceType";
+    PEGGED_REF_PRICE = 1095, "PeggedRefPrice";
+    PEG_SECURITY_ID_SOURCE = 1096, "PegSecurityIDSource";
+    PEG_SECURITY_ID = 1097, "PegSecurityID";
+    PEG_SYMBOL = 1098, "PegSymbol";
+    PEG_SECURITY_DESC = 1099, "PegSecurityDesc";
+    TRIGGER_TYPE = 1100, "TriggerType";
+    TRIGGER_ACTION = 1101, "TriggerAction";
+    TRIGGER_PRICE = 1102, "TriggerPrice";
+    TRIGGER_SYMBOL = 1103, "TriggerSymbol";
+    TRIGGER_SECURITY_ID = 1104, "TriggerSecurityID";
+    TRIGGER_SECURITY_ID_SOURCE = 1105, "TriggerSecurityIDSource";
+    TRIGGER_SECURITY_DESC = 1106, "TriggerSecurityDesc";
+    TRIGGER_PRICE_TYPE = 1107, "TriggerPriceType";
+    TRIGGER_PRICE_TYPE_SCOPE = 1108, "TriggerPriceTypeScope";
+    TRIGGER_PRICE_DIRECTION = 1109, "TriggerPriceDirection";
+    TRIGGER_NEW_PRICE = 1110, "TriggerNewPrice";
+    TRIGGER_ORDER_TYPE = 1111, "TriggerOrderType";
+    TRIGGER_NEW_QTY = 1112, "TriggerNewQty";
+    TRIGGER_TRADING_SESSION_ID = 1113, "TriggerTradingSessionID";
+    TRIGGER_TRADING_SESSION_SUB_ID = 1114, "TriggerTradingSessionSubID";
+    ORDER_CATEGORY = 1115, "OrderCategory";
+    NO_ROOT_PARTY_IDS = 1116, "NoRootPartyIDs";
+    ROOT_PARTY_ID = 1117, "RootPartyID";
+    ROOT_PARTY_ID_SOURCE = 1118, "RootPartyIDSource";
+    ROOT_PARTY_ROLE = 1119, "RootPartyRole";
+    NO_ROOT_PARTY_SUB_IDS = 1120, "NoRootPartySubIDs";
+    ROOT_PARTY_SUB_ID = 1121, "RootPartySubID";
+    ROOT_PARTY_SUB_ID_TYPE = 1122, "RootPartySubIDType";
+    TRADE_HANDLING_INSTR = 1123, "TradeHandlingInstr";
+    ORIG_TRADE_HANDLING_INSTR = 1124, "OrigTradeHandlingInstr";
+    ORIG_TRADE_DATE = 1125, "OrigTradeDate";
+    ORIG_TRADE_ID = 1126, "OrigTradeID";
+    ORIG_SECONDARY_TRADE_ID = 1127, "OrigSecondaryTradeID";
+    APPL_VER_ID = 1128, "ApplVerID";
+    CSTM_APPL_VER_ID = 1129, "CstmApplVerID";
+    REF_APPL_VER_ID = 1130, "RefApplVerID";
+    REF_CSTM_APPL_VER_ID = 1131, "RefCstmApplVerID";
+    TZ_TRANSACT_TIME = 1132, "TZTransactTime";
+    EX_DESTINATION_ID_SOURCE = 1133, "ExDestinationIDSource";
+    REPORTED_PX_DIFF = 1134, "ReportedPxDiff";
+    RPT_SYS = 1135, "RptSys";
+    ALLOC_CLEARING_FEE_INDICATOR = 1136, "AllocClearingFeeIndicator";
+    DEFAULT_APPL_VER_ID = 1137, "DefaultApplVerID";
+    DISPLAY_QTY = 1138, "DisplayQty";
+    EXCHANGE_SPECIAL_INSTRUCTIONS = 1139, "ExchangeSpecialInstructions";
+    MAX_TRADE_VOL = 1140, "MaxTradeVol";
+    NO_MD_FEED_TYPES = 1141, "NoMDFeedTypes";
+    MATCH_ALGORITHM = 1142, "MatchAlgorithm";
+    MAX_PRICE_VARIATION = 1143, "MaxPriceVariation";
+    IMPLIED_MARKET_INDICATOR = 1144, "ImpliedMarketIndicator";
+    EVENT_TIME = 1145, "EventTime";
+    MIN_PRICE_INCREMENT_AMOUNT = 1146, "MinPriceIncrementAmount";
+    UNIT_OF_MEASURE_QTY = 1147, "UnitOfMeasureQty";
+    LOW_LIMIT_PRICE = 1148, "LowLimitPrice";
+    HIGH_LIMIT_PRICE = 1149, "HighLimitPrice";
+    TRADING_REFERENCE_PRICE = 1150, "TradingReferencePrice";
+    SECURITY_GROUP = 1151, "SecurityGroup";
+    LEG_NUMBER = 1152, "LegNumber";
+    SETTLEMENT_CYCLE_NO = 1153, "SettlementCycleNo";
+    SIDE_CURRENCY = 1154, "SideCurrency";
+    SIDE_SETTL_CURRENCY = 1155, "SideSettlCurrency";
+    APPL_EXT_ID = 1156, "ApplExtID";
+    CCY_AMT = 1157, "CcyAmt";
+    NO_SETTL_DETAILS = 1158, "NoSettlDetails";
+    SETTL_OBLIG_MODE = 1159, "SettlObligMode";
+    SETTL_OBLIG_MSG_ID = 1160, "SettlObligMsgID";
+    SETTL_OBLIG_ID = 1161, "SettlObligID";
+    SETTL_OBLIG_TRANS_TYPE = 1162, "SettlObligTransType";
+    SETTL_OBLIG_REF_ID = 1163, "SettlObligRefID";
+    SETTL_OBLIG_SOURCE = 1164, "SettlObligSource";
+    NO_SETTL_OBLIG = 1165, "NoSettlOblig";
+    QUOTE_MSG_ID = 1166, "QuoteMsgID";
+    QUOTE_ENTRY_STATUS = 1167, "QuoteEntryStatus";
+    TOT_NO_CXLD_QUOTES = 1168, "TotNoCxldQuotes";
+    TOT_NO_ACC_QUOTES = 1169, "TotNoAccQuotes";
+    TOT_NO_REJ_QUOTES = 1170, "TotNoRejQuotes";
+    PRIVATE_QUOTE = 1171, "PrivateQuote";
+    RESPONDENT_TYPE = 1172, "RespondentType";
+    MD_SUB_BOOK_TYPE = 1173, "MDSubBookType";
+    SECURITY_TRADING_EVENT = 1174, "SecurityTradingEvent";
+    NO_STATS_INDICATORS = 1175, "NoStatsIndicators";
+    STATS_TYPE = 1176, "StatsType";
+    NO_OF_SEC_SIZES = 1177, "NoOfSecSizes";
+    MD_SEC_SIZE_TYPE = 1178, "MDSecSizeType";
+    MD_SEC_SIZE = 1179, "MDSecSize";
+    APPL_ID = 1180, "ApplID";
+    APPL_SEQ_NUM = 1181, "ApplSeqNum";
+    APPL_BEG_SEQ_NUM = 1182, "ApplBegSeqNum";
+    APPL_END_SEQ_NUM = 1183, "ApplEndSeqNum";
+    SECURITY_XML_LEN = 1184, "SecurityXMLLen";
+    SECURITY_XML = 1185, "SecurityXML";
+    SECURITY_XML_SCHEMA = 1186, "SecurityXMLSchema";
+    REFRESH_INDICATOR = 1187, "RefreshIndicator";
+    VOLATILITY = 1188, "Volatility";
+    TIME_TO_EXPIRATION = 1189, "TimeToExpiration";
+    RISK_FREE_RATE = 1190, "RiskFreeRate";
+    PRICE_UNIT_OF_MEASURE = 1191, "PriceUnitOfMeasure";
+    PRICE_UNIT_OF_MEASURE_QTY = 1192, "PriceUnitOfMeasureQty";
+    SETTL_METHOD = 1193, "SettlMethod";
+    EXERCISE_STYLE = 1194, "ExerciseStyle";
+    OPT_PAYOUT_AMOUNT = 1195, "OptPayoutAmount";
+    PRICE_QUOTE_METHOD = 1196, "PriceQuoteMethod";
+    VALUATION_METHOD = 1197, "ValuationMethod";
+    LIST_METHOD = 1198, "ListMethod";
+    CAP_PRICE = 1199, "CapPrice";
+    FLOOR_PRICE = 1200, "FloorPrice";
+    NO_STRIKE_RULES = 1201, "NoStrikeRules";
+    START_STRIKE_PX_RANGE = 1202, "StartStrikePxRange";
+    END_STRIKE_PX_RANGE = 1203, "EndStrikePxRange";
+    STRIKE_INCREMENT = 1204, "StrikeIncrement";
+    NO_TICK_RULES = 1205, "NoTickRules";
+    START_TICK_PRICE_RANGE = 1206, "StartTickPriceRange";
+    END_TICK_PRICE_RANGE = 1207, "EndTickPriceRange";
+    TICK_INCREMENT = 1208, "TickIncrement";
+    TICK_RULE_TYPE = 1209, "TickRuleType";
+    NESTED_INSTR_ATTRIB_TYPE = 1210, "NestedInstrAttribType";
+    NESTED_INSTR_ATTRIB_VALUE = 1211, "NestedInstrAttribValue";
+    LEG_MATURITY_TIME = 1212, "LegMaturityTime";
+    UNDERLYING_MATURITY_TIME = 1213, "UnderlyingMaturityTime";
+    DERIVATIVE_SYMBOL = 1214, "DerivativeSymbol";
+    DERIVATIVE_SYMBOL_SFX = 1215, "DerivativeSymbolSfx";
+    DERIVATIVE_SECURITY_ID = 1216, "DerivativeSecurityID";
+    DERIVATIVE_SECURITY_ID_SOURCE = 1217, "DerivativeSecurityIDSource";
+    NO_DERIVATIVE_SECURITY_ALT_ID = 1218, "NoDerivativeSecurityAltID";
+    DERIVATIVE_SECURITY_ALT_ID = 1219, "DerivativeSecurityAltID";
+    DERIVATIVE_SECURITY_ALT_ID_SOURCE = 1220, "DerivativeSecurityAltIDSource";
+    SECONDARY_LOW_LIMIT_PRICE = 1221, "SecondaryLowLimitPrice";
+    MATURITY_RULE_ID = 1222, "MaturityRuleID";
+    STRIKE_RULE_ID = 1223, "StrikeRuleID";
+    LEG_UNIT_OF_MEASURE_QTY = 1224, "LegUnitOfMeasureQty";
+    DERIVATIVE_OPT_PAY_AMOUNT = 1225, "DerivativeOptPayAmount";
+    END_MATURITY_MONTH_YEAR = 1226, "EndMaturityMonthYear";
+    PRODUCT_COMPLEX = 1227, "ProductComplex";
+    DERIVATIVE_PRODUCT_COMPLEX = 1228, "DerivativeProductComplex";
+    MATURITY_MONTH_YEAR_INCREMENT = 1229, "MaturityMonthYearIncrement";
+    SECONDARY_HIGH_LIMIT_PRICE = 1230, "SecondaryHighLimitPrice";
+    MIN_LOT_SIZE = 1231, "MinLotSize";
+    NO_EXEC_INST_RULES = 1232, "NoExecInstRules";
+    NO_LOT_TYPE_RULES = 1234, "NoLotTypeRules";
+    NO_MATCH_RULES = 1235, "NoMatchRules";
+    NO_MATURITY_RULES = 1236, "NoMaturityRules";
+    NO_ORD_TYPE_RULES = 1237, "NoOrdTypeRules";
+    NO_TIME_IN_FORCE_RULES = 1239, "NoTimeInForceRules";
+    SECONDARY_TRADING_REFERENCE_PRICE = 1240, "SecondaryTradingReferencePrice";
+    START_MATURITY_MONTH_YEAR = 1241, "StartMaturityMonthYear";
+    FLEX_PRODUCT_ELIGIBILITY_INDICATOR = 1242, "FlexProductEligibilityIndicator";
+    DERIV_FLEX_PRODUCT_ELIGIBILITY_INDICATOR = 1243, "DerivFlexProductEligibilityIndicator";
+    FLEXIBLE_INDICATOR = 1244, "FlexibleIndicator";
+    TRADING_CURRENCY = 1245, "TradingCurrency";
+    DERIVATIVE_PRODUCT = 1246, "DerivativeProduct";
+    DERIVATIVE_SECURITY_GROUP = 1247, "DerivativeSecurityGroup";
+    DERIVATIVE_CFI_CODE = 1248, "DerivativeCFICode";
+    DERIVATIVE_SECURITY_TYPE = 1249, "DerivativeSecurityType";
+    DERIVATIVE_SECURITY_SUB_TYPE = 1250, "DerivativeSecuritySubType";
+    DERIVATIVE_MATURITY_MONTH_YEAR = 1251, "DerivativeMaturityMonthYear";
+    DERIVATIVE_MATURITY_DATE = 1252, "DerivativeMaturityDate";
+    DERIVATIVE_MATURITY_TIME = 1253, "DerivativeMaturityTime";
+    DERIVATIVE_SETTLE_ON_OPEN_FLAG = 1254, "DerivativeSettleOnOpenFlag";
+    DERIVATIVE_INSTRMT_ASSIGNMENT_METHOD = 1255, "DerivativeInstrmtAssignmentMethod";
+    DERIVATIVE_SECURITY_STATUS = 1256, "DerivativeSecurityStatus";
+    DERIVATIVE_INSTR_REGISTRY = 1257, "DerivativeInstrRegistry";
+    DERIVATIVE_COUNTRY_OF_ISSUE = 1258, "DerivativeCountryOfIssue";
+    DERIVATIVE_STATE_OR_PROVINCE_OF_ISSUE = 1259, "DerivativeStateOrProvinceOfIssue";
+    DERIVATIVE_LOCALE_OF_ISSUE = 1260, "DerivativeLocaleOfIssue";
+    DERIVATIVE_STRIKE_PRICE = 1261, "DerivativeStrikePrice";
+    DERIVATIVE_STRIKE_CURRENCY = 1262, "DerivativeStrikeCurrency";
+    DERIVATIVE_STRIKE_MULTIPLIER = 1263, "DerivativeStrikeMultiplier";
+    DERIVATIVE_STRIKE_VALUE = 1264, "DerivativeStrikeValue";
+    DERIVATIVE_OPT_ATTRIBUTE = 1265, "DerivativeOptAttribute";
+    DERIVATIVE_CONTRACT_MULTIPLIER = 1266, "DerivativeContractMultiplier";
+    DERIVATIVE_MIN_PRICE_INCREMENT = 1267, "DerivativeMinPriceIncrement";
+    DERIVATIVE_MIN_PRICE_INCREMENT_AMOUNT = 1268, "DerivativeMinPriceIncrementAmount";
+    DERIVATIVE_UNIT_OF_MEASURE = 1269, "DerivativeUnitOfMeasure";
+    DERIVATIVE_UNIT_OF_MEASURE_QTY = 1270, "DerivativeUnitOfMeasureQty";
+    DERIVATIVE_TIME_UNIT = 1271, "DerivativeTimeUnit";
+    DERIVATIVE_SECURITY_EXCHANGE = 1272, "DerivativeSecurityExchange";
+    DERIVATIVE_POSITION_LIMIT = 1273, "DerivativePositionLimit";
+    DERIVATIVE_NT_POSITION_LIMIT = 1274, "DerivativeNTPositionLimit";
+    DERIVATIVE_ISSUER = 1275, "DerivativeIssuer";
+    DERIVATIVE_ISSUE_DATE = 1276, "DerivativeIssueDate";
+    DERIVATIVE_ENCODED_ISSUER_LEN = 1277, "DerivativeEncodedIssuerLen";
+    DERIVATIVE_ENCODED_ISSUER = 1278, "DerivativeEncodedIssuer";
+    DERIVATIVE_SECURITY_DESC = 1279, "DerivativeSecurityDesc";
+    DERIVATIVE_ENCODED_SECURITY_DESC_LEN = 1280, "DerivativeEncodedSecurityDescLen";
+    DERIVATIVE_ENCODED_SECURITY_DESC = 1281, "DerivativeEncodedSecurityDesc";
+    DERIVATIVE_SECURITY_XML_LEN = 1282, "DerivativeSecurityXMLLen";
+    DERIVATIVE_SECURITY_XML = 1283, "DerivativeSecurityXML";
+    DERIVATIVE_SECURITY_XML_SCHEMA = 1284, "DerivativeSecurityXMLSchema";
+    DERIVATIVE_CONTRACT_SETTL_MONTH = 1285, "DerivativeContractSettlMonth";
+    NO_DERIVATIVE_EVENTS = 1286, "NoDerivativeEvents";
+    DERIVATIVE_EVENT_TYPE = 1287, "DerivativeEventType";
+    DERIVATIVE_EVENT_DATE = 1288, "DerivativeEventDate";
+    DERIVATIVE_EVENT_TIME = 1289, "DerivativeEventTime";
+    DERIVATIVE_EVENT_PX = 1290, "DerivativeEventPx";
+    DERIVATIVE_EVENT_TEXT = 1291, "DerivativeEventText";
+    NO_DERIVATIVE_INSTRUMENT_PARTIES = 1292, "NoDerivativeInstrumentParties";
+    DERIVATIVE_INSTRUMENT_PARTY_ID = 1293, "DerivativeInstrumentPartyID";
+    DERIVATIVE_INSTRUMENT_PARTY_ID_SOURCE = 1294, "DerivativeInstrumentPartyIDSource";
+    DERIVATIVE_INSTRUMENT_PARTY_ROLE = 1295, "DerivativeInstrumentPartyRole";
+    NO_DERIVATIVE_INSTRUMENT_PARTY_SUB_IDS = 1296, "NoDerivativeInstrumentPartySubIDs";
+    DERIVATIVE_INSTRUMENT_PARTY_SUB_ID = 1297, "DerivativeInstrumentPartySubID";
+    DERIVATIVE_INSTRUMENT_PARTY_SUB_ID_TYPE = 1298, "DerivativeInstrumentPartySubIDType";
+    DERIVATIVE_EXERCISE_STYLE = 1299, "DerivativeExerciseStyle";
+    MARKET_SEGMENT_ID = 1300, "MarketSegmentID";
+    MARKET_ID = 1301, "MarketID";
+    MATURITY_MONTH_YEAR_INCREMENT_UNITS = 1302, "MaturityMonthYearIncrementUnits";
+    MATURITY_MONTH_YEAR_FORMAT = 1303, "MaturityMonthYearFormat";
+    STRIKE_EXERCISE_STYLE = 1304, "StrikeExerciseStyle";
+    SECONDARY_PRICE_LIMIT_TYPE = 1305, "SecondaryPriceLimitType";
+    PRICE_LIMIT_TYPE = 1306, "PriceLimitType";
+    DERIVATIVE_SECURITY_LIST_REQUEST_TYPE = 1307, "DerivativeSecurityListRequestType";
+    EXEC_INST_VALUE = 1308, "ExecInstValue";
+    NO_TRADING_SESSION_RULES = 1309, "NoTradingSessionRules";
+    NO_MARKET_SEGMENTS = 1310, "NoMarketSegments";
+    NO_DERIVATIVE_INSTR_ATTRIB = 1311, "NoDerivativeInstrAttrib";
+    NO_NESTED_INSTR_ATTRIB = 1312, "NoNestedInstrAttrib";
+    DERIVATIVE_INSTR_ATTRIB_TYPE = 1313, "DerivativeInstrAttribType";
+    DERIVATIVE_INSTR_ATTRIB_VALUE = 1314, "DerivativeInstrAttribValue";
+    DERIVATIVE_PRICE_UNIT_OF_MEASURE = 1315, "DerivativePriceUnitOfMeasure";
+    DERIVATIVE_PRICE_UNIT_OF_MEASURE_QTY = 1316, "DerivativePriceUnitOfMeasureQty";
+    DERIVATIVE_SETTL_METHOD = 1317, "DerivativeSettlMethod";
+    DERIVATIVE_PRICE_QUOTE_METHOD = 1318, "DerivativePriceQuoteMethod";
+    DERIVATIVE_VALUATION_METHOD = 1319, "DerivativeValuationMethod";
+    DERIVATIVE_LIST_METHOD = 1320, "DerivativeListMethod";
+    DERIVATIVE_CAP_PRICE = 1321, "DerivativeCapPrice";
+    DERIVATIVE_FLOOR_PRICE = 1322, "DerivativeFloorPrice";
+    DERIVATIVE_PUT_OR_CALL = 1323, "DerivativePutOrCall";
+    LIST_UPDATE_ACTION = 1324, "ListUpdateAction";
+    PARENT_MKT_SEGM_ID = 1325, "ParentMktSegmID";
+    TRADING_SESSION_DESC = 1326, "TradingSessionDesc";
+    TRAD_SES_UPDATE_ACTION = 1327, "TradSesUpdateAction";
+    REJECT_TEXT = 1328, "RejectText";
+    FEE_MULTIPLIER = 1329, "FeeMultiplier";
+    UNDERLYING_LEG_SYMBOL = 1330, "UnderlyingLegSymbol";
+    UNDERLYING_LEG_SYMBOL_SFX = 1331, "UnderlyingLegSymbolSfx";
+    UNDERLYING_LEG_SECURITY_ID = 1332, "UnderlyingLegSecurityID";
+    UNDERLYING_LEG_SECURITY_ID_SOURCE = 1333, "UnderlyingLegSecurityIDSource";
+    NO_UNDERLYING_LEG_SECURITY_ALT_ID = 1334, "NoUnderlyingLegSecurityAltID";
+    UNDERLYING_LEG_SECURITY_ALT_ID = 1335, "UnderlyingLegSecurityAltID";
+    UNDERLYING_LEG_SECURITY_ALT_ID_SOURCE = 1336, "UnderlyingLegSecurityAltIDSource";
+    UNDERLYING_LEG_SECURITY_TYPE = 1337, "UnderlyingLegSecurityType";
+    UNDERLYING_LEG_SECURITY_SUB_TYPE = 1338, "UnderlyingLegSecuritySubType";
+    UNDERLYING_LEG_MATURITY_MONTH_YEAR = 1339, "UnderlyingLegMaturityMonthYear";
+    UNDERLYING_LEG_STRIKE_PRICE = 1340, "UnderlyingLegStrikePrice";
+    UNDERLYING_LEG_SECURITY_EXCHANGE = 1341, "UnderlyingLegSecurityExchange";
+    NO_OF_LEG_UNDERLYINGS = 1342, "NoOfLegUnderlyings";
+    UNDERLYING_LEG_PUT_OR_CALL = 1343, "UnderlyingLegPutOrCall";
+    UNDERLYING_LEG_CFI_CODE = 1344, "UnderlyingLegCFICode";
+    UNDERLYING_LEG_MATURITY_DATE = 1345, "UnderlyingLegMaturityDate";
+    APPL_REQ_ID = 1346, "ApplReqID";
+    APPL_REQ_TYPE = 1347, "ApplReqType";
+    APPL_RESPONSE_TYPE = 1348, "ApplResponseType";
+    APPL_TOTAL_MESSAGE_COUNT = 1349, "ApplTotalMessageCount";
+    APPL_LAST_SEQ_NUM = 1350, "ApplLastSeqNum";
+    NO_APPL_IDS = 1351, "NoApplIDs";
+    APPL_RESEND_FLAG = 1352, "ApplResendFlag";
+    APPL_RESPONSE_ID = 1353, "ApplResponseID";
+    APPL_RESPONSE_ERROR = 1354, "ApplResponseError";
+    REF_APPL_ID = 1355, "RefApplID";
+    APPL_REPORT_ID = 1356, "ApplReportID";
+    REF_APPL_LAST_SEQ_NUM = 1357, "RefApplLastSeqNum";
+    LEG_PUT_OR_CALL = 1358, "LegPutOrCall";
+    ENCODED_SYMBOL_LEN = 1359, "EncodedSymbolLen";
+    ENCODED_SYMBOL = 1360, "EncodedSymbol";
+    TOT_NO_FILLS = 1361, "TotNoFills";
+    NO_FILLS = 1362, "NoFills";
+    FILL_EXEC_ID = 1363, "FillExecID";
+    FILL_PX = 1364, "FillPx";
+    FILL_QTY = 1365, "FillQty";
+    LEG_ALLOC_ID = 1366, "LegAllocID";
+    LEG_ALLOC_SETTL_CURRENCY = 1367, "LegAllocSettlCurrency";
+    TRAD_SES_EVENT = 1368, "TradSesEvent";
+    MASS_ACTION_REPORT_ID = 1369, "MassActionReportID";
+    NO_NOT_AFFECTED_ORDERS = 1370, "NoNotAffectedOrders";
+    NOT_AFFECTED_ORDER_ID = 1371, "NotAffectedOrderID";
+    NOT_AFF_ORIG_CL_ORD_ID = 1372, "NotAffOrigClOrdID";
+    MASS_ACTION_TYPE = 1373, "MassActionType";
+    MASS_ACTION_SCOPE = 1374, "MassActionScope";
+    MASS_ACTION_RESPONSE = 1375, "MassActionResponse";
+    MASS_ACTION_REJECT_REASON = 1376, "MassActionRejectReason";
+    MULTILEG_MODEL = 1377, "MultilegModel";
+    MULTILEG_PRICE_METHOD = 1378, "MultilegPriceMethod";
+    LEG_VOLATILITY = 1379, "LegVolatility";
+    DIVIDEND_YIELD = 1380, "DividendYield";
+    LEG_DIVIDEND_YIELD = 1381, "LegDividendYield";
+    CURRENCY_RATIO = 1382, "CurrencyRatio";
+    LEG_CURRENCY_RATIO = 1383, "LegCurrencyRatio";
+    LEG_EXEC_INST = 1384, "LegExecInst";
+    CONTINGENCY_TYPE = 1385, "ContingencyType";
+    LIST_REJECT_REASON = 1386, "ListRejectReason";
+    NO_TRD_REP_INDICATORS = 1387, "NoTrdRepIndicators";
+    TRD_REP_PARTY_ROLE = 1388, "TrdRepPartyRole";
+    TRD_REP_INDICATOR = 1389, "TrdRepIndicator";
+    TRADE_PUBLISH_INDICATOR = 1390, "TradePublishIndicator";
+    UNDERLYING_LEG_OPT_ATTRIBUTE = 1391, "UnderlyingLegOptAttribute";
+    UNDERLYING_LEG_SECURITY_DESC = 1392, "UnderlyingLegSecurityDesc";
+    MARKET_REQ_ID = 1393, "MarketReqID";
+    MARKET_REPORT_ID = 1394, "MarketReportID";
+    MARKET_UPDATE_ACTION = 1395, "MarketUpdateAction";
+    MARKET_SEGMENT_DESC = 1396, "MarketSegmentDesc";
+    ENCODED_MKT_SEGM_DESC_LEN = 1397, "EncodedMktSegmDescLen";
+    ENCODED_MKT_SEGM_DESC = 1398, "EncodedMktSegmDesc";
+    APPL_NEW_SEQ_NUM = 1399, "ApplNewSeqNum";
+    ENCRYPTED_PASSWORD_METHOD = 1400, "EncryptedPasswordMethod";
+    ENCRYPTED_PASSWORD_LEN = 1401, "EncryptedPasswordLen";
+    ENCRYPTED_PASSWORD = 1402, "EncryptedPassword";
+    ENCRYPTED_NEW_PASSWORD_LEN = 1403, "EncryptedNewPasswordLen";
+    ENCRYPTED_NEW_PASSWORD = 1404, "EncryptedNewPassword";
+    UNDERLYING_LEG_MATURITY_TIME = 1405, "UnderlyingLegMaturityTime";
+    REF_APPL_EXT_ID = 1406, "RefApplExtID";
+    DEFAULT_APPL_EXT_ID = 1407, "DefaultApplExtID";
+    DEFAULT_CSTM_APPL_VER_ID = 1408, "DefaultCstmApplVerID";
+    SESSION_STATUS = 1409, "SessionStatus";
+    DEFAULT_VER_INDICATOR = 1410, "DefaultVerIndicator";
+    NESTED4_PARTY_SUB_ID_TYPE = 1411, "Nested4PartySubIDType";
+    NESTED4_PARTY_SUB_ID = 1412, "Nested4PartySubID";
+    NO_NESTED4_PARTY_SUB_IDS = 1413, "NoNested4PartySubIDs";
+    NO_NESTED4_PARTY_IDS = 1414, "NoNested4PartyIDs";
+    NESTED4_PARTY_ID = 1415, "Nested4PartyID";
+    NESTED4_PARTY_ID_SOURCE = 1416, "Nested4PartyIDSource";
+    NESTED4_PARTY_ROLE = 1417, "Nested4PartyRole";
+    LEG_LAST_QTY = 1418, "LegLastQty";
+    UNDERLYING_EXERCISE_STYLE = 1419, "UnderlyingExerciseStyle";
+    LEG_EXERCISE_STYLE = 1420, "LegExerciseStyle";
+    LEG_PRICE_UNIT_OF_MEASURE = 1421, "LegPriceUnitOfMeasure";
+    LEG_PRICE_UNIT_OF_MEASURE_QTY = 1422, "LegPriceUnitOfMeasureQty";
+    UNDERLYING_UNIT_OF_MEASURE_QTY = 1423, "UnderlyingUnitOfMeasureQty";
+    UNDERLYING_PRICE_UNIT_OF_MEASURE = 1424, "UnderlyingPriceUnitOfMeasure";
+    UNDERLYING_PRICE_UNIT_OF_MEASURE_QTY = 1425, "UnderlyingPriceUnitOfMeasureQty";
+    APPL_REPORT_TYPE = 1426, "ApplReportType";
+    SIDE_EXEC_ID = 1427, "SideExecID";
+    ORDER_DELAY = 1428, "OrderDelay";
+    ORDER_DELAY_UNIT = 1429, "OrderDelayUnit";
+    VENUE_TYPE = 1430, "VenueType";
+    REF_ORD_ID_REASON = 1431, "RefOrdIDReason";
+    ORIG_CUST_ORDER_CAPACITY = 1432, "OrigCustOrderCapacity";
+    REF_APPL_REQ_ID = 1433, "RefApplReqID";
+    MODEL_TYPE = 1434, "ModelType";
+    CONTRACT_MULTIPLIER_UNIT = 1435, "ContractMultiplierUnit";
+    LEG_CONTRACT_MULTIPLIER_UNIT = 1436, "LegContractMultiplierUnit";
+    UNDERLYING_CONTRACT_MULTIPLIER_UNIT = 1437, "UnderlyingContractMultiplierUnit";
+    DERIVATIVE_CONTRACT_MULTIPLIER_UNIT = 1438, "DerivativeContractMultiplierUnit";
+    FLOW_SCHEDULE_TYPE = 1439, "FlowScheduleType";
+    LEG_FLOW_SCHEDULE_TYPE = 1440, "LegFlowScheduleType";
+    UNDERLYING_FLOW_SCHEDULE_TYPE = 1441, "UnderlyingFlowScheduleType";
+    DERIVATIVE_FLOW_SCHEDULE_TYPE = 1442, "DerivativeFlowScheduleType";
+    FILL_LIQUIDITY_IND = 1443, "FillLiquidityInd";
+    SIDE_LIQUIDITY_IND = 1444, "SideLiquidityInd";
+    NO_RATE_SOURCES = 1445, "NoRateSources";
+    RATE_SOURCE = 1446, "RateSource";
+    RATE_SOURCE_TYPE = 1447, "RateSourceType";
+    REFERENCE_PAGE = 1448, "ReferencePage";
+    RESTRUCTURING_TYPE = 1449, "RestructuringType";
+    SENIORITY = 1450, "Seniority";
+    NOTIONAL_PERCENTAGE_OUTSTANDING = 1451, "NotionalPercentageOutstanding";
+    ORIGINAL_NOTIONAL_PERCENTAGE_OUTSTANDING = 1452, "OriginalNotionalPercentageOutstanding";
+    UNDERLYING_RESTRUCTURING_TYPE = 1453, "UnderlyingRestructuringType";
+    UNDERLYING_SENIORITY = 1454, "UnderlyingSeniority";
+    UNDERLYING_NOTIONAL_PERCENTAGE_OUTSTANDING = 1455, "UnderlyingNotionalPercentageOutstanding";
+    UNDERLYING_ORIGINAL_NOTIONAL_PERCENTAGE_OUTSTANDING = 1456, "UnderlyingOriginalNotionalPercentageOutstanding";
+    ATTACHMENT_POINT = 1457, "AttachmentPoint";
+    DETACHMENT_POINT = 1458, "DetachmentPoint";
+    UNDERLYING_ATTACHMENT_POINT = 1459, "UnderlyingAttachmentPoint";
+    UNDERLYING_DETACHMENT_POINT = 1460, "UnderlyingDetachmentPoint";
+    NO_TARGET_PARTY_IDS = 1461, "NoTargetPartyIDs";
+    TARGET_PARTY_ID = 1462, "TargetPartyID";
+    TARGET_PARTY_ID_SOURCE = 1463, "TargetPartyIDSource";
+    TARGET_PARTY_ROLE = 1464, "TargetPartyRole";
+    SECURITY_LIST_ID = 1465, "SecurityListID";
+    SECURITY_LIST_REF_ID = 1466, "SecurityListRefID";
+    SECURITY_LIST_DESC = 1467, "SecurityListDesc";
+    ENCODED_SECURITY_LIST_DESC_LEN = 1468, "EncodedSecurityListDescLen";
+    ENCODED_SECURITY_LIST_DESC = 1469, "EncodedSecurityListDesc";
+    SECURITY_LIST_TYPE = 1470, "SecurityListType";
+    SECURITY_LIST_TYPE_SOURCE = 1471, "SecurityListTypeSource";
+    NEWS_ID = 1472, "NewsID";
+    NEWS_CATEGORY = 1473, "NewsCategory";
+    LANGUAGE_CODE = 1474, "LanguageCode";
+    NO_NEWS_REF_IDS = 1475, "NoNewsRefIDs";
+    NEWS_REF_ID = 1476, "NewsRefID";
+    NEWS_REF_TYPE = 1477, "NewsRefType";
+    STRIKE_PRICE_DETERMINATION_METHOD = 1478, "StrikePriceDeterminationMethod";
+    STRIKE_PRICE_BOUNDARY_METHOD = 1479, "StrikePriceBoundaryMethod";
+    STRIKE_PRICE_BOUNDARY_PRECISION = 1480, "StrikePriceBoundaryPrecision";
+    UNDERLYING_PRICE_DETERMINATION_METHOD = 1481, "UnderlyingPriceDeterminationMethod";
+    OPT_PAYOUT_TYPE = 1482, "OptPayoutType";
+    NO_COMPLEX_EVENTS = 1483, "NoComplexEvents";
+    COMPLEX_EVENT_TYPE = 1484, "ComplexEventType";
+    COMPLEX_OPT_PAYOUT_AMOUNT = 1485, "ComplexOptPayoutAmount";
+    COMPLEX_EVENT_PRICE = 1486, "ComplexEventPrice";
+    COMPLEX_EVENT_PRICE_BOUNDARY_METHOD = 1487, "ComplexEventPriceBoundaryMethod";
+    COMPLEX_EVENT_PRICE_BOUNDARY_PRECISION = 1488, "ComplexEventPriceBoundaryPrecision";
+    COMPLEX_EVENT_PRICE_TIME_TYPE = 1489, "ComplexEventPriceTimeType";
+    COMPLEX_EVENT_CONDITION = 1490, "ComplexEventCondition";
+    NO_COMPLEX_EVENT_DATES = 1491, "NoComplexEventDates";
+    COMPLEX_EVENT_START_DATE = 1492, "ComplexEventStartDate";
+    COMPLEX_EVENT_END_DATE = 1493, "ComplexEventEndDate";
+    NO_COMPLEX_EVENT_TIMES = 1494, "NoComplexEventTimes";
+    COMPLEX_EVENT_START_TIME = 1495, "ComplexEventStartTime";
+    COMPLEX_EVENT_END_TIME = 1496, "ComplexEventEndTime";
+    STREAM_ASGN_REQ_ID = 1497, "StreamAsgnReqID";
+    STREAM_ASGN_REQ_TYPE = 1498, "StreamAsgnReqType";
+    NO_ASGN_REQS = 1499, "NoAsgnReqs";
+    MD_STREAM_ID = 1500, "MDStreamID";
+    STREAM_ASGN_RPT_ID = 1501, "StreamAsgnRptID";
+    STREAM_ASGN_REJ_REASON = 1502, "StreamAsgnRejReason";
+    STREAM_ASGN_ACK_TYPE = 1503, "StreamAsgnAckType";
+    REL_SYM_TRANSACT_TIME = 1504, "RelSymTransactTime";
+    PARTY_DETAILS_LIST_REQUEST_ID = 1505, "PartyDetailsListRequestID";
+    NO_PARTY_LIST_RESPONSE_TYPES = 1506, "NoPartyListResponseTypes";
+    PARTY_LIST_RESPONSE_TYPE = 1507, "PartyListResponseType";
+    NO_REQUESTED_PARTY_ROLES = 1508, "NoRequestedPartyRoles";
+    REQUESTED_PARTY_ROLE = 1509, "RequestedPartyRole";
+    PARTY_DETAILS_LIST_REPORT_ID = 1510, "PartyDetailsListReportID";
+    PARTY_DETAILS_REQUEST_RESULT = 1511, "PartyDetailsRequestResult";
+    TOT_NO_PARTY_LIST = 1512, "TotNoPartyList";
+    NO_PARTY_LIST = 1513, "NoPartyList";
+    NO_PARTY_RELATIONSHIPS = 1514, "NoPartyRelationships";
+    PARTY_RELATIONSHIP = 1515, "PartyRelationship";
+    NO_PARTY_ALT_IDS = 1516, "NoPartyAltIDs";
+    PARTY_ALT_ID = 1517, "PartyAltID";
+    PARTY_ALT_ID_SOURCE = 1518, "PartyAltIDSource";
+    NO_PARTY_ALT_SUB_IDS = 1519, "NoPartyAltSubIDs";
+    PARTY_ALT_SUB_ID = 1520, "PartyAltSubID";
+    PARTY_ALT_SUB_ID_TYPE = 1521, "PartyAltSubIDType";
+    NO_CONTEXT_PARTY_IDS = 1522, "NoContextPartyIDs";
+    CONTEXT_PARTY_ID = 1523, "ContextPartyID";
+    CONTEXT_PARTY_ID_SOURCE = 1524, "ContextPartyIDSource";
+    CONTEXT_PARTY_ROLE = 1525, "ContextPartyRole";
+    NO_CONTEXT_PARTY_SUB_IDS = 1526, "NoContextPartySubIDs";
+    CONTEXT_PARTY_SUB_ID = 1527, "ContextPartySubID";
+    CONTEXT_PARTY_SUB_ID_TYPE = 1528, "ContextPartySubIDType";
+    NO_RISK_LIMITS = 1529, "NoRiskLimits";
+    RISK_LIMIT_TYPE = 1530, "RiskLimitType";
+    RISK_LIMIT_AMOUNT = 1531, "RiskLimitAmount";
+    RISK_LIMIT_CURRENCY = 1532, "RiskLimitCurrency";
+    RISK_LIMIT_PLATFORM = 1533, "RiskLimitPlatform";
+    NO_RISK_INSTRUMENTS = 1534, "NoRiskInstruments";
+    RISK_INSTRUMENT_OPERATOR = 1535, "RiskInstrumentOperator";
+    RISK_SYMBOL = 1536, "RiskSymbol";
+    RISK_SYMBOL_SFX = 1537, "RiskSymbolSfx";
+    RISK_SECURITY_ID = 1538, "RiskSecurityID";
+    RISK_SECURITY_ID_SOURCE = 1539, "RiskSecurityIDSource";
+    NO_RISK_SECURITY_ALT_ID = 1540, "NoRiskSecurityAltID";
+    RISK_SECURITY_ALT_ID = 1541, "RiskSecurityAltID";
+    RISK_SECURITY_ALT_ID_SOURCE = 1542, "RiskSecurityAltIDSource";
+    RISK_PRODUCT = 1543, "RiskProduct";
+    RISK_PRODUCT_COMPLEX = 1544, "RiskProductComplex";
+    RISK_SECURITY_GROUP = 1545, "RiskSecurityGroup";
+    RISK_CFI_CODE = 1546, "RiskCFICode";
+    RISK_SECURITY_TYPE = 1547, "RiskSecurityType";
+    RISK_SECURITY_SUB_TYPE = 1548, "RiskSecuritySubType";
+    RISK_MATURITY_MONTH_YEAR = 1549, "RiskMaturityMonthYear";
+    RISK_MATURITY_TIME = 1550, "RiskMaturityTime";
+    RISK_RESTRUCTURING_TYPE = 1551, "RiskRestructuringType";
+    RISK_SENIORITY = 1552, "RiskSeniority";
+    RISK_PUT_OR_CALL = 1553, "RiskPutOrCall";
+    RISK_FLEXIBLE_INDICATOR = 1554, "RiskFlexibleIndicator";
+    RISK_COUPON_RATE = 1555, "RiskCouponRate";
+    RISK_SECURITY_DESC = 1556, "RiskSecurityDesc";
+    RISK_INSTRUMENT_SETTL_TYPE = 1557, "RiskInstrumentSettlType";
+    RISK_INSTRUMENT_MULTIPLIER = 1558, "RiskInstrumentMultiplier";
+    NO_RISK_WARNING_LEVELS = 1559, "NoRiskWarningLevels";
+    RISK_WARNING_LEVEL_PERCENT = 1560, "RiskWarningLevelPercent";
+    RISK_WARNING_LEVEL_NAME = 1561, "RiskWarningLevelName";
+    NO_RELATED_PARTY_IDS = 1562, "NoRelatedPartyIDs";
+    RELATED_PARTY_ID = 1563, "RelatedPartyID";
+    RELATED_PARTY_ID_SOURCE = 1564, "RelatedPartyIDSource";
+    RELATED_PARTY_ROLE = 1565, "RelatedPartyRole";
+    NO_RELATED_PARTY_SUB_IDS = 1566, "NoRelatedPartySubIDs";
+    RELATED_PARTY_SUB_ID = 1567, "RelatedPartySubID";
+    RELATED_PARTY_SUB_ID_TYPE = 1568, "RelatedPartySubIDType";
+    NO_RELATED_PARTY_ALT_IDS = 1569, "NoRelatedPartyAltIDs";
+    RELATED_PARTY_ALT_ID = 1570, "RelatedPartyAltID";
+    RELATED_PARTY_ALT_ID_SOURCE = 1571, "RelatedPartyAltIDSource";
+    NO_RELATED_PARTY_ALT_SUB_IDS = 1572, "NoRelatedPartyAltSubIDs";
+    RELATED_PARTY_ALT_SUB_ID = 1573, "RelatedPartyAltSubID";
+    RELATED_PARTY_ALT_SUB_ID_TYPE = 1574, "RelatedPartyAltSubIDType";
+    NO_RELATED_CONTEXT_PARTY_IDS = 1575, "NoRelatedContextPartyIDs";
+    RELATED_CONTEXT_PARTY_ID = 1576, "RelatedContextPartyID";
+    RELATED_CONTEXT_PARTY_ID_SOURCE = 1577, "RelatedContextPartyIDSource";
+    RELATED_CONTEXT_PARTY_ROLE = 1578, "RelatedContextPartyRole";
+    NO_RELATED_CONTEXT_PARTY_SUB_IDS = 1579, "NoRelatedContextPartySubIDs";
+    RELATED_CONTEXT_PARTY_SUB_ID = 1580, "RelatedContextPartySubID";
+    RELATED_CONTEXT_PARTY_SUB_ID_TYPE = 1581, "RelatedContextPartySubIDType";
+    NO_RELATIONSHIP_RISK_LIMITS = 1582, "NoRelationshipRiskLimits";
+    RELATIONSHIP_RISK_LIMIT_TYPE = 1583, "RelationshipRiskLimitType";
+    RELATIONSHIP_RISK_LIMIT_AMOUNT = 1584, "RelationshipRiskLimitAmount";
+    RELATIONSHIP_RISK_LIMIT_CURRENCY = 1585, "RelationshipRiskLimitCurrency";
+    RELATIONSHIP_RISK_LIMIT_PLATFORM = 1586, "RelationshipRiskLimitPlatform";
+    NO_RELATIONSHIP_RISK_INSTRUMENTS = 1587, "NoRelationshipRiskInstruments";
+    RELATIONSHIP_RISK_INSTRUMENT_OPERATOR = 1588, "RelationshipRiskInstrumentOperator";
+    RELATIONSHIP_RISK_SYMBOL = 1589, "RelationshipRiskSymbol";
+    RELATIONSHIP_RISK_SYMBOL_SFX = 1590, "RelationshipRiskSymbolSfx";
+    RELATIONSHIP_RISK_SECURITY_ID = 1591, "RelationshipRiskSecurityID";
+    RELATIONSHIP_RISK_SECURITY_ID_SOURCE = 1592, "RelationshipRiskSecurityIDSource";
+    NO_RELATIONSHIP_RISK_SECURITY_ALT_ID = 1593, "NoRelationshipRiskSecurityAltID";
+    RELATIONSHIP_RISK_SECURITY_ALT_ID = 1594, "RelationshipRiskSecurityAltID";
+    RELATIONSHIP_RISK_SECURITY_ALT_ID_SOURCE = 1595, "RelationshipRiskSecurityAltIDSource";
+    RELATIONSHIP_RISK_PRODUCT = 1596, "RelationshipRiskProduct";
+    RELATIONSHIP_RISK_PRODUCT_COMPLEX = 1597, "RelationshipRiskProductComplex";
+    RELATIONSHIP_RISK_SECURITY_GROUP = 1598, "RelationshipRiskSecurityGroup";
+    RELATIONSHIP_RISK_CFI_CODE = 1599, "RelationshipRiskCFICode";
+    RELATIONSHIP_RISK_SECURITY_TYPE = 1600, "RelationshipRiskSecurityType";
+    RELATIONSHIP_RISK_SECURITY_SUB_TYPE = 1601, "RelationshipRiskSecuritySubType";
+    RELATIONSHIP_RISK_MATURITY_MONTH_YEAR = 1602, "RelationshipRiskMaturityMonthYear";
+    RELATIONSHIP_RISK_MATURITY_TIME = 1603, "RelationshipRiskMaturityTime";
+    RELATIONSHIP_RISK_RESTRUCTURING_TYPE = 1604, "RelationshipRiskRestructuringType";
+    RELATIONSHIP_RISK_SENIORITY = 1605, "RelationshipRiskSeniority";
+    RELATIONSHIP_RISK_PUT_OR_CALL = 1606, "RelationshipRiskPutOrCall";
+    RELATIONSHIP_RISK_FLEXIBLE_INDICATOR = 1607, "RelationshipRiskFlexibleIndicator";
+    RELATIONSHIP_RISK_COUPON_RATE = 1608, "RelationshipRiskCouponRate";
+    RELATIONSHIP_RISK_SECURITY_EXCHANGE = 1609, "RelationshipRiskSecurityExchange";
+    RELATIONSHIP_RISK_SECURITY_DESC = 1610, "RelationshipRiskSecurityDesc";
+    RELATIONSHIP_RISK_INSTRUMENT_SETTL_TYPE = 1611, "RelationshipRiskInstrumentSettlType";
+    RELATIONSHIP_RISK_INSTRUMENT_MULTIPLIER = 1612, "RelationshipRiskInstrumentMultiplier";
+    NO_RELATIONSHIP_RISK_WARNING_LEVELS = 1613, "NoRelationshipRiskWarningLevels";
+    RELATIONSHIP_RISK_WARNING_LEVEL_PERCENT = 1614, "RelationshipRiskWarningLevelPercent";
+    RELATIONSHIP_RISK_WARNING_LEVEL_NAME = 1615, "RelationshipRiskWarningLevelName";
+    RISK_SECURITY_EXCHANGE = 1616, "RiskSecurityExchange";
+    STREAM_ASGN_TYPE = 1617, "StreamAsgnType";
+    RELATIONSHIP_RISK_ENCODED_SECURITY_DESC_LEN = 1618, "RelationshipRiskEncodedSecurityDescLen";
+    RELATIONSHIP_RISK_ENCODED_SECURITY_DESC = 1619, "RelationshipRiskEncodedSecurityDesc";
+    RISK_ENCODED_SECURITY_DESC_LEN = 1620, "RiskEncodedSecurityDescLen";
+    RISK_ENCODED_SECURITY_DESC = 1621, "RiskEncodedSecurityDesc";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_names() {
+        assert_eq!(Some("MsgSeqNum"), get_tag_name(MSG_SEQ_NUM));
+        assert_eq!(Some("SenderCompID"), get_tag_name(Tag(49)));
+        assert_eq!(Some("MDStreamID"), get_tag_name(Tag(1500)));
+        assert_eq!(Some("RiskEncodedSecurityDescLen"), get_tag_name(Tag(1620)));
+        assert_eq!(None, get_tag_name(Tag(5000)));
+    }
+
+    #[test]
+    fn test_tag_by_name() {
+        assert_eq!(Some(Tag(453)), get_tag_by_name("NoPartyIDs"));
+        assert_eq!(
+            Some(DEFAULT_APPL_VER_ID),
+            get_tag_by_name("DefaultApplVerID")
+        );
+        assert_eq!(None, get_tag_by_name("Unknown"));
+    }
+}
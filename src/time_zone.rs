@@ -0,0 +1,650 @@
+use std::{
+    io,
+    path::{Component, Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{fix_time, LocalMktDate};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+const ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+
+/// Time zone of a [`crate::SessionSchedule`]: UTC or a zone of the IANA time zone
+/// database read from a TZif file (RFC 8536).
+///
+/// Times after the last transition of the file follow the POSIX TZ rule of its footer, so
+/// both "fat" and "slim" files are supported.
+#[derive(Debug, Clone)]
+pub struct TimeZone {
+    name: String,
+    initial_offset: i32,
+    transitions: Vec<Transition>,
+    rule: Option<PosixRule>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Transition {
+    time: i64,
+    offset: i32,
+}
+
+impl TimeZone {
+    pub fn utc() -> Self {
+        Self {
+            name: "UTC".to_string(),
+            initial_offset: 0,
+            transitions: Vec::new(),
+            rule: None,
+        }
+    }
+
+    /// Loads the zone by its name, e.g. `America/New_York`, from `$TZDIR` or
+    /// `/usr/share/zoneinfo`. `UTC` does not need the database.
+    pub fn load(name: &str) -> io::Result<Self> {
+        if name == "UTC" {
+            return Ok(Self::utc());
+        }
+
+        let path = Path::new(name);
+        if !path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Invalid time zone name",
+            ));
+        }
+
+        let dir = std::env::var_os("TZDIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(ZONEINFO_DIR));
+
+        let data = std::fs::read(dir.join(path))?;
+        Self::from_tzif(name, &data)
+    }
+
+    pub fn from_tzif(name: impl Into<String>, data: &[u8]) -> io::Result<Self> {
+        let mut reader = TzifReader { data, offset: 0 };
+
+        // Version 2+ files repeat the data with 64-bit times after the version 1 block
+        let mut header = reader.read_header()?;
+        let mut time_size = 4;
+
+        if header.version >= b'2' {
+            reader.skip(header.get_data_len(time_size))?;
+            header = reader.read_header()?;
+            time_size = 8;
+        }
+
+        let mut times = Vec::new();
+        for _ in 0..header.time_count {
+            times.push(reader.read_time(time_size)?);
+        }
+
+        let indexes = reader.take(header.time_count)?;
+
+        let mut offsets = Vec::new();
+        for _ in 0..header.type_count {
+            offsets.push(reader.read_time(4)? as i32);
+            // isdst and abbreviation index
+            reader.skip(2)?;
+        }
+
+        reader.skip(
+            header.char_count
+                + header.leap_count * (time_size + 4)
+                + header.std_count
+                + header.ut_count,
+        )?;
+
+        let transitions = times
+            .into_iter()
+            .zip(indexes)
+            .map(|(time, index)| {
+                let offset = offsets
+                    .get(*index as usize)
+                    .copied()
+                    .ok_or_else(|| invalid_data("Invalid local time type"))?;
+
+                Ok(Transition { time, offset })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let rule = match reader.read_footer(time_size)? {
+            Some(footer) => {
+                Some(PosixRule::parse(footer).ok_or_else(|| invalid_data("Invalid TZ rule"))?)
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            name: name.into(),
+            initial_offset: offsets.first().copied().unwrap_or(0),
+            transitions,
+            rule,
+        })
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Seconds east of UTC at the time
+    pub fn get_offset(&self, time: SystemTime) -> i32 {
+        self.offset_at(unix_seconds(time))
+    }
+
+    pub(crate) fn offset_at(&self, utc: i64) -> i32 {
+        let index = self
+            .transitions
+            .partition_point(|transition| transition.time <= utc);
+
+        if index == self.transitions.len() {
+            if let Some(rule) = &self.rule {
+                return rule.offset_at(utc);
+            }
+        }
+
+        match index {
+            0 => self.initial_offset,
+            index => self.transitions[index - 1].offset,
+        }
+    }
+
+    pub(crate) fn to_local(&self, utc: i64) -> i64 {
+        utc + self.offset_at(utc) as i64
+    }
+
+    // Local times skipped by a transition are shifted by it, repeated ones resolve to one of
+    // the two instants
+    pub(crate) fn to_utc(&self, local: i64) -> i64 {
+        let guess = local - self.offset_at(local) as i64;
+        local - self.offset_at(guess) as i64
+    }
+}
+
+pub(crate) fn unix_seconds(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(err) => {
+            let duration = err.duration();
+            let seconds = -(duration.as_secs() as i64);
+
+            match duration.subsec_nanos() {
+                0 => seconds,
+                _ => seconds - 1,
+            }
+        }
+    }
+}
+
+pub(crate) fn from_unix_seconds(seconds: i64) -> SystemTime {
+    if seconds >= 0 {
+        UNIX_EPOCH + Duration::from_secs(seconds as u64)
+    } else {
+        UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs())
+    }
+}
+
+fn invalid_data(reason: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, reason)
+}
+
+struct TzifHeader {
+    version: u8,
+    ut_count: usize,
+    std_count: usize,
+    leap_count: usize,
+    time_count: usize,
+    type_count: usize,
+    char_count: usize,
+}
+
+impl TzifHeader {
+    fn get_data_len(&self, time_size: usize) -> usize {
+        self.time_count * (time_size + 1)
+            + self.type_count * 6
+            + self.char_count
+            + self.leap_count * (time_size + 4)
+            + self.std_count
+            + self.ut_count
+    }
+}
+
+struct TzifReader<'d> {
+    data: &'d [u8],
+    offset: usize,
+}
+
+impl<'d> TzifReader<'d> {
+    fn take(&mut self, len: usize) -> io::Result<&'d [u8]> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| invalid_data("TZif data is truncated"))?;
+
+        let result = &self.data[self.offset..end];
+        self.offset = end;
+        Ok(result)
+    }
+
+    fn skip(&mut self, len: usize) -> io::Result<()> {
+        self.take(len).map(|_| ())
+    }
+
+    fn read_header(&mut self) -> io::Result<TzifHeader> {
+        if self.take(4)? != b"TZif" {
+            return Err(invalid_data("Not a TZif file"));
+        }
+
+        let version = self.take(1)?[0];
+        self.skip(15)?;
+
+        let mut counts = [0usize; 6];
+        for count in counts.iter_mut() {
+            *count = self.read_time(4)? as u32 as usize;
+        }
+
+        let [ut_count, std_count, leap_count, time_count, type_count, char_count] = counts;
+
+        Ok(TzifHeader {
+            version,
+            ut_count,
+            std_count,
+            leap_count,
+            time_count,
+            type_count,
+            char_count,
+        })
+    }
+
+    // Big-endian signed value of 4 or 8 bytes
+    fn read_time(&mut self, size: usize) -> io::Result<i64> {
+        let bytes = self.take(size)?;
+
+        Ok(match size {
+            4 => i32::from_be_bytes(bytes.try_into().unwrap()) as i64,
+            _ => i64::from_be_bytes(bytes.try_into().unwrap()),
+        })
+    }
+
+    // POSIX TZ string between two newlines, only version 2+ files have it
+    fn read_footer(&mut self, time_size: usize) -> io::Result<Option<&'d str>> {
+        if time_size == 4 || self.offset == self.data.len() {
+            return Ok(None);
+        }
+
+        let rest = &self.data[self.offset..];
+        let footer = rest
+            .strip_prefix(b"\n")
+            .and_then(|rest| rest.split(|b| *b == b'\n').next())
+            .and_then(|footer| std::str::from_utf8(footer).ok())
+            .ok_or_else(|| invalid_data("Invalid TZif footer"))?;
+
+        Ok(Some(footer).filter(|footer| !footer.is_empty()))
+    }
+}
+
+/// Rule of a POSIX TZ string such as `EST5EDT,M3.2.0,M11.1.0`
+#[derive(Debug, Clone)]
+struct PosixRule {
+    std_offset: i32,
+    dst: Option<DstRule>,
+}
+
+#[derive(Debug, Clone)]
+struct DstRule {
+    offset: i32,
+    start: RuleDate,
+    start_time: i32,
+    end: RuleDate,
+    end_time: i32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum RuleDate {
+    /// `Jn`: day of year 1..=365, February 29 is never counted
+    Julian(u16),
+    /// `n`: day of year 0..=365
+    DayOfYear(u16),
+    /// `Mm.w.d`: weekday `d` (0 is Sunday) of week `w` (5 is the last one) of month `m`
+    Month { month: u8, week: u8, weekday: u8 },
+}
+
+impl PosixRule {
+    const DEFAULT_TRANSITION_TIME: i32 = 2 * 3600;
+
+    fn parse(src: &str) -> Option<Self> {
+        let mut cursor = PosixCursor {
+            src: src.as_bytes(),
+            offset: 0,
+        };
+
+        cursor.skip_name()?;
+        // POSIX offsets are positive west of Greenwich
+        let std_offset = -cursor.read_time()?;
+
+        if cursor.is_end() {
+            return Some(Self {
+                std_offset,
+                dst: None,
+            });
+        }
+
+        cursor.skip_name()?;
+
+        let offset = match cursor.peek() {
+            None | Some(b',') => std_offset + 3600,
+            _ => -cursor.read_time()?,
+        };
+
+        // Without dates the US rules of 2007 are used, same as glibc
+        let dst = if cursor.is_end() {
+            DstRule {
+                offset,
+                start: RuleDate::Month {
+                    month: 3,
+                    week: 2,
+                    weekday: 0,
+                },
+                start_time: Self::DEFAULT_TRANSITION_TIME,
+                end: RuleDate::Month {
+                    month: 11,
+                    week: 1,
+                    weekday: 0,
+                },
+                end_time: Self::DEFAULT_TRANSITION_TIME,
+            }
+        } else {
+            cursor.expect(b',')?;
+            let start = cursor.read_date()?;
+            let start_time = cursor.read_transition_time()?;
+            cursor.expect(b',')?;
+            let end = cursor.read_date()?;
+            let end_time = cursor.read_transition_time()?;
+
+            DstRule {
+                offset,
+                start,
+                start_time,
+                end,
+                end_time,
+            }
+        };
+
+        if !cursor.is_end() {
+            return None;
+        }
+
+        Some(Self {
+            std_offset,
+            dst: Some(dst),
+        })
+    }
+
+    fn offset_at(&self, utc: i64) -> i32 {
+        let Some(dst) = &self.dst else {
+            return self.std_offset;
+        };
+
+        let local = utc + self.std_offset as i64;
+        let Some(date) = LocalMktDate::from_days_since_epoch(local.div_euclid(SECONDS_PER_DAY))
+        else {
+            return self.std_offset;
+        };
+
+        let year = date.get_year();
+        let start = dst.start.get_day(year) * SECONDS_PER_DAY + dst.start_time as i64
+            - self.std_offset as i64;
+        let end = dst.end.get_day(year) * SECONDS_PER_DAY + dst.end_time as i64 - dst.offset as i64;
+
+        // Southern hemisphere zones start DST at the end of the year
+        let in_dst = if start < end {
+            (start..end).contains(&utc)
+        } else {
+            !(end..start).contains(&utc)
+        };
+
+        if in_dst {
+            dst.offset
+        } else {
+            self.std_offset
+        }
+    }
+}
+
+impl RuleDate {
+    // Days since the epoch
+    fn get_day(&self, year: u16) -> i64 {
+        let first_day = |month| {
+            LocalMktDate::new(year, month, 1)
+                .map(|date| date.days_since_epoch())
+                .unwrap_or_default()
+        };
+
+        match *self {
+            Self::Julian(day) => {
+                let leap_day = fix_time::is_leap_year(year) && day >= 60;
+                first_day(1) + day as i64 - 1 + leap_day as i64
+            }
+            Self::DayOfYear(day) => first_day(1) + day as i64,
+            Self::Month {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = first_day(month);
+                // 1970-01-01 is Thursday
+                let first_weekday = (first + 4).rem_euclid(7);
+
+                let mut day =
+                    (weekday as i64 - first_weekday).rem_euclid(7) + (week as i64 - 1) * 7;
+                if day >= fix_time::days_in_month(year, month) as i64 {
+                    day -= 7;
+                }
+
+                first + day
+            }
+        }
+    }
+}
+
+struct PosixCursor<'s> {
+    src: &'s [u8],
+    offset: usize,
+}
+
+impl PosixCursor<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.src.get(self.offset).copied()
+    }
+
+    fn is_end(&self) -> bool {
+        self.offset == self.src.len()
+    }
+
+    fn expect(&mut self, expected: u8) -> Option<()> {
+        if self.peek()? != expected {
+            return None;
+        }
+
+        self.offset += 1;
+        Some(())
+    }
+
+    // `EST` or quoted `<+0330>`
+    fn skip_name(&mut self) -> Option<()> {
+        if self.peek()? == b'<' {
+            let len = self.src[self.offset..].iter().position(|b| *b == b'>')?;
+            self.offset += len + 1;
+            return Some(());
+        }
+
+        let start = self.offset;
+        while self.peek().is_some_and(|b| b.is_ascii_alphabetic()) {
+            self.offset += 1;
+        }
+
+        (self.offset - start >= 3).then_some(())
+    }
+
+    fn read_number(&mut self) -> Option<i32> {
+        let start = self.offset;
+        while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.offset += 1;
+        }
+
+        std::str::from_utf8(&self.src[start..self.offset])
+            .ok()?
+            .parse()
+            .ok()
+    }
+
+    // `[+-]hh[:mm[:ss]]` in seconds
+    fn read_time(&mut self) -> Option<i32> {
+        let sign = match self.peek()? {
+            b'-' => {
+                self.offset += 1;
+                -1
+            }
+            b'+' => {
+                self.offset += 1;
+                1
+            }
+            _ => 1,
+        };
+
+        let mut seconds = self.read_number()? * 3600;
+
+        for multiplier in [60, 1] {
+            if self.peek() != Some(b':') {
+                break;
+            }
+
+            self.offset += 1;
+            seconds += self.read_number()? * multiplier;
+        }
+
+        Some(sign * seconds)
+    }
+
+    fn read_transition_time(&mut self) -> Option<i32> {
+        if self.peek() != Some(b'/') {
+            return Some(PosixRule::DEFAULT_TRANSITION_TIME);
+        }
+
+        self.offset += 1;
+        self.read_time()
+    }
+
+    fn read_date(&mut self) -> Option<RuleDate> {
+        match self.peek()? {
+            b'J' => {
+                self.offset += 1;
+                let day = self.read_number()?;
+                (1..=365)
+                    .contains(&day)
+                    .then_some(RuleDate::Julian(day as u16))
+            }
+            b'M' => {
+                self.offset += 1;
+                let month = self.read_number()?;
+                self.expect(b'.')?;
+                let week = self.read_number()?;
+                self.expect(b'.')?;
+                let weekday = self.read_number()?;
+
+                let valid = (1..=12).contains(&month)
+                    && (1..=5).contains(&week)
+                    && (0..=6).contains(&weekday);
+
+                valid.then_some(RuleDate::Month {
+                    month: month as u8,
+                    week: week as u8,
+                    weekday: weekday as u8,
+                })
+            }
+            _ => {
+                let day = self.read_number()?;
+                (0..=365)
+                    .contains(&day)
+                    .then_some(RuleDate::DayOfYear(day as u16))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use super::PosixRule;
+    use crate::{TimeZone, UtcTimestamp};
+
+    const NEW_YORK: &[u8] = include_bytes!("../test_data/zoneinfo/America/New_York");
+
+    fn at(src: &str) -> SystemTime {
+        UtcTimestamp::parse(src).unwrap().to_system_time()
+    }
+
+    #[test]
+    fn test_utc() {
+        let utc = TimeZone::load("UTC").unwrap();
+        assert_eq!("UTC", utc.get_name());
+        assert_eq!(0, utc.get_offset(at("20240701-12:00:00")));
+    }
+
+    #[test]
+    fn test_tzif_transitions_and_footer_rule() {
+        let new_york = TimeZone::from_tzif("America/New_York", NEW_YORK).unwrap();
+        assert_eq!("America/New_York", new_york.get_name());
+
+        assert_eq!(-5 * 3600, new_york.get_offset(at("20240115-12:00:00")));
+        assert_eq!(-4 * 3600, new_york.get_offset(at("20240701-12:00:00")));
+
+        // DST starts on 2024-03-10 at 02:00 EST
+        assert_eq!(-5 * 3600, new_york.get_offset(at("20240310-06:59:59")));
+        assert_eq!(-4 * 3600, new_york.get_offset(at("20240310-07:00:00")));
+
+        // After the last transition of the file
+        assert_eq!(-5 * 3600, new_york.get_offset(at("20600115-12:00:00")));
+        assert_eq!(-4 * 3600, new_york.get_offset(at("20600701-12:00:00")));
+        assert_eq!(-4 * 3600, new_york.get_offset(at("20601107-05:59:59")));
+        assert_eq!(-5 * 3600, new_york.get_offset(at("20601107-06:00:00")));
+    }
+
+    #[test]
+    fn test_local_time_conversion() {
+        let new_york = TimeZone::from_tzif("America/New_York", NEW_YORK).unwrap();
+
+        let utc = super::unix_seconds(at("20240701-21:00:00"));
+        let local = new_york.to_local(utc);
+        assert_eq!(utc - 4 * 3600, local);
+        assert_eq!(utc, new_york.to_utc(local));
+    }
+
+    #[test]
+    fn test_posix_rules() {
+        let sydney = PosixRule::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+        let utc = |src| super::unix_seconds(at(src));
+        assert_eq!(11 * 3600, sydney.offset_at(utc("20240115-00:00:00")));
+        assert_eq!(10 * 3600, sydney.offset_at(utc("20240715-00:00:00")));
+
+        let tehran = PosixRule::parse("<+0330>-3:30").unwrap();
+        assert_eq!(3 * 3600 + 1800, tehran.offset_at(utc("20240715-00:00:00")));
+
+        assert!(PosixRule::parse("EST5EDT,M13.1.0,M11.1.0").is_none());
+        assert!(PosixRule::parse("E5").is_none());
+    }
+
+    #[test]
+    fn test_invalid_zones() {
+        assert!(TimeZone::from_tzif("Broken", b"TZif2").is_err());
+        assert!(TimeZone::from_tzif("Broken", &NEW_YORK[..100]).is_err());
+        assert!(TimeZone::from_tzif("Broken", b"not a tzif file at all").is_err());
+        assert!(TimeZone::load("../etc/passwd").is_err());
+        assert!(TimeZone::load("/etc/passwd").is_err());
+    }
+}
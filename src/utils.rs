@@ -53,7 +53,7 @@ pub fn bytes_to_fix_string(data: &[u8]) -> String {
         }
     }
 
-    return String::from_utf8(str).unwrap();
+    return String::from_utf8_lossy(&str).into_owned();
 }
 
 pub fn split_fix_to_tags(fix: &[u8]) -> HashMap<Vec<u8>, Vec<Vec<u8>>> {
@@ -97,7 +97,10 @@ pub fn convert_fix_message_to_string(mut src: Vec<u8>) -> String {
         }
     }
 
-    String::from_utf8(src).unwrap()
+    match String::from_utf8(src) {
+        Ok(result) => result,
+        Err(err) => String::from_utf8_lossy(err.as_bytes()).into_owned(),
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,256 @@
+use crate::DataDictionaryError;
+
+/// Element of an XML document. Only the parts used by the FIX dictionaries are kept:
+/// names, attributes and child elements. Text content is skipped.
+#[derive(Debug, Clone)]
+pub(crate) struct XmlElement {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<XmlElement>,
+}
+
+impl XmlElement {
+    pub fn parse(src: &str) -> Result<Self, DataDictionaryError> {
+        let mut parser = XmlParser {
+            src: src.as_bytes(),
+            position: 0,
+        };
+
+        parser.skip_misc()?;
+        let result = parser.read_element()?;
+        parser.skip_misc()?;
+
+        if parser.position < parser.src.len() {
+            return Err(parser.error("Unexpected content after the root element"));
+        }
+
+        Ok(result)
+    }
+
+    pub fn get_attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attr_name, _)| attr_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn get_child(&self, name: &str) -> Option<&XmlElement> {
+        self.children.iter().find(|itm| itm.name == name)
+    }
+}
+
+struct XmlParser<'s> {
+    src: &'s [u8],
+    position: usize,
+}
+
+impl<'s> XmlParser<'s> {
+    fn error(&self, reason: &'static str) -> DataDictionaryError {
+        DataDictionaryError::InvalidXml {
+            offset: self.position,
+            reason,
+        }
+    }
+
+    fn starts_with(&self, prefix: &[u8]) -> bool {
+        self.src[self.position..].starts_with(prefix)
+    }
+
+    fn skip_whitespaces(&mut self) {
+        while self.position < self.src.len() && self.src[self.position].is_ascii_whitespace() {
+            self.position += 1;
+        }
+    }
+
+    fn skip_until(&mut self, end: &[u8]) -> Result<(), DataDictionaryError> {
+        match self.src[self.position..]
+            .windows(end.len())
+            .position(|window| window == end)
+        {
+            Some(index) => {
+                self.position += index + end.len();
+                Ok(())
+            }
+            None => Err(self.error("Unexpected end of document")),
+        }
+    }
+
+    // Whitespaces, comments, processing instructions and DOCTYPE
+    fn skip_misc(&mut self) -> Result<(), DataDictionaryError> {
+        loop {
+            self.skip_whitespaces();
+
+            if self.starts_with(b"<!--") {
+                self.skip_until(b"-->")?;
+            } else if self.starts_with(b"<?") {
+                self.skip_until(b"?>")?;
+            } else if self.starts_with(b"<!DOCTYPE") {
+                self.skip_until(b">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn read_name(&mut self) -> Result<String, DataDictionaryError> {
+        let start = self.position;
+
+        while self.position < self.src.len() {
+            let b = self.src[self.position];
+            if !(b.is_ascii_alphanumeric() || b == b'_' || b == b'-' || b == b':' || b == b'.') {
+                break;
+            }
+            self.position += 1;
+        }
+
+        if start == self.position {
+            return Err(self.error("Name expected"));
+        }
+
+        Ok(String::from_utf8_lossy(&self.src[start..self.position]).into_owned())
+    }
+
+    fn expect(&mut self, b: u8, reason: &'static str) -> Result<(), DataDictionaryError> {
+        if self.src.get(self.position) != Some(&b) {
+            return Err(self.error(reason));
+        }
+
+        self.position += 1;
+        Ok(())
+    }
+
+    fn read_element(&mut self) -> Result<XmlElement, DataDictionaryError> {
+        self.expect(b'<', "Element expected")?;
+
+        let mut result = XmlElement {
+            name: self.read_name()?,
+            attributes: Vec::new(),
+            children: Vec::new(),
+        };
+
+        loop {
+            self.skip_whitespaces();
+
+            if self.starts_with(b"/>") {
+                self.position += 2;
+                return Ok(result);
+            }
+
+            if self.starts_with(b">") {
+                self.position += 1;
+                break;
+            }
+
+            let name = self.read_name()?;
+            self.skip_whitespaces();
+            self.expect(b'=', "'=' expected after the attribute name")?;
+            self.skip_whitespaces();
+            let value = self.read_attribute_value()?;
+
+            result.attributes.push((name, value));
+        }
+
+        loop {
+            // Text content is not used by the dictionaries
+            match self.src[self.position..].iter().position(|b| *b == b'<') {
+                Some(index) => self.position += index,
+                None => return Err(self.error("Closing tag expected")),
+            }
+
+            if self.starts_with(b"</") {
+                self.position += 2;
+                let name = self.read_name()?;
+
+                if name != result.name {
+                    return Err(self.error("Closing tag does not match the opening one"));
+                }
+
+                self.skip_whitespaces();
+                self.expect(b'>', "'>' expected")?;
+                return Ok(result);
+            }
+
+            if self.starts_with(b"<!--") {
+                self.skip_until(b"-->")?;
+            } else if self.starts_with(b"<![CDATA[") {
+                self.skip_until(b"]]>")?;
+            } else if self.starts_with(b"<?") {
+                self.skip_until(b"?>")?;
+            } else {
+                result.children.push(self.read_element()?);
+            }
+        }
+    }
+
+    fn read_attribute_value(&mut self) -> Result<String, DataDictionaryError> {
+        let quote = match self.src.get(self.position) {
+            Some(b'"') => b'"',
+            Some(b'\'') => b'\'',
+            _ => return Err(self.error("Quoted attribute value expected")),
+        };
+
+        self.position += 1;
+        let start = self.position;
+
+        let Some(len) = self.src[start..].iter().position(|b| *b == quote) else {
+            return Err(self.error("Attribute value is not closed"));
+        };
+
+        self.position = start + len + 1;
+
+        let value = String::from_utf8_lossy(&self.src[start..start + len]);
+        Ok(decode_entities(&value))
+    }
+}
+
+fn decode_entities(src: &str) -> String {
+    if !src.contains('&') {
+        return src.to_string();
+    }
+
+    src.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XmlElement;
+
+    #[test]
+    fn test_parse() {
+        let src = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <!-- comment -->
+        <fix major='4' minor="4">
+            <fields>
+                <field number="1" name="Account" type="STRING"/>
+                <field number="4" name="AdvSide" type="CHAR">
+                    <value enum="B" description="BUY &amp; HOLD"/>
+                </field>
+            </fields>
+            text is skipped
+        </fix>"#;
+
+        let root = XmlElement::parse(src).unwrap();
+
+        assert_eq!("fix", root.name);
+        assert_eq!(Some("4"), root.get_attribute("major"));
+
+        let fields = root.get_child("fields").unwrap();
+        assert_eq!(2, fields.children.len());
+        assert_eq!(
+            Some("BUY & HOLD"),
+            fields.children[1].children[0].get_attribute("description")
+        );
+    }
+
+    #[test]
+    fn test_invalid_xml() {
+        assert!(XmlElement::parse("<fix><fields></fix>").is_err());
+        assert!(XmlElement::parse("<fix major=4/>").is_err());
+        assert!(XmlElement::parse("<fix/><fix/>").is_err());
+        assert!(XmlElement::parse("<fix>").is_err());
+    }
+}